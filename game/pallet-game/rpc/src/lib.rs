@@ -0,0 +1,172 @@
+//! RPC interface for the game pallet.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use gafi_support::game::{ItemBalance, PoolInfo, TradeDetails, UpgradeLevel};
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_game_rpc_runtime_api::GameRuntimeApi;
+
+#[rpc(client, server)]
+pub trait GameApi<BlockHash, AccountId, CollectionId, ItemId, TradeId, PoolId, Balance, BlockNumber>
+{
+	/// Free and reserved balances of every item held by `who`.
+	#[method(name = "game_inventory")]
+	fn inventory(
+		&self,
+		who: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<ItemBalance<CollectionId, ItemId>>>;
+
+	/// Trades and auctions that have not ended yet and involve `item` in `collection`.
+	#[method(name = "game_activeTrades")]
+	fn active_trades(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<TradeDetails<TradeId, AccountId, Balance, CollectionId, ItemId, BlockNumber>>>;
+
+	/// Configuration and loot table of a minting pool.
+	#[method(name = "game_pool")]
+	fn pool_details(
+		&self,
+		pool: PoolId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<PoolInfo<AccountId, Balance, BlockNumber, CollectionId, ItemId>>>;
+
+	/// Upgrade levels of `item` in `collection`, ordered by level.
+	#[method(name = "game_upgradePath")]
+	fn upgrade_path(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<UpgradeLevel<CollectionId, ItemId, Balance>>>;
+}
+
+/// Provides RPC methods to query the game pallet.
+pub struct Game<C, Block> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block> Game<C, Block> {
+	/// Creates a new instance of the Game RPC helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+fn runtime_error(err: impl std::fmt::Debug) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(
+		Error::RuntimeError.into(),
+		"Unable to query game state.",
+		Some(format!("{:?}", err)),
+	))
+	.into()
+}
+
+#[async_trait]
+impl<C, Block, AccountId, CollectionId, ItemId, TradeId, PoolId, Balance, BlockNumber>
+	GameApiServer<
+		<Block as BlockT>::Hash,
+		AccountId,
+		CollectionId,
+		ItemId,
+		TradeId,
+		PoolId,
+		Balance,
+		BlockNumber,
+	> for Game<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: GameRuntimeApi<
+		Block,
+		AccountId,
+		CollectionId,
+		ItemId,
+		TradeId,
+		PoolId,
+		Balance,
+		BlockNumber,
+	>,
+	AccountId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	CollectionId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	ItemId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	TradeId: Codec + Serialize + Send + Sync + 'static,
+	PoolId: Codec + DeserializeOwned + Send + Sync + 'static,
+	Balance: Codec + Serialize + Send + Sync + 'static,
+	BlockNumber: Codec + Serialize + Send + Sync + 'static,
+{
+	fn inventory(
+		&self,
+		who: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<ItemBalance<CollectionId, ItemId>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.inventory(at, who).map_err(runtime_error)
+	}
+
+	fn active_trades(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<TradeDetails<TradeId, AccountId, Balance, CollectionId, ItemId, BlockNumber>>>
+	{
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.active_trades(at, collection, item).map_err(runtime_error)
+	}
+
+	fn pool_details(
+		&self,
+		pool: PoolId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<PoolInfo<AccountId, Balance, BlockNumber, CollectionId, ItemId>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.pool_details(at, pool).map_err(runtime_error)
+	}
+
+	fn upgrade_path(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<UpgradeLevel<CollectionId, ItemId, Balance>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.upgrade_path(at, collection, item).map_err(runtime_error)
+	}
+}
@@ -0,0 +1,42 @@
+//! Runtime API definition for the game pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use gafi_support::game::{ItemBalance, PoolInfo, TradeDetails, UpgradeLevel};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// Version 2 returns the material bundle of every level from `upgrade_path`.
+	#[api_version(2)]
+	pub trait GameRuntimeApi<AccountId, CollectionId, ItemId, TradeId, PoolId, Balance, BlockNumber>
+	where
+		AccountId: Codec,
+		CollectionId: Codec,
+		ItemId: Codec,
+		TradeId: Codec,
+		PoolId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		/// Free and reserved balances of every item held by `who`.
+		fn inventory(who: AccountId) -> Vec<ItemBalance<CollectionId, ItemId>>;
+
+		/// Trades and auctions that have not ended yet and involve `item` in `collection`.
+		fn active_trades(
+			collection: CollectionId,
+			item: ItemId,
+		) -> Vec<TradeDetails<TradeId, AccountId, Balance, CollectionId, ItemId, BlockNumber>>;
+
+		/// Configuration and loot table of a minting pool.
+		fn pool_details(
+			pool: PoolId,
+		) -> Option<PoolInfo<AccountId, Balance, BlockNumber, CollectionId, ItemId>>;
+
+		/// Upgrade levels of `item` in `collection`, ordered by level.
+		fn upgrade_path(
+			collection: CollectionId,
+			item: ItemId,
+		) -> Vec<UpgradeLevel<CollectionId, ItemId, Balance>>;
+	}
+}
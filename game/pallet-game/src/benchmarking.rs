@@ -7,10 +7,14 @@ use crate::{pallet::BenchmarkHelper as GameBenchmarkHelper, Call, Config};
 use frame_benchmarking::{account, benchmarks_instance_pallet, Box};
 use frame_support::{assert_ok, dispatch::UnfilteredDispatchable, traits::Currency};
 use frame_system::RawOrigin;
-use gafi_support::game::{Loot, MintSettings, MintType, NFT};
-use pallet_nfts::BenchmarkHelper;
+use gafi_support::game::{
+	GameRole, Loot, MatchResult, MintSettings, MintType, MintWindow, SalvageOutput, UpgradeFailure,
+	NFT,
+};
+use pallet_nfts::{BenchmarkHelper, CollectionRole, CollectionRoles};
 use scale_info::prelude::{format, string::String};
 use sp_core::Get;
+use sp_runtime::traits::Hash;
 use sp_std::vec;
 
 const UNIT: u128 = 1_000_000_000_000_000_000u128;
@@ -47,6 +51,13 @@ fn default_mint_config<T: Config<I>, I: 'static>() -> MintSettingsFor<T, I> {
 		price: <T as pallet::Config<I>>::Currency::minimum_balance(),
 		start_block: None,
 		end_block: None,
+		max_per_account: Some(1000),
+		max_per_window: Some(MintWindow {
+			length: <T as pallet::Config<I>>::Helper::block(10),
+			max_mints: 100,
+		}),
+		cooldown: Some(<T as pallet::Config<I>>::Helper::block(1)),
+		pity: None,
 	}
 }
 
@@ -145,6 +156,7 @@ fn do_create_dynamic_pool<T: Config<I>, I: 'static>() -> (T::AccountId, T::Accou
 				item: <T as pallet_nfts::Config>::Helper::item(0),
 			}),
 			weight: 10,
+			tier: None,
 		},
 		Loot {
 			maybe_nft: Some(NFT {
@@ -152,6 +164,7 @@ fn do_create_dynamic_pool<T: Config<I>, I: 'static>() -> (T::AccountId, T::Accou
 				item: <T as pallet_nfts::Config>::Helper::item(1),
 			}),
 			weight: 10,
+			tier: None,
 		},
 		Loot {
 			maybe_nft: Some(NFT {
@@ -159,6 +172,7 @@ fn do_create_dynamic_pool<T: Config<I>, I: 'static>() -> (T::AccountId, T::Accou
 				item: <T as pallet_nfts::Config>::Helper::item(2),
 			}),
 			weight: 10,
+			tier: None,
 		},
 	];
 
@@ -166,7 +180,8 @@ fn do_create_dynamic_pool<T: Config<I>, I: 'static>() -> (T::AccountId, T::Accou
 		RawOrigin::Signed(who.clone()).into(),
 		table.clone(),
 		T::Lookup::unlookup(who.clone()),
-		default_mint_config::<T, I>()
+		default_mint_config::<T, I>(),
+		None
 	));
 
 	(who.clone(), who)
@@ -197,6 +212,7 @@ fn do_create_stable_pool<T: Config<I>, I: 'static>() -> (T::AccountId, T::Accoun
 				item: <T as pallet_nfts::Config>::Helper::item(0),
 			}),
 			weight: 10,
+			tier: None,
 		},
 		Loot {
 			maybe_nft: Some(NFT {
@@ -204,6 +220,7 @@ fn do_create_stable_pool<T: Config<I>, I: 'static>() -> (T::AccountId, T::Accoun
 				item: <T as pallet_nfts::Config>::Helper::item(1),
 			}),
 			weight: 10,
+			tier: None,
 		},
 		Loot {
 			maybe_nft: Some(NFT {
@@ -211,6 +228,7 @@ fn do_create_stable_pool<T: Config<I>, I: 'static>() -> (T::AccountId, T::Accoun
 				item: <T as pallet_nfts::Config>::Helper::item(2),
 			}),
 			weight: 10,
+			tier: None,
 		},
 	];
 
@@ -218,13 +236,24 @@ fn do_create_stable_pool<T: Config<I>, I: 'static>() -> (T::AccountId, T::Accoun
 		RawOrigin::Signed(owner.clone()).into(),
 		table.clone(),
 		T::Lookup::unlookup(admin.clone()),
-		default_mint_config::<T, I>()
+		default_mint_config::<T, I>(),
+		None
 	));
 
 	(owner, admin)
 }
 
-fn do_set_upgrade_item<T: Config<I>, I: 'static>(who: &T::AccountId) {
+fn upgrade_materials<T: Config<I>, I: 'static>(m: u32) -> Bundle<T::CollectionId, T::ItemId> {
+	(0..m)
+		.map(|i| Package {
+			collection: <T as pallet_nfts::Config>::Helper::collection(0),
+			item: <T as pallet_nfts::Config>::Helper::item(1 + (i % 2) as u16),
+			amount: 1,
+		})
+		.collect()
+}
+
+fn do_set_upgrade_item<T: Config<I>, I: 'static>(who: &T::AccountId, m: u32) {
 	assert_ok!(PalletGame::<T, I>::set_upgrade_item(
 		RawOrigin::Signed(who.clone()).into(),
 		<T as pallet_nfts::Config>::Helper::collection(0),
@@ -233,7 +262,8 @@ fn do_set_upgrade_item<T: Config<I>, I: 'static>(who: &T::AccountId) {
 		default_item_config(),
 		bvec![0u8; 50],
 		1,
-		<T as pallet::Config<I>>::Currency::minimum_balance()
+		<T as pallet::Config<I>>::Currency::minimum_balance(),
+		upgrade_materials::<T, I>(m)
 	));
 }
 
@@ -249,6 +279,7 @@ fn do_set_price<T: Config<I>, I: 'static>(who: &T::AccountId) {
 		package,
 		<T as pallet::Config<I>>::Currency::minimum_balance(),
 		None,
+		None,
 		None
 	));
 }
@@ -272,6 +303,7 @@ fn do_set_bundle<T: Config<I>, I: 'static>(who: &T::AccountId) {
 		bundle.clone(),
 		<T as pallet::Config<I>>::Currency::minimum_balance(),
 		None,
+		None,
 		None
 	));
 }
@@ -295,6 +327,7 @@ fn do_set_wishlist<T: Config<I>, I: 'static>(who: &T::AccountId) {
 		bundle,
 		<T as pallet::Config<I>>::Currency::minimum_balance(),
 		None,
+		None,
 		None
 	));
 }
@@ -320,11 +353,186 @@ fn do_set_auction<T: Config<I>, I: 'static>() -> T::AccountId {
 		source,
 		Some(<T as pallet::Config<I>>::Currency::minimum_balance()),
 		Some(<T as pallet::Config<I>>::Helper::block(0)),
-		<T as pallet::Config<I>>::Helper::block(10)
+		<T as pallet::Config<I>>::Helper::block(10),
+		None
+	));
+	who
+}
+
+fn do_set_candle_auction<T: Config<I>, I: 'static>() -> T::AccountId {
+	let (who, _, _) = new_account_with_item::<T, I>(0);
+
+	let source = vec![
+		Package {
+			collection: <T as pallet_nfts::Config>::Helper::collection(0),
+			item: <T as pallet_nfts::Config>::Helper::item(0),
+			amount: 10,
+		},
+		Package {
+			collection: <T as pallet_nfts::Config>::Helper::collection(0),
+			item: <T as pallet_nfts::Config>::Helper::item(1),
+			amount: 10,
+		},
+	];
+
+	assert_ok!(PalletGame::<T, I>::set_candle_auction(
+		RawOrigin::Signed(who.clone()).into(),
+		source,
+		Some(<T as pallet::Config<I>>::Currency::minimum_balance()),
+		<T as pallet::Config<I>>::Helper::block(0),
+		<T as pallet::Config<I>>::Helper::block(5),
+		<T as pallet::Config<I>>::Helper::block(10),
+		None
+	));
+	who
+}
+
+fn do_bid_candle_auction<T: Config<I>, I: 'static>(b: u32) {
+	for i in 0..b {
+		let bidder = new_funded_account::<T, I>(i, 3, 1000_000_000u128 * UNIT);
+		assert_ok!(PalletGame::<T, I>::bid_candle_auction(
+			RawOrigin::Signed(bidder).into(),
+			<T as pallet::Config<I>>::Helper::trade(0),
+			<T as pallet::Config<I>>::Currency::minimum_balance() * (i + 1).into()
+		));
+	}
+}
+
+fn do_set_dutch_auction<T: Config<I>, I: 'static>() -> T::AccountId {
+	let (who, _, _) = new_account_with_item::<T, I>(0);
+
+	let source = vec![
+		Package {
+			collection: <T as pallet_nfts::Config>::Helper::collection(0),
+			item: <T as pallet_nfts::Config>::Helper::item(0),
+			amount: 10,
+		},
+		Package {
+			collection: <T as pallet_nfts::Config>::Helper::collection(0),
+			item: <T as pallet_nfts::Config>::Helper::item(1),
+			amount: 10,
+		},
+	];
+
+	assert_ok!(PalletGame::<T, I>::set_dutch_auction(
+		RawOrigin::Signed(who.clone()).into(),
+		source,
+		<T as pallet::Config<I>>::Currency::minimum_balance() * 10u32.into(),
+		<T as pallet::Config<I>>::Currency::minimum_balance(),
+		Some(<T as pallet::Config<I>>::Helper::block(0)),
+		<T as pallet::Config<I>>::Helper::block(10),
+		None
 	));
 	who
 }
 
+fn do_set_rental<T: Config<I>, I: 'static>() -> T::AccountId {
+	let (who, _, _) = new_account_with_item::<T, I>(0);
+
+	let source = vec![
+		Package {
+			collection: <T as pallet_nfts::Config>::Helper::collection(0),
+			item: <T as pallet_nfts::Config>::Helper::item(0),
+			amount: 10,
+		},
+		Package {
+			collection: <T as pallet_nfts::Config>::Helper::collection(0),
+			item: <T as pallet_nfts::Config>::Helper::item(1),
+			amount: 10,
+		},
+	];
+
+	assert_ok!(PalletGame::<T, I>::set_rental(
+		RawOrigin::Signed(who.clone()).into(),
+		source,
+		<T as pallet::Config<I>>::Currency::minimum_balance(),
+		<T as pallet::Config<I>>::Helper::block(10),
+		None
+	));
+	who
+}
+
+fn do_set_sealed_auction<T: Config<I>, I: 'static>() -> T::AccountId {
+	let (who, _, _) = new_account_with_item::<T, I>(0);
+
+	let source = vec![
+		Package {
+			collection: <T as pallet_nfts::Config>::Helper::collection(0),
+			item: <T as pallet_nfts::Config>::Helper::item(0),
+			amount: 10,
+		},
+		Package {
+			collection: <T as pallet_nfts::Config>::Helper::collection(0),
+			item: <T as pallet_nfts::Config>::Helper::item(1),
+			amount: 10,
+		},
+	];
+
+	assert_ok!(PalletGame::<T, I>::set_sealed_auction(
+		RawOrigin::Signed(who.clone()).into(),
+		source,
+		Some(<T as pallet::Config<I>>::Currency::minimum_balance()),
+		Some(<T as pallet::Config<I>>::Helper::block(0)),
+		<T as pallet::Config<I>>::Helper::block(5),
+		<T as pallet::Config<I>>::Helper::block(10),
+		None
+	));
+	who
+}
+
+fn do_commit_sealed_bid<T: Config<I>, I: 'static>(b: u32) -> Vec<(T::AccountId, BalanceOf<T, I>)> {
+	let mut bids = Vec::new();
+	for i in 0..b {
+		let bidder = new_funded_account::<T, I>(i, 4, 1000_000_000u128 * UNIT);
+		let bid = <T as pallet::Config<I>>::Currency::minimum_balance() * (i + 1).into();
+		assert_ok!(PalletGame::<T, I>::commit_sealed_bid(
+			RawOrigin::Signed(bidder.clone()).into(),
+			<T as pallet::Config<I>>::Helper::trade(0),
+			T::Hashing::hash_of(&(&bidder, bid, [0u8; 32])),
+			bid
+		));
+		bids.push((bidder, bid));
+	}
+	bids
+}
+
+fn do_create_match<T: Config<I>, I: 'static>(
+	p: u32,
+) -> (T::AccountId, T::AccountId, Vec<T::AccountId>) {
+	let (owner, admin) = do_create_game_collection::<T, I>();
+	do_create_item::<T, I>(&admin, 0, 0, Some(1000));
+	assert_ok!(PalletGame::<T, I>::create_match(
+		RawOrigin::Signed(admin.clone()).into(),
+		<T as pallet::Config<I>>::Helper::game(0),
+		<T as pallet::Config<I>>::Currency::minimum_balance(),
+		None,
+		<T as pallet::Config<I>>::Helper::block(10)
+	));
+
+	let mut players = Vec::new();
+	for i in 0..p {
+		let player = new_funded_account::<T, I>(i, 7, 1000_000_000u128 * UNIT);
+		assert_ok!(PalletGame::<T, I>::transfer(
+			RawOrigin::Signed(owner.clone()).into(),
+			<T as pallet_nfts::Config>::Helper::collection(0),
+			<T as pallet_nfts::Config>::Helper::item(0),
+			T::Lookup::unlookup(player.clone()),
+			10
+		));
+		assert_ok!(PalletGame::<T, I>::join_match(
+			RawOrigin::Signed(player.clone()).into(),
+			<T as pallet::Config<I>>::Helper::match_id(0),
+			vec![Package {
+				collection: <T as pallet_nfts::Config>::Helper::collection(0),
+				item: <T as pallet_nfts::Config>::Helper::item(0),
+				amount: 1,
+			}]
+		));
+		players.push(player);
+	}
+	(owner, admin, players)
+}
+
 benchmarks_instance_pallet! {
 
 	create_game {
@@ -384,6 +592,7 @@ benchmarks_instance_pallet! {
 					item: <T as pallet_nfts::Config>::Helper::item(0),
 				}),
 				weight: 10,
+				tier: None,
 		}; length as usize];
 
 		let s = <T as pallet_nfts::Config>::StringLimit::get();
@@ -391,7 +600,8 @@ benchmarks_instance_pallet! {
 			loot_table: table.clone(),
 			mint_settings: default_mint_config::<T, I>(),
 			admin: T::Lookup::unlookup(who.clone()),
-			data: bvec![0u8; s as usize]
+			data: bvec![0u8; s as usize],
+			maybe_asset: None,
 		};
 	}:  { call.dispatch_bypass_filter(RawOrigin::Signed(who.clone()).into())? }
 	verify {
@@ -416,6 +626,7 @@ benchmarks_instance_pallet! {
 					item: <T as pallet_nfts::Config>::Helper::item(0),
 				}),
 				weight: 10,
+				tier: None,
 		}; length as usize];
 
 		let s = <T as pallet_nfts::Config>::StringLimit::get();
@@ -423,7 +634,8 @@ benchmarks_instance_pallet! {
 			loot_table: table.clone(),
 			mint_settings: default_mint_config::<T, I>(),
 			admin: T::Lookup::unlookup(admin.clone()),
-			data: bvec![0u8; s as usize]
+			data: bvec![0u8; s as usize],
+			maybe_asset: None,
 		};
 	}:  { call.dispatch_bypass_filter(RawOrigin::Signed(who.clone()).into())? }
 	verify {
@@ -563,6 +775,7 @@ benchmarks_instance_pallet! {
 
 	set_upgrade_item {
 		let s in 0 .. <T as pallet_nfts::Config>::StringLimit::get();
+		let m in 0 .. <T as pallet::Config<I>>::MaxBundle::get();
 		let (_, _, who) = new_account_with_item::<T, I>(0);
 
 
@@ -574,6 +787,7 @@ benchmarks_instance_pallet! {
 			data: bvec![0u8; s as usize],
 			level: 0,
 			fee: <T as pallet::Config<I>>::Currency::minimum_balance(),
+			materials: upgrade_materials::<T, I>(m),
 			};
 	}: { call.dispatch_bypass_filter(RawOrigin::Signed(who.clone()).into())? }
 	verify {
@@ -587,9 +801,10 @@ benchmarks_instance_pallet! {
 	}
 
 	upgrade_item {
+		let m in 0 .. <T as pallet::Config<I>>::MaxBundle::get();
 		let (who, _, admin) = new_account_with_item::<T, I>(0);
 
-		do_set_upgrade_item::<T, I>(&admin);
+		do_set_upgrade_item::<T, I>(&admin, m);
 
 		let call = Call::<T, I>::upgrade_item {
 			collection: <T as pallet_nfts::Config>::Helper::collection(0),
@@ -622,6 +837,7 @@ benchmarks_instance_pallet! {
 			unit_price: <T as pallet::Config<I>>::Currency::minimum_balance(),
 			start_block: None,
 			end_block: None,
+			maybe_asset: None,
 		};
 	}: { call.dispatch_bypass_filter(RawOrigin::Signed(who.clone()).into())? }
 	verify {
@@ -672,6 +888,7 @@ benchmarks_instance_pallet! {
 			price: <T as pallet::Config<I>>::Currency::minimum_balance(),
 			start_block: None,
 			end_block: None,
+			maybe_asset: None,
 		};
 	}: { call.dispatch_bypass_filter(RawOrigin::Signed(who.clone()).into())? }
 	verify {
@@ -717,6 +934,7 @@ benchmarks_instance_pallet! {
 			price: <T as pallet::Config<I>>::Currency::minimum_balance(),
 			start_block: None,
 			end_block: None,
+			maybe_asset: None,
 		};
 	}: { call.dispatch_bypass_filter(RawOrigin::Signed(who.clone()).into())? }
 	verify {
@@ -790,6 +1008,7 @@ benchmarks_instance_pallet! {
 			maybe_price: Some(<T as pallet::Config<I>>::Currency::minimum_balance()),
 			start_block: None,
 			end_block: None,
+			maybe_asset: None,
 		};
 	}: { call.dispatch_bypass_filter(RawOrigin::Signed(who.clone()).into())? }
 	verify {
@@ -839,6 +1058,7 @@ benchmarks_instance_pallet! {
 			Some(<T as pallet::Config<I>>::Currency::minimum_balance()),
 			None,
 			None,
+			None,
 		));
 
 		let call = Call::<T, I>::make_swap {
@@ -869,6 +1089,7 @@ benchmarks_instance_pallet! {
 			maybe_price: Some(<T as pallet::Config<I>>::Currency::minimum_balance()),
 			start_block: Some(<T as pallet::Config<I>>::Helper::block(0)),
 			duration:  <T as pallet::Config<I>>::Helper::block(10),
+			maybe_asset: None,
 		};
 	}: { call.dispatch_bypass_filter(RawOrigin::Signed(who.clone()).into())? }
 	verify {
@@ -939,6 +1160,7 @@ benchmarks_instance_pallet! {
 			unit_price: <T as pallet::Config<I>>::Currency::minimum_balance(),
 			start_block: None,
 			end_block: None,
+			maybe_asset: None,
 		};
 	}: { call.dispatch_bypass_filter(RawOrigin::Signed(caller.clone()).into())? }
 	verify {
@@ -969,6 +1191,7 @@ benchmarks_instance_pallet! {
 			<T as pallet::Config<I>>::Currency::minimum_balance(),
 			None,
 			None,
+			None,
 		));
 
 		let call = Call::<T, I>::sell_item {
@@ -1001,11 +1224,18 @@ benchmarks_instance_pallet! {
 	}
 
 	set_accept_adding {
+		do_create_game::<T, I>();
 		let who = new_funded_account::<T, I>(0, 0, 1000_000_000u128 * UNIT);
 
 		assert_ok!(PalletGame::<T, I>::create_collection(RawOrigin::Signed(who.clone()).into(),
 		T::Lookup::unlookup(who.clone()),
 		));
+		// replace a previous acceptance
+		assert_ok!(PalletGame::<T, I>::set_accept_adding(
+			RawOrigin::Signed(who.clone()).into(),
+			<T as pallet::Config<I>>::Helper::game(0),
+			<T as pallet_nfts::Config>::Helper::collection(0),
+		));
 
 		let call = Call::<T, I>::set_accept_adding {
 			game: <T as pallet::Config<I>>::Helper::game(0),
@@ -1089,12 +1319,14 @@ benchmarks_instance_pallet! {
 					item: <T as pallet_nfts::Config>::Helper::item(0),
 				}),
 				weight: 10,
+				tier: None,
 		}; s as usize];
 
 		let call = Call::<T, I>::create_dynamic_pool {
 			loot_table: table.clone(),
 			mint_settings: default_mint_config::<T, I>(),
 			admin: T::Lookup::unlookup(who.clone()),
+			maybe_asset: None,
 		};
 	}:  { call.dispatch_bypass_filter(RawOrigin::Signed(who.clone()).into())? }
 	verify {
@@ -1120,12 +1352,14 @@ benchmarks_instance_pallet! {
 					item: <T as pallet_nfts::Config>::Helper::item(0),
 				}),
 				weight: 10,
+				tier: None,
 		}; s as usize];
 
 		let call = Call::<T, I>::create_stable_pool {
 			loot_table: table.clone(),
 			mint_settings: default_mint_config::<T, I>(),
 			admin: T::Lookup::unlookup(who.clone()),
+			maybe_asset: None,
 		};
 	}:  { call.dispatch_bypass_filter(RawOrigin::Signed(who.clone()).into())? }
 	verify {
@@ -1144,7 +1378,7 @@ benchmarks_instance_pallet! {
 
 		let call = Call::<T, I>::request_mint {
 			pool: <T as pallet::Config<I>>::Helper::pool(0),
-			mint_to: mint_to.clone(), amount: 10 };
+			mint_to: mint_to.clone(), amount: 10, maybe_proof: None };
 	}: { call.dispatch_bypass_filter(RawOrigin::Signed(miner.clone()).into())? }
 	verify {
 		assert_last_event::<T, I>(Event::RequestMint {
@@ -1155,4 +1389,1167 @@ benchmarks_instance_pallet! {
 			// nfts: vec![ NFT{collection: <T as pallet_nfts::Config>::Helper::collection(0), item: <T as pallet_nfts::Config>::Helper::item(0)}; 10]
 		}.into());
 	}
+
+	start_game_destroy {
+		let (owner, _) = do_create_game::<T, I>();
+
+		let call = Call::<T, I>::start_game_destroy {
+			game: <T as pallet::Config<I>>::Helper::game(0),
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(owner.clone()).into())? }
+	verify {
+		assert_last_event::<T, I>(Event::GameDestructionStarted {
+			game: <T as pallet::Config<I>>::Helper::game(0),
+		}.into() );
+	}
+
+	destroy_game_roles {
+		let r in 0 .. <T as pallet::Config<I>>::RemoveRolesLimit::get();
+		let (owner, _) = do_create_game::<T, I>();
+		let game = <T as pallet::Config<I>>::Helper::game(0);
+
+		// acceptances to add collections return a deposit, the heaviest removal
+		let _ = GameRoleOf::<T, I>::clear_prefix(game, u32::MAX, None);
+		for i in 0..r {
+			let who = new_funded_account::<T, I>(i, 2, 1000_000_000u128 * UNIT);
+			let collection = <T as pallet_nfts::Config>::Helper::collection(i as u16);
+			let deposit = T::GameDeposit::get();
+			assert_ok!(<T as pallet::Config<I>>::Currency::reserve(&who, deposit));
+			AddingAcceptance::<T, I>::insert(collection, game);
+			AddingAcceptanceOf::<T, I>::insert(game, collection, (who, deposit));
+		}
+		assert_ok!(PalletGame::<T, I>::start_game_destroy(
+			RawOrigin::Signed(owner.clone()).into(),
+			game
+		));
+
+		let call = Call::<T, I>::destroy_game_roles { game };
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(owner.clone()).into())? }
+	verify {
+		assert_last_event::<T, I>(Event::GameRolesDestroyed {
+			game,
+			roles_destroyed: r,
+		}.into() );
+	}
+
+	finish_game_destroy {
+		let c in 0 .. <T as pallet::Config<I>>::MaxGameCollection::get();
+		let (owner, admin) = do_create_game::<T, I>();
+		let game = <T as pallet::Config<I>>::Helper::game(0);
+
+		for _ in 0..c {
+			assert_ok!(PalletGame::<T, I>::create_game_collection(
+				RawOrigin::Signed(admin.clone()).into(),
+				game
+			));
+		}
+		assert_ok!(PalletGame::<T, I>::start_game_destroy(
+			RawOrigin::Signed(owner.clone()).into(),
+			game
+		));
+		assert_ok!(PalletGame::<T, I>::destroy_game_roles(
+			RawOrigin::Signed(owner.clone()).into(),
+			game
+		));
+		// the treasury balance is returned to the owner
+		let treasury = PalletGame::<T, I>::game_account(&game);
+		let amount = <T as pallet::Config<I>>::Currency::minimum_balance();
+		<T as pallet::Config<I>>::Currency::make_free_balance_be(&treasury, amount + amount);
+
+		let call = Call::<T, I>::finish_game_destroy {
+			game,
+			witness: GameDestroyWitness { collections: c },
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(owner.clone()).into())? }
+	verify {
+		assert_last_event::<T, I>(Event::GameDestroyed { game }.into() );
+	}
+
+	set_candle_auction {
+		let s in 0 .. <T as pallet::Config<I>>::MaxBundle::get();
+
+		let (who, _, _) = new_account_with_item::<T, I>(0);
+		let bundle = vec![
+		Package {
+			collection: <T as pallet_nfts::Config>::Helper::collection(0),
+			item: <T as pallet_nfts::Config>::Helper::item(0),
+			amount: 1,
+		}; s as usize];
+
+		let call = Call::<T, I>::set_candle_auction {
+			source: bundle.clone(),
+			maybe_price: Some(<T as pallet::Config<I>>::Currency::minimum_balance()),
+			start_block: <T as pallet::Config<I>>::Helper::block(0),
+			early_end: <T as pallet::Config<I>>::Helper::block(5),
+			end_block: <T as pallet::Config<I>>::Helper::block(10),
+			maybe_asset: None,
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(who.clone()).into())? }
+	verify {
+		assert_last_event::<T, I>(Event::CandleAuctionSet {
+			trade: <T as pallet::Config<I>>::Helper::trade(0),
+			who,
+			source: bundle,
+			maybe_price: Some(<T as pallet::Config<I>>::Currency::minimum_balance()),
+			start_block: <T as pallet::Config<I>>::Helper::block(0),
+			early_end: <T as pallet::Config<I>>::Helper::block(5),
+			end_block: <T as pallet::Config<I>>::Helper::block(10),
+		}.into() );
+	}
+
+	bid_candle_auction {
+		let _ = do_set_candle_auction::<T, I>();
+		let max_bid = <T as pallet::Config<I>>::MaxCandleBid::get();
+		do_bid_candle_auction::<T, I>(max_bid - 1);
+
+		let caller = new_funded_account::<T, I>(0, 0, 1000_000_000u128 * UNIT);
+		let bid = <T as pallet::Config<I>>::Currency::minimum_balance() * (max_bid + 1).into();
+
+		let call = Call::<T, I>::bid_candle_auction {
+			trade: <T as pallet::Config<I>>::Helper::trade(0),
+			bid,
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(caller.clone()).into())? }
+	verify {
+		assert_last_event::<T, I>(Event::Bid {
+			trade: <T as pallet::Config<I>>::Helper::trade(0),
+			who: caller,
+			bid,
+		}.into() );
+	}
+
+	close_candle_auction {
+		let b in 1 .. <T as pallet::Config<I>>::MaxCandleBid::get();
+
+		let _ = do_set_candle_auction::<T, I>();
+		do_bid_candle_auction::<T, I>(b);
+
+		let caller = new_funded_account::<T, I>(0, 0, 1000_000_000u128 * UNIT);
+		frame_system::Pallet::<T>::set_block_number(<T as pallet::Config<I>>::Helper::block(10));
+		PalletGame::<T, I>::draw_candle_closing(&<T as pallet::Config<I>>::Helper::trade(0), 0);
+
+		let call = Call::<T, I>::close_candle_auction {
+			trade: <T as pallet::Config<I>>::Helper::trade(0),
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(caller.clone()).into())? }
+	verify {
+		assert!(CandleAuctionOf::<T, I>::get(<T as pallet::Config<I>>::Helper::trade(0)).is_none());
+	}
+
+	set_dutch_auction {
+		let s in 0 .. <T as pallet::Config<I>>::MaxBundle::get();
+
+		let (who, _, _) = new_account_with_item::<T, I>(0);
+		let bundle = vec![
+		Package {
+			collection: <T as pallet_nfts::Config>::Helper::collection(0),
+			item: <T as pallet_nfts::Config>::Helper::item(0),
+			amount: 1,
+		}; s as usize];
+		let start_price = <T as pallet::Config<I>>::Currency::minimum_balance() * 10u32.into();
+
+		let call = Call::<T, I>::set_dutch_auction {
+			source: bundle.clone(),
+			start_price,
+			floor_price: <T as pallet::Config<I>>::Currency::minimum_balance(),
+			start_block: Some(<T as pallet::Config<I>>::Helper::block(0)),
+			duration: <T as pallet::Config<I>>::Helper::block(10),
+			maybe_asset: None,
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(who.clone()).into())? }
+	verify {
+		assert_last_event::<T, I>(Event::DutchAuctionSet {
+			trade: <T as pallet::Config<I>>::Helper::trade(0),
+			who,
+			source: bundle,
+			start_price,
+			floor_price: <T as pallet::Config<I>>::Currency::minimum_balance(),
+			start_block: Some(<T as pallet::Config<I>>::Helper::block(0)),
+			duration: <T as pallet::Config<I>>::Helper::block(10),
+		}.into() );
+	}
+
+	buy_dutch_auction {
+		let _ = do_set_dutch_auction::<T, I>();
+
+		let caller = new_funded_account::<T, I>(0, 3, 1000_000_000u128 * UNIT);
+		let price = <T as pallet::Config<I>>::Currency::minimum_balance() * 10u32.into();
+		frame_system::Pallet::<T>::set_block_number(<T as pallet::Config<I>>::Helper::block(0));
+
+		let call = Call::<T, I>::buy_dutch_auction {
+			trade: <T as pallet::Config<I>>::Helper::trade(0),
+			bid_price: price,
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(caller.clone()).into())? }
+	verify {
+		assert_last_event::<T, I>(Event::DutchAuctionBought {
+			trade: <T as pallet::Config<I>>::Helper::trade(0),
+			who: caller,
+			price,
+		}.into() );
+	}
+
+	set_sealed_auction {
+		let s in 0 .. <T as pallet::Config<I>>::MaxBundle::get();
+
+		let (who, _, _) = new_account_with_item::<T, I>(0);
+		let bundle = vec![
+		Package {
+			collection: <T as pallet_nfts::Config>::Helper::collection(0),
+			item: <T as pallet_nfts::Config>::Helper::item(0),
+			amount: 1,
+		}; s as usize];
+
+		let call = Call::<T, I>::set_sealed_auction {
+			source: bundle.clone(),
+			maybe_price: Some(<T as pallet::Config<I>>::Currency::minimum_balance()),
+			start_block: Some(<T as pallet::Config<I>>::Helper::block(0)),
+			reveal_block: <T as pallet::Config<I>>::Helper::block(5),
+			end_block: <T as pallet::Config<I>>::Helper::block(10),
+			maybe_asset: None,
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(who.clone()).into())? }
+	verify {
+		assert_last_event::<T, I>(Event::SealedAuctionSet {
+			trade: <T as pallet::Config<I>>::Helper::trade(0),
+			who,
+			source: bundle,
+			maybe_price: Some(<T as pallet::Config<I>>::Currency::minimum_balance()),
+			start_block: Some(<T as pallet::Config<I>>::Helper::block(0)),
+			reveal_block: <T as pallet::Config<I>>::Helper::block(5),
+			end_block: <T as pallet::Config<I>>::Helper::block(10),
+		}.into() );
+	}
+
+	commit_sealed_bid {
+		let _ = do_set_sealed_auction::<T, I>();
+		let max_bid = <T as pallet::Config<I>>::MaxSealedBid::get();
+		let _ = do_commit_sealed_bid::<T, I>(max_bid - 1);
+
+		let caller = new_funded_account::<T, I>(0, 0, 1000_000_000u128 * UNIT);
+		let deposit = <T as pallet::Config<I>>::Currency::minimum_balance();
+
+		let call = Call::<T, I>::commit_sealed_bid {
+			trade: <T as pallet::Config<I>>::Helper::trade(0),
+			commitment: T::Hashing::hash_of(&(&caller, deposit, [0u8; 32])),
+			deposit,
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(caller.clone()).into())? }
+	verify {
+		assert_last_event::<T, I>(Event::SealedBidCommitted {
+			trade: <T as pallet::Config<I>>::Helper::trade(0),
+			who: caller,
+			deposit,
+		}.into() );
+	}
+
+	reveal_sealed_bid {
+		let _ = do_set_sealed_auction::<T, I>();
+		let max_bid = <T as pallet::Config<I>>::MaxSealedBid::get();
+		let bids = do_commit_sealed_bid::<T, I>(max_bid);
+		let (caller, bid) = bids[bids.len() - 1].clone();
+		frame_system::Pallet::<T>::set_block_number(<T as pallet::Config<I>>::Helper::block(5));
+
+		let call = Call::<T, I>::reveal_sealed_bid {
+			trade: <T as pallet::Config<I>>::Helper::trade(0),
+			bid,
+			salt: [0u8; 32],
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(caller.clone()).into())? }
+	verify {
+		assert_last_event::<T, I>(Event::SealedBidRevealed {
+			trade: <T as pallet::Config<I>>::Helper::trade(0),
+			who: caller,
+			bid,
+		}.into() );
+	}
+
+	close_sealed_auction {
+		let b in 1 .. <T as pallet::Config<I>>::MaxSealedBid::get();
+
+		let _ = do_set_sealed_auction::<T, I>();
+		let bids = do_commit_sealed_bid::<T, I>(b);
+		frame_system::Pallet::<T>::set_block_number(<T as pallet::Config<I>>::Helper::block(5));
+		for (bidder, bid) in bids {
+			assert_ok!(PalletGame::<T, I>::reveal_sealed_bid(
+				RawOrigin::Signed(bidder).into(),
+				<T as pallet::Config<I>>::Helper::trade(0),
+				bid,
+				[0u8; 32]
+			));
+		}
+
+		let caller = new_funded_account::<T, I>(0, 0, 1000_000_000u128 * UNIT);
+		frame_system::Pallet::<T>::set_block_number(<T as pallet::Config<I>>::Helper::block(10));
+
+		let call = Call::<T, I>::close_sealed_auction {
+			trade: <T as pallet::Config<I>>::Helper::trade(0),
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(caller.clone()).into())? }
+	verify {
+		assert!(SealedAuctionOf::<T, I>::get(<T as pallet::Config<I>>::Helper::trade(0)).is_none());
+	}
+
+	set_royalty {
+		let (owner, _) = do_create_collection::<T, I>();
+		let beneficiary = new_funded_account::<T, I>(2, 2, 1000_000_000u128 * UNIT);
+
+		let call = Call::<T, I>::set_royalty {
+			collection: <T as pallet_nfts::Config>::Helper::collection(0),
+			maybe_item: Some(<T as pallet_nfts::Config>::Helper::item(0)),
+			beneficiary: T::Lookup::unlookup(beneficiary.clone()),
+			rate: <T as pallet::Config<I>>::MaxRoyalty::get(),
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(owner.clone()).into())? }
+	verify {
+		assert_last_event::<T, I>(Event::RoyaltySet {
+			collection: <T as pallet_nfts::Config>::Helper::collection(0),
+			maybe_item: Some(<T as pallet_nfts::Config>::Helper::item(0)),
+			beneficiary,
+			rate: <T as pallet::Config<I>>::MaxRoyalty::get(),
+		}.into() );
+	}
+
+	clear_royalty {
+		let (owner, _) = do_create_collection::<T, I>();
+		let beneficiary = new_funded_account::<T, I>(2, 2, 1000_000_000u128 * UNIT);
+		assert_ok!(PalletGame::<T, I>::set_royalty(
+			RawOrigin::Signed(owner.clone()).into(),
+			<T as pallet_nfts::Config>::Helper::collection(0),
+			Some(<T as pallet_nfts::Config>::Helper::item(0)),
+			T::Lookup::unlookup(beneficiary),
+			<T as pallet::Config<I>>::MaxRoyalty::get()
+		));
+
+		let call = Call::<T, I>::clear_royalty {
+			collection: <T as pallet_nfts::Config>::Helper::collection(0),
+			maybe_item: Some(<T as pallet_nfts::Config>::Helper::item(0)),
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(owner.clone()).into())? }
+	verify {
+		assert_last_event::<T, I>(Event::RoyaltyCleared {
+			collection: <T as pallet_nfts::Config>::Helper::collection(0),
+			maybe_item: Some(<T as pallet_nfts::Config>::Helper::item(0)),
+		}.into() );
+	}
+
+	set_trade_fee {
+		let (owner, _) = do_create_game::<T, I>();
+		let fee_account = new_funded_account::<T, I>(2, 2, 1000_000_000u128 * UNIT);
+
+		let call = Call::<T, I>::set_trade_fee {
+			game: <T as pallet::Config<I>>::Helper::game(0),
+			fee: <T as pallet::Config<I>>::MaxTradeFee::get(),
+			fee_account: Some(T::Lookup::unlookup(fee_account.clone())),
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(owner.clone()).into())? }
+	verify {
+		assert_last_event::<T, I>(Event::TradeFeeSet {
+			game: <T as pallet::Config<I>>::Helper::game(0),
+			fee: <T as pallet::Config<I>>::MaxTradeFee::get(),
+			fee_account: Some(fee_account),
+		}.into() );
+	}
+
+	set_book_price {
+		let m in 1 .. <T as pallet::Config<I>>::MaxBookOrders::get();
+
+		let (who, _, _) = new_account_with_item::<T, I>(0);
+		let buyer = new_funded_account::<T, I>(2, 2, 1000_000_000u128 * UNIT);
+		for _ in 0 .. m {
+			assert_ok!(PalletGame::<T, I>::set_book_order(
+				RawOrigin::Signed(buyer.clone()).into(),
+				Package {
+					collection: <T as pallet_nfts::Config>::Helper::collection(0),
+					item: <T as pallet_nfts::Config>::Helper::item(0),
+					amount: 1,
+				},
+				<T as pallet::Config<I>>::Currency::minimum_balance(),
+				None,
+			));
+		}
+
+		let call = Call::<T, I>::set_book_price {
+			package: Package {
+				collection: <T as pallet_nfts::Config>::Helper::collection(0),
+				item: <T as pallet_nfts::Config>::Helper::item(0),
+				amount: m,
+			},
+			unit_price: <T as pallet::Config<I>>::Currency::minimum_balance(),
+			maybe_asset: None,
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(who.clone()).into())? }
+	verify {
+		assert!(BidBookOf::<T, I>::get((
+			<T as pallet_nfts::Config>::Helper::collection(0),
+			<T as pallet_nfts::Config>::Helper::item(0),
+			None,
+		)).is_empty());
+	}
+
+	set_book_order {
+		let m in 1 .. <T as pallet::Config<I>>::MaxBookOrders::get();
+
+		let (who, _, _) = new_account_with_item::<T, I>(0);
+		for _ in 0 .. m {
+			assert_ok!(PalletGame::<T, I>::set_book_price(
+				RawOrigin::Signed(who.clone()).into(),
+				Package {
+					collection: <T as pallet_nfts::Config>::Helper::collection(0),
+					item: <T as pallet_nfts::Config>::Helper::item(0),
+					amount: 1,
+				},
+				<T as pallet::Config<I>>::Currency::minimum_balance(),
+				None,
+			));
+		}
+
+		let buyer = new_funded_account::<T, I>(2, 2, 1000_000_000u128 * UNIT);
+		let call = Call::<T, I>::set_book_order {
+			package: Package {
+				collection: <T as pallet_nfts::Config>::Helper::collection(0),
+				item: <T as pallet_nfts::Config>::Helper::item(0),
+				amount: m,
+			},
+			unit_price: <T as pallet::Config<I>>::Currency::minimum_balance(),
+			maybe_asset: None,
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(buyer.clone()).into())? }
+	verify {
+		assert!(AskBookOf::<T, I>::get((
+			<T as pallet_nfts::Config>::Helper::collection(0),
+			<T as pallet_nfts::Config>::Helper::item(0),
+			None,
+		)).is_empty());
+	}
+
+	expire_trade {
+		let _ = do_set_auction::<T, I>();
+
+		let bidder = new_funded_account::<T, I>(0, 0, 1000_000_000u128 * UNIT);
+		assert_ok!(PalletGame::<T, I>::bid_auction(
+			RawOrigin::Signed(bidder.clone()).into(),
+			<T as pallet::Config<I>>::Helper::trade(0),
+			<T as pallet::Config<I>>::Currency::minimum_balance()
+		));
+
+		frame_system::Pallet::<T>::set_block_number(<T as pallet::Config<I>>::Helper::block(10));
+	}: {
+		PalletGame::<T, I>::expire_trade(
+			&<T as pallet::Config<I>>::Helper::trade(0),
+			TradeType::Auction,
+		)
+	}
+	verify {
+		assert_last_event::<T, I>(Event::TradeExpired {
+			trade: <T as pallet::Config<I>>::Helper::trade(0),
+		}.into() );
+	}
+
+	set_payment_asset {
+		let (owner, _) = do_create_game::<T, I>();
+
+		let call = Call::<T, I>::set_payment_asset {
+			game: <T as pallet::Config<I>>::Helper::game(0),
+			maybe_asset: None,
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(owner.clone()).into())? }
+	verify {
+		assert_last_event::<T, I>(Event::PaymentAssetSet {
+			game: <T as pallet::Config<I>>::Helper::game(0),
+			maybe_asset: None,
+		}.into() );
+	}
+
+	create_recipe {
+		let i in 1 .. <T as pallet::Config<I>>::MaxBundle::get();
+		let o in 1 .. <T as pallet::Config<I>>::MaxBundle::get();
+
+		let (owner, admin) = do_create_game_collection::<T, I>();
+		do_create_item::<T, I>(&admin, 0, 0, Some(1000));
+		do_create_item::<T, I>(&admin, 0, 1, Some(1000));
+		assert_ok!(PalletGame::<T, I>::transfer(
+			RawOrigin::Signed(owner.clone()).into(),
+			<T as pallet_nfts::Config>::Helper::collection(0),
+			<T as pallet_nfts::Config>::Helper::item(1),
+			T::Lookup::unlookup(admin.clone()),
+			o,
+		));
+
+		let input = vec![
+		Package {
+			collection: <T as pallet_nfts::Config>::Helper::collection(0),
+			item: <T as pallet_nfts::Config>::Helper::item(0),
+			amount: 1,
+		}; i as usize];
+
+		let output = vec![
+		Package {
+			collection: <T as pallet_nfts::Config>::Helper::collection(0),
+			item: <T as pallet_nfts::Config>::Helper::item(1),
+			amount: 1,
+		}; o as usize];
+
+		let call = Call::<T, I>::create_recipe {
+			game: <T as pallet::Config<I>>::Helper::game(0),
+			input: input.clone(),
+			maybe_fee: Some(<T as pallet::Config<I>>::Currency::minimum_balance()),
+			output: output.clone(),
+			maybe_crafts: Some(1),
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(admin.clone()).into())? }
+	verify {
+		assert_last_event::<T, I>(Event::RecipeCreated {
+			recipe: <T as pallet::Config<I>>::Helper::recipe(0),
+			who: admin,
+			game: <T as pallet::Config<I>>::Helper::game(0),
+			input,
+			maybe_fee: Some(<T as pallet::Config<I>>::Currency::minimum_balance()),
+			output,
+			maybe_crafts: Some(1),
+		}.into() );
+	}
+
+	remove_recipe {
+		let (owner, admin) = do_create_game_collection::<T, I>();
+		do_create_item::<T, I>(&admin, 0, 0, Some(1000));
+
+		let max = <T as pallet::Config<I>>::MaxBundle::get();
+		assert_ok!(PalletGame::<T, I>::transfer(
+			RawOrigin::Signed(owner.clone()).into(),
+			<T as pallet_nfts::Config>::Helper::collection(0),
+			<T as pallet_nfts::Config>::Helper::item(0),
+			T::Lookup::unlookup(admin.clone()),
+			max,
+		));
+
+		let bundle = vec![Package {
+			collection: <T as pallet_nfts::Config>::Helper::collection(0),
+			item: <T as pallet_nfts::Config>::Helper::item(0),
+			amount: 1,
+		}; max as usize];
+		assert_ok!(PalletGame::<T, I>::create_recipe(
+			RawOrigin::Signed(admin.clone()).into(),
+			<T as pallet::Config<I>>::Helper::game(0),
+			bundle.clone(),
+			None,
+			bundle,
+			Some(1),
+		));
+
+		let call = Call::<T, I>::remove_recipe {
+			recipe: <T as pallet::Config<I>>::Helper::recipe(0),
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(admin.clone()).into())? }
+	verify {
+		assert_last_event::<T, I>(Event::RecipeRemoved {
+			recipe: <T as pallet::Config<I>>::Helper::recipe(0),
+		}.into() );
+	}
+
+	craft {
+		let (owner, admin) = do_create_game_collection::<T, I>();
+		let player = new_funded_account::<T, I>(2, 2, 1000_000_000u128 * UNIT);
+
+		let max = <T as pallet::Config<I>>::MaxBundle::get() as u16;
+		let mut input = vec![];
+		let mut output = vec![];
+		for i in 0..max {
+			do_create_item::<T, I>(&admin, 0, i, Some(1000));
+			do_create_item::<T, I>(&admin, 0, max + i, Some(1000));
+			assert_ok!(PalletGame::<T, I>::transfer(
+				RawOrigin::Signed(owner.clone()).into(),
+				<T as pallet_nfts::Config>::Helper::collection(0),
+				<T as pallet_nfts::Config>::Helper::item(i),
+				T::Lookup::unlookup(player.clone()),
+				10,
+			));
+			assert_ok!(PalletGame::<T, I>::transfer(
+				RawOrigin::Signed(owner.clone()).into(),
+				<T as pallet_nfts::Config>::Helper::collection(0),
+				<T as pallet_nfts::Config>::Helper::item(max + i),
+				T::Lookup::unlookup(admin.clone()),
+				1,
+			));
+			input.push(Package {
+				collection: <T as pallet_nfts::Config>::Helper::collection(0),
+				item: <T as pallet_nfts::Config>::Helper::item(i),
+				amount: 1,
+			});
+			output.push(Package {
+				collection: <T as pallet_nfts::Config>::Helper::collection(0),
+				item: <T as pallet_nfts::Config>::Helper::item(max + i),
+				amount: 1,
+			});
+		}
+
+		assert_ok!(PalletGame::<T, I>::create_recipe(
+			RawOrigin::Signed(admin.clone()).into(),
+			<T as pallet::Config<I>>::Helper::game(0),
+			input,
+			Some(<T as pallet::Config<I>>::Currency::minimum_balance()),
+			output,
+			Some(1),
+		));
+
+		let call = Call::<T, I>::craft {
+			recipe: <T as pallet::Config<I>>::Helper::recipe(0),
+			times: 1,
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(player.clone()).into())? }
+	verify {
+		assert_last_event::<T, I>(Event::Crafted {
+			who: player,
+			recipe: <T as pallet::Config<I>>::Helper::recipe(0),
+			times: 1,
+		}.into() );
+	}
+
+	set_upgrade_chance {
+		let (_, _, admin) = new_account_with_item::<T, I>(0);
+		do_set_upgrade_item::<T, I>(&admin, <T as pallet::Config<I>>::MaxBundle::get());
+
+		let call = Call::<T, I>::set_upgrade_chance {
+			collection: <T as pallet_nfts::Config>::Helper::collection(0),
+			item: <T as pallet_nfts::Config>::Helper::item(0),
+			level: 1,
+			success_rate: 5_000,
+			on_failure: UpgradeFailure::Downgrade,
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(admin.clone()).into())? }
+	verify {
+		assert_last_event::<T, I>(Event::UpgradeChanceSet {
+			who: admin,
+			collection: <T as pallet_nfts::Config>::Helper::collection(0),
+			item: <T as pallet_nfts::Config>::Helper::item(0),
+			level: 1,
+			success_rate: 5_000,
+			on_failure: UpgradeFailure::Downgrade,
+		}.into() );
+	}
+
+	set_salvage {
+		let m in 1 .. <T as pallet::Config<I>>::MaxBundle::get();
+		let (_, _, admin) = new_account_with_item::<T, I>(0);
+
+		let output = SalvageOutput::Fixed(vec![
+		Package {
+			collection: <T as pallet_nfts::Config>::Helper::collection(0),
+			item: <T as pallet_nfts::Config>::Helper::item(1),
+			amount: 1,
+		}; m as usize]);
+
+		let call = Call::<T, I>::set_salvage {
+			collection: <T as pallet_nfts::Config>::Helper::collection(0),
+			item: <T as pallet_nfts::Config>::Helper::item(0),
+			output: output.clone(),
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(admin.clone()).into())? }
+	verify {
+		assert_last_event::<T, I>(Event::SalvageSet {
+			who: admin,
+			collection: <T as pallet_nfts::Config>::Helper::collection(0),
+			item: <T as pallet_nfts::Config>::Helper::item(0),
+			output,
+		}.into() );
+	}
+
+	clear_salvage {
+		let (_, _, admin) = new_account_with_item::<T, I>(0);
+
+		assert_ok!(PalletGame::<T, I>::set_salvage(
+			RawOrigin::Signed(admin.clone()).into(),
+			<T as pallet_nfts::Config>::Helper::collection(0),
+			<T as pallet_nfts::Config>::Helper::item(0),
+			SalvageOutput::Fixed(vec![Package {
+				collection: <T as pallet_nfts::Config>::Helper::collection(0),
+				item: <T as pallet_nfts::Config>::Helper::item(1),
+				amount: 1,
+			}]),
+		));
+
+		let call = Call::<T, I>::clear_salvage {
+			collection: <T as pallet_nfts::Config>::Helper::collection(0),
+			item: <T as pallet_nfts::Config>::Helper::item(0),
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(admin.clone()).into())? }
+	verify {
+		assert_last_event::<T, I>(Event::SalvageCleared {
+			collection: <T as pallet_nfts::Config>::Helper::collection(0),
+			item: <T as pallet_nfts::Config>::Helper::item(0),
+		}.into() );
+	}
+
+	salvage {
+		let (who, _, admin) = new_account_with_item::<T, I>(0);
+		do_create_item::<T, I>(&admin, 0, 3, None);
+
+		assert_ok!(PalletGame::<T, I>::set_salvage(
+			RawOrigin::Signed(admin.clone()).into(),
+			<T as pallet_nfts::Config>::Helper::collection(0),
+			<T as pallet_nfts::Config>::Helper::item(0),
+			SalvageOutput::Random(vec![Loot {
+				maybe_nft: Some(NFT {
+					collection: <T as pallet_nfts::Config>::Helper::collection(0),
+					item: <T as pallet_nfts::Config>::Helper::item(3),
+				}),
+				weight: 10,
+				tier: None,
+			}]),
+		));
+
+		let amount = <T as pallet::Config<I>>::MaxMintItem::get();
+		let call = Call::<T, I>::salvage {
+			collection: <T as pallet_nfts::Config>::Helper::collection(0),
+			item: <T as pallet_nfts::Config>::Helper::item(0),
+			amount,
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(who.clone()).into())? }
+	verify {
+		assert_last_event::<T, I>(Event::SalvageRequested {
+			who,
+			collection: <T as pallet_nfts::Config>::Helper::collection(0),
+			item: <T as pallet_nfts::Config>::Helper::item(0),
+			amount,
+			block_number: frame_system::Pallet::<T>::block_number() + T::MintInterval::get(),
+		}.into() );
+	}
+
+	set_rental {
+		let s in 0 .. <T as pallet::Config<I>>::MaxBundle::get();
+
+		let (who, _, _) = new_account_with_item::<T, I>(0);
+		let bundle = vec![
+		Package {
+			collection: <T as pallet_nfts::Config>::Helper::collection(0),
+			item: <T as pallet_nfts::Config>::Helper::item(0),
+			amount: 1,
+		}; s as usize];
+		let price_per_block = <T as pallet::Config<I>>::Currency::minimum_balance();
+
+		let call = Call::<T, I>::set_rental {
+			source: bundle.clone(),
+			price_per_block,
+			max_duration: <T as pallet::Config<I>>::Helper::block(10),
+			maybe_asset: None,
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(who.clone()).into())? }
+	verify {
+		assert_last_event::<T, I>(Event::RentalSet {
+			trade: <T as pallet::Config<I>>::Helper::trade(0),
+			who,
+			source: bundle,
+			price_per_block,
+			max_duration: <T as pallet::Config<I>>::Helper::block(10),
+		}.into() );
+	}
+
+	rent {
+		let _ = do_set_rental::<T, I>();
+
+		let caller = new_funded_account::<T, I>(0, 3, 1000_000_000u128 * UNIT);
+		frame_system::Pallet::<T>::set_block_number(<T as pallet::Config<I>>::Helper::block(0));
+
+		let call = Call::<T, I>::rent {
+			trade: <T as pallet::Config<I>>::Helper::trade(0),
+			duration: <T as pallet::Config<I>>::Helper::block(10),
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(caller.clone()).into())? }
+	verify {
+		assert_last_event::<T, I>(Event::Rented {
+			trade: <T as pallet::Config<I>>::Helper::trade(0),
+			who: caller,
+			duration: <T as pallet::Config<I>>::Helper::block(10),
+			price: <T as pallet::Config<I>>::Currency::minimum_balance() * 10u32.into(),
+			end_block: <T as pallet::Config<I>>::Helper::block(10),
+		}.into() );
+	}
+
+	return_rental {
+		let _ = do_set_rental::<T, I>();
+
+		let renter = new_funded_account::<T, I>(0, 3, 1000_000_000u128 * UNIT);
+		frame_system::Pallet::<T>::set_block_number(<T as pallet::Config<I>>::Helper::block(0));
+		assert_ok!(PalletGame::<T, I>::rent(
+			RawOrigin::Signed(renter.clone()).into(),
+			<T as pallet::Config<I>>::Helper::trade(0),
+			<T as pallet::Config<I>>::Helper::block(10)
+		));
+		frame_system::Pallet::<T>::set_block_number(<T as pallet::Config<I>>::Helper::block(10));
+
+		let caller = new_funded_account::<T, I>(0, 4, 1000_000_000u128 * UNIT);
+		let call = Call::<T, I>::return_rental {
+			trade: <T as pallet::Config<I>>::Helper::trade(0),
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(caller.clone()).into())? }
+	verify {
+		assert_last_event::<T, I>(Event::RentalReturned {
+			trade: <T as pallet::Config<I>>::Helper::trade(0),
+			who: renter,
+		}.into() );
+	}
+
+	set_soulbound {
+		let (_, _, admin) = new_account_with_item::<T, I>(0);
+
+		let call = Call::<T, I>::set_soulbound {
+			collection: <T as pallet_nfts::Config>::Helper::collection(0),
+			item: <T as pallet_nfts::Config>::Helper::item(0),
+			soulbound: true,
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(admin.clone()).into())? }
+	verify {
+		assert_last_event::<T, I>(Event::SoulboundSet {
+			collection: <T as pallet_nfts::Config>::Helper::collection(0),
+			item: <T as pallet_nfts::Config>::Helper::item(0),
+			soulbound: true,
+		}.into() );
+	}
+
+	approve_operator {
+		let (who, _, _) = new_account_with_item::<T, I>(0);
+		let operator = new_funded_account::<T, I>(0, 5, 1000_000_000u128 * UNIT);
+
+		let call = Call::<T, I>::approve_operator {
+			operator: T::Lookup::unlookup(operator.clone()),
+			maybe_collection: Some(<T as pallet_nfts::Config>::Helper::collection(0)),
+			maybe_deadline: None,
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(who.clone()).into())? }
+	verify {
+		assert_last_event::<T, I>(Event::OperatorApproved {
+			who,
+			operator,
+			maybe_collection: Some(<T as pallet_nfts::Config>::Helper::collection(0)),
+			maybe_deadline: None,
+		}.into() );
+	}
+
+	revoke_operator {
+		let (who, _, _) = new_account_with_item::<T, I>(0);
+		let operator = new_funded_account::<T, I>(0, 5, 1000_000_000u128 * UNIT);
+		assert_ok!(PalletGame::<T, I>::approve_operator(
+			RawOrigin::Signed(who.clone()).into(),
+			T::Lookup::unlookup(operator.clone()),
+			Some(<T as pallet_nfts::Config>::Helper::collection(0)),
+			None,
+		));
+
+		let call = Call::<T, I>::revoke_operator {
+			operator: T::Lookup::unlookup(operator.clone()),
+			maybe_collection: Some(<T as pallet_nfts::Config>::Helper::collection(0)),
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(who.clone()).into())? }
+	verify {
+		assert_last_event::<T, I>(Event::OperatorRevoked {
+			who,
+			operator,
+			maybe_collection: Some(<T as pallet_nfts::Config>::Helper::collection(0)),
+		}.into() );
+	}
+
+	transfer_from {
+		let (who, _, _) = new_account_with_item::<T, I>(0);
+		let operator = new_funded_account::<T, I>(0, 5, 1000_000_000u128 * UNIT);
+		let dest = new_funded_account::<T, I>(0, 6, 1000_000_000u128 * UNIT);
+		assert_ok!(PalletGame::<T, I>::approve_operator(
+			RawOrigin::Signed(who.clone()).into(),
+			T::Lookup::unlookup(operator.clone()),
+			None,
+			None,
+		));
+
+		let call = Call::<T, I>::transfer_from {
+			from: T::Lookup::unlookup(who.clone()),
+			collection: <T as pallet_nfts::Config>::Helper::collection(0),
+			item: <T as pallet_nfts::Config>::Helper::item(0),
+			dest: T::Lookup::unlookup(dest.clone()),
+			amount: 10,
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(operator.clone()).into())? }
+	verify {
+		assert_last_event::<T, I>(Event::Transferred {
+			from: who,
+			collection: <T as pallet_nfts::Config>::Helper::collection(0),
+			item: <T as pallet_nfts::Config>::Helper::item(0),
+			dest,
+			amount: 10,
+		}.into() );
+	}
+
+	create_match {
+		let (_, admin) = do_create_game_collection::<T, I>();
+
+		let call = Call::<T, I>::create_match {
+			game: <T as pallet::Config<I>>::Helper::game(0),
+			stake: <T as pallet::Config<I>>::Currency::minimum_balance(),
+			maybe_server: None,
+			end_block: <T as pallet::Config<I>>::Helper::block(10),
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(admin.clone()).into())? }
+	verify {
+		assert_last_event::<T, I>(Event::MatchCreated {
+			match_id: <T as pallet::Config<I>>::Helper::match_id(0),
+			who: admin,
+			game: <T as pallet::Config<I>>::Helper::game(0),
+			stake: <T as pallet::Config<I>>::Currency::minimum_balance(),
+			maybe_server: None,
+			end_block: <T as pallet::Config<I>>::Helper::block(10),
+		}.into() );
+	}
+
+	join_match {
+		let s in 0 .. <T as pallet::Config<I>>::MaxBundle::get();
+
+		let (owner, _, _) = do_create_match::<T, I>(0);
+		let player = new_funded_account::<T, I>(0, 7, 1000_000_000u128 * UNIT);
+		assert_ok!(PalletGame::<T, I>::transfer(
+			RawOrigin::Signed(owner.clone()).into(),
+			<T as pallet_nfts::Config>::Helper::collection(0),
+			<T as pallet_nfts::Config>::Helper::item(0),
+			T::Lookup::unlookup(player.clone()),
+			10
+		));
+
+		let items = vec![
+		Package {
+			collection: <T as pallet_nfts::Config>::Helper::collection(0),
+			item: <T as pallet_nfts::Config>::Helper::item(0),
+			amount: 1,
+		}; s as usize];
+
+		let call = Call::<T, I>::join_match {
+			match_id: <T as pallet::Config<I>>::Helper::match_id(0),
+			items: items.clone(),
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(player.clone()).into())? }
+	verify {
+		assert_last_event::<T, I>(Event::MatchJoined {
+			match_id: <T as pallet::Config<I>>::Helper::match_id(0),
+			who: player,
+			items,
+		}.into() );
+	}
+
+	settle_match {
+		let p in 1 .. <T as pallet::Config<I>>::MaxMatchPlayers::get();
+
+		let (_, admin, players) = do_create_match::<T, I>(p);
+		let result = MatchResult {
+			winners: vec![(players[0].clone(), 10_000)],
+			losers: players[1..]
+				.iter()
+				.map(|player| (player.clone(), Some(players[0].clone())))
+				.collect(),
+		};
+
+		let call = Call::<T, I>::settle_match {
+			match_id: <T as pallet::Config<I>>::Helper::match_id(0),
+			result: result.clone(),
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(admin.clone()).into())? }
+	verify {
+		assert_last_event::<T, I>(Event::MatchSettled {
+			match_id: <T as pallet::Config<I>>::Helper::match_id(0),
+			result,
+		}.into() );
+	}
+
+	refund_match {
+		let p in 1 .. <T as pallet::Config<I>>::MaxMatchPlayers::get();
+
+		let (_, _, players) = do_create_match::<T, I>(p);
+		frame_system::Pallet::<T>::set_block_number(<T as pallet::Config<I>>::Helper::block(10));
+
+		let call = Call::<T, I>::refund_match {
+			match_id: <T as pallet::Config<I>>::Helper::match_id(0),
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(players[0].clone()).into())? }
+	verify {
+		assert_last_event::<T, I>(Event::MatchRefunded {
+			match_id: <T as pallet::Config<I>>::Helper::match_id(0),
+		}.into() );
+	}
+
+	set_treasury_routing {
+		let (owner, _) = do_create_game::<T, I>();
+
+		let call = Call::<T, I>::set_treasury_routing {
+			game: <T as pallet::Config<I>>::Helper::game(0),
+			enabled: true,
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(owner.clone()).into())? }
+	verify {
+		assert_last_event::<T, I>(Event::TreasuryRoutingSet {
+			game: <T as pallet::Config<I>>::Helper::game(0),
+			enabled: true,
+		}.into() );
+	}
+
+	spend_treasury {
+		let (owner, _) = do_create_game::<T, I>();
+		let game = <T as pallet::Config<I>>::Helper::game(0);
+		let treasury = PalletGame::<T, I>::game_account(&game);
+		let amount = <T as pallet::Config<I>>::Currency::minimum_balance();
+		<T as pallet::Config<I>>::Currency::make_free_balance_be(
+			&treasury,
+			amount + amount + amount,
+		);
+		let dest = new_funded_account::<T, I>(8, 8, 0);
+
+		let call = Call::<T, I>::spend_treasury {
+			game,
+			maybe_asset: None,
+			dest: T::Lookup::unlookup(dest.clone()),
+			amount,
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(owner.clone()).into())? }
+	verify {
+		assert_last_event::<T, I>(Event::TreasurySpent {
+			game,
+			maybe_asset: None,
+			dest,
+			amount,
+		}.into() );
+	}
+
+	set_accept_game_ownership {
+		do_create_game::<T, I>();
+		let new_owner = new_funded_account::<T, I>(8, 8, 1000_000_000u128 * UNIT);
+
+		let call = Call::<T, I>::set_accept_game_ownership {
+			maybe_game: Some(<T as pallet::Config<I>>::Helper::game(0)),
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(new_owner.clone()).into())? }
+	verify {
+		assert_last_event::<T, I>(Event::GameOwnershipAcceptanceChanged {
+			who: new_owner,
+			maybe_game: Some(<T as pallet::Config<I>>::Helper::game(0)),
+		}.into() );
+	}
+
+	transfer_game_ownership {
+		let c in 0 .. <T as pallet::Config<I>>::MaxGameCollection::get();
+		let (owner, admin) = do_create_game::<T, I>();
+		for _ in 0..c {
+			assert_ok!(PalletGame::<T, I>::create_game_collection(
+				RawOrigin::Signed(admin.clone()).into(),
+				<T as pallet::Config<I>>::Helper::game(0)
+			));
+		}
+		let new_owner = new_funded_account::<T, I>(8, 8, 1000_000_000u128 * UNIT);
+		assert_ok!(PalletGame::<T, I>::set_accept_game_ownership(
+			RawOrigin::Signed(new_owner.clone()).into(),
+			Some(<T as pallet::Config<I>>::Helper::game(0)),
+		));
+
+		let call = Call::<T, I>::transfer_game_ownership {
+			game: <T as pallet::Config<I>>::Helper::game(0),
+			new_owner: T::Lookup::unlookup(new_owner.clone()),
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(owner.clone()).into())? }
+	verify {
+		assert_last_event::<T, I>(Event::GameOwnershipTransferred {
+			game: <T as pallet::Config<I>>::Helper::game(0),
+			new_owner,
+		}.into() );
+	}
+
+	set_game_admin {
+		let c in 0 .. <T as pallet::Config<I>>::MaxGameCollection::get();
+		let (owner, old_admin) = do_create_game::<T, I>();
+		for _ in 0..c {
+			assert_ok!(PalletGame::<T, I>::create_game_collection(
+				RawOrigin::Signed(old_admin.clone()).into(),
+				<T as pallet::Config<I>>::Helper::game(0)
+			));
+		}
+		let admin = new_funded_account::<T, I>(8, 8, 1000_000_000u128 * UNIT);
+
+		let call = Call::<T, I>::set_game_admin {
+			game: <T as pallet::Config<I>>::Helper::game(0),
+			admin: T::Lookup::unlookup(admin.clone()),
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(owner.clone()).into())? }
+	verify {
+		assert_last_event::<T, I>(Event::GameAdminSet {
+			game: <T as pallet::Config<I>>::Helper::game(0),
+			admin,
+		}.into() );
+	}
+
+	grant_role {
+		let (owner, _) = do_create_game::<T, I>();
+		let who = new_funded_account::<T, I>(8, 8, 1000_000_000u128 * UNIT);
+
+		let call = Call::<T, I>::grant_role {
+			game: <T as pallet::Config<I>>::Helper::game(0),
+			who: T::Lookup::unlookup(who.clone()),
+			role: GameRole::Minter,
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(owner.clone()).into())? }
+	verify {
+		assert_last_event::<T, I>(Event::RoleGranted {
+			game: <T as pallet::Config<I>>::Helper::game(0),
+			who,
+			role: GameRole::Minter,
+		}.into() );
+	}
+
+	revoke_role {
+		let (owner, _) = do_create_game::<T, I>();
+		let who = new_funded_account::<T, I>(8, 8, 1000_000_000u128 * UNIT);
+		assert_ok!(PalletGame::<T, I>::grant_role(
+			RawOrigin::Signed(owner.clone()).into(),
+			<T as pallet::Config<I>>::Helper::game(0),
+			T::Lookup::unlookup(who.clone()),
+			GameRole::Minter,
+		));
+
+		let call = Call::<T, I>::revoke_role {
+			game: <T as pallet::Config<I>>::Helper::game(0),
+			who: T::Lookup::unlookup(who.clone()),
+			role: GameRole::Minter,
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(owner.clone()).into())? }
+	verify {
+		assert_last_event::<T, I>(Event::RoleRevoked {
+			game: <T as pallet::Config<I>>::Helper::game(0),
+			who,
+			role: GameRole::Minter,
+		}.into() );
+	}
+
+	process_upgrade_request {
+		let a in 1 .. <T as pallet::Config<I>>::MaxMintItem::get();
+		let (who, _, admin) = new_account_with_item::<T, I>(0);
+		do_set_upgrade_item::<T, I>(&admin, 0);
+		assert_ok!(PalletGame::<T, I>::set_upgrade_chance(
+			RawOrigin::Signed(admin.clone()).into(),
+			<T as pallet_nfts::Config>::Helper::collection(0),
+			<T as pallet_nfts::Config>::Helper::item(0),
+			1,
+			5_000,
+			UpgradeFailure::Destroy,
+		));
+		assert_ok!(PalletGame::<T, I>::upgrade_item(
+			RawOrigin::Signed(who.clone()).into(),
+			<T as pallet_nfts::Config>::Helper::collection(0),
+			<T as pallet_nfts::Config>::Helper::item(0),
+			a,
+		));
+
+		let execute_block = frame_system::Pallet::<T>::block_number() + T::MintInterval::get();
+		let request = UpgradeRequestOf::<T, I>::get(execute_block)[0].clone();
+	}: { PalletGame::<T, I>::processing_upgrade_request(request)? }
+	verify {
+		assert_eq!(
+			ReservedBalanceOf::<T, I>::get((
+				who,
+				<T as pallet_nfts::Config>::Helper::collection(0),
+				<T as pallet_nfts::Config>::Helper::item(0),
+			)),
+			0
+		);
+	}
 }
@@ -12,6 +12,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		to: &T::AccountId,
 		amount: Amount,
 	) -> Result<(), Error<T, I>> {
+		Self::ensure_transferable(collection, item)?;
 		Self::sub_item_balance(from, collection, item, amount)?;
 		Self::add_item_balance(to, collection, item, amount)?;
 		Ok(())
@@ -64,7 +65,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	}
 
 	/// Add a new `amount` of reserved `item` in `collection` to `who`.
-	fn add_reserved_balance(
+	pub(crate) fn add_reserved_balance(
 		who: &T::AccountId,
 		collection: &T::CollectionId,
 		item: &T::ItemId,
@@ -77,7 +78,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	}
 
 	/// Subtract a new `amount` of reserved `item` in `collection` to `who`.
-	fn sub_reserved_balance(
+	pub(crate) fn sub_reserved_balance(
 		who: &T::AccountId,
 		collection: &T::CollectionId,
 		item: &T::ItemId,
@@ -105,6 +106,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		item: &T::ItemId,
 		amount: Amount,
 	) -> Result<(), Error<T, I>> {
+		Self::ensure_transferable(collection, item)?;
 		Self::sub_item_balance(who, collection, item, amount)?;
 		Self::add_reserved_balance(who, collection, item, amount)?;
 		Ok(())
@@ -133,6 +135,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 	///  Move the item reserved item balance of one account into the item balance of another,
 	/// according to `status`.
+	///
+	/// Soulbound items are moved too, they were transferable when they were reserved.
 	pub(crate) fn repatriate_reserved_item(
 		slashed: &T::AccountId,
 		collection: &T::CollectionId,
@@ -174,6 +178,42 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		id
 	}
 
+	/// Give `amount` of `item` in `collection` to `who`.
+	///
+	/// Items with a finite supply are taken from the supply held by the collection owner.
+	pub(crate) fn give_item(
+		who: &T::AccountId,
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+		amount: Amount,
+	) -> DispatchResult {
+		if Self::is_infinite(collection, item) {
+			Self::add_item_balance(who, collection, item, amount)?;
+		} else {
+			let owner =
+				T::Nfts::collection_owner(collection).ok_or(Error::<T, I>::UnknownCollection)?;
+			// soulbound items are handed out from the owner supply as well
+			Self::sub_item_balance(&owner, collection, item, amount)
+				.map_err(|_| Error::<T, I>::ExceedTotalAmount)?;
+			Self::add_item_balance(who, collection, item, amount)?;
+		}
+		Ok(())
+	}
+
+	/// Get the available recipe id and increase the id by 1.
+	pub(crate) fn get_recipe_id() -> T::RecipeId {
+		let id = NextRecipeId::<T, I>::get().unwrap_or(T::RecipeId::initial_value());
+		NextRecipeId::<T, I>::set(Some(id.increment()));
+		id
+	}
+
+	/// Get the available match id and increase the id by 1.
+	pub(crate) fn get_match_id() -> T::MatchId {
+		let id = NextMatchId::<T, I>::get().unwrap_or(T::MatchId::initial_value());
+		NextMatchId::<T, I>::set(Some(id.increment()));
+		id
+	}
+
 	/// Check if `item` in `collection` is in infinite supply.
 	pub(crate) fn is_infinite(collection: &T::CollectionId, item: &T::ItemId) -> bool {
 		SupplyOf::<T, I>::get(collection, item)
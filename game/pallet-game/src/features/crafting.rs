@@ -0,0 +1,204 @@
+/// Crafting module burns the input items of a recipe to produce its output items
+use crate::*;
+use frame_support::pallet_prelude::*;
+use gafi_support::game::{Amount, Bundle, Crafting, GameRole};
+use sp_runtime::Saturating;
+
+impl<T: Config<I>, I: 'static>
+	Crafting<T::AccountId, BalanceOf<T, I>, T::GameId, T::CollectionId, T::ItemId, T::RecipeId>
+	for Pallet<T, I>
+{
+	fn do_create_recipe(
+		recipe: &T::RecipeId,
+		who: &T::AccountId,
+		game: &T::GameId,
+		input: Bundle<T::CollectionId, T::ItemId>,
+		maybe_fee: Option<BalanceOf<T, I>>,
+		output: Bundle<T::CollectionId, T::ItemId>,
+		maybe_crafts: Option<Amount>,
+	) -> DispatchResult {
+		let details = Game::<T, I>::get(game).ok_or(Error::<T, I>::UnknownGame)?;
+		ensure!(!details.is_destroying, Error::<T, I>::GameDestroying);
+
+		Self::ensure_recipe_bundle(game, &input)?;
+		Self::ensure_recipe_bundle(game, &output)?;
+
+		// only the collection admins could produce their items
+		for package in output.iter() {
+			if !T::Nfts::is_admin(&package.collection, who) {
+				Self::ensure_collection_role(who, &package.collection, GameRole::UpgradeDesigner)?;
+			}
+		}
+
+		let input_bundle: BundleFor<T, I> =
+			BoundedVec::try_from(input.clone()).map_err(|_| Error::<T, I>::ExceedMaxBundle)?;
+		let output_bundle: BundleFor<T, I> =
+			BoundedVec::try_from(output.clone()).map_err(|_| Error::<T, I>::ExceedMaxBundle)?;
+
+		let deposit = T::BundleDeposit::get();
+		<T as Config<I>>::Currency::reserve(who, deposit)?;
+
+		// reserve the finite supply outputs of every craft
+		if let Some(crafts) = maybe_crafts {
+			ensure!(crafts > 0, Error::<T, I>::InvalidRecipe);
+		}
+		for package in output.iter() {
+			if Self::is_infinite(&package.collection, &package.item) {
+				continue
+			}
+			let crafts = maybe_crafts.ok_or(Error::<T, I>::InvalidRecipe)?;
+			let amount = package.amount.saturating_mul(crafts);
+			Self::sub_item_balance(who, &package.collection, &package.item, amount)?;
+			Self::add_reserved_balance(who, &package.collection, &package.item, amount)?;
+		}
+
+		RecipeOf::<T, I>::insert(
+			recipe,
+			RecipeDetails {
+				owner: who.clone(),
+				owner_deposit: deposit,
+				game: *game,
+				input: input_bundle,
+				maybe_fee,
+				game_revenue: details.owner == *who || details.admin == *who,
+				output: output_bundle,
+				maybe_crafts,
+			},
+		);
+		Game::<T, I>::mutate(game, |maybe_details| {
+			if let Some(details) = maybe_details {
+				details.recipes.saturating_inc();
+			}
+		});
+
+		Self::deposit_event(Event::<T, I>::RecipeCreated {
+			recipe: *recipe,
+			who: who.clone(),
+			game: *game,
+			input,
+			maybe_fee,
+			output,
+			maybe_crafts,
+		});
+
+		Ok(())
+	}
+
+	fn do_remove_recipe(recipe: &T::RecipeId, who: &T::AccountId) -> DispatchResult {
+		let details = RecipeOf::<T, I>::get(recipe).ok_or(Error::<T, I>::UnknownRecipe)?;
+		// the game owner clears the recipes of a game being destroyed
+		let game_details = Game::<T, I>::get(details.game).ok_or(Error::<T, I>::UnknownGame)?;
+		ensure!(
+			details.owner == *who || (game_details.is_destroying && game_details.owner == *who),
+			Error::<T, I>::NoPermission
+		);
+
+		// release the outputs of the remaining crafts
+		if let Some(crafts) = details.maybe_crafts {
+			for package in details.output.iter() {
+				if Self::is_infinite(&package.collection, &package.item) {
+					continue
+				}
+				Self::unreserved_item(
+					&details.owner,
+					&package.collection,
+					&package.item,
+					package.amount.saturating_mul(crafts),
+				)?;
+			}
+		}
+
+		<T as Config<I>>::Currency::unreserve(&details.owner, details.owner_deposit);
+		RecipeOf::<T, I>::remove(recipe);
+		Game::<T, I>::mutate(details.game, |maybe_details| {
+			if let Some(details) = maybe_details {
+				details.recipes.saturating_dec();
+			}
+		});
+
+		Self::deposit_event(Event::<T, I>::RecipeRemoved { recipe: *recipe });
+		Ok(())
+	}
+
+	fn do_craft(who: &T::AccountId, recipe: &T::RecipeId, times: Amount) -> DispatchResult {
+		ensure!(times > 0, Error::<T, I>::InvalidAmount);
+		let details = RecipeOf::<T, I>::get(recipe).ok_or(Error::<T, I>::UnknownRecipe)?;
+		let game_details = Game::<T, I>::get(details.game).ok_or(Error::<T, I>::UnknownGame)?;
+		ensure!(!game_details.is_destroying, Error::<T, I>::GameDestroying);
+
+		if let Some(crafts) = details.maybe_crafts {
+			ensure!(times <= crafts, Error::<T, I>::ExceedTotalAmount);
+			RecipeOf::<T, I>::mutate(recipe, |maybe_details| {
+				if let Some(details) = maybe_details {
+					details.maybe_crafts = Some(crafts.saturating_sub(times));
+				}
+			});
+		}
+
+		// burn input
+		for package in details.input.iter() {
+			let amount = package.amount.saturating_mul(times);
+			Self::sub_item_balance(who, &package.collection, &package.item, amount)?;
+			Self::decrease_finite_item_supply(&package.collection, &package.item, amount);
+		}
+
+		if let Some(fee) = details.maybe_fee {
+			let receiver = match details.game_revenue {
+				true => Self::revenue_account(&details.game, details.owner.clone()),
+				false => details.owner.clone(),
+			};
+			Self::pay(
+				Self::game_payment_asset(&details.game),
+				who,
+				&receiver,
+				fee.saturating_mul(times.into()),
+			)?;
+		}
+
+		// produce output
+		for package in details.output.iter() {
+			let amount = package.amount.saturating_mul(times);
+			if !Self::is_infinite(&package.collection, &package.item) {
+				Self::sub_reserved_balance(
+					&details.owner,
+					&package.collection,
+					&package.item,
+					amount,
+				)?;
+			}
+			Self::add_item_balance(who, &package.collection, &package.item, amount)?;
+		}
+
+		Self::deposit_event(Event::<T, I>::Crafted {
+			who: who.clone(),
+			recipe: *recipe,
+			times,
+		});
+
+		Ok(())
+	}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Ensure `bundle` is not empty and only holds existing items of `game`.
+	fn ensure_recipe_bundle(
+		game: &T::GameId,
+		bundle: &Bundle<T::CollectionId, T::ItemId>,
+	) -> DispatchResult {
+		ensure!(!bundle.is_empty(), Error::<T, I>::InvalidRecipe);
+
+		let collections = CollectionsOf::<T, I>::get(game);
+		for package in bundle.iter() {
+			ensure!(package.amount > 0, Error::<T, I>::InvalidRecipe);
+			ensure!(
+				collections.contains(&package.collection),
+				Error::<T, I>::InvalidRecipe
+			);
+			ensure!(
+				SupplyOf::<T, I>::contains_key(package.collection, package.item),
+				Error::<T, I>::UnknownItem
+			);
+		}
+		Ok(())
+	}
+}
@@ -1,6 +1,6 @@
 use crate::*;
 use frame_support::pallet_prelude::*;
-use gafi_support::game::{Amount, CreateItem};
+use gafi_support::game::{Amount, CreateItem, GameRole};
 impl<T: Config<I>, I: 'static> CreateItem<T::AccountId, T::CollectionId, T::ItemId, ItemConfig>
 	for Pallet<T, I>
 {
@@ -12,7 +12,9 @@ impl<T: Config<I>, I: 'static> CreateItem<T::AccountId, T::CollectionId, T::Item
 	) -> DispatchResult {
 		if let Some(collection_owner) = T::Nfts::collection_owner(collection) {
 			ensure!(
-				T::Nfts::is_admin(collection, who) | T::Nfts::is_issuer(collection, who),
+				T::Nfts::is_admin(collection, who) |
+					T::Nfts::is_issuer(collection, who) |
+					Self::has_collection_role(collection, who, GameRole::Minter),
 				Error::<T, I>::NoPermission
 			);
 
@@ -60,7 +62,9 @@ impl<T: Config<I>, I: 'static> CreateItem<T::AccountId, T::CollectionId, T::Item
 		// Ensure the caller has the required permission
 		if let Some(collection_owner) = T::Nfts::collection_owner(collection) {
 			ensure!(
-				T::Nfts::is_admin(collection, who) || T::Nfts::is_issuer(collection, who),
+				T::Nfts::is_admin(collection, who) ||
+					T::Nfts::is_issuer(collection, who) ||
+					Self::has_collection_role(collection, who, GameRole::Minter),
 				Error::<T, I>::NoPermission
 			);
 
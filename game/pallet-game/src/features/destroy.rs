@@ -0,0 +1,121 @@
+use crate::*;
+use frame_support::{pallet_prelude::*, traits::ExistenceRequirement};
+use gafi_support::game::Destroy;
+use sp_runtime::Saturating;
+
+impl<T: Config<I>, I: 'static> Destroy<T::AccountId, T::GameId, GameDestroyWitness>
+	for Pallet<T, I>
+{
+	fn do_start_game_destroy(who: &T::AccountId, game: &T::GameId) -> DispatchResult {
+		Game::<T, I>::try_mutate(game, |maybe_details| -> DispatchResult {
+			let details = maybe_details.as_mut().ok_or(Error::<T, I>::UnknownGame)?;
+			ensure!(details.owner == *who, Error::<T, I>::NoPermission);
+			ensure!(!details.is_destroying, Error::<T, I>::GameDestroying);
+
+			details.is_destroying = true;
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::<T, I>::GameDestructionStarted { game: *game });
+		Ok(())
+	}
+
+	fn do_destroy_game_roles(
+		game: &T::GameId,
+		max_roles: u32,
+	) -> Result<u32, sp_runtime::DispatchError> {
+		let details = Game::<T, I>::get(game).ok_or(Error::<T, I>::UnknownGame)?;
+		ensure!(details.is_destroying, Error::<T, I>::GameNotDestroying);
+
+		let mut roles_destroyed =
+			GameRoleOf::<T, I>::drain_prefix(game).take(max_roles as usize).count() as u32;
+		// granted roles and pending acceptances are destroyed within the same limit
+		roles_destroyed.saturating_accrue(
+			GrantedRoleOf::<T, I>::drain_prefix((game,))
+				.take(max_roles.saturating_sub(roles_destroyed) as usize)
+				.count() as u32,
+		);
+		for (collection, (depositor, deposit)) in AddingAcceptanceOf::<T, I>::drain_prefix(game)
+			.take(max_roles.saturating_sub(roles_destroyed) as usize)
+		{
+			AddingAcceptance::<T, I>::remove(collection);
+			<T as Config<I>>::Currency::unreserve(&depositor, deposit);
+			roles_destroyed.saturating_inc();
+		}
+		for (who, _) in OwnershipAcceptorOf::<T, I>::drain_prefix(game)
+			.take(max_roles.saturating_sub(roles_destroyed) as usize)
+		{
+			GameOwnershipAcceptance::<T, I>::remove(who);
+			roles_destroyed.saturating_inc();
+		}
+
+		Self::deposit_event(Event::<T, I>::GameRolesDestroyed {
+			game: *game,
+			roles_destroyed,
+		});
+		Ok(roles_destroyed)
+	}
+
+	fn do_finish_game_destroy(game: &T::GameId, witness: &GameDestroyWitness) -> DispatchResult {
+		let details = Game::<T, I>::get(game).ok_or(Error::<T, I>::UnknownGame)?;
+		ensure!(details.is_destroying, Error::<T, I>::GameNotDestroying);
+		ensure!(
+			details.destroy_witness() == *witness,
+			Error::<T, I>::BadWitness
+		);
+		// the weight is charged for the witnessed collections only
+		ensure!(
+			CollectionsOf::<T, I>::decode_len(game).unwrap_or(0) as u32 <= witness.collections,
+			Error::<T, I>::BadWitness
+		);
+		ensure!(
+			GameRoleOf::<T, I>::iter_prefix(game).next().is_none() &&
+				GrantedRoleOf::<T, I>::iter_prefix((game,)).next().is_none() &&
+				AddingAcceptanceOf::<T, I>::iter_prefix(game).next().is_none() &&
+				OwnershipAcceptorOf::<T, I>::iter_prefix(game).next().is_none(),
+			Error::<T, I>::RolesRemaining
+		);
+		ensure!(details.recipes == 0, Error::<T, I>::RecipesRemaining);
+
+		// assets left in the treasury must be spent by the owner, its native balance is returned
+		let treasury = Self::game_account(game);
+		ensure!(
+			frame_system::Pallet::<T>::consumers(&treasury) == 0 &&
+				frame_system::Pallet::<T>::sufficients(&treasury) == 0,
+			Error::<T, I>::TreasuryNotEmpty
+		);
+		<T as Config<I>>::Currency::transfer(
+			&treasury,
+			&details.owner,
+			<T as Config<I>>::Currency::free_balance(&treasury),
+			ExistenceRequirement::AllowDeath,
+		)?;
+		if frame_system::Pallet::<T>::providers(&treasury) > 0 {
+			let _ = frame_system::Pallet::<T>::dec_providers(&treasury);
+		}
+
+		// release collections
+		for collection in CollectionsOf::<T, I>::take(game) {
+			GamesOf::<T, I>::mutate_exists(collection, |maybe_games| {
+				if let Some(games) = maybe_games {
+					games.retain(|id| id != game);
+					if games.is_empty() {
+						*maybe_games = None;
+					}
+				}
+			});
+			if CollectionGameOf::<T, I>::get(collection) == Some(*game) {
+				CollectionGameOf::<T, I>::remove(collection);
+			}
+		}
+
+		GameMetadataOf::<T, I>::remove(game);
+		GameAccount::<T, I>::remove(&details.owner, game);
+		Game::<T, I>::remove(game);
+
+		<T as Config<I>>::Currency::unreserve(&details.owner, details.owner_deposit);
+
+		Self::deposit_event(Event::<T, I>::GameDestroyed { game: *game });
+		Ok(())
+	}
+}
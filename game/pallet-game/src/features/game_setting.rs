@@ -1,6 +1,8 @@
 use crate::*;
-use frame_support::pallet_prelude::*;
+use frame_support::{pallet_prelude::*, traits::BalanceStatus};
+use frame_system::RawOrigin;
 use pallet_nfts::{CollectionRole, CollectionRoles};
+use sp_runtime::traits::Zero;
 
 impl<T: Config<I>, I: 'static> GameSetting<T::AccountId, T::GameId, T::StringLimit>
 	for Pallet<T, I>
@@ -13,9 +15,12 @@ impl<T: Config<I>, I: 'static> GameSetting<T::AccountId, T::GameId, T::StringLim
 		let game_details = Game::<T, I>::get(game).ok_or(Error::<T, I>::UnknownGame)?;
 
 		ensure!(
-			game_details.admin == origin || game_details.owner == origin,
+			game_details.admin == origin ||
+				game_details.owner == origin ||
+				Self::has_game_role(&game, &origin, GameRole::Moderator),
 			Error::<T, I>::NoPermission
 		);
+		ensure!(!game_details.is_destroying, Error::<T, I>::GameDestroying);
 
 		GameMetadataOf::<T, I>::try_mutate_exists(game, |metadata| {
 			*metadata = Some(GameMetadata { data: data.clone() });
@@ -32,7 +37,9 @@ impl<T: Config<I>, I: 'static> GameSetting<T::AccountId, T::GameId, T::StringLim
 		let game_details = Game::<T, I>::get(game).ok_or(Error::<T, I>::UnknownGame)?;
 
 		ensure!(
-			game_details.admin == origin || game_details.owner == origin,
+			game_details.admin == origin ||
+				game_details.owner == origin ||
+				Self::has_game_role(&game, &origin, GameRole::Moderator),
 			Error::<T, I>::NoPermission
 		);
 
@@ -59,6 +66,12 @@ impl<T: Config<I>, I: 'static> GameSetting<T::AccountId, T::GameId, T::StringLim
 			collections: 0,
 			owner_deposit: T::GameDeposit::get(),
 			admin: admin.clone(),
+			is_destroying: false,
+			trade_fee: 0,
+			fee_account: None,
+			payment_asset: None,
+			treasury_routing: false,
+			recipes: 0,
 		};
 
 		GameRoleOf::<T, I>::insert(
@@ -77,4 +90,227 @@ impl<T: Config<I>, I: 'static> GameSetting<T::AccountId, T::GameId, T::StringLim
 		});
 		Ok(())
 	}
+
+	fn do_set_trade_fee(
+		who: &T::AccountId,
+		game: &T::GameId,
+		fee: u16,
+		fee_account: Option<T::AccountId>,
+	) -> DispatchResult {
+		ensure!(fee <= T::MaxTradeFee::get(), Error::<T, I>::TradeFeeTooHigh);
+
+		Game::<T, I>::try_mutate(game, |maybe_details| -> DispatchResult {
+			let details = maybe_details.as_mut().ok_or(Error::<T, I>::UnknownGame)?;
+			// a trade operator only adjusts the fee paid to the current fee account
+			ensure!(
+				details.owner == *who ||
+					(details.fee_account == fee_account &&
+						Self::has_game_role(game, who, GameRole::TradeOperator)),
+				Error::<T, I>::NoPermission
+			);
+			ensure!(!details.is_destroying, Error::<T, I>::GameDestroying);
+
+			details.trade_fee = fee;
+			details.fee_account = fee_account.clone();
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::<T, I>::TradeFeeSet {
+			game: *game,
+			fee,
+			fee_account,
+		});
+		Ok(())
+	}
+}
+
+impl<T: Config<I>, I: 'static> PaymentAsset<T::AccountId, T::GameId, T::AssetId> for Pallet<T, I> {
+	fn do_set_payment_asset(
+		who: &T::AccountId,
+		game: &T::GameId,
+		maybe_asset: Option<T::AssetId>,
+	) -> DispatchResult {
+		Game::<T, I>::try_mutate(game, |maybe_details| -> DispatchResult {
+			let details = maybe_details.as_mut().ok_or(Error::<T, I>::UnknownGame)?;
+			ensure!(details.owner == *who, Error::<T, I>::NoPermission);
+			ensure!(!details.is_destroying, Error::<T, I>::GameDestroying);
+			if let Some(asset) = &maybe_asset {
+				Self::ensure_asset(asset)?;
+			}
+
+			details.payment_asset = maybe_asset;
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::<T, I>::PaymentAssetSet {
+			game: *game,
+			maybe_asset,
+		});
+		Ok(())
+	}
+}
+
+impl<T: Config<I>, I: 'static> GameOwnership<T::AccountId, T::GameId> for Pallet<T, I> {
+	fn do_set_accept_game_ownership(
+		who: &T::AccountId,
+		maybe_game: Option<T::GameId>,
+	) -> DispatchResult {
+		if let Some(game) = maybe_game {
+			let details = Game::<T, I>::get(game).ok_or(Error::<T, I>::UnknownGame)?;
+			ensure!(!details.is_destroying, Error::<T, I>::GameDestroying);
+		}
+
+		if let Some(previous) = GameOwnershipAcceptance::<T, I>::take(who) {
+			OwnershipAcceptorOf::<T, I>::remove(previous, who);
+		}
+		if let Some(game) = maybe_game {
+			GameOwnershipAcceptance::<T, I>::insert(who, game);
+			OwnershipAcceptorOf::<T, I>::insert(game, who, ());
+		}
+
+		Self::deposit_event(Event::<T, I>::GameOwnershipAcceptanceChanged {
+			who: who.clone(),
+			maybe_game,
+		});
+		Ok(())
+	}
+
+	fn do_transfer_game_ownership(
+		who: &T::AccountId,
+		game: &T::GameId,
+		new_owner: &T::AccountId,
+	) -> DispatchResult {
+		ensure!(
+			GameOwnershipAcceptance::<T, I>::get(new_owner) == Some(*game),
+			Error::<T, I>::UnknownAcceptance
+		);
+
+		Game::<T, I>::try_mutate(game, |maybe_details| -> DispatchResult {
+			let details = maybe_details.as_mut().ok_or(Error::<T, I>::UnknownGame)?;
+			ensure!(details.owner == *who, Error::<T, I>::NoPermission);
+			ensure!(!details.is_destroying, Error::<T, I>::GameDestroying);
+
+			// move the game deposit
+			let unmoved = <T as Config<I>>::Currency::repatriate_reserved(
+				&details.owner,
+				new_owner,
+				details.owner_deposit,
+				BalanceStatus::Reserved,
+			)?;
+			ensure!(
+				unmoved.is_zero(),
+				Error::<T, I>::InsufficientReservedBalance
+			);
+
+			Self::transfer_collections(game, &details.owner, new_owner)?;
+
+			GameAccount::<T, I>::remove(&details.owner, game);
+			GameAccount::<T, I>::insert(new_owner, game, ());
+			details.owner = new_owner.clone();
+			Ok(())
+		})?;
+		GameOwnershipAcceptance::<T, I>::remove(new_owner);
+		OwnershipAcceptorOf::<T, I>::remove(game, new_owner);
+
+		Self::deposit_event(Event::<T, I>::GameOwnershipTransferred {
+			game: *game,
+			new_owner: new_owner.clone(),
+		});
+		Ok(())
+	}
+
+	fn do_set_game_admin(
+		who: &T::AccountId,
+		game: &T::GameId,
+		admin: &T::AccountId,
+	) -> DispatchResult {
+		Game::<T, I>::try_mutate(game, |maybe_details| -> DispatchResult {
+			let details = maybe_details.as_mut().ok_or(Error::<T, I>::UnknownGame)?;
+			ensure!(details.owner == *who, Error::<T, I>::NoPermission);
+			ensure!(!details.is_destroying, Error::<T, I>::GameDestroying);
+
+			// move the roles of the previous admin
+			let roles = GameRoleOf::<T, I>::take(game, &details.admin).unwrap_or(CollectionRoles(
+				CollectionRole::Admin | CollectionRole::Freezer | CollectionRole::Issuer,
+			));
+			GameRoleOf::<T, I>::insert(game, admin, roles);
+			Self::rotate_collection_team(game, &details.owner, &details.admin, admin)?;
+			details.admin = admin.clone();
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::<T, I>::GameAdminSet {
+			game: *game,
+			admin: admin.clone(),
+		});
+		Ok(())
+	}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Whether `who` is the owner or the admin of `game`, and the game is not being destroyed.
+	pub fn is_game_admin(game: &T::GameId, who: &T::AccountId) -> bool {
+		Game::<T, I>::get(game).map_or(false, |details| {
+			!details.is_destroying && (details.owner == *who || details.admin == *who)
+		})
+	}
+
+	/// Whether `collection` belongs to `game`.
+	pub fn is_game_collection(game: &T::GameId, collection: &T::CollectionId) -> bool {
+		CollectionsOf::<T, I>::get(game).contains(collection)
+	}
+
+	/// Transfer the collections of `game` owned by `owner` to `new_owner`.
+	///
+	/// Replaces any collection ownership acceptance of `new_owner` in pallet-nfts.
+	fn transfer_collections(
+		game: &T::GameId,
+		owner: &T::AccountId,
+		new_owner: &T::AccountId,
+	) -> DispatchResult {
+		if owner == new_owner {
+			return Ok(())
+		}
+		for collection in CollectionsOf::<T, I>::get(game) {
+			if T::Nfts::collection_owner(&collection).as_ref() != Some(owner) {
+				continue
+			}
+			pallet_nfts::Pallet::<T>::set_accept_ownership(
+				RawOrigin::Signed(new_owner.clone()).into(),
+				Some(collection),
+			)?;
+			pallet_nfts::Pallet::<T>::transfer_ownership(
+				RawOrigin::Signed(owner.clone()).into(),
+				collection,
+				T::Lookup::unlookup(new_owner.clone()),
+			)?;
+		}
+		Ok(())
+	}
+
+	/// Hand the pallet-nfts team of the collections of `game` owned by `owner` from
+	/// `old_admin` to `admin`.
+	fn rotate_collection_team(
+		game: &T::GameId,
+		owner: &T::AccountId,
+		old_admin: &T::AccountId,
+		admin: &T::AccountId,
+	) -> DispatchResult {
+		for collection in CollectionsOf::<T, I>::get(game) {
+			if T::Nfts::collection_owner(&collection).as_ref() != Some(owner) ||
+				!T::Nfts::is_admin(&collection, old_admin)
+			{
+				continue
+			}
+			let admin = T::Lookup::unlookup(admin.clone());
+			pallet_nfts::Pallet::<T>::set_team(
+				RawOrigin::Signed(owner.clone()).into(),
+				collection,
+				Some(admin.clone()),
+				Some(admin.clone()),
+				Some(admin),
+			)?;
+		}
+		Ok(())
+	}
 }
@@ -1,5 +1,5 @@
 use crate::*;
-use gafi_support::game::{LootTable, NFT};
+use gafi_support::game::{LootTable, Tier, NFT};
 use sp_runtime::Saturating;
 
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
@@ -23,19 +23,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		table: &LootTable<T::CollectionId, T::ItemId>,
 		random: u32,
 	) -> Option<Option<NFT<T::CollectionId, T::ItemId>>> {
-		let mut positon = random;
-		let mut i = 0;
-
-		while i < table.len() && positon > table[i].weight {
-			positon -= table[i].weight;
-			i.saturating_inc();
-		}
-
-		if i < table.len() {
-			Some(table[i].clone().maybe_nft)
-		} else {
-			None
-		}
+		Self::loot_position(table, random, None).map(|i| table[i].clone().maybe_nft)
 	}
 
 	/// Takes loot from the provided `table` based on the given `random` position.
@@ -61,20 +49,40 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		table: &mut LootTable<T::CollectionId, T::ItemId>,
 		random: u32,
 	) -> Option<Option<NFT<T::CollectionId, T::ItemId>>> {
+		Self::loot_position(table, random, None).map(|i| {
+			table[i].weight.saturating_dec();
+			table[i].clone().maybe_nft
+		})
+	}
+
+	/// Index in `table` of the loot at the `random` position.
+	///
+	/// If `maybe_tier` is set, only the weights of the loots of that tier are counted.
+	pub(crate) fn loot_position(
+		table: &LootTable<T::CollectionId, T::ItemId>,
+		random: u32,
+		maybe_tier: Option<Tier>,
+	) -> Option<usize> {
 		let mut positon = random;
-		let mut i = 0;
 
-		while i < table.len() && positon > table[i].weight {
-			positon -= table[i].weight;
-			i.saturating_inc();
+		for (i, loot) in table.iter().enumerate() {
+			if maybe_tier.is_some() && loot.tier != maybe_tier {
+				continue
+			}
+			if positon <= loot.weight {
+				return Some(i)
+			}
+			positon -= loot.weight;
 		}
+		None
+	}
 
-		if i < table.len() {
-			table[i].weight.saturating_dec();
-			Some(table[i].clone().maybe_nft)
-		} else {
-			None
-		}
+	/// Total weight of the loots of `tier` in `table`.
+	pub(crate) fn tier_weight(table: &LootTable<T::CollectionId, T::ItemId>, tier: Tier) -> u32 {
+		table
+			.iter()
+			.filter(|loot| loot.tier == Some(tier))
+			.fold(0, |weight, loot| weight.saturating_add(loot.weight))
 	}
 }
 
@@ -94,6 +102,7 @@ fn get_loot_should_works() {
 					item: 0,
 				}),
 				weight: 200,
+				tier: None,
 			},
 			Loot {
 				maybe_nft: Some(NFT {
@@ -101,6 +110,7 @@ fn get_loot_should_works() {
 					item: 1,
 				}),
 				weight: 200,
+				tier: None,
 			},
 			Loot {
 				maybe_nft: Some(NFT {
@@ -108,6 +118,7 @@ fn get_loot_should_works() {
 					item: 2,
 				}),
 				weight: 200,
+				tier: None,
 			},
 		]
 		.to_vec();
@@ -155,6 +166,7 @@ fn take_loot_should_works() {
 					item: 0,
 				}),
 				weight: 200,
+				tier: None,
 			},
 			Loot {
 				maybe_nft: Some(NFT {
@@ -162,6 +174,7 @@ fn take_loot_should_works() {
 					item: 1,
 				}),
 				weight: 200,
+				tier: None,
 			},
 			Loot {
 				maybe_nft: Some(NFT {
@@ -169,6 +182,7 @@ fn take_loot_should_works() {
 					item: 2,
 				}),
 				weight: 200,
+				tier: None,
 			},
 		]
 		.to_vec();
@@ -0,0 +1,253 @@
+/// Match escrow module holds the stakes and items of players until a game server settles the
+/// match
+use crate::{features::settlement::BASIS_POINTS, *};
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::BlockNumberFor;
+use gafi_support::game::{Bundle, GameRole, MatchEscrow, MatchResult};
+use sp_runtime::{traits::Zero, Saturating};
+
+impl<T: Config<I>, I: 'static>
+	MatchEscrow<
+		T::AccountId,
+		BalanceOf<T, I>,
+		T::GameId,
+		T::MatchId,
+		T::CollectionId,
+		T::ItemId,
+		BlockNumberFor<T>,
+	> for Pallet<T, I>
+{
+	fn do_create_match(
+		match_id: &T::MatchId,
+		who: &T::AccountId,
+		game: &T::GameId,
+		stake: BalanceOf<T, I>,
+		maybe_server: Option<T::AccountId>,
+		end_block: BlockNumberFor<T>,
+	) -> DispatchResult {
+		let details = Game::<T, I>::get(game).ok_or(Error::<T, I>::UnknownGame)?;
+		ensure!(
+			details.admin == *who ||
+				details.owner == *who ||
+				Self::has_game_role(game, who, GameRole::Moderator),
+			Error::<T, I>::NoPermission
+		);
+		ensure!(!details.is_destroying, Error::<T, I>::GameDestroying);
+		ensure!(
+			end_block > <frame_system::Pallet<T>>::block_number(),
+			Error::<T, I>::MatchEnded
+		);
+
+		let deposit = T::BundleDeposit::get();
+		<T as Config<I>>::Currency::reserve(who, deposit)?;
+
+		MatchOf::<T, I>::insert(
+			match_id,
+			MatchDetails {
+				owner: who.clone(),
+				owner_deposit: deposit,
+				game: *game,
+				stake,
+				maybe_asset: Self::game_payment_asset(game),
+				maybe_server: maybe_server.clone(),
+				end_block,
+				players: BoundedVec::default(),
+			},
+		);
+
+		Self::deposit_event(Event::<T, I>::MatchCreated {
+			match_id: *match_id,
+			who: who.clone(),
+			game: *game,
+			stake,
+			maybe_server,
+			end_block,
+		});
+
+		Ok(())
+	}
+
+	fn do_join_match(
+		match_id: &T::MatchId,
+		who: &T::AccountId,
+		items: Bundle<T::CollectionId, T::ItemId>,
+	) -> DispatchResult {
+		let mut details = MatchOf::<T, I>::get(match_id).ok_or(Error::<T, I>::UnknownMatch)?;
+		ensure!(
+			<frame_system::Pallet<T>>::block_number() < details.end_block,
+			Error::<T, I>::MatchEnded
+		);
+		ensure!(
+			!details.players.iter().any(|(player, _)| player == who),
+			Error::<T, I>::AlreadyJoined
+		);
+
+		// only the items of the game can be staked
+		let collections = CollectionsOf::<T, I>::get(details.game);
+		for package in items.iter() {
+			ensure!(
+				collections.contains(&package.collection),
+				Error::<T, I>::IncorrectCollection
+			);
+		}
+		let bundle: BundleFor<T, I> =
+			BoundedVec::try_from(items.clone()).map_err(|_| Error::<T, I>::ExceedMaxBundle)?;
+		details
+			.players
+			.try_push((who.clone(), bundle))
+			.map_err(|_| Error::<T, I>::MatchFull)?;
+
+		// lock stake and items
+		if !details.stake.is_zero() {
+			Self::reserve_payment(details.maybe_asset, who, details.stake)?;
+		}
+		for package in items.iter() {
+			Self::reserved_item(who, &package.collection, &package.item, package.amount)?;
+		}
+
+		MatchOf::<T, I>::insert(match_id, details);
+
+		Self::deposit_event(Event::<T, I>::MatchJoined {
+			match_id: *match_id,
+			who: who.clone(),
+			items,
+		});
+
+		Ok(())
+	}
+
+	fn do_settle_match(
+		match_id: &T::MatchId,
+		who: &T::AccountId,
+		result: MatchResult<T::AccountId>,
+	) -> DispatchResult {
+		let details = MatchOf::<T, I>::get(match_id).ok_or(Error::<T, I>::UnknownMatch)?;
+		let game = Game::<T, I>::get(details.game).ok_or(Error::<T, I>::UnknownGame)?;
+		ensure!(
+			game.admin == *who ||
+				game.owner == *who ||
+				details.maybe_server.as_ref() == Some(who) ||
+				Self::has_game_role(&details.game, who, GameRole::Moderator),
+			Error::<T, I>::NoPermission
+		);
+		ensure!(
+			<frame_system::Pallet<T>>::block_number() < details.end_block,
+			Error::<T, I>::MatchEnded
+		);
+
+		// validate result
+		let is_player =
+			|account: &T::AccountId| details.players.iter().any(|(player, _)| player == account);
+		if !result.winners.is_empty() {
+			let total_shares = result.winners.iter().fold(0u32, |total, (_, share)| {
+				total.saturating_add((*share).into())
+			});
+			ensure!(
+				total_shares == u32::from(BASIS_POINTS),
+				Error::<T, I>::InvalidMatchResult
+			);
+		}
+		for (winner, _) in result.winners.iter() {
+			ensure!(is_player(winner), Error::<T, I>::InvalidMatchResult);
+		}
+		for (loser, maybe_receiver) in result.losers.iter() {
+			ensure!(is_player(loser), Error::<T, I>::InvalidMatchResult);
+			if let Some(receiver) = maybe_receiver {
+				ensure!(is_player(receiver), Error::<T, I>::InvalidMatchResult);
+			}
+		}
+
+		let maybe_asset = details.maybe_asset;
+		for (player, items) in details.players.iter() {
+			// share the stake among the winners, the last winner takes the rounding
+			if !details.stake.is_zero() {
+				match result.winners.split_last() {
+					Some(((last, _), winners)) => {
+						let mut remaining = details.stake;
+						for (winner, share) in winners.iter() {
+							let amount = Self::basis_points(details.stake, *share);
+							Self::pay_reserved(maybe_asset, player, winner, amount)?;
+							remaining = remaining.saturating_sub(amount);
+						}
+						Self::pay_reserved(maybe_asset, player, last, remaining)?;
+					},
+					None => Self::unreserve_payment(maybe_asset, player, details.stake)?,
+				}
+			}
+
+			let maybe_loss = result
+				.losers
+				.iter()
+				.find(|(loser, _)| loser == player)
+				.map(|(_, maybe_receiver)| maybe_receiver);
+			for package in items.iter() {
+				match maybe_loss {
+					Some(Some(receiver)) => Self::repatriate_reserved_item(
+						player,
+						&package.collection,
+						&package.item,
+						receiver,
+						package.amount,
+						ItemBalanceStatus::Free,
+					)?,
+					Some(None) => {
+						Self::sub_reserved_balance(
+							player,
+							&package.collection,
+							&package.item,
+							package.amount,
+						)?;
+						Self::decrease_finite_item_supply(
+							&package.collection,
+							&package.item,
+							package.amount,
+						);
+					},
+					None => Self::unreserved_item(
+						player,
+						&package.collection,
+						&package.item,
+						package.amount,
+					)?,
+				}
+			}
+		}
+
+		<T as Config<I>>::Currency::unreserve(&details.owner, details.owner_deposit);
+		MatchOf::<T, I>::remove(match_id);
+
+		Self::deposit_event(Event::<T, I>::MatchSettled {
+			match_id: *match_id,
+			result,
+		});
+
+		Ok(())
+	}
+
+	fn do_refund_match(match_id: &T::MatchId) -> DispatchResult {
+		let details = MatchOf::<T, I>::get(match_id).ok_or(Error::<T, I>::UnknownMatch)?;
+		ensure!(
+			<frame_system::Pallet<T>>::block_number() >= details.end_block,
+			Error::<T, I>::MatchNotEnded
+		);
+
+		let maybe_asset = details.maybe_asset;
+		for (player, items) in details.players.iter() {
+			if !details.stake.is_zero() {
+				Self::unreserve_payment(maybe_asset, player, details.stake)?;
+			}
+			for package in items.iter() {
+				Self::unreserved_item(player, &package.collection, &package.item, package.amount)?;
+			}
+		}
+
+		<T as Config<I>>::Currency::unreserve(&details.owner, details.owner_deposit);
+		MatchOf::<T, I>::remove(match_id);
+
+		Self::deposit_event(Event::<T, I>::MatchRefunded {
+			match_id: *match_id,
+		});
+
+		Ok(())
+	}
+}
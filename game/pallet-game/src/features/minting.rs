@@ -1,7 +1,16 @@
 use crate::*;
-use frame_support::{pallet_prelude::*, StorageNMap};
+use frame_support::{pallet_prelude::*, traits::PalletInfoAccess, StorageNMap};
 use frame_system::pallet_prelude::BlockNumberFor;
-use sp_runtime::Saturating;
+use gafi_support::game::{GameRole, MintProof, MintVoucher, Pity};
+use sp_core::H256;
+use sp_io::hashing::blake2_256;
+use sp_runtime::{
+	traits::{Verify, Zero},
+	Saturating,
+};
+
+/// Maximum depth of the merkle proof of a mint allowlist.
+const MAX_PROOF_DEPTH: usize = 32;
 
 impl<T: Config<I>, I: 'static>
 	Mining<
@@ -12,6 +21,7 @@ impl<T: Config<I>, I: 'static>
 		T::PoolId,
 		BlockNumberFor<T>,
 		T::StringLimit,
+		T::OffchainSignature,
 	> for Pallet<T, I>
 {
 	fn do_set_pool_metadata(
@@ -21,7 +31,9 @@ impl<T: Config<I>, I: 'static>
 	) -> DispatchResult {
 		let pool_details = PoolOf::<T, I>::get(pool).ok_or(Error::<T, I>::UnknownMiningPool)?;
 		ensure!(
-			pool_details.admin == origin || pool_details.owner == origin,
+			pool_details.admin == origin ||
+				pool_details.owner == origin ||
+				Self::has_pool_role(&pool, &origin, GameRole::PoolManager),
 			Error::<T, I>::NoPermission
 		);
 
@@ -39,7 +51,11 @@ impl<T: Config<I>, I: 'static>
 	fn do_clear_pool_metadata(origin: T::AccountId, pool: T::PoolId) -> DispatchResult {
 		let pool_details = PoolOf::<T, I>::get(pool).ok_or(Error::<T, I>::UnknownMiningPool)?;
 
-		ensure!(pool_details.admin == origin, Error::<T, I>::NoPermission);
+		ensure!(
+			pool_details.admin == origin ||
+				Self::has_pool_role(&pool, &origin, GameRole::PoolManager),
+			Error::<T, I>::NoPermission
+		);
 
 		let _metadata =
 			PoolMetadataOf::<T, I>::take(pool).ok_or(Error::<T, I>::MetadataNotFound)?;
@@ -57,13 +73,14 @@ impl<T: Config<I>, I: 'static>
 		who: &T::AccountId,
 		loot_table: LootTable<T::CollectionId, T::ItemId>,
 		admin: &T::AccountId,
-		mint_settings: MintSettings<BalanceOf<T, I>, BlockNumberFor<T>, T::CollectionId>,
+		mint_settings: MintSettingsFor<T, I>,
 	) -> DispatchResult {
 		// ensure pool is available
 		ensure!(
 			PoolOf::<T, I>::get(pool).is_none(),
 			Error::<T, I>::PoolIdInUse
 		);
+		Self::ensure_pity(&loot_table, &mint_settings)?;
 
 		// Deposit balance
 		<T as Config<I>>::Currency::reserve(&who, T::MiningPoolDeposit::get())?;
@@ -71,13 +88,16 @@ impl<T: Config<I>, I: 'static>
 		// reserve resource
 		for loot in &loot_table {
 			if let Some(nft) = &loot.maybe_nft {
-				Self::reserved_item(who, &nft.collection, &nft.item, loot.weight)?;
+				Self::ensure_poolable(who, &nft.collection, &nft.item)?;
+				Self::sub_item_balance(who, &nft.collection, &nft.item, loot.weight)?;
+				Self::add_reserved_balance(who, &nft.collection, &nft.item, loot.weight)?;
 			}
 		}
 
 		let table = LootTableFor::<T, I>::try_from(loot_table.clone())
 			.map_err(|_| Error::<T, I>::ExceedMaxLoot)?;
 		LootTableOf::<T, I>::insert(pool, table);
+		Self::set_pool_treasury(pool, who);
 
 		// create new pool
 		let pool_details = PoolDetails {
@@ -105,12 +125,14 @@ impl<T: Config<I>, I: 'static>
 		who: &T::AccountId,
 		loot_table: LootTable<T::CollectionId, T::ItemId>,
 		admin: &T::AccountId,
-		mint_settings: MintSettings<BalanceOf<T, I>, BlockNumberFor<T>, T::CollectionId>,
+		mint_settings: MintSettingsFor<T, I>,
 	) -> DispatchResult {
+		Self::ensure_pity(&loot_table, &mint_settings)?;
+
 		// ensure collection owner & infinite supply
 		for fraction in &loot_table {
 			if let Some(nft) = &fraction.maybe_nft {
-				Self::ensure_collection_owner(who, &nft.collection)?;
+				Self::ensure_collection_role(who, &nft.collection, GameRole::PoolManager)?;
 				ensure!(
 					Self::is_infinite(&nft.collection, &nft.item),
 					Error::<T, I>::NotInfiniteSupply
@@ -125,6 +147,7 @@ impl<T: Config<I>, I: 'static>
 			.map_err(|_| Error::<T, I>::ExceedMaxLoot)?;
 
 		LootTableOf::<T, I>::insert(pool, table);
+		Self::set_pool_treasury(pool, who);
 
 		let pool_details = PoolDetails {
 			pool_type: PoolType::Stable,
@@ -149,6 +172,7 @@ impl<T: Config<I>, I: 'static>
 		who: &T::AccountId,
 		target: &T::AccountId,
 		amount: Amount,
+		maybe_proof: Option<MintProofFor<T>>,
 	) -> DispatchResult {
 		if let Some(pool_details) = PoolOf::<T, I>::get(pool) {
 			// verify mint settings
@@ -164,28 +188,53 @@ impl<T: Config<I>, I: 'static>
 				amount <= T::MaxMintItem::get(),
 				Error::<T, I>::ExceedAllowedAmount
 			);
-			match mint_settings.mint_type {
+			let mut maybe_allowance = None;
+			let mut maybe_nonce = None;
+			match &mint_settings.mint_type {
 				MintType::HolderOf(collection) => {
 					ensure!(
 						ItemBalanceOf::<T, I>::contains_prefix((who.clone(), collection,)),
 						Error::<T, I>::NotWhitelisted
 					);
 				},
+				MintType::MerkleAllowlist(root) => {
+					maybe_allowance = Some(Self::verify_allowlist(root, who, maybe_proof)?);
+				},
+				MintType::SignedVoucher(signer) => {
+					maybe_nonce = Some(Self::use_voucher(
+						pool,
+						signer,
+						who,
+						amount,
+						maybe_proof,
+						block_number,
+					)?);
+				},
 				_ => {},
 			};
+			let maybe_usage = Self::record_mint(
+				pool,
+				who,
+				&mint_settings,
+				amount,
+				maybe_allowance,
+				block_number,
+			)?;
 
-			let reserve = pool_details.mint_settings.price.saturating_mul(amount.into());
-			<T as Config<I>>::Currency::reserve(&who, reserve)?;
+			let reserve = mint_settings.price.saturating_mul(amount.into());
+			Self::reserve_payment(PoolAssetOf::<T, I>::get(pool), who, reserve)?;
 			let execute_block = block_number.saturating_add(T::MintInterval::get());
 
 			let mint_request = MintRequest {
 				miner: who.clone(),
 				pool: pool.clone(),
 				target: target.clone(),
-				mining_fee: pool_details.mint_settings.price,
+				mining_fee: mint_settings.price,
 				miner_reserve: reserve,
 				amount,
 				block_number: execute_block,
+				maybe_usage,
+				maybe_nonce,
 			};
 
 			MintRequestOf::<T, I>::try_mutate(execute_block, |request_vec| -> DispatchResult {
@@ -221,32 +270,26 @@ impl<T: Config<I>, I: 'static>
 			// random minting
 			let mut nfts: Vec<NFT<T::CollectionId, T::ItemId>> = Vec::new();
 			{
-				let mut total_weight = Self::total_weight(&table);
+				let maybe_pity = pool_details.mint_settings.pity;
+				let mut counter = PityCounterOf::<T, I>::get(pool, who);
 				for index in 0..amount {
-					if let Some(random) = T::GameRandomness::random_number(total_weight, index) {
-						// ensure position
-						ensure!(random <= total_weight, Error::<T, I>::MintFailed);
-						match Self::take_loot(&mut table, random) {
-							Some(maybe_nft) =>
-								if let Some(nft) = maybe_nft {
-									Self::repatriate_reserved_item(
-										&pool_details.owner,
-										&nft.collection,
-										&nft.item,
-										target,
-										1,
-										ItemBalanceStatus::Free,
-									)?;
-									nfts.push(nft);
-								},
-							None => return Err(Error::<T, I>::MintFailed.into()),
-						};
-
-						total_weight = total_weight.saturating_sub(1);
-					} else {
-						return Err(Error::<T, I>::SoldOut.into())
+					let maybe_nft =
+						Self::roll_loot(&mut table, maybe_pity, &mut counter, index, true)?;
+					if let Some(nft) = maybe_nft {
+						Self::repatriate_reserved_item(
+							&pool_details.owner,
+							&nft.collection,
+							&nft.item,
+							target,
+							1,
+							ItemBalanceStatus::Free,
+						)?;
+						nfts.push(nft);
 					}
 				}
+				if maybe_pity.is_some() {
+					PityCounterOf::<T, I>::insert(pool, who, counter);
+				}
 
 				let table = LootTableFor::<T, I>::try_from(table)
 					.map_err(|_| Error::<T, I>::ExceedMaxLoot)?;
@@ -276,24 +319,20 @@ impl<T: Config<I>, I: 'static>
 			// random minting
 			let mut nfts: Vec<NFT<T::CollectionId, T::ItemId>> = Vec::new();
 			{
-				let table = LootTableOf::<T, I>::get(pool).into();
-				let total_weight = Self::total_weight(&table);
+				let mut table = LootTableOf::<T, I>::get(pool).into();
+				let maybe_pity = pool_details.mint_settings.pity;
+				let mut counter = PityCounterOf::<T, I>::get(pool, who);
 				for index in 0..amount {
-					if let Some(random) = T::GameRandomness::random_number(total_weight, index) {
-						// ensure position
-						ensure!(random <= total_weight, Error::<T, I>::MintFailed);
-						match Self::get_loot(&table, random) {
-							Some(maybe_nft) =>
-								if let Some(nft) = maybe_nft {
-									Self::add_item_balance(target, &nft.collection, &nft.item, 1)?;
-									nfts.push(nft);
-								},
-							None => return Err(Error::<T, I>::MintFailed.into()),
-						};
-					} else {
-						return Err(Error::<T, I>::SoldOut.into())
+					let maybe_nft =
+						Self::roll_loot(&mut table, maybe_pity, &mut counter, index, false)?;
+					if let Some(nft) = maybe_nft {
+						Self::add_item_balance(target, &nft.collection, &nft.item, 1)?;
+						nfts.push(nft);
 					}
 				}
+				if maybe_pity.is_some() {
+					PityCounterOf::<T, I>::insert(pool, who, counter);
+				}
 			}
 
 			Self::deposit_event(Event::<T, I>::Minted {
@@ -308,3 +347,226 @@ impl<T: Config<I>, I: 'static>
 		return Ok(())
 	}
 }
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Record `amount` items requested by `who` from `pool`, enforcing the mint limits of
+	/// `mint_settings` and the allowlist allowance of `who` if any.
+	///
+	/// Returns the usage of the record to give back if the request is refunded.
+	pub(crate) fn record_mint(
+		pool: &T::PoolId,
+		who: &T::AccountId,
+		mint_settings: &MintSettingsFor<T, I>,
+		amount: Amount,
+		maybe_allowance: Option<Amount>,
+		block_number: BlockNumberFor<T>,
+	) -> Result<Option<MintUsage<BlockNumberFor<T>>>, DispatchError> {
+		// nothing to track without limits
+		if maybe_allowance.is_none() &&
+			mint_settings.max_per_account.is_none() &&
+			mint_settings.max_per_window.is_none() &&
+			mint_settings.cooldown.is_none()
+		{
+			return Ok(None)
+		}
+
+		MintRecordOf::<T, I>::try_mutate(pool, who, |record| {
+			if let (Some(cooldown), Some(last_block)) = (mint_settings.cooldown, record.last_block)
+			{
+				ensure!(
+					block_number >= last_block.saturating_add(cooldown),
+					Error::<T, I>::MintCooldown
+				);
+			}
+
+			let total = record.total.saturating_add(amount);
+			if let Some(max_per_account) = mint_settings.max_per_account {
+				ensure!(total <= max_per_account, Error::<T, I>::ExceedMintLimit);
+			}
+			if let Some(allowance) = maybe_allowance {
+				ensure!(total <= allowance, Error::<T, I>::ExceedMintAllowance);
+			}
+
+			if let Some(window) = mint_settings.max_per_window {
+				// start a new window on the first mint or once the current one has passed
+				if record.window_mints == 0 ||
+					block_number >= record.window_start.saturating_add(window.length)
+				{
+					record.window_start = block_number;
+					record.window_mints = 0;
+				}
+				let window_mints = record.window_mints.saturating_add(amount);
+				ensure!(
+					window_mints <= window.max_mints,
+					Error::<T, I>::ExceedMintWindowLimit
+				);
+				record.window_mints = window_mints;
+			}
+
+			let usage = MintUsage {
+				block_number,
+				window_start: record.window_start,
+				prev_block: record.last_block,
+			};
+			record.total = total;
+			record.last_block = Some(block_number);
+			Ok(Some(usage))
+		})
+	}
+
+	/// Give back the mint limits and the voucher used by the refunded `request`.
+	///
+	/// The window and the cooldown are only restored while no later request changed them.
+	pub(crate) fn release_mint(request: &MintRequestFor<T, I>) {
+		if let Some(usage) = &request.maybe_usage {
+			MintRecordOf::<T, I>::mutate(request.pool, &request.miner, |record| {
+				record.total = record.total.saturating_sub(request.amount);
+				if record.window_start == usage.window_start {
+					record.window_mints = record.window_mints.saturating_sub(request.amount);
+				}
+				if record.last_block == Some(usage.block_number) {
+					record.last_block = usage.prev_block;
+				}
+			});
+		}
+		if let Some(nonce) = request.maybe_nonce {
+			UsedVoucherOf::<T, I>::remove(request.pool, nonce);
+		}
+	}
+
+	/// Verify the merkle `maybe_proof` of `who` against the allowlist `root`.
+	///
+	/// Returns the maximum items `who` is allowed to mint.
+	pub(crate) fn verify_allowlist(
+		root: &H256,
+		who: &T::AccountId,
+		maybe_proof: Option<MintProofFor<T>>,
+	) -> Result<Amount, DispatchError> {
+		let (max_amount, proof) = match maybe_proof {
+			Some(MintProof::Allowlist { max_amount, proof }) => (max_amount, proof),
+			_ => return Err(Error::<T, I>::InvalidMintProof.into()),
+		};
+		ensure!(
+			proof.len() <= MAX_PROOF_DEPTH,
+			Error::<T, I>::InvalidMintProof
+		);
+
+		let leaf = blake2_256(&(who, max_amount).encode());
+		let computed = proof.iter().fold(leaf, |node, sibling| {
+			// pairs are hashed in sorted order, so the proof needs no position of the nodes
+			let (left, right) = if node <= sibling.0 {
+				(node, sibling.0)
+			} else {
+				(sibling.0, node)
+			};
+			blake2_256(&[left, right].concat())
+		});
+		ensure!(H256(computed) == *root, Error::<T, I>::NotWhitelisted);
+
+		Ok(max_amount)
+	}
+
+	/// Verify the voucher in `maybe_proof` was signed by `signer` for `who` to mint `amount`
+	/// items and mark it as used.
+	///
+	/// Returns the nonce of the voucher.
+	pub(crate) fn use_voucher(
+		pool: &T::PoolId,
+		signer: &T::AccountId,
+		who: &T::AccountId,
+		amount: Amount,
+		maybe_proof: Option<MintProofFor<T>>,
+		block_number: BlockNumberFor<T>,
+	) -> Result<u32, DispatchError> {
+		let (max_amount, nonce, expiry, signature) = match maybe_proof {
+			Some(MintProof::Voucher {
+				max_amount,
+				nonce,
+				expiry,
+				signature,
+			}) => (max_amount, nonce, expiry, signature),
+			_ => return Err(Error::<T, I>::InvalidMintProof.into()),
+		};
+		ensure!(amount <= max_amount, Error::<T, I>::ExceedMintAllowance);
+		ensure!(block_number <= expiry, Error::<T, I>::VoucherExpired);
+		ensure!(
+			!UsedVoucherOf::<T, I>::contains_key(pool, nonce),
+			Error::<T, I>::VoucherUsed
+		);
+
+		let voucher: MintVoucherFor<T, I> = MintVoucher {
+			pool: *pool,
+			who: who.clone(),
+			max_amount,
+			nonce,
+			expiry,
+		};
+		// bind the voucher to this chain and pallet instance
+		let genesis_hash = <frame_system::Pallet<T>>::block_hash(BlockNumberFor::<T>::zero());
+		let payload = (genesis_hash, <Self as PalletInfoAccess>::name(), voucher).encode();
+		ensure!(
+			signature.verify(&payload[..], signer),
+			Error::<T, I>::NotWhitelisted
+		);
+
+		UsedVoucherOf::<T, I>::insert(pool, nonce, ());
+		Ok(nonce)
+	}
+
+	/// Ensure the pity of `mint_settings` guarantees a tier present in `table`.
+	pub(crate) fn ensure_pity(
+		table: &LootTable<T::CollectionId, T::ItemId>,
+		mint_settings: &MintSettingsFor<T, I>,
+	) -> DispatchResult {
+		if let Some(pity) = mint_settings.pity {
+			ensure!(
+				pity.threshold > 0 && table.iter().any(|loot| loot.tier == Some(pity.tier)),
+				Error::<T, I>::InvalidPity
+			);
+		}
+		Ok(())
+	}
+
+	/// Roll the loot of the `index`-th mint from `table`, updating the pity `counter` of the
+	/// miner.
+	///
+	/// Once the pity is due, only the loots of its tier are rolled. When the tier is sold out in a
+	/// dynamic pool the whole table is rolled and the pity stays due.
+	///
+	/// Dynamic pools set `take` to take the loot out of the table.
+	pub(crate) fn roll_loot(
+		table: &mut LootTable<T::CollectionId, T::ItemId>,
+		maybe_pity: Option<Pity>,
+		counter: &mut Amount,
+		index: u32,
+		take: bool,
+	) -> Result<Option<NFT<T::CollectionId, T::ItemId>>, DispatchError> {
+		let maybe_tier = maybe_pity
+			.filter(|pity| *counter >= pity.threshold)
+			.map(|pity| pity.tier)
+			.filter(|tier| Self::tier_weight(table, *tier) > 0);
+		let total_weight = match maybe_tier {
+			Some(tier) => Self::tier_weight(table, tier),
+			None => Self::total_weight(table),
+		};
+
+		let random =
+			T::GameRandomness::random_number(total_weight, index).ok_or(Error::<T, I>::SoldOut)?;
+		// ensure position
+		ensure!(random <= total_weight, Error::<T, I>::MintFailed);
+		let position =
+			Self::loot_position(table, random, maybe_tier).ok_or(Error::<T, I>::MintFailed)?;
+
+		if take {
+			table[position].weight.saturating_dec();
+		}
+		if let Some(pity) = maybe_pity {
+			if table[position].tier == Some(pity.tier) {
+				*counter = 0;
+			} else {
+				counter.saturating_inc();
+			}
+		}
+		Ok(table[position].maybe_nft.clone())
+	}
+}
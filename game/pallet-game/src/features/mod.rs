@@ -16,9 +16,24 @@ pub use mutate_item::*;
 pub mod transfer_item;
 pub use transfer_item::*;
 
+pub mod operator;
+pub use operator::*;
+
+pub mod soulbound;
+pub use soulbound::*;
+
 pub mod upgrade_item;
 pub use upgrade_item::*;
 
+pub mod crafting;
+pub use crafting::*;
+
+pub mod salvage;
+pub use salvage::*;
+
+pub mod match_escrow;
+pub use match_escrow::*;
+
 pub mod common;
 pub use common::*;
 
@@ -27,3 +42,18 @@ pub use minting::*;
 
 pub mod loot_table;
 pub use loot_table::*;
+
+pub mod query;
+pub use query::*;
+
+pub mod destroy;
+pub use destroy::*;
+
+pub mod royalty;
+pub use royalty::*;
+
+pub mod settlement;
+pub use settlement::*;
+
+pub mod treasury;
+pub use treasury::*;
@@ -2,6 +2,7 @@ use crate::*;
 use frame_support::{pallet_prelude::*, traits::tokens::nonfungibles_v2::Create};
 use gafi_support::game::MutateCollection;
 use pallet_nfts::{CollectionRole, CollectionRoles, CollectionSettings, MintSettings};
+use sp_runtime::Saturating;
 
 impl<T: Config<I>, I: 'static>
 	MutateCollection<
@@ -21,7 +22,9 @@ impl<T: Config<I>, I: 'static>
 				)),
 			Error::<T, I>::NoPermission
 		);
-		if let Some(game_details) = Game::<T, I>::get(game) {
+		if let Some(mut game_details) = Game::<T, I>::get(game) {
+			ensure!(!game_details.is_destroying, Error::<T, I>::GameDestroying);
+
 			let config: CollectionConfigFor<T, I> = CollectionConfig {
 				settings: CollectionSettings::default(),
 				max_supply: None,
@@ -42,6 +45,10 @@ impl<T: Config<I>, I: 'static>
 						game_vec.try_push(*game).map_err(|_| Error::<T, I>::ExceedMaxGameShare)?;
 						Ok(())
 					})?;
+					CollectionGameOf::<T, I>::insert(collection, game);
+
+					game_details.collections.saturating_inc();
+					Game::<T, I>::insert(game, game_details);
 
 					Self::deposit_event(Event::<T, I>::CollectionCreated {
 						who: who.clone(),
@@ -85,8 +92,22 @@ impl<T: Config<I>, I: 'static>
 				T::Nfts::is_admin(collection, who),
 				Error::<T, I>::NoPermission
 			);
-			<T as Config<I>>::Currency::reserve(&collection_owner, T::GameDeposit::get())?;
+			let details = Game::<T, I>::get(game).ok_or(Error::<T, I>::UnknownGame)?;
+			ensure!(!details.is_destroying, Error::<T, I>::GameDestroying);
+
+			// the previous acceptance is replaced along with its deposit
+			if let Some(previous) = AddingAcceptance::<T, I>::take(collection) {
+				if let Some((depositor, deposit)) =
+					AddingAcceptanceOf::<T, I>::take(previous, collection)
+				{
+					<T as Config<I>>::Currency::unreserve(&depositor, deposit);
+				}
+			}
+
+			let deposit = T::GameDeposit::get();
+			<T as Config<I>>::Currency::reserve(&collection_owner, deposit)?;
 			AddingAcceptance::<T, I>::insert(collection, game);
+			AddingAcceptanceOf::<T, I>::insert(game, collection, (collection_owner, deposit));
 
 			Self::deposit_event(Event::<T, I>::AddingAcceptanceSet {
 				who: who.clone(),
@@ -116,6 +137,13 @@ impl<T: Config<I>, I: 'static>
 			None => return Err(Error::<T, I>::UnknownAcceptance.into()),
 		};
 
+		Game::<T, I>::try_mutate(game, |maybe_details| -> DispatchResult {
+			let details = maybe_details.as_mut().ok_or(Error::<T, I>::UnknownGame)?;
+			ensure!(!details.is_destroying, Error::<T, I>::GameDestroying);
+			details.collections.saturating_inc();
+			Ok(())
+		})?;
+
 		CollectionsOf::<T, I>::try_mutate(&game, |collection_vec| -> DispatchResult {
 			ensure!(
 				!collection_vec.contains(collection),
@@ -163,6 +191,14 @@ impl<T: Config<I>, I: 'static>
 			}
 		})?;
 		GamesOf::<T, I>::remove(collection);
+		if CollectionGameOf::<T, I>::get(collection) == Some(*game) {
+			CollectionGameOf::<T, I>::remove(collection);
+		}
+		Game::<T, I>::mutate(game, |maybe_details| {
+			if let Some(details) = maybe_details {
+				details.collections.saturating_dec();
+			}
+		});
 		Self::deposit_event(Event::<T, I>::CollectionRemoved {
 			who: who.clone(),
 			game: *game,
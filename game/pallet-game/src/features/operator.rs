@@ -0,0 +1,92 @@
+/// Operator module lets approved accounts transfer items on behalf of their owners
+use crate::*;
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::BlockNumberFor;
+use gafi_support::game::{Amount, Operator, TransferItem};
+
+impl<T: Config<I>, I: 'static> Operator<T::AccountId, T::CollectionId, T::ItemId, BlockNumberFor<T>>
+	for Pallet<T, I>
+{
+	fn do_approve_operator(
+		who: &T::AccountId,
+		operator: &T::AccountId,
+		maybe_collection: Option<T::CollectionId>,
+		maybe_deadline: Option<BlockNumberFor<T>>,
+	) -> DispatchResult {
+		if let Some(deadline) = maybe_deadline {
+			ensure!(
+				deadline >= <frame_system::Pallet<T>>::block_number(),
+				Error::<T, I>::ApprovalExpired
+			);
+		}
+
+		OperatorApprovalOf::<T, I>::insert((who, operator, maybe_collection), maybe_deadline);
+
+		Self::deposit_event(Event::<T, I>::OperatorApproved {
+			who: who.clone(),
+			operator: operator.clone(),
+			maybe_collection,
+			maybe_deadline,
+		});
+		Ok(())
+	}
+
+	fn do_revoke_operator(
+		who: &T::AccountId,
+		operator: &T::AccountId,
+		maybe_collection: Option<T::CollectionId>,
+	) -> DispatchResult {
+		ensure!(
+			OperatorApprovalOf::<T, I>::contains_key((who, operator, maybe_collection)),
+			Error::<T, I>::UnknownApproval
+		);
+		OperatorApprovalOf::<T, I>::remove((who, operator, maybe_collection));
+
+		Self::deposit_event(Event::<T, I>::OperatorRevoked {
+			who: who.clone(),
+			operator: operator.clone(),
+			maybe_collection,
+		});
+		Ok(())
+	}
+
+	fn do_transfer_from(
+		operator: &T::AccountId,
+		from: &T::AccountId,
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+		destination: &T::AccountId,
+		amount: Amount,
+	) -> DispatchResult {
+		Self::ensure_operator(operator, from, collection)?;
+		Self::do_transfer_item(from, collection, item, destination, amount)
+	}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Ensure `operator` is approved by `who` to transfer items in `collection`, either by an
+	/// approval of `collection` or by an approval of any collection.
+	pub(crate) fn ensure_operator(
+		operator: &T::AccountId,
+		who: &T::AccountId,
+		collection: &T::CollectionId,
+	) -> DispatchResult {
+		let block_number = <frame_system::Pallet<T>>::block_number();
+		let approvals = [Some(*collection), None]
+			.into_iter()
+			.filter_map(|maybe_collection| {
+				OperatorApprovalOf::<T, I>::get((who, operator, maybe_collection))
+			})
+			.collect::<Vec<_>>();
+
+		ensure!(!approvals.is_empty(), Error::<T, I>::NoPermission);
+		ensure!(
+			approvals
+				.iter()
+				.any(|maybe_deadline| maybe_deadline
+					.map_or(true, |deadline| deadline >= block_number)),
+			Error::<T, I>::ApprovalExpired
+		);
+		Ok(())
+	}
+}
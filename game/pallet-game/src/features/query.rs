@@ -0,0 +1,262 @@
+/// Query module provides read-only helpers used by the runtime API
+use crate::*;
+use frame_support::pallet_prelude::*;
+use gafi_support::game::{Amount, ItemBalance, PoolInfo, TradeDetails, UpgradeLevel};
+use sp_runtime::Saturating;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Free, reserved and rented balances of every item held by `who`.
+	pub fn inventory_of(who: &T::AccountId) -> Vec<ItemBalance<T::CollectionId, T::ItemId>> {
+		let mut inventory: Vec<ItemBalance<T::CollectionId, T::ItemId>> =
+			ItemBalanceOf::<T, I>::iter_prefix((who,))
+				.filter(|(_, free)| *free > 0)
+				.map(|((collection, item), free)| ItemBalance {
+					collection,
+					item,
+					free,
+					reserved: 0,
+					rented: 0,
+				})
+				.collect();
+
+		for ((collection, item), reserved) in ReservedBalanceOf::<T, I>::iter_prefix((who,)) {
+			if reserved == 0 {
+				continue
+			}
+			match inventory
+				.iter_mut()
+				.find(|balance| balance.collection == collection && balance.item == item)
+			{
+				Some(balance) => balance.reserved = reserved,
+				None => inventory.push(ItemBalance {
+					collection,
+					item,
+					free: 0,
+					reserved,
+					rented: 0,
+				}),
+			}
+		}
+
+		for ((collection, item), rented) in UseRightOf::<T, I>::iter_prefix((who,)) {
+			match inventory
+				.iter_mut()
+				.find(|balance| balance.collection == collection && balance.item == item)
+			{
+				Some(balance) => balance.rented = rented,
+				None => inventory.push(ItemBalance {
+					collection,
+					item,
+					free: 0,
+					reserved: 0,
+					rented,
+				}),
+			}
+		}
+
+		inventory
+	}
+
+	/// Trades and auctions that have not ended yet and involve `item` in `collection`.
+	pub fn active_trades(
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+	) -> Vec<
+		TradeDetails<
+			T::TradeId,
+			T::AccountId,
+			BalanceOf<T, I>,
+			T::CollectionId,
+			T::ItemId,
+			BlockNumberFor<T>,
+		>,
+	> {
+		let block_number = <frame_system::Pallet<T>>::block_number();
+		let contains = |bundle: &[Package<T::CollectionId, T::ItemId>]| {
+			bundle
+				.iter()
+				.any(|package| package.collection == *collection && package.item == *item)
+		};
+
+		let mut trades = Vec::new();
+
+		for (trade, config) in TradeConfigOf::<T, I>::iter() {
+			if let Some(end_block) = config.end_block {
+				if block_number > end_block {
+					continue
+				}
+			}
+
+			let bundle = BundleOf::<T, I>::get(trade);
+			let maybe_required = config.maybe_required.map(|required| required.into_inner());
+			let involved = contains(&bundle) ||
+				maybe_required.as_ref().map_or(false, |required| contains(required));
+
+			if involved {
+				trades.push(TradeDetails {
+					trade,
+					trade_type: config.trade,
+					owner: config.owner,
+					maybe_price: config.maybe_price,
+					bundle: bundle.into_inner(),
+					maybe_required,
+					start_block: config.start_block,
+					end_block: config.end_block,
+				});
+			}
+		}
+
+		for (trade, config) in AuctionConfigOf::<T, I>::iter() {
+			let end_block = config.start_block.saturating_add(config.duration);
+			if block_number >= end_block {
+				continue
+			}
+
+			let bundle = BundleOf::<T, I>::get(trade);
+			if contains(&bundle) {
+				trades.push(TradeDetails {
+					trade,
+					trade_type: TradeType::Auction,
+					owner: config.owner,
+					maybe_price: config.maybe_price,
+					bundle: bundle.into_inner(),
+					maybe_required: None,
+					start_block: Some(config.start_block),
+					end_block: Some(end_block),
+				});
+			}
+		}
+
+		for (trade, config) in CandleAuctionOf::<T, I>::iter() {
+			if block_number >= config.end_block {
+				continue
+			}
+
+			let bundle = BundleOf::<T, I>::get(trade);
+			if contains(&bundle) {
+				trades.push(TradeDetails {
+					trade,
+					trade_type: TradeType::CandleAuction,
+					owner: config.owner,
+					maybe_price: config.maybe_price,
+					bundle: bundle.into_inner(),
+					maybe_required: None,
+					start_block: Some(config.start_block),
+					end_block: Some(config.end_block),
+				});
+			}
+		}
+
+		for (trade, config) in SealedAuctionOf::<T, I>::iter() {
+			if block_number >= config.end_block {
+				continue
+			}
+
+			let bundle = BundleOf::<T, I>::get(trade);
+			if contains(&bundle) {
+				trades.push(TradeDetails {
+					trade,
+					trade_type: TradeType::SealedAuction,
+					owner: config.owner,
+					maybe_price: config.maybe_price,
+					bundle: bundle.into_inner(),
+					maybe_required: None,
+					start_block: Some(config.start_block),
+					end_block: Some(config.end_block),
+				});
+			}
+		}
+
+		for (trade, config) in DutchAuctionOf::<T, I>::iter() {
+			let bundle = BundleOf::<T, I>::get(trade);
+			if contains(&bundle) {
+				trades.push(TradeDetails {
+					trade,
+					trade_type: TradeType::DutchAuction,
+					maybe_price: Some(Self::dutch_auction_price(&config, block_number)),
+					owner: config.owner,
+					bundle: bundle.into_inner(),
+					maybe_required: None,
+					start_block: Some(config.start_block),
+					end_block: None,
+				});
+			}
+		}
+
+		for (trade, config) in RentalOf::<T, I>::iter() {
+			let bundle = BundleOf::<T, I>::get(trade);
+			if contains(&bundle) {
+				trades.push(TradeDetails {
+					trade,
+					trade_type: TradeType::Rental,
+					owner: config.owner,
+					maybe_price: Some(config.price_per_block),
+					bundle: bundle.into_inner(),
+					maybe_required: None,
+					start_block: None,
+					end_block: config.maybe_renter.map(|(_, end_block)| end_block),
+				});
+			}
+		}
+
+		trades
+	}
+
+	/// Amount of `item` in `collection` rented by `who`.
+	///
+	/// Rented items can be used in games but stay reserved by their owner.
+	pub fn use_right_of(
+		who: &T::AccountId,
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+	) -> Amount {
+		UseRightOf::<T, I>::get((who, collection, item))
+	}
+
+	/// Configuration and loot table of the minting `pool`.
+	pub fn pool_info(
+		pool: &T::PoolId,
+	) -> Option<
+		PoolInfo<T::AccountId, BalanceOf<T, I>, BlockNumberFor<T>, T::CollectionId, T::ItemId>,
+	> {
+		PoolOf::<T, I>::get(pool).map(|details| {
+			let table = LootTableOf::<T, I>::get(pool).into_inner();
+			PoolInfo {
+				pool_type: details.pool_type,
+				owner: details.owner,
+				admin: details.admin,
+				mint_settings: details.mint_settings,
+				total_weight: Self::total_weight(&table),
+				table,
+			}
+		})
+	}
+
+	/// Upgrade levels of `item` in `collection`, ordered by level.
+	///
+	/// `item` may be the original item or any of its upgraded items.
+	pub fn upgrade_path(
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+	) -> Vec<UpgradeLevel<T::CollectionId, T::ItemId, BalanceOf<T, I>>> {
+		let origin = match OriginItemOf::<T, I>::get((collection, item)) {
+			Some((_, origin)) => origin,
+			None => *item,
+		};
+
+		let mut path: Vec<UpgradeLevel<T::CollectionId, T::ItemId, BalanceOf<T, I>>> =
+			UpgradeConfigOf::<T, I>::iter_prefix((collection, origin))
+				.map(|(level, config)| UpgradeLevel {
+					level,
+					item: config.item,
+					fee: config.fee,
+					materials: config.materials.into_inner(),
+					success_rate: config.success_rate,
+					on_failure: config.on_failure,
+				})
+				.collect();
+		path.sort_by_key(|upgrade| upgrade.level);
+
+		path
+	}
+}
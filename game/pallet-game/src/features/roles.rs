@@ -1,6 +1,54 @@
 use crate::*;
+use frame_support::pallet_prelude::*;
+use gafi_support::game::{GameRole, GameRoles};
 use pallet_nfts::CollectionRole;
 
+impl<T: Config<I>, I: 'static> GameRoles<T::AccountId, T::GameId> for Pallet<T, I> {
+	fn do_grant_role(
+		who: &T::AccountId,
+		game: &T::GameId,
+		account: &T::AccountId,
+		role: GameRole,
+	) -> DispatchResult {
+		Self::ensure_game_owner(who, game)?;
+		ensure!(
+			!Game::<T, I>::get(game).map_or(false, |details| details.is_destroying),
+			Error::<T, I>::GameDestroying
+		);
+
+		GrantedRoleOf::<T, I>::insert((game, account, role), ());
+
+		Self::deposit_event(Event::<T, I>::RoleGranted {
+			game: *game,
+			who: account.clone(),
+			role,
+		});
+		Ok(())
+	}
+
+	fn do_revoke_role(
+		who: &T::AccountId,
+		game: &T::GameId,
+		account: &T::AccountId,
+		role: GameRole,
+	) -> DispatchResult {
+		Self::ensure_game_owner(who, game)?;
+		ensure!(
+			GrantedRoleOf::<T, I>::contains_key((game, account, role)),
+			Error::<T, I>::UnknownRole
+		);
+
+		GrantedRoleOf::<T, I>::remove((game, account, role));
+
+		Self::deposit_event(Event::<T, I>::RoleRevoked {
+			game: *game,
+			who: account.clone(),
+			role,
+		});
+		Ok(())
+	}
+}
+
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// Returns true if a specified account has a provided role within that game.
 	///
@@ -17,4 +65,44 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		GameRoleOf::<T, I>::get(&game, &who)
 			.map_or(false, |roles| roles.has_role(role))
 	}
+
+	/// Whether `who` was granted `role` in `game`.
+	pub fn has_game_role(game: &T::GameId, who: &T::AccountId, role: GameRole) -> bool {
+		GrantedRoleOf::<T, I>::contains_key((game, who, role))
+	}
+
+	/// Whether `who` was granted `role` in the game that created `collection`.
+	///
+	/// Roles granted in the other games the collection was added to do not count.
+	pub fn has_collection_role(
+		collection: &T::CollectionId,
+		who: &T::AccountId,
+		role: GameRole,
+	) -> bool {
+		Self::collection_game(collection)
+			.map_or(false, |game| Self::has_game_role(&game, who, role))
+	}
+
+	/// The game that created `collection`.
+	pub(crate) fn collection_game(collection: &T::CollectionId) -> Option<T::GameId> {
+		CollectionGameOf::<T, I>::get(collection)
+	}
+
+	/// Whether `who` was granted `role` in the game of the items minted by `pool`.
+	pub(crate) fn has_pool_role(pool: &T::PoolId, who: &T::AccountId, role: GameRole) -> bool {
+		Self::pool_game(pool).map_or(false, |game| Self::has_game_role(&game, who, role))
+	}
+
+	/// Return `Ok(())` if `who` is the owner of `collection` or was granted `role` in the game
+	/// that created it.
+	pub(crate) fn ensure_collection_role(
+		who: &T::AccountId,
+		collection: &T::CollectionId,
+		role: GameRole,
+	) -> Result<(), Error<T, I>> {
+		if Self::has_collection_role(collection, who, role) {
+			return Ok(())
+		}
+		Self::ensure_collection_owner(who, collection)
+	}
 }
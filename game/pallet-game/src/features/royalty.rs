@@ -0,0 +1,96 @@
+use crate::*;
+use frame_support::pallet_prelude::*;
+use gafi_support::game::{GameRole, Package, Royalty};
+use sp_runtime::traits::Zero;
+
+impl<T: Config<I>, I: 'static> Royalty<T::AccountId, T::CollectionId, T::ItemId> for Pallet<T, I> {
+	fn do_set_royalty(
+		who: &T::AccountId,
+		collection: &T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		beneficiary: &T::AccountId,
+		rate: u16,
+	) -> DispatchResult {
+		let maybe_config = match maybe_item {
+			Some(item) => ItemRoyaltyOf::<T, I>::get(collection, item),
+			None => CollectionRoyaltyOf::<T, I>::get(collection),
+		};
+		// a trade operator only adjusts the rate paid to the current beneficiary
+		if maybe_config.map_or(false, |config| config.beneficiary == *beneficiary) {
+			Self::ensure_collection_role(who, collection, GameRole::TradeOperator)?;
+		} else {
+			Self::ensure_collection_owner(who, collection)?;
+		}
+		ensure!(rate <= T::MaxRoyalty::get(), Error::<T, I>::RoyaltyTooHigh);
+
+		let config = RoyaltyConfig {
+			beneficiary: beneficiary.clone(),
+			rate,
+		};
+		match maybe_item {
+			Some(item) => ItemRoyaltyOf::<T, I>::insert(collection, item, config),
+			None => CollectionRoyaltyOf::<T, I>::insert(collection, config),
+		};
+
+		Self::deposit_event(Event::<T, I>::RoyaltySet {
+			collection: *collection,
+			maybe_item,
+			beneficiary: beneficiary.clone(),
+			rate,
+		});
+		Ok(())
+	}
+
+	fn do_clear_royalty(
+		who: &T::AccountId,
+		collection: &T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+	) -> DispatchResult {
+		Self::ensure_collection_owner(who, collection)?;
+
+		let maybe_config = match maybe_item {
+			Some(item) => ItemRoyaltyOf::<T, I>::take(collection, item),
+			None => CollectionRoyaltyOf::<T, I>::take(collection),
+		};
+		ensure!(maybe_config.is_some(), Error::<T, I>::UnknownRoyalty);
+
+		Self::deposit_event(Event::<T, I>::RoyaltyCleared {
+			collection: *collection,
+			maybe_item,
+		});
+		Ok(())
+	}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Royalty of `item` in `collection`, the item royalty overrides the collection royalty.
+	pub fn royalty_of(
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+	) -> Option<RoyaltyConfig<T::AccountId>> {
+		ItemRoyaltyOf::<T, I>::get(collection, item)
+			.or_else(|| CollectionRoyaltyOf::<T, I>::get(collection))
+	}
+
+	/// Royalties owed when `bundle` is sold at `price`.
+	pub(crate) fn royalties(
+		bundle: &[Package<T::CollectionId, T::ItemId>],
+		price: BalanceOf<T, I>,
+	) -> Vec<(
+		Package<T::CollectionId, T::ItemId>,
+		T::AccountId,
+		BalanceOf<T, I>,
+	)> {
+		Self::price_shares(bundle, price)
+			.into_iter()
+			.filter_map(|(package, share)| {
+				let config = Self::royalty_of(&package.collection, &package.item)?;
+				let royalty = Self::basis_points(share, config.rate);
+				if royalty.is_zero() {
+					return None
+				}
+				Some((package, config.beneficiary, royalty))
+			})
+			.collect()
+	}
+}
@@ -0,0 +1,241 @@
+/// Salvage module burns items to give back materials, either a fixed bundle or a rolled loot
+use crate::*;
+use frame_support::pallet_prelude::*;
+use gafi_support::game::{Amount, Bundle, GameRole, LootTable, Package, Salvage, SalvageOutput};
+use sp_runtime::Saturating;
+
+impl<T: Config<I>, I: 'static> Salvage<T::AccountId, T::CollectionId, T::ItemId> for Pallet<T, I> {
+	fn do_set_salvage(
+		who: &T::AccountId,
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+		output: SalvageOutput<
+			Bundle<T::CollectionId, T::ItemId>,
+			LootTable<T::CollectionId, T::ItemId>,
+		>,
+	) -> DispatchResult {
+		ensure!(
+			T::Nfts::is_admin(collection, who) ||
+				Self::has_collection_role(collection, who, GameRole::UpgradeDesigner),
+			Error::<T, I>::NoPermission
+		);
+		ensure!(
+			SupplyOf::<T, I>::contains_key(collection, item),
+			Error::<T, I>::UnknownItem
+		);
+
+		let stored_output = match output.clone() {
+			SalvageOutput::Fixed(bundle) => {
+				ensure!(!bundle.is_empty(), Error::<T, I>::InvalidSalvage);
+				for package in bundle.iter() {
+					ensure!(package.amount > 0, Error::<T, I>::InvalidAmount);
+					ensure!(
+						T::Nfts::is_admin(&package.collection, who) ||
+							Self::has_collection_role(
+								&package.collection,
+								who,
+								GameRole::UpgradeDesigner
+							),
+						Error::<T, I>::NoPermission
+					);
+					ensure!(
+						SupplyOf::<T, I>::contains_key(package.collection, package.item),
+						Error::<T, I>::UnknownItem
+					);
+				}
+				SalvageOutput::Fixed(
+					BundleFor::<T, I>::try_from(bundle)
+						.map_err(|_| Error::<T, I>::ExceedMaxBundle)?,
+				)
+			},
+			SalvageOutput::Random(table) => {
+				ensure!(
+					Self::total_weight(&table) > 0,
+					Error::<T, I>::InvalidSalvage
+				);
+				// loot is minted, so it must be in infinite supply
+				for nft in table.iter().filter_map(|loot| loot.maybe_nft.as_ref()) {
+					ensure!(
+						T::Nfts::is_admin(&nft.collection, who) ||
+							Self::has_collection_role(
+								&nft.collection,
+								who,
+								GameRole::UpgradeDesigner
+							),
+						Error::<T, I>::NoPermission
+					);
+					ensure!(
+						Self::is_infinite(&nft.collection, &nft.item),
+						Error::<T, I>::NotInfiniteSupply
+					);
+				}
+				SalvageOutput::Random(
+					LootTableFor::<T, I>::try_from(table)
+						.map_err(|_| Error::<T, I>::ExceedMaxLoot)?,
+				)
+			},
+		};
+
+		// replace the previous salvage
+		if let Some(details) = SalvageOf::<T, I>::get(collection, item) {
+			<T as Config<I>>::Currency::unreserve(&details.owner, details.owner_deposit);
+		}
+
+		let deposit = T::BundleDeposit::get();
+		<T as Config<I>>::Currency::reserve(who, deposit)?;
+
+		SalvageOf::<T, I>::insert(
+			collection,
+			item,
+			SalvageDetails {
+				owner: who.clone(),
+				owner_deposit: deposit,
+				output: stored_output,
+			},
+		);
+
+		Self::deposit_event(Event::<T, I>::SalvageSet {
+			who: who.clone(),
+			collection: *collection,
+			item: *item,
+			output,
+		});
+
+		Ok(())
+	}
+
+	fn do_clear_salvage(
+		who: &T::AccountId,
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+	) -> DispatchResult {
+		let details =
+			SalvageOf::<T, I>::get(collection, item).ok_or(Error::<T, I>::UnknownSalvage)?;
+		ensure!(details.owner == *who, Error::<T, I>::NoPermission);
+
+		<T as Config<I>>::Currency::unreserve(&details.owner, details.owner_deposit);
+		SalvageOf::<T, I>::remove(collection, item);
+
+		Self::deposit_event(Event::<T, I>::SalvageCleared {
+			collection: *collection,
+			item: *item,
+		});
+		Ok(())
+	}
+
+	fn do_salvage(
+		who: &T::AccountId,
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+		amount: Amount,
+	) -> DispatchResult {
+		ensure!(amount > 0, Error::<T, I>::InvalidAmount);
+		let details =
+			SalvageOf::<T, I>::get(collection, item).ok_or(Error::<T, I>::UnknownSalvage)?;
+		// loot is rolled once per item
+		if let SalvageOutput::Random(_) = details.output {
+			ensure!(
+				amount <= T::MaxMintItem::get(),
+				Error::<T, I>::ExceedAllowedAmount
+			);
+		}
+
+		// burn item
+		Self::sub_item_balance(who, collection, item, amount)?;
+		Self::decrease_finite_item_supply(collection, item, amount);
+
+		let table = match details.output {
+			SalvageOutput::Fixed(bundle) => {
+				let output: Bundle<T::CollectionId, T::ItemId> = bundle
+					.into_iter()
+					.map(|package| {
+						Package::new(
+							package.collection,
+							package.item,
+							package.amount.saturating_mul(amount),
+						)
+					})
+					.collect();
+				return Self::give_salvage(who, collection, item, amount, output)
+			},
+			SalvageOutput::Random(table) => table,
+		};
+
+		let block_number = <frame_system::Pallet<T>>::block_number();
+		let execute_block = block_number.saturating_add(T::MintInterval::get());
+		let request = SalvageRequest {
+			who: who.clone(),
+			collection: *collection,
+			item: *item,
+			amount,
+			table,
+		};
+		SalvageRequestOf::<T, I>::try_mutate(execute_block, |requests| -> DispatchResult {
+			requests.try_push(request).map_err(|_| Error::<T, I>::OverRequest)?;
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::<T, I>::SalvageRequested {
+			who: who.clone(),
+			collection: *collection,
+			item: *item,
+			amount,
+			block_number: execute_block,
+		});
+
+		Ok(())
+	}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Roll the loot table of a salvage `request` once per item and give the loot.
+	pub fn processing_salvage_request(request: SalvageRequestFor<T, I>) -> DispatchResult {
+		let table = request.table.into_inner();
+		let total_weight = Self::total_weight(&table);
+		let mut output: Bundle<T::CollectionId, T::ItemId> = Vec::new();
+		for index in 0..request.amount {
+			let random = T::GameRandomness::random_number(total_weight, index)
+				.ok_or(Error::<T, I>::MintFailed)?;
+			let maybe_nft = Self::get_loot(&table, random).ok_or(Error::<T, I>::MintFailed)?;
+			if let Some(nft) = maybe_nft {
+				match output.iter_mut().find(|package| {
+					package.collection == nft.collection && package.item == nft.item
+				}) {
+					Some(package) => package.amount.saturating_inc(),
+					None => output.push(Package::new(nft.collection, nft.item, 1)),
+				}
+			}
+		}
+
+		Self::give_salvage(
+			&request.who,
+			&request.collection,
+			&request.item,
+			request.amount,
+			output,
+		)
+	}
+
+	/// Give the `output` of salvaging `amount` of `item` to `who`.
+	fn give_salvage(
+		who: &T::AccountId,
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+		amount: Amount,
+		output: Bundle<T::CollectionId, T::ItemId>,
+	) -> DispatchResult {
+		for package in output.iter() {
+			Self::give_item(who, &package.collection, &package.item, package.amount)?;
+		}
+
+		Self::deposit_event(Event::<T, I>::Salvaged {
+			who: who.clone(),
+			collection: *collection,
+			item: *item,
+			amount,
+			output,
+		});
+
+		Ok(())
+	}
+}
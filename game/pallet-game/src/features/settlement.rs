@@ -0,0 +1,320 @@
+/// Settlement module pays the price of trades, deducting royalties and trade fees
+use crate::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{tokens::Preservation, BalanceStatus, ExistenceRequirement},
+};
+use gafi_support::game::{Amount, Package};
+use sp_runtime::{
+	traits::{AccountIdConversion, Zero},
+	Saturating,
+};
+
+/// Royalties and trade fees are in basis points of the price.
+pub(crate) const BASIS_POINTS: u16 = 10_000;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// The account holding the asset payments reserved by trades and mints.
+	pub(crate) fn escrow_account() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	/// Asset the items of `collection` are paid in, `None` for the native currency.
+	///
+	/// The asset is set by the game that created the collection.
+	pub(crate) fn payment_asset_of(collection: &T::CollectionId) -> Option<T::AssetId> {
+		Self::collection_game(collection).and_then(|game| Self::game_payment_asset(&game))
+	}
+
+	/// Asset `game` is paid in, `None` for the native currency.
+	pub(crate) fn game_payment_asset(game: &T::GameId) -> Option<T::AssetId> {
+		Game::<T, I>::get(game).and_then(|details| details.payment_asset)
+	}
+
+	/// Record the asset `trade` is paid in, the native currency if `None`.
+	pub(crate) fn set_trade_asset(
+		trade: &T::TradeId,
+		maybe_asset: Option<T::AssetId>,
+	) -> DispatchResult {
+		if let Some(asset) = maybe_asset {
+			Self::ensure_asset(&asset)?;
+			TradeAssetOf::<T, I>::insert(trade, asset);
+		}
+		Ok(())
+	}
+
+	/// Record the asset mints of `pool` are paid in, the native currency if `None`.
+	pub(crate) fn set_pool_asset(
+		pool: &T::PoolId,
+		maybe_asset: Option<T::AssetId>,
+	) -> DispatchResult {
+		if let Some(asset) = maybe_asset {
+			Self::ensure_asset(&asset)?;
+			PoolAssetOf::<T, I>::insert(pool, asset);
+		}
+		Ok(())
+	}
+
+	/// Ensure `asset` exists.
+	pub(crate) fn ensure_asset(asset: &T::AssetId) -> DispatchResult {
+		ensure!(
+			<T::Assets as fungibles::Inspect<T::AccountId>>::asset_exists(*asset),
+			Error::<T, I>::UnknownAsset
+		);
+		Ok(())
+	}
+
+	/// Give `who` a provider reference if it has none.
+	///
+	/// Accounts of the pallet receive assets without holding the native currency, a provider
+	/// lets them hold assets that are not sufficient.
+	pub(crate) fn ensure_provider(who: &T::AccountId) {
+		if frame_system::Pallet::<T>::providers(who) == 0 {
+			frame_system::Pallet::<T>::inc_providers(who);
+		}
+	}
+
+	/// Reserve `amount` of `maybe_asset` from `who`.
+	///
+	/// Assets are moved to the escrow account until they are paid or unreserved.
+	pub(crate) fn reserve_payment(
+		maybe_asset: Option<T::AssetId>,
+		who: &T::AccountId,
+		amount: BalanceOf<T, I>,
+	) -> DispatchResult {
+		match maybe_asset {
+			Some(asset) => {
+				let escrow = Self::escrow_account();
+				Self::ensure_provider(&escrow);
+				<T::Assets as fungibles::Mutate<T::AccountId>>::transfer(
+					asset,
+					who,
+					&escrow,
+					amount,
+					Preservation::Preserve,
+				)
+				.map(|_| ())
+			},
+			None => <T as pallet::Config<I>>::Currency::reserve(who, amount),
+		}
+	}
+
+	/// Return `amount` of `maybe_asset` reserved from `who`.
+	pub(crate) fn unreserve_payment(
+		maybe_asset: Option<T::AssetId>,
+		who: &T::AccountId,
+		amount: BalanceOf<T, I>,
+	) -> DispatchResult {
+		match maybe_asset {
+			Some(asset) => <T::Assets as fungibles::Mutate<T::AccountId>>::transfer(
+				asset,
+				&Self::escrow_account(),
+				who,
+				amount,
+				Preservation::Expendable,
+			)
+			.map(|_| ()),
+			None => {
+				<T as pallet::Config<I>>::Currency::unreserve(who, amount);
+				Ok(())
+			},
+		}
+	}
+
+	/// Pay `amount` of `maybe_asset` from `from` to `to`.
+	pub(crate) fn pay(
+		maybe_asset: Option<T::AssetId>,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: BalanceOf<T, I>,
+	) -> DispatchResult {
+		match maybe_asset {
+			Some(asset) => <T::Assets as fungibles::Mutate<T::AccountId>>::transfer(
+				asset,
+				from,
+				to,
+				amount,
+				Preservation::Preserve,
+			)
+			.map(|_| ()),
+			None => <T as pallet::Config<I>>::Currency::transfer(
+				from,
+				to,
+				amount,
+				ExistenceRequirement::KeepAlive,
+			),
+		}
+	}
+
+	/// Pay `amount` of `maybe_asset` reserved from `from` to `to`.
+	pub(crate) fn pay_reserved(
+		maybe_asset: Option<T::AssetId>,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: BalanceOf<T, I>,
+	) -> DispatchResult {
+		match maybe_asset {
+			Some(asset) => <T::Assets as fungibles::Mutate<T::AccountId>>::transfer(
+				asset,
+				&Self::escrow_account(),
+				to,
+				amount,
+				Preservation::Expendable,
+			)
+			.map(|_| ()),
+			None => {
+				let unmoved = <T as pallet::Config<I>>::Currency::repatriate_reserved(
+					from,
+					to,
+					amount,
+					BalanceStatus::Free,
+				)?;
+				ensure!(
+					unmoved.is_zero(),
+					Error::<T, I>::InsufficientReservedBalance
+				);
+				Ok(())
+			},
+		}
+	}
+
+	/// Calculate `rate` basis points of `amount`.
+	pub(crate) fn basis_points(amount: BalanceOf<T, I>, rate: u16) -> BalanceOf<T, I> {
+		amount.saturating_mul(rate.into()) / BASIS_POINTS.into()
+	}
+
+	/// Split `price` of `bundle` over its packages by their amount.
+	pub(crate) fn price_shares(
+		bundle: &[Package<T::CollectionId, T::ItemId>],
+		price: BalanceOf<T, I>,
+	) -> Vec<(Package<T::CollectionId, T::ItemId>, BalanceOf<T, I>)> {
+		let total: Amount =
+			bundle.iter().fold(0, |acc, package| acc.saturating_add(package.amount));
+		if total == 0 {
+			return Vec::new()
+		}
+
+		bundle
+			.iter()
+			.map(|package| {
+				let share = price.saturating_mul(package.amount.into()) / total.into();
+				(package.clone(), share)
+			})
+			.collect()
+	}
+
+	/// Trade fees owed to the games of `bundle` when it is sold at `price`.
+	///
+	/// The fee of an item is paid to the game that created its collection.
+	pub(crate) fn trade_fees(
+		bundle: &[Package<T::CollectionId, T::ItemId>],
+		price: BalanceOf<T, I>,
+	) -> Vec<(T::GameId, T::AccountId, BalanceOf<T, I>)> {
+		let mut fees: Vec<(T::GameId, T::AccountId, BalanceOf<T, I>)> = Vec::new();
+		for (package, share) in Self::price_shares(bundle, price) {
+			let game = match GamesOf::<T, I>::get(package.collection).first() {
+				Some(game) => *game,
+				None => continue,
+			};
+			let details = match Game::<T, I>::get(game) {
+				Some(details) => details,
+				None => continue,
+			};
+
+			let fee = Self::basis_points(share, details.trade_fee);
+			if fee.is_zero() {
+				continue
+			}
+			match fees.iter_mut().find(|(id, _, _)| *id == game) {
+				Some((_, _, amount)) => *amount = amount.saturating_add(fee),
+				None => {
+					let fee_account = match details.fee_account {
+						Some(fee_account) => fee_account,
+						None => Self::revenue_account(&game, details.owner),
+					};
+					fees.push((game, fee_account, fee))
+				},
+			}
+		}
+		fees
+	}
+
+	/// Transfer `price` of `bundle` from `from` to `to`, paying royalties and trade fees first.
+	///
+	/// The price is paid in the asset of `trade`.
+	pub(crate) fn transfer_price(
+		trade: &T::TradeId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		bundle: &[Package<T::CollectionId, T::ItemId>],
+		price: BalanceOf<T, I>,
+	) -> DispatchResult {
+		let maybe_asset = TradeAssetOf::<T, I>::get(trade);
+		let mut remaining = price;
+		for (package, beneficiary, royalty) in Self::royalties(bundle, price) {
+			Self::pay(maybe_asset, from, &beneficiary, royalty)?;
+			remaining = remaining.saturating_sub(royalty);
+			Self::deposit_event(Event::<T, I>::RoyaltyPaid {
+				trade: *trade,
+				collection: package.collection,
+				item: package.item,
+				beneficiary,
+				amount: royalty,
+			});
+		}
+
+		for (game, fee_account, fee) in Self::trade_fees(bundle, price) {
+			Self::pay(maybe_asset, from, &fee_account, fee)?;
+			remaining = remaining.saturating_sub(fee);
+			Self::deposit_event(Event::<T, I>::TradeFeePaid {
+				trade: *trade,
+				game,
+				fee_account,
+				amount: fee,
+			});
+		}
+
+		Self::pay(maybe_asset, from, to, remaining)?;
+		Ok(())
+	}
+
+	/// Move reserved `price` of `bundle` from `from` to `to`, paying royalties and trade fees
+	/// first.
+	///
+	/// The price is paid in the asset of `trade`.
+	pub(crate) fn repatriate_price(
+		trade: &T::TradeId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		bundle: &[Package<T::CollectionId, T::ItemId>],
+		price: BalanceOf<T, I>,
+	) -> DispatchResult {
+		let maybe_asset = TradeAssetOf::<T, I>::get(trade);
+		let mut remaining = price;
+		for (package, beneficiary, royalty) in Self::royalties(bundle, price) {
+			Self::pay_reserved(maybe_asset, from, &beneficiary, royalty)?;
+			remaining = remaining.saturating_sub(royalty);
+			Self::deposit_event(Event::<T, I>::RoyaltyPaid {
+				trade: *trade,
+				collection: package.collection,
+				item: package.item,
+				beneficiary,
+				amount: royalty,
+			});
+		}
+
+		for (game, fee_account, fee) in Self::trade_fees(bundle, price) {
+			Self::pay_reserved(maybe_asset, from, &fee_account, fee)?;
+			remaining = remaining.saturating_sub(fee);
+			Self::deposit_event(Event::<T, I>::TradeFeePaid {
+				trade: *trade,
+				game,
+				fee_account,
+				amount: fee,
+			});
+		}
+
+		Self::pay_reserved(maybe_asset, from, to, remaining)?;
+		Ok(())
+	}
+}
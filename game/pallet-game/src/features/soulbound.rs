@@ -0,0 +1,85 @@
+/// Soulbound module binds items to their holders, they can not be transferred or traded
+use crate::*;
+use frame_support::pallet_prelude::*;
+use gafi_support::game::{GameRole, Package, Soulbound};
+
+impl<T: Config<I>, I: 'static> Soulbound<T::AccountId, T::CollectionId, T::ItemId>
+	for Pallet<T, I>
+{
+	fn do_set_soulbound(
+		who: &T::AccountId,
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+		soulbound: bool,
+	) -> DispatchResult {
+		ensure!(
+			T::Nfts::is_admin(collection, who) ||
+				Self::has_collection_role(collection, who, GameRole::Moderator),
+			Error::<T, I>::NoPermission
+		);
+		ensure!(
+			SupplyOf::<T, I>::contains_key(collection, item),
+			Error::<T, I>::UnknownItem
+		);
+
+		if soulbound {
+			SoulboundOf::<T, I>::insert(collection, item, true);
+		} else {
+			SoulboundOf::<T, I>::remove(collection, item);
+		}
+
+		Self::deposit_event(Event::<T, I>::SoulboundSet {
+			collection: *collection,
+			item: *item,
+			soulbound,
+		});
+		Ok(())
+	}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Whether `item` in `collection` is soulbound, upgraded items follow their original item.
+	pub fn is_soulbound(collection: &T::CollectionId, item: &T::ItemId) -> bool {
+		let (collection, item) =
+			OriginItemOf::<T, I>::get((collection, item)).unwrap_or((*collection, *item));
+		SoulboundOf::<T, I>::get(collection, item)
+	}
+
+	/// Ensure `item` in `collection` is not soulbound.
+	pub(crate) fn ensure_transferable(
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+	) -> Result<(), Error<T, I>> {
+		ensure!(
+			!Self::is_soulbound(collection, item),
+			Error::<T, I>::SoulboundItem
+		);
+		Ok(())
+	}
+
+	/// Ensure no item in `bundle` is soulbound.
+	pub(crate) fn ensure_transferable_bundle(
+		bundle: &[Package<T::CollectionId, T::ItemId>],
+	) -> Result<(), Error<T, I>> {
+		for package in bundle.iter() {
+			Self::ensure_transferable(&package.collection, &package.item)?;
+		}
+		Ok(())
+	}
+
+	/// Ensure `item` in `collection` can be minted from a pool of `who`.
+	///
+	/// The owner, admin and `PoolManager`s of the collection can still mint soulbound items.
+	pub(crate) fn ensure_poolable(
+		who: &T::AccountId,
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+	) -> Result<(), Error<T, I>> {
+		if T::Nfts::is_admin(collection, who) ||
+			Self::ensure_collection_role(who, collection, GameRole::PoolManager).is_ok()
+		{
+			return Ok(())
+		}
+		Self::ensure_transferable(collection, item)
+	}
+}
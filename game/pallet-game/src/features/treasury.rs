@@ -0,0 +1,110 @@
+/// Treasury module keeps the revenue of each game in an account derived from the game id
+use crate::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{fungibles, tokens::Preservation, ExistenceRequirement},
+};
+use gafi_support::game::GameTreasury;
+use sp_runtime::traits::AccountIdConversion;
+
+impl<T: Config<I>, I: 'static> GameTreasury<T::AccountId, T::GameId, T::AssetId, BalanceOf<T, I>>
+	for Pallet<T, I>
+{
+	fn do_set_treasury_routing(
+		who: &T::AccountId,
+		game: &T::GameId,
+		enabled: bool,
+	) -> DispatchResult {
+		Game::<T, I>::try_mutate(game, |maybe_details| -> DispatchResult {
+			let details = maybe_details.as_mut().ok_or(Error::<T, I>::UnknownGame)?;
+			ensure!(details.owner == *who, Error::<T, I>::NoPermission);
+			ensure!(!details.is_destroying, Error::<T, I>::GameDestroying);
+
+			details.treasury_routing = enabled;
+			Ok(())
+		})?;
+		if enabled {
+			Self::ensure_provider(&Self::game_account(game));
+		}
+
+		Self::deposit_event(Event::<T, I>::TreasuryRoutingSet {
+			game: *game,
+			enabled,
+		});
+		Ok(())
+	}
+
+	fn do_spend_treasury(
+		who: &T::AccountId,
+		game: &T::GameId,
+		maybe_asset: Option<T::AssetId>,
+		dest: &T::AccountId,
+		amount: BalanceOf<T, I>,
+	) -> DispatchResult {
+		Self::ensure_game_owner(who, game)?;
+
+		// the treasury may be emptied, so the game can be destroyed
+		let treasury = Self::game_account(game);
+		match maybe_asset {
+			Some(asset) => {
+				<T::Assets as fungibles::Mutate<T::AccountId>>::transfer(
+					asset,
+					&treasury,
+					dest,
+					amount,
+					Preservation::Expendable,
+				)?;
+			},
+			None => <T as Config<I>>::Currency::transfer(
+				&treasury,
+				dest,
+				amount,
+				ExistenceRequirement::AllowDeath,
+			)?,
+		}
+
+		Self::deposit_event(Event::<T, I>::TreasurySpent {
+			game: *game,
+			maybe_asset,
+			dest: dest.clone(),
+			amount,
+		});
+		Ok(())
+	}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// The treasury account of `game`.
+	pub fn game_account(game: &T::GameId) -> T::AccountId {
+		T::PalletId::get().into_sub_account_truncating(game)
+	}
+
+	/// Account receiving the revenue of `game`, its treasury if routed, otherwise `default`.
+	pub(crate) fn revenue_account(game: &T::GameId, default: T::AccountId) -> T::AccountId {
+		match Game::<T, I>::get(game) {
+			Some(details) if details.treasury_routing => Self::game_account(game),
+			_ => default,
+		}
+	}
+
+	/// Route the mint revenue of `pool` to its game if `who` is the owner or admin of the game.
+	///
+	/// Pools created by other accounts keep their revenue whether the game routes it or not.
+	pub(crate) fn set_pool_treasury(pool: &T::PoolId, who: &T::AccountId) {
+		if let Some(game) = Self::pool_game(pool) {
+			if let Some(details) = Game::<T, I>::get(game) {
+				if details.owner == *who || details.admin == *who {
+					PoolTreasuryOf::<T, I>::insert(pool, game);
+				}
+			}
+		}
+	}
+
+	/// The game of the items minted by `pool`.
+	pub(crate) fn pool_game(pool: &T::PoolId) -> Option<T::GameId> {
+		LootTableOf::<T, I>::get(pool)
+			.iter()
+			.find_map(|loot| loot.maybe_nft.as_ref())
+			.and_then(|nft| GamesOf::<T, I>::get(nft.collection).first().copied())
+	}
+}
@@ -1,6 +1,6 @@
-use crate::*;
-use frame_support::{pallet_prelude::*, traits::ExistenceRequirement};
-use gafi_support::game::{Amount, Level, UpgradeItem};
+use crate::{features::settlement::BASIS_POINTS, *};
+use frame_support::pallet_prelude::*;
+use gafi_support::game::{Amount, Bundle, GameRole, Level, UpgradeFailure, UpgradeItem};
 use sp_runtime::Saturating;
 
 impl<T: Config<I>, I: 'static>
@@ -21,13 +21,26 @@ impl<T: Config<I>, I: 'static>
 		config: &ItemConfig,
 		level: Level,
 		fee: BalanceOf<T, I>,
+		materials: Bundle<T::CollectionId, T::ItemId>,
 	) -> DispatchResult {
 		// ensure collection ownership
 		ensure!(
-			T::Nfts::is_admin(collection, who),
+			T::Nfts::is_admin(collection, who) ||
+				Self::has_collection_role(collection, who, GameRole::UpgradeDesigner),
 			Error::<T, I>::NoPermission
 		);
 
+		// ensure materials exist
+		for package in materials.iter() {
+			ensure!(package.amount > 0, Error::<T, I>::InvalidAmount);
+			ensure!(
+				SupplyOf::<T, I>::contains_key(package.collection, package.item),
+				Error::<T, I>::UnknownItem
+			);
+		}
+		let materials: BundleFor<T, I> =
+			BoundedVec::try_from(materials).map_err(|_| Error::<T, I>::ExceedMaxBundle)?;
+
 		// ensure upgrade level available
 		ensure!(
 			!UpgradeConfigOf::<T, I>::contains_key((collection, item, level)),
@@ -50,6 +63,9 @@ impl<T: Config<I>, I: 'static>
 			UpgradeItemConfig {
 				item: *new_item,
 				fee,
+				materials,
+				success_rate: BASIS_POINTS,
+				on_failure: UpgradeFailure::Keep,
 			},
 		);
 
@@ -71,26 +87,40 @@ impl<T: Config<I>, I: 'static>
 		amount: Amount,
 	) -> DispatchResult {
 		ensure!(amount > 0, Error::<T, I>::InvalidAmount);
+		// the upgrade may be rolled once per item
+		ensure!(
+			amount <= T::MaxMintItem::get(),
+			Error::<T, I>::ExceedAllowedAmount
+		);
 
-		let mut next_level = LevelOf::<T, I>::get(collection, item);
-		next_level.saturating_inc();
-
-		// get origin item
-		let origin_item = match OriginItemOf::<T, I>::get((collection, item)) {
-			Some(val) => val.1,
-			None => *item,
-		};
-
-		if let Some(config) = UpgradeConfigOf::<T, I>::get((collection, origin_item, next_level)) {
+		if let Some(config) = Self::next_upgrade_config(collection, item) {
 			if let Some(owner) = T::Nfts::collection_owner(collection) {
-				<T as pallet::Config<I>>::Currency::transfer(
-					&who,
+				Self::pay(
+					Self::payment_asset_of(collection),
+					who,
 					&owner,
 					config.fee.saturating_mul(amount.into()),
-					ExistenceRequirement::KeepAlive,
 				)?;
 			}
 
+			// burn materials
+			for package in config.materials.iter() {
+				let material_amount = package.amount.saturating_mul(amount);
+				Self::sub_item_balance(who, &package.collection, &package.item, material_amount)?;
+				Self::decrease_finite_item_supply(
+					&package.collection,
+					&package.item,
+					material_amount,
+				);
+			}
+
+			if config.success_rate == 0 {
+				return Self::fail_upgrade(who, collection, item, amount, config.on_failure)
+			}
+			if config.success_rate < BASIS_POINTS {
+				return Self::request_upgrade(who, collection, item, amount, &config)
+			}
+
 			Self::convert_item(who, collection, item, &config.item, amount)?;
 
 			Self::deposit_event(Event::Upgraded {
@@ -106,4 +136,189 @@ impl<T: Config<I>, I: 'static>
 
 		Err(Error::<T, I>::UnknownUpgrade.into())
 	}
+
+	fn do_set_upgrade_chance(
+		who: &T::AccountId,
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+		level: Level,
+		success_rate: u16,
+		on_failure: UpgradeFailure,
+	) -> DispatchResult {
+		ensure!(
+			T::Nfts::is_admin(collection, who) ||
+				Self::has_collection_role(collection, who, GameRole::UpgradeDesigner),
+			Error::<T, I>::NoPermission
+		);
+		ensure!(
+			success_rate <= BASIS_POINTS,
+			Error::<T, I>::InvalidSuccessRate
+		);
+
+		UpgradeConfigOf::<T, I>::try_mutate((collection, item, level), |maybe_config| {
+			let config = maybe_config.as_mut().ok_or(Error::<T, I>::UnknownUpgrade)?;
+			config.success_rate = success_rate;
+			config.on_failure = on_failure;
+			Ok::<(), Error<T, I>>(())
+		})?;
+
+		Self::deposit_event(Event::<T, I>::UpgradeChanceSet {
+			who: who.clone(),
+			collection: *collection,
+			item: *item,
+			level,
+			success_rate,
+			on_failure,
+		});
+
+		Ok(())
+	}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// The upgrade config of the level above `item`.
+	pub(crate) fn next_upgrade_config(
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+	) -> Option<ItemUpgradeConfigFor<T, I>> {
+		let mut next_level = LevelOf::<T, I>::get(collection, item);
+		next_level.saturating_inc();
+
+		// get origin item
+		let origin_item = match OriginItemOf::<T, I>::get((collection, item)) {
+			Some(val) => val.1,
+			None => *item,
+		};
+		UpgradeConfigOf::<T, I>::get((collection, origin_item, next_level))
+	}
+
+	/// Reserve `amount` of `item` held by `who` and roll the upgrade after `MintInterval`.
+	fn request_upgrade(
+		who: &T::AccountId,
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+		amount: Amount,
+		config: &ItemUpgradeConfigFor<T, I>,
+	) -> DispatchResult {
+		Self::sub_item_balance(who, collection, item, amount)?;
+		Self::add_reserved_balance(who, collection, item, amount)?;
+
+		let block_number = <frame_system::Pallet<T>>::block_number();
+		let execute_block = block_number.saturating_add(T::MintInterval::get());
+		let request = UpgradeRequest {
+			who: who.clone(),
+			collection: *collection,
+			item: *item,
+			new_item: config.item,
+			amount,
+			success_rate: config.success_rate,
+			on_failure: config.on_failure,
+		};
+		UpgradeRequestOf::<T, I>::try_mutate(execute_block, |requests| -> DispatchResult {
+			requests.try_push(request).map_err(|_| Error::<T, I>::OverRequest)?;
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::<T, I>::UpgradeRequested {
+			who: who.clone(),
+			collection: *collection,
+			item: *item,
+			amount,
+			block_number: execute_block,
+		});
+		Ok(())
+	}
+
+	/// Roll each item of an upgrade `request`, upgrading the successes and applying the failure
+	/// outcome to the rest.
+	pub fn processing_upgrade_request(request: UpgradeRequestFor<T>) -> DispatchResult {
+		let who = &request.who;
+		Self::unreserved_item(who, &request.collection, &request.item, request.amount)?;
+
+		let upgraded = (0..request.amount)
+			.filter(|index| Self::roll_upgrade(request.success_rate, *index))
+			.fold(0, |acc: Amount, _| acc.saturating_add(1));
+		if upgraded > 0 {
+			Self::convert_item(
+				who,
+				&request.collection,
+				&request.item,
+				&request.new_item,
+				upgraded,
+			)?;
+			Self::deposit_event(Event::Upgraded {
+				who: who.clone(),
+				collection: request.collection,
+				item: request.item,
+				new_item: request.new_item,
+				amount: upgraded,
+			});
+		}
+
+		let failed = request.amount.saturating_sub(upgraded);
+		if failed > 0 {
+			Self::fail_upgrade(
+				who,
+				&request.collection,
+				&request.item,
+				failed,
+				request.on_failure,
+			)?;
+		}
+		Ok(())
+	}
+
+	/// Roll an upgrade attempt succeeding with `success_rate` in basis points.
+	fn roll_upgrade(success_rate: u16, adjust: Amount) -> bool {
+		if success_rate >= BASIS_POINTS {
+			return true
+		}
+		match T::GameRandomness::random_number(BASIS_POINTS.into(), adjust) {
+			Some(random) => random <= success_rate.into(),
+			None => false,
+		}
+	}
+
+	/// Apply the failure `outcome` to `amount` of `item` held by `who`.
+	fn fail_upgrade(
+		who: &T::AccountId,
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+		amount: Amount,
+		outcome: UpgradeFailure,
+	) -> DispatchResult {
+		match outcome {
+			UpgradeFailure::Keep => {},
+			UpgradeFailure::Downgrade => {
+				// the original item has no lower level
+				if let Some(lower_item) = Self::lower_item(collection, item) {
+					Self::convert_item(who, collection, item, &lower_item, amount)?;
+				}
+			},
+			UpgradeFailure::Destroy => {
+				Self::sub_item_balance(who, collection, item, amount)?;
+				Self::decrease_finite_item_supply(collection, item, amount);
+			},
+		}
+
+		Self::deposit_event(Event::<T, I>::UpgradeFailed {
+			who: who.clone(),
+			collection: *collection,
+			item: *item,
+			amount,
+			outcome,
+		});
+
+		Ok(())
+	}
+
+	/// The item one level below `item`, `None` for an original item.
+	fn lower_item(collection: &T::CollectionId, item: &T::ItemId) -> Option<T::ItemId> {
+		let (_, origin) = OriginItemOf::<T, I>::get((collection, item))?;
+		let level = LevelOf::<T, I>::get(collection, item);
+		if level <= 1 {
+			return Some(origin)
+		}
+		UpgradeConfigOf::<T, I>::get((collection, origin, level - 1)).map(|config| config.item)
+	}
 }
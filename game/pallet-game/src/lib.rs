@@ -32,6 +32,7 @@ mod mock;
 mod tests;
 
 mod features;
+pub mod migration;
 mod trades;
 mod types;
 
@@ -45,9 +46,13 @@ use frame_support::{
 	ensure,
 	pallet_prelude::*,
 	traits::{
-		tokens::nonfungibles_v2::{Create, Inspect, InspectRole, Mutate, Transfer},
-		BalanceStatus, Currency, Incrementable, ReservableCurrency,
+		tokens::{
+			fungibles,
+			nonfungibles_v2::{Create, Inspect, InspectRole, Mutate, Transfer},
+		},
+		Currency, Incrementable, ReservableCurrency,
 	},
+	PalletId,
 };
 use frame_system::{pallet_prelude::BlockNumberFor, Config as SystemConfig};
 use gafi_support::game::*;
@@ -66,15 +71,20 @@ pub mod pallet {
 	use sp_core::Get;
 
 	use super::*;
+	use crate::features::settlement::BASIS_POINTS;
 	use frame_system::pallet_prelude::{OriginFor, *};
-	use gafi_support::game::{Bundle, GameRandomness, Loot, NFT};
+	use gafi_support::game::{Bundle, GameRandomness, GameRole, Loot, NFT};
 	use pallet_nfts::CollectionRoles;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T, I = ()>(_);
 
 	#[cfg(feature = "runtime-benchmarks")]
-	pub trait BenchmarkHelper<GameId, TradeId, BlockNumber, PoolId> {
+	pub trait BenchmarkHelper<GameId, TradeId, BlockNumber, PoolId, RecipeId, MatchId> {
 		fn game(i: u16) -> GameId;
 
 		fn trade(i: u16) -> TradeId;
@@ -82,11 +92,21 @@ pub mod pallet {
 		fn block(i: u16) -> BlockNumber;
 
 		fn pool(i: u16) -> PoolId;
+
+		fn recipe(i: u16) -> RecipeId;
+
+		fn match_id(i: u16) -> MatchId;
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]
-	impl<GameId: From<u16>, TradeId: From<u16>, BlockNumber: From<u16>, PoolId: From<u16>>
-		BenchmarkHelper<GameId, TradeId, BlockNumber, PoolId> for ()
+	impl<
+			GameId: From<u16>,
+			TradeId: From<u16>,
+			BlockNumber: From<u16>,
+			PoolId: From<u16>,
+			RecipeId: From<u16>,
+			MatchId: From<u16>,
+		> BenchmarkHelper<GameId, TradeId, BlockNumber, PoolId, RecipeId, MatchId> for ()
 	{
 		fn game(i: u16) -> GameId {
 			i.into()
@@ -103,6 +123,14 @@ pub mod pallet {
 		fn pool(i: u16) -> PoolId {
 			i.into()
 		}
+
+		fn recipe(i: u16) -> RecipeId {
+			i.into()
+		}
+
+		fn match_id(i: u16) -> MatchId {
+			i.into()
+		}
 	}
 
 	/// Configure the pallet by specifying the parameters and types on which it depends.
@@ -121,6 +149,20 @@ pub mod pallet {
 		/// The currency mechanism, used for paying for reserves.
 		type Currency: ReservableCurrency<Self::AccountId>;
 
+		/// The type used to identify a fungible asset accepted as payment.
+		type AssetId: Member + Parameter + MaxEncodedLen + Copy;
+
+		/// The fungible assets a game can price its trades, mints and upgrades in.
+		type Assets: fungibles::Mutate<
+			Self::AccountId,
+			AssetId = Self::AssetId,
+			Balance = BalanceOf<Self, I>,
+		>;
+
+		/// The pallet id, used for deriving the account holding asset payments in escrow.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
 		/// pallet_nfts
 		type Nfts: Mutate<Self::AccountId, ItemConfig>
 			+ Transfer<Self::AccountId>
@@ -140,6 +182,12 @@ pub mod pallet {
 		/// The type used to identify a unique minting pool
 		type PoolId: Member + Parameter + MaxEncodedLen + Copy + Incrementable;
 
+		/// The type used to identify a unique crafting recipe
+		type RecipeId: Member + Parameter + MaxEncodedLen + Copy + Incrementable;
+
+		/// The type used to identify a unique match escrow
+		type MatchId: Member + Parameter + MaxEncodedLen + Copy + Incrementable;
+
 		/// The basic amount of funds that must be reserved for a game.
 		#[pallet::constant]
 		type GameDeposit: Get<BalanceOf<Self, I>>;
@@ -191,6 +239,38 @@ pub mod pallet {
 		#[pallet::constant]
 		type MintInterval: Get<BlockNumberFor<Self>>;
 
+		/// Maximum number of game roles removed in a single `destroy_game_roles` call.
+		#[pallet::constant]
+		type RemoveRolesLimit: Get<u32>;
+
+		/// Maximum number of bids recorded for a candle auction.
+		#[pallet::constant]
+		type MaxCandleBid: Get<u32>;
+
+		/// Maximum number of bids committed for a sealed-bid auction.
+		#[pallet::constant]
+		type MaxSealedBid: Get<u32>;
+
+		/// Maximum royalty rate in basis points.
+		#[pallet::constant]
+		type MaxRoyalty: Get<u16>;
+
+		/// Maximum trade fee of a game in basis points.
+		#[pallet::constant]
+		type MaxTradeFee: Get<u16>;
+
+		/// Maximum number of listings or purchases kept in the order book of an item.
+		#[pallet::constant]
+		type MaxBookOrders: Get<u32>;
+
+		/// Maximum number of trades expiring in the same block.
+		#[pallet::constant]
+		type MaxExpiringTrades: Get<u32>;
+
+		/// Maximum number of players joining a match.
+		#[pallet::constant]
+		type MaxMatchPlayers: Get<u32>;
+
 		#[cfg(feature = "runtime-benchmarks")]
 		/// A set of helper functions for benchmarking.
 		type Helper: BenchmarkHelper<
@@ -198,6 +278,8 @@ pub mod pallet {
 			Self::TradeId,
 			BlockNumberFor<Self>,
 			Self::PoolId,
+			Self::RecipeId,
+			Self::MatchId,
 		>;
 	}
 
@@ -234,6 +316,11 @@ pub mod pallet {
 	pub(super) type NextPoolId<T: Config<I>, I: 'static = ()> =
 		StorageValue<_, T::PoolId, OptionQuery>;
 
+	/// Storing next crafting recipe id
+	#[pallet::storage]
+	pub(super) type NextRecipeId<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, T::RecipeId, OptionQuery>;
+
 	/// Collections in the game
 	#[pallet::storage]
 	pub(super) type CollectionsOf<T: Config<I>, I: 'static = ()> = StorageMap<
@@ -254,6 +341,11 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// The game that created each collection.
+	#[pallet::storage]
+	pub(super) type CollectionGameOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::CollectionId, T::GameId, OptionQuery>;
+
 	/// Metadata of a game.
 	#[pallet::storage]
 	pub type GameMetadataOf<T: Config<I>, I: 'static = ()> =
@@ -276,6 +368,19 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Game-scoped roles granted by the game owners
+	#[pallet::storage]
+	pub(super) type GrantedRoleOf<T: Config<I>, I: 'static = ()> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::GameId>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Blake2_128Concat, GameRole>,
+		),
+		(),
+		OptionQuery,
+	>;
+
 	/// Item balances of account
 	#[pallet::storage]
 	pub(super) type ItemBalanceOf<T: Config<I>, I: 'static = ()> = StorageNMap<
@@ -302,6 +407,19 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Storing the amount of items rented by an account
+	#[pallet::storage]
+	pub(super) type UseRightOf<T: Config<I>, I: 'static = ()> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Blake2_128Concat, T::CollectionId>,
+			NMapKey<Twox64Concat, T::ItemId>,
+		),
+		u32,
+		ValueQuery,
+	>;
+
 	/// Storing Nft supplies, `None` indicates infinite supply
 	#[pallet::storage]
 	pub(super) type SupplyOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
@@ -329,6 +447,35 @@ pub mod pallet {
 	pub(super) type PoolOf<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Twox64Concat, T::PoolId, PoolDetailsFor<T, I>, OptionQuery>;
 
+	/// Items minted by each account from a pool
+	#[pallet::storage]
+	pub(super) type MintRecordOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::PoolId,
+		Blake2_128Concat,
+		T::AccountId,
+		MintRecord<BlockNumberFor<T>>,
+		ValueQuery,
+	>;
+
+	/// Mints of each account from a pool since its last loot of the pity tier
+	#[pallet::storage]
+	pub(super) type PityCounterOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::PoolId,
+		Blake2_128Concat,
+		T::AccountId,
+		Amount,
+		ValueQuery,
+	>;
+
+	/// Voucher nonces used in each pool
+	#[pallet::storage]
+	pub(super) type UsedVoucherOf<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Twox64Concat, T::PoolId, Twox64Concat, u32, (), OptionQuery>;
+
 	/// Storing mint request
 	#[pallet::storage]
 	pub(super) type MintRequestOf<T: Config<I>, I: 'static = ()> = StorageMap<
@@ -339,6 +486,26 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Storing the upgrade attempts resolved in a block
+	#[pallet::storage]
+	pub(super) type UpgradeRequestOf<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<UpgradeRequestFor<T>, T::MaxMintRequest>,
+		ValueQuery,
+	>;
+
+	/// Storing the salvages rolled in a block
+	#[pallet::storage]
+	pub(super) type SalvageRequestOf<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<SalvageRequestFor<T, I>, T::MaxMintRequest>,
+		ValueQuery,
+	>;
+
 	/// Level of item
 	#[pallet::storage]
 	pub(super) type LevelOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
@@ -374,6 +541,59 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Storing the crafting recipes of games
+	#[pallet::storage]
+	pub(super) type RecipeOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::RecipeId, RecipeDetailsFor<T, I>, OptionQuery>;
+
+	/// Storing the items received when salvaging an item
+	#[pallet::storage]
+	pub(super) type SalvageOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		SalvageDetailsFor<T, I>,
+		OptionQuery,
+	>;
+
+	/// Storing the operators approved to transfer items of an account, in a collection or in any
+	/// collection, with the last block of the approval if any
+	#[pallet::storage]
+	pub(super) type OperatorApprovalOf<T: Config<I>, I: 'static = ()> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Blake2_128Concat, Option<T::CollectionId>>,
+		),
+		Option<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// Storing next match id
+	#[pallet::storage]
+	pub(super) type NextMatchId<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, T::MatchId, OptionQuery>;
+
+	/// Storing the matches waiting for their result
+	#[pallet::storage]
+	pub(super) type MatchOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::MatchId, MatchDetailsFor<T, I>, OptionQuery>;
+
+	/// Storing the soulbound items, which can not be transferred or traded
+	#[pallet::storage]
+	pub(super) type SoulboundOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		bool,
+		ValueQuery,
+	>;
+
 	/// Storing random seed generated from the off-chain worker every block
 	#[pallet::storage]
 	pub(crate) type RandomSeed<T: Config<I>, I: 'static = ()> =
@@ -404,11 +624,178 @@ pub mod pallet {
 	pub(super) type HighestBidOf<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Blake2_128Concat, T::TradeId, (T::AccountId, BalanceOf<T, I>), OptionQuery>;
 
+	/// Storing candle auction configuration
+	#[pallet::storage]
+	pub(super) type CandleAuctionOf<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::TradeId,
+		CandleAuctionConfig<T::AccountId, BalanceOf<T, I>, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// Storing the bids of candle auction with the block they were made
+	#[pallet::storage]
+	pub(super) type CandleBidsOf<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::TradeId,
+		BoundedVec<(T::AccountId, BalanceOf<T, I>, BlockNumberFor<T>), T::MaxCandleBid>,
+		ValueQuery,
+	>;
+
+	/// Storing the candle auctions drawing their closing block in a block
+	#[pallet::storage]
+	pub(super) type CandleClosingOf<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<T::TradeId, T::MaxExpiringTrades>,
+		ValueQuery,
+	>;
+
+	/// Storing dutch auction configuration
+	#[pallet::storage]
+	pub(super) type DutchAuctionOf<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::TradeId,
+		DutchAuctionConfig<T::AccountId, BalanceOf<T, I>, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// Storing rental configuration
+	#[pallet::storage]
+	pub(super) type RentalOf<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::TradeId,
+		RentalConfig<T::AccountId, BalanceOf<T, I>, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// Storing sealed-bid auction configuration
+	#[pallet::storage]
+	pub(super) type SealedAuctionOf<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::TradeId,
+		SealedAuctionConfig<T::AccountId, BalanceOf<T, I>, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// Storing the committed bids of sealed-bid auction
+	#[pallet::storage]
+	pub(super) type SealedBidsOf<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::TradeId,
+		BoundedVec<(T::AccountId, SealedBid<T::Hash, BalanceOf<T, I>>), T::MaxSealedBid>,
+		ValueQuery,
+	>;
+
+	/// Storing royalty of collections
+	#[pallet::storage]
+	pub(super) type CollectionRoyaltyOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, RoyaltyConfig<T::AccountId>, OptionQuery>;
+
+	/// Storing royalty of items, overrides the royalty of the collection
+	#[pallet::storage]
+	pub(super) type ItemRoyaltyOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		RoyaltyConfig<T::AccountId>,
+		OptionQuery,
+	>;
+
+	/// Storing the listings in the order book of items per payment asset, lowest price first
+	#[pallet::storage]
+	pub(super) type AskBookOf<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		(T::CollectionId, T::ItemId, Option<T::AssetId>),
+		BoundedVec<(T::TradeId, BalanceOf<T, I>), T::MaxBookOrders>,
+		ValueQuery,
+	>;
+
+	/// Storing the purchases in the order book of items per payment asset, highest price first
+	#[pallet::storage]
+	pub(super) type BidBookOf<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		(T::CollectionId, T::ItemId, Option<T::AssetId>),
+		BoundedVec<(T::TradeId, BalanceOf<T, I>), T::MaxBookOrders>,
+		ValueQuery,
+	>;
+
+	/// Storing the asset paid for trades not priced in the native currency
+	#[pallet::storage]
+	pub(super) type TradeAssetOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::TradeId, T::AssetId, OptionQuery>;
+
+	/// Storing the asset paid for mints in pools not priced in the native currency
+	#[pallet::storage]
+	pub(super) type PoolAssetOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::PoolId, T::AssetId, OptionQuery>;
+
+	/// Storing the game whose revenue account receives the mint revenue of a pool
+	#[pallet::storage]
+	pub(super) type PoolTreasuryOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::PoolId, T::GameId, OptionQuery>;
+
+	/// Storing the trades expiring in a block
+	#[pallet::storage]
+	pub(super) type TradeExpiryOf<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<(T::TradeId, TradeType), T::MaxExpiringTrades>,
+		ValueQuery,
+	>;
+
+	/// The first block whose expiring trades have not been processed
+	#[pallet::storage]
+	pub(super) type NextExpiryBlock<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
 	/// Store accepts to add collections to the games
 	#[pallet::storage]
 	pub(super) type AddingAcceptance<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Blake2_128Concat, T::CollectionId, T::GameId, OptionQuery>;
 
+	/// The accepts to add collections to each game, with the account and the deposit reserved
+	/// for them
+	#[pallet::storage]
+	pub(super) type AddingAcceptanceOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::GameId,
+		Blake2_128Concat,
+		T::CollectionId,
+		(T::AccountId, BalanceOf<T, I>),
+		OptionQuery,
+	>;
+
+	/// The game ownership each account accepts to receive
+	#[pallet::storage]
+	pub(super) type GameOwnershipAcceptance<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::GameId, OptionQuery>;
+
+	/// The accounts accepting the ownership of each game
+	#[pallet::storage]
+	pub(super) type OwnershipAcceptorOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::GameId,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -478,6 +865,37 @@ pub mod pallet {
 			dest: T::AccountId,
 			amount: Amount,
 		},
+		MatchCreated {
+			match_id: T::MatchId,
+			who: T::AccountId,
+			game: T::GameId,
+			stake: BalanceOf<T, I>,
+			maybe_server: Option<T::AccountId>,
+			end_block: BlockNumberFor<T>,
+		},
+		MatchJoined {
+			match_id: T::MatchId,
+			who: T::AccountId,
+			items: Bundle<T::CollectionId, T::ItemId>,
+		},
+		MatchSettled {
+			match_id: T::MatchId,
+			result: MatchResult<T::AccountId>,
+		},
+		MatchRefunded {
+			match_id: T::MatchId,
+		},
+		OperatorApproved {
+			who: T::AccountId,
+			operator: T::AccountId,
+			maybe_collection: Option<T::CollectionId>,
+			maybe_deadline: Option<BlockNumberFor<T>>,
+		},
+		OperatorRevoked {
+			who: T::AccountId,
+			operator: T::AccountId,
+			maybe_collection: Option<T::CollectionId>,
+		},
 		UpgradeSet {
 			who: T::AccountId,
 			collection: T::CollectionId,
@@ -492,21 +910,92 @@ pub mod pallet {
 			new_item: T::ItemId,
 			amount: Amount,
 		},
-		PriceSet {
-			trade: T::TradeId,
+		UpgradeRequested {
 			who: T::AccountId,
 			collection: T::CollectionId,
 			item: T::ItemId,
 			amount: Amount,
-			unit_price: BalanceOf<T, I>,
-			start_block: Option<BlockNumberFor<T>>,
-			end_block: Option<BlockNumberFor<T>>,
+			block_number: BlockNumberFor<T>,
 		},
-		ItemBought {
-			trade: T::TradeId,
+		UpgradeChanceSet {
 			who: T::AccountId,
-			amount: Amount,
-			bid_unit_price: BalanceOf<T, I>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			level: Level,
+			success_rate: u16,
+			on_failure: UpgradeFailure,
+		},
+		UpgradeFailed {
+			who: T::AccountId,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			amount: Amount,
+			outcome: UpgradeFailure,
+		},
+		RecipeCreated {
+			recipe: T::RecipeId,
+			who: T::AccountId,
+			game: T::GameId,
+			input: Bundle<T::CollectionId, T::ItemId>,
+			maybe_fee: Option<BalanceOf<T, I>>,
+			output: Bundle<T::CollectionId, T::ItemId>,
+			maybe_crafts: Option<Amount>,
+		},
+		RecipeRemoved {
+			recipe: T::RecipeId,
+		},
+		Crafted {
+			who: T::AccountId,
+			recipe: T::RecipeId,
+			times: Amount,
+		},
+		SalvageSet {
+			who: T::AccountId,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			output: SalvageOutput<
+				Bundle<T::CollectionId, T::ItemId>,
+				LootTable<T::CollectionId, T::ItemId>,
+			>,
+		},
+		SalvageCleared {
+			collection: T::CollectionId,
+			item: T::ItemId,
+		},
+		SoulboundSet {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			soulbound: bool,
+		},
+		SalvageRequested {
+			who: T::AccountId,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			amount: Amount,
+			block_number: BlockNumberFor<T>,
+		},
+		Salvaged {
+			who: T::AccountId,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			amount: Amount,
+			output: Bundle<T::CollectionId, T::ItemId>,
+		},
+		PriceSet {
+			trade: T::TradeId,
+			who: T::AccountId,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			amount: Amount,
+			unit_price: BalanceOf<T, I>,
+			start_block: Option<BlockNumberFor<T>>,
+			end_block: Option<BlockNumberFor<T>>,
+		},
+		ItemBought {
+			trade: T::TradeId,
+			who: T::AccountId,
+			amount: Amount,
+			bid_unit_price: BalanceOf<T, I>,
 		},
 		BundleSet {
 			trade: T::TradeId,
@@ -605,6 +1094,163 @@ pub mod pallet {
 			who: T::AccountId,
 			pool: T::PoolId,
 		},
+		CandleAuctionSet {
+			trade: T::TradeId,
+			who: T::AccountId,
+			source: Bundle<T::CollectionId, T::ItemId>,
+			maybe_price: Option<BalanceOf<T, I>>,
+			start_block: BlockNumberFor<T>,
+			early_end: BlockNumberFor<T>,
+			end_block: BlockNumberFor<T>,
+		},
+		CandleAuctionClaimed {
+			trade: T::TradeId,
+			closing_block: BlockNumberFor<T>,
+			maybe_bid: Option<(T::AccountId, BalanceOf<T, I>)>,
+		},
+		DutchAuctionSet {
+			trade: T::TradeId,
+			who: T::AccountId,
+			source: Bundle<T::CollectionId, T::ItemId>,
+			start_price: BalanceOf<T, I>,
+			floor_price: BalanceOf<T, I>,
+			start_block: Option<BlockNumberFor<T>>,
+			duration: BlockNumberFor<T>,
+		},
+		DutchAuctionBought {
+			trade: T::TradeId,
+			who: T::AccountId,
+			price: BalanceOf<T, I>,
+		},
+		RentalSet {
+			trade: T::TradeId,
+			who: T::AccountId,
+			source: Bundle<T::CollectionId, T::ItemId>,
+			price_per_block: BalanceOf<T, I>,
+			max_duration: BlockNumberFor<T>,
+		},
+		Rented {
+			trade: T::TradeId,
+			who: T::AccountId,
+			duration: BlockNumberFor<T>,
+			price: BalanceOf<T, I>,
+			end_block: BlockNumberFor<T>,
+		},
+		RentalReturned {
+			trade: T::TradeId,
+			who: T::AccountId,
+		},
+		SealedAuctionSet {
+			trade: T::TradeId,
+			who: T::AccountId,
+			source: Bundle<T::CollectionId, T::ItemId>,
+			maybe_price: Option<BalanceOf<T, I>>,
+			start_block: Option<BlockNumberFor<T>>,
+			reveal_block: BlockNumberFor<T>,
+			end_block: BlockNumberFor<T>,
+		},
+		SealedBidCommitted {
+			trade: T::TradeId,
+			who: T::AccountId,
+			deposit: BalanceOf<T, I>,
+		},
+		SealedBidRevealed {
+			trade: T::TradeId,
+			who: T::AccountId,
+			bid: BalanceOf<T, I>,
+		},
+		SealedAuctionClaimed {
+			trade: T::TradeId,
+			maybe_bid: Option<(T::AccountId, BalanceOf<T, I>)>,
+			slashed: BalanceOf<T, I>,
+		},
+		RoyaltySet {
+			collection: T::CollectionId,
+			maybe_item: Option<T::ItemId>,
+			beneficiary: T::AccountId,
+			rate: u16,
+		},
+		RoyaltyCleared {
+			collection: T::CollectionId,
+			maybe_item: Option<T::ItemId>,
+		},
+		RoyaltyPaid {
+			trade: T::TradeId,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			beneficiary: T::AccountId,
+			amount: BalanceOf<T, I>,
+		},
+		TradeFeeSet {
+			game: T::GameId,
+			fee: u16,
+			fee_account: Option<T::AccountId>,
+		},
+		TradeFeePaid {
+			trade: T::TradeId,
+			game: T::GameId,
+			fee_account: T::AccountId,
+			amount: BalanceOf<T, I>,
+		},
+		PaymentAssetSet {
+			game: T::GameId,
+			maybe_asset: Option<T::AssetId>,
+		},
+		GameOwnershipAcceptanceChanged {
+			who: T::AccountId,
+			maybe_game: Option<T::GameId>,
+		},
+		GameOwnershipTransferred {
+			game: T::GameId,
+			new_owner: T::AccountId,
+		},
+		GameAdminSet {
+			game: T::GameId,
+			admin: T::AccountId,
+		},
+		RoleGranted {
+			game: T::GameId,
+			who: T::AccountId,
+			role: GameRole,
+		},
+		RoleRevoked {
+			game: T::GameId,
+			who: T::AccountId,
+			role: GameRole,
+		},
+		TreasuryRoutingSet {
+			game: T::GameId,
+			enabled: bool,
+		},
+		TreasurySpent {
+			game: T::GameId,
+			maybe_asset: Option<T::AssetId>,
+			dest: T::AccountId,
+			amount: BalanceOf<T, I>,
+		},
+		OrderMatched {
+			ask: T::TradeId,
+			bid: T::TradeId,
+			seller: T::AccountId,
+			buyer: T::AccountId,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			amount: Amount,
+			unit_price: BalanceOf<T, I>,
+		},
+		TradeExpired {
+			trade: T::TradeId,
+		},
+		GameDestructionStarted {
+			game: T::GameId,
+		},
+		GameRolesDestroyed {
+			game: T::GameId,
+			roles_destroyed: u32,
+		},
+		GameDestroyed {
+			game: T::GameId,
+		},
 	}
 
 	#[pallet::error]
@@ -620,6 +1266,11 @@ pub mod pallet {
 		UnknownBid,
 		UnknownAcceptance,
 		UnknownMiningPool,
+		UnknownRecipe,
+		UnknownSalvage,
+		UnknownRental,
+		UnknownApproval,
+		UnknownMatch,
 
 		MetadataNotFound,
 
@@ -642,6 +1293,8 @@ pub mod pallet {
 		/// Too many attempts
 		WithdrawReserveFailed,
 		UpgradeExists,
+		/// The success rate exceeds 100%
+		InvalidSuccessRate,
 		/// Add the same collection into a game
 		CollectionExists,
 
@@ -653,6 +1306,10 @@ pub mod pallet {
 
 		/// Transfer is locked for any trade
 		ItemLocked,
+		/// The item is soulbound and can not be transferred or traded
+		SoulboundItem,
+		/// The approval of the operator has expired
+		ApprovalExpired,
 
 		/// The bid is lower than the set price.
 		BidTooLow,
@@ -676,6 +1333,67 @@ pub mod pallet {
 		AuctionInProgress,
 		AuctionNotStarted,
 		AuctionEnded,
+		/// The auction periods are not in order
+		InvalidAuctionPeriod,
+		/// Exceed max bids in a candle auction
+		ExceedMaxCandleBid,
+		/// The floor price is higher than the start price
+		InvalidAuctionPrice,
+		/// Exceed max bids in a sealed-bid auction
+		ExceedMaxSealedBid,
+		/// The bidder has already committed a bid
+		BidCommitted,
+		/// The bidder has not committed a bid
+		BidNotCommitted,
+		/// The bid has already been revealed
+		BidRevealed,
+		/// The revealed bid does not match the commitment
+		InvalidReveal,
+		/// The revealed bid is higher than the deposit
+		InsufficientDeposit,
+		/// The reveal period has not started
+		RevealNotStarted,
+
+		// rental
+		/// The items are rented
+		ItemRented,
+		/// The items are not rented
+		NotRented,
+		/// The rental term has not ended
+		RentalNotEnded,
+		/// The rental duration is zero or exceeds the maximum duration
+		InvalidRentalDuration,
+
+		// royalty
+		/// The royalty rate exceeds `MaxRoyalty`
+		RoyaltyTooHigh,
+		UnknownRoyalty,
+		/// The trade fee exceeds `MaxTradeFee`
+		TradeFeeTooHigh,
+		/// The asset does not exist
+		UnknownAsset,
+
+		// order book
+		/// The order book of the item is full
+		OrderBookFull,
+
+		// crafting
+		/// The recipe has no input or output, or uses items outside of the game
+		InvalidRecipe,
+		/// The salvage output is empty
+		InvalidSalvage,
+
+		// match
+		/// The match has the maximum number of players
+		MatchFull,
+		/// The player has already joined the match
+		AlreadyJoined,
+		/// The match has timed out
+		MatchEnded,
+		/// The match has not timed out
+		MatchNotEnded,
+		/// The shares do not sum to 100% or the result names accounts outside of the match
+		InvalidMatchResult,
 
 		// trade type
 		NotSetPrice,
@@ -693,22 +1411,77 @@ pub mod pallet {
 		MintEnded,
 		NotWhitelisted,
 		OverRequest,
+		/// The account has minted the maximum items of the pool
+		ExceedMintLimit,
+		/// The account has minted the maximum items of the current window
+		ExceedMintWindowLimit,
+		/// The account must wait for the cooldown since its last mint request
+		MintCooldown,
+		/// The mint proof does not match the mint type of the pool
+		InvalidMintProof,
+		/// The account has minted the maximum items of its allowlist entry or voucher
+		ExceedMintAllowance,
+		/// The voucher has passed its expiry block
+		VoucherExpired,
+		/// The voucher nonce has already been used
+		VoucherUsed,
+		/// The pity threshold is zero or its tier is not in the loot table
+		InvalidPity,
+
+		// destroy
+		/// The game is being destroyed
+		GameDestroying,
+		/// The game destruction has not been started
+		GameNotDestroying,
+		/// The witness data given does not match the current state of the game
+		BadWitness,
+		/// Game roles and acceptances must be destroyed before finishing the destruction
+		RolesRemaining,
+		/// Game recipes must be removed before finishing the destruction
+		RecipesRemaining,
+		/// The game treasury holds assets that must be spent before finishing the destruction
+		TreasuryNotEmpty,
+
+		// roles
+		/// The account does not hold the role
+		UnknownRole,
 	}
 
 	#[pallet::hooks]
 	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
 		/// Process minting requests in `block_number`.
 		fn on_initialize(block_number: BlockNumberFor<T>) -> Weight {
+			// the requests are read here and removed in `on_finalize`
+			let mut weight = T::DbWeight::get().reads_writes(4, 4);
 			for request in MintRequestOf::<T, I>::get(block_number) {
 				let res = Self::processing_mint_request(request);
 				if let Err(e) = res {
 					log::error!("Mint Request Failed: {:?}", e);
 				}
 			}
-			Weight::zero()
+			for request in UpgradeRequestOf::<T, I>::get(block_number) {
+				weight.saturating_accrue(
+					<T as pallet::Config<I>>::WeightInfo::process_upgrade_request(request.amount),
+				);
+				let res = Self::processing_upgrade_request(request);
+				if let Err(e) = res {
+					log::error!("Upgrade Request Failed: {:?}", e);
+				}
+			}
+			for request in SalvageRequestOf::<T, I>::get(block_number) {
+				let res = Self::processing_salvage_request(request);
+				if let Err(e) = res {
+					log::error!("Salvage Request Failed: {:?}", e);
+				}
+			}
+			for (index, trade) in CandleClosingOf::<T, I>::get(block_number).iter().enumerate() {
+				Self::draw_candle_closing(trade, index as u32);
+			}
+			weight
 		}
 
-		/// Remove any existing minting requests on the `block_number`.
+		/// Remove any existing minting, upgrade, salvage and candle closing requests on the
+		/// `block_number`.
 		fn on_finalize(block_number: BlockNumberFor<T>) {
 			if !MintRequestOf::<T, I>::get(block_number).is_empty() {
 				let res = Self::remove_mint_request(block_number);
@@ -716,6 +1489,14 @@ pub mod pallet {
 					log::error!("Remove Mint Request Failed: {:?}", e);
 				}
 			}
+			UpgradeRequestOf::<T, I>::remove(block_number);
+			SalvageRequestOf::<T, I>::remove(block_number);
+			CandleClosingOf::<T, I>::remove(block_number);
+		}
+
+		/// Expire the trades which passed their end block.
+		fn on_idle(block_number: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::expire_trades(block_number, remaining_weight)
 		}
 	}
 
@@ -833,7 +1614,8 @@ pub mod pallet {
 
 		/// Create an certain amount of item for a particular collection.
 		///
-		/// The origin must be Signed and the sender should be the Admin of `collection`.
+		/// The origin must be Signed and the sender should be the Admin of `collection` or a
+		/// `Minter` of its game.
 		///
 		/// - `collection`: The collection of the item to be minted.
 		/// - `item`: An identifier of the new item.
@@ -940,7 +1722,8 @@ pub mod pallet {
 
 		/// Set upgrade rule for item.
 		///
-		/// Origin must be Signed and signer should be the Admin of `collection`.
+		/// Origin must be Signed and signer should be the Admin of `collection` or an
+		/// `UpgradeDesigner` of its game.
 		///
 		/// Arguments:
 		/// - `collection`: The collection of the item to be upgrade-rule set.
@@ -950,12 +1733,18 @@ pub mod pallet {
 		/// - `data`: `new_item` metadata.
 		/// - `level`: Upgrade level.
 		/// - `fee`: Upgrade fee.
+		/// - `materials`: Items burned for each upgraded item.
 		///
 		/// Emits `UpgradeSet`.
 		///
 		/// Weight: `O(1)`
 		#[pallet::call_index(10)]
-		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::set_upgrade_item(data.len() as u32))]
+		#[pallet::weight(
+			<T as pallet::Config<I>>::WeightInfo::set_upgrade_item(
+				data.len() as u32,
+				materials.len() as u32
+			)
+		)]
 		pub fn set_upgrade_item(
 			origin: OriginFor<T>,
 			collection: T::CollectionId,
@@ -965,14 +1754,26 @@ pub mod pallet {
 			data: BoundedVec<u8, T::StringLimit>,
 			level: Level,
 			fee: BalanceOf<T, I>,
+			materials: Bundle<T::CollectionId, T::ItemId>,
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin.clone())?;
 			let data_len = data.len() as u32;
+			let materials_len = materials.len() as u32;
 			pallet_nfts::pallet::Pallet::<T>::set_metadata(origin, collection, item, data)?;
-			Self::do_set_upgrade_item(&sender, &collection, &item, &new_item, &config, level, fee)?;
+			Self::do_set_upgrade_item(
+				&sender,
+				&collection,
+				&item,
+				&new_item,
+				&config,
+				level,
+				fee,
+				materials,
+			)?;
 
 			Ok(Some(<T as pallet::Config<I>>::WeightInfo::set_upgrade_item(
 				data_len,
+				materials_len,
 			))
 			.into())
 		}
@@ -981,27 +1782,50 @@ pub mod pallet {
 		///
 		/// The origin must be signed and the signer must have a sufficient `amount` of `items`.
 		///
-		/// Signer must pay `fee` * `amount` to upgrade the item.
+		/// Signer must pay `fee` * `amount` and burn the upgrade materials `amount` times to
+		/// upgrade the item.
+		///
+		/// Each of the `amount` items fails the upgrade with the chance set by
+		/// `set_upgrade_chance`. A chance other than 0% or 100% is rolled after `MintInterval`
+		/// blocks, the items are reserved until then. The fee and materials are paid whatever the
+		/// outcome. `amount` must not exceed `MaxMintItem`.
 		///
 		/// Arguments:
 		/// - `collection`: The collection of the item to be upgraded.
 		/// - `item`: The item to be upgraded.
 		/// - `amount`: The amount of `item` to be upgraded.
 		///
-		/// Emits `Upgraded`.
+		/// Emits `Upgraded`, `UpgradeFailed` or `UpgradeRequested`.
 		///
-		/// Weight: `O(1)`
+		/// Weight: `O(m + a)` where `m` is the number of materials and `a = amount` for a rolled
+		/// upgrade
 		#[pallet::call_index(11)]
-		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::upgrade_item())]
+		#[pallet::weight(
+			<T as pallet::Config<I>>::WeightInfo::upgrade_item(T::MaxBundle::get()).saturating_add(
+				<T as pallet::Config<I>>::WeightInfo::process_upgrade_request(*amount),
+			)
+		)]
 		pub fn upgrade_item(
 			origin: OriginFor<T>,
 			collection: T::CollectionId,
 			item: T::ItemId,
 			amount: Amount,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
+			let maybe_config = Self::next_upgrade_config(&collection, &item);
 			Self::do_upgrade_item(&sender, &collection, &item, amount)?;
-			Ok(())
+
+			let materials = maybe_config.as_ref().map_or(0, |config| config.materials.len() as u32);
+			let mut weight = <T as pallet::Config<I>>::WeightInfo::upgrade_item(materials);
+			// the rolls are paid upfront
+			if maybe_config.map_or(false, |config| {
+				config.success_rate > 0 && config.success_rate < BASIS_POINTS
+			}) {
+				weight.saturating_accrue(
+					<T as pallet::Config<I>>::WeightInfo::process_upgrade_request(amount),
+				);
+			}
+			Ok(Some(weight).into())
 		}
 
 		/// Set the price for NFTs within a collection.
@@ -1013,6 +1837,7 @@ pub mod pallet {
 		/// - `start_block`: The block to start setting the price, `None` indicates the current
 		///   block.
 		/// - `end_block`: The block to end setting the price, `None` indicates no end.
+		/// - `maybe_asset`: Maybe the asset the trade is paid in, the native currency if `None`.
 		///
 		/// Emits `PriceSet`.
 		///
@@ -1025,9 +1850,11 @@ pub mod pallet {
 			unit_price: BalanceOf<T, I>,
 			start_block: Option<BlockNumberFor<T>>,
 			end_block: Option<BlockNumberFor<T>>,
+			maybe_asset: Option<T::AssetId>,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 			let trade = Self::get_trade_id();
+			Self::set_trade_asset(&trade, maybe_asset)?;
 			Self::do_set_price(&trade, &sender, package, unit_price, start_block, end_block)?;
 			Ok(())
 		}
@@ -1087,6 +1914,7 @@ pub mod pallet {
 		/// - `unit_price`: The price of each item the sender is willing to pay.
 		/// - `start_block`: The block to start set buy.
 		/// - `end_block`: The block to end set buy.
+		/// - `maybe_asset`: Maybe the asset the trade is paid in, the native currency if `None`.
 		///
 		/// Emits `BuySet`.
 		///
@@ -1099,9 +1927,11 @@ pub mod pallet {
 			unit_price: BalanceOf<T, I>,
 			start_block: Option<BlockNumberFor<T>>,
 			end_block: Option<BlockNumberFor<T>>,
+			maybe_asset: Option<T::AssetId>,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 			let trade = Self::get_trade_id();
+			Self::set_trade_asset(&trade, maybe_asset)?;
 			Self::do_set_buy(&trade, &sender, package, unit_price, start_block, end_block)?;
 			Ok(())
 		}
@@ -1139,6 +1969,7 @@ pub mod pallet {
 		/// - `start_block`: The block to start setting the price, `None` indicates the current
 		///   block.
 		/// - `end_block`: The block to end setting the price, `None` indicates no end.
+		/// - `maybe_asset`: Maybe the asset the trade is paid in, the native currency if `None`.
 		///
 		/// Emits `BundleSet`.
 		///
@@ -1151,9 +1982,11 @@ pub mod pallet {
 			price: BalanceOf<T, I>,
 			start_block: Option<BlockNumberFor<T>>,
 			end_block: Option<BlockNumberFor<T>>,
+			maybe_asset: Option<T::AssetId>,
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
 			let trade = Self::get_trade_id();
+			Self::set_trade_asset(&trade, maybe_asset)?;
 			let bundle_len = bundle.len() as u32;
 			Self::do_set_bundle(&trade, &sender, bundle, price, start_block, end_block)?;
 			Ok(Some(<T as pallet::Config<I>>::WeightInfo::set_bundle(bundle_len)).into())
@@ -1190,9 +2023,16 @@ pub mod pallet {
 		///
 		/// Emits `TradeCanceled`.
 		///
-		/// Weight: `O(1)`
+		/// Weight: `O(1)`, `O(b)` for candle and sealed auctions where `b` is their maximum bids
 		#[pallet::call_index(18)]
-		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::cancel_trade())]
+		#[pallet::weight(match trade_type {
+			TradeType::Auction => <T as pallet::Config<I>>::WeightInfo::close_auction(),
+			TradeType::CandleAuction =>
+				<T as pallet::Config<I>>::WeightInfo::close_candle_auction(T::MaxCandleBid::get()),
+			TradeType::SealedAuction =>
+				<T as pallet::Config<I>>::WeightInfo::close_sealed_auction(T::MaxSealedBid::get()),
+			_ => <T as pallet::Config<I>>::WeightInfo::cancel_trade(),
+		})]
 		pub fn cancel_trade(
 			origin: OriginFor<T>,
 			trade: T::TradeId,
@@ -1211,6 +2051,7 @@ pub mod pallet {
 		/// - `price`: The price the sender is willing to pay.
 		/// - `start_block`: The block to start set wishlist, `None` indicates the current block.
 		/// - `end_block`: The block to end set wishlist, `None` indicates no end.
+		/// - `maybe_asset`: Maybe the asset the trade is paid in, the native currency if `None`.
 		///
 		/// Emits `WishlistSet`.
 		///
@@ -1223,9 +2064,11 @@ pub mod pallet {
 			price: BalanceOf<T, I>,
 			start_block: Option<BlockNumberFor<T>>,
 			end_block: Option<BlockNumberFor<T>>,
+			maybe_asset: Option<T::AssetId>,
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
 			let trade = Self::get_trade_id();
+			Self::set_trade_asset(&trade, maybe_asset)?;
 			let bundle_len = bundle.len() as u32;
 			Self::do_set_wishlist(&trade, &sender, bundle, price, start_block, end_block)?;
 			Ok(Some(<T as pallet::Config<I>>::WeightInfo::order_bundle(
@@ -1329,6 +2172,7 @@ pub mod pallet {
 		/// - `maybe_price`: Maybe the price that sender willing to accept.
 		/// - `start_block`: The block to start set swap, `None` indicates the current block.
 		/// - `end_block`: The block to end set swap, `None` indicates no end.
+		/// - `maybe_asset`: Maybe the asset the trade is paid in, the native currency if `None`.
 		///
 		/// Emits `SwapSet`.
 		///
@@ -1347,9 +2191,11 @@ pub mod pallet {
 			maybe_price: Option<BalanceOf<T, I>>,
 			start_block: Option<BlockNumberFor<T>>,
 			end_block: Option<BlockNumberFor<T>>,
+			maybe_asset: Option<T::AssetId>,
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
 			let trade = Self::get_trade_id();
+			Self::set_trade_asset(&trade, maybe_asset)?;
 
 			let source_len = source.len() as u32;
 			let required_len = required.len() as u32;
@@ -1401,6 +2247,7 @@ pub mod pallet {
 		/// - `maybe_price`: Maybe a minimum bid.
 		/// - `start_block`: The block to start the auction, `None` indicates the current block.
 		/// - `duration`: The duration of the auction measured by the number of blocks.
+		/// - `maybe_asset`: Maybe the asset the trade is paid in, the native currency if `None`.
 		///
 		/// Emits `AuctionSet`.
 		///
@@ -1413,9 +2260,11 @@ pub mod pallet {
 			maybe_price: Option<BalanceOf<T, I>>,
 			start_block: Option<BlockNumberFor<T>>,
 			duration: BlockNumberFor<T>,
+			maybe_asset: Option<T::AssetId>,
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
 			let trade = Self::get_trade_id();
+			Self::set_trade_asset(&trade, maybe_asset)?;
 			let source_len = source.len() as u32;
 			Self::do_set_auction(&trade, &sender, source, maybe_price, start_block, duration)?;
 			Ok(Some(<T as pallet::Config<I>>::WeightInfo::set_auction(
@@ -1681,6 +2530,8 @@ pub mod pallet {
 		/// - `loot_table`: A bundle of NFTs for minting.
 		/// - `admin`: The Admin of this minting pool.
 		/// - `mint_settings`: The minting pool settings.
+		/// - `maybe_asset`: Maybe the asset the mints of the pool are paid in, the native currency
+		///   if `None`.
 		///
 		/// Emits `MiningPoolCreated`.
 		///
@@ -1694,9 +2545,11 @@ pub mod pallet {
 			loot_table: LootTable<T::CollectionId, T::ItemId>,
 			admin: AccountIdLookupOf<T>,
 			mint_settings: MintSettingsFor<T, I>,
+			maybe_asset: Option<T::AssetId>,
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
 			let id = Self::get_pool_id();
+			Self::set_pool_asset(&id, maybe_asset)?;
 			let admin = T::Lookup::lookup(admin)?;
 			let table_len = loot_table.len() as u32;
 			Self::do_create_dynamic_pool(&id, &sender, loot_table, &admin, mint_settings)?;
@@ -1710,14 +2563,17 @@ pub mod pallet {
 
 		/// Create a stable minting pool.
 		///
-		/// Origin must be Signed and the sender should be the owner of all collections in the
-		/// `loot_table`. Collection in `loot_table` must be infinite supply.
+		/// Origin must be Signed and the sender should be the owner, or a `PoolManager` of the
+		/// game, of all collections in the `loot_table`. Collection in `loot_table` must be
+		/// infinite supply.
 		///
 		/// Note: The minting chance will not be changed after each NFT is minted.
 		///
 		/// - `loot_table`: A bundle of NFTs for minting.
 		/// - `admin`: The Admin of this minting pool.
 		/// - `mint_settings`: The minting pool settings.
+		/// - `maybe_asset`: Maybe the asset the mints of the pool are paid in, the native currency
+		///   if `None`.
 		///
 		/// Emits `MiningPoolCreated`.
 		///
@@ -1731,9 +2587,11 @@ pub mod pallet {
 			loot_table: LootTable<T::CollectionId, T::ItemId>,
 			admin: AccountIdLookupOf<T>,
 			mint_settings: MintSettingsFor<T, I>,
+			maybe_asset: Option<T::AssetId>,
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
 			let id = Self::get_pool_id();
+			Self::set_pool_asset(&id, maybe_asset)?;
 			let admin = T::Lookup::lookup(admin)?;
 			let table_len = loot_table.len() as u32;
 			Self::do_create_stable_pool(&id, &sender, loot_table, &admin, mint_settings)?;
@@ -1752,6 +2610,8 @@ pub mod pallet {
 		/// - `pool`: The pool to be minted.
 		/// - `mint_to`: Account into which the item will be minted.
 		/// - `amount`: The amount may be minted.
+		/// - `maybe_proof`: Merkle proof or signed voucher, required by `MerkleAllowlist` and
+		///   `SignedVoucher` pools.
 		///
 		/// Emits `Minted` event when successful.
 		///
@@ -1763,11 +2623,12 @@ pub mod pallet {
 			pool: T::PoolId,
 			mint_to: AccountIdLookupOf<T>,
 			amount: Amount,
+			maybe_proof: Option<MintProofFor<T>>,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 			let target = T::Lookup::lookup(mint_to)?;
 
-			Self::do_request_mint(&pool, &sender, &target, amount)?;
+			Self::do_request_mint(&pool, &sender, &target, amount, maybe_proof)?;
 			Ok(())
 		}
 
@@ -1922,6 +2783,8 @@ pub mod pallet {
 		/// * `admin` - The admin account for the pool.
 		/// * `mint_settings` - The mint settings for the pool.
 		/// * `data` - The data to set as the pool metadata.
+		/// * `maybe_asset` - Maybe the asset the mints of the pool are paid in, the native currency
+		///   if `None`.
 		///
 		/// # Returns
 		///
@@ -1937,9 +2800,11 @@ pub mod pallet {
 			admin: AccountIdLookupOf<T>,
 			mint_settings: MintSettingsFor<T, I>,
 			data: BoundedVec<u8, T::StringLimit>,
+			maybe_asset: Option<T::AssetId>,
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
 			let id = Self::get_pool_id();
+			Self::set_pool_asset(&id, maybe_asset)?;
 			let admin = T::Lookup::lookup(admin)?;
 			let table_len = loot_table.len() as u32;
 			Self::do_create_dynamic_pool(&id, &sender, loot_table, &admin, mint_settings)?;
@@ -1961,6 +2826,8 @@ pub mod pallet {
 		/// * `admin` - The admin account for the pool.
 		/// * `mint_settings` - The mint settings for the pool.
 		/// * `data` - The data to set as the pool metadata.
+		/// * `maybe_asset` - Maybe the asset the mints of the pool are paid in, the native currency
+		///   if `None`.
 		///
 		/// # Returns
 		///
@@ -1976,9 +2843,11 @@ pub mod pallet {
 			admin: AccountIdLookupOf<T>,
 			mint_settings: MintSettingsFor<T, I>,
 			data: BoundedVec<u8, T::StringLimit>,
+			maybe_asset: Option<T::AssetId>,
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
 			let id = Self::get_pool_id();
+			Self::set_pool_asset(&id, maybe_asset)?;
 			let admin = T::Lookup::lookup(admin)?;
 			let table_len = loot_table.len() as u32;
 			Self::do_create_stable_pool(&id, &sender, loot_table, &admin, mint_settings)?;
@@ -2036,37 +2905,1251 @@ pub mod pallet {
 			Self::do_clear_pool_metadata(sender.clone(), pool)?;
 			Ok(())
 		}
-	}
-}
 
-impl<T: Config<I>, I: 'static> Pallet<T, I> {
-	pub fn processing_mint_request(payload: MintRequestFor<T, I>) -> DispatchResult {
-		if let Some(pool_details) = PoolOf::<T, I>::get(payload.pool) {
-			if let Ok(_) = match pool_details.pool_type {
-				PoolType::Dynamic => Self::do_mint_dynamic_pool(
-					&payload.pool,
-					&payload.miner,
-					&payload.target,
-					payload.amount,
-				),
-				PoolType::Stable => Self::do_mint_stable_pool(
-					&payload.pool,
-					&payload.miner,
-					&payload.target,
-					payload.amount,
-				),
-			} {
-				<T as pallet::Config<I>>::Currency::repatriate_reserved(
-					&payload.miner,
-					&pool_details.owner,
-					payload.miner_reserve,
-					BalanceStatus::Free,
-				)?;
-				return Ok(())
-			}
+		/// Create a candle auction for `source`.
+		///
+		/// Origin must be Signed and signer must be the owner of the `source`.
+		/// Bids are accepted until `end_block`, the auction closes at a random block between
+		/// `early_end` and `end_block` and the highest bid at that block wins.
+		///
+		/// - `source`: The bundle for auction.
+		/// - `maybe_price`: Maybe a minimum bid.
+		/// - `start_block`: The block to start the auction.
+		/// - `early_end`: The block to start the ending period.
+		/// - `end_block`: The block to end the auction.
+		/// - `maybe_asset`: Maybe the asset the trade is paid in, the native currency if `None`.
+		///
+		/// Emits `CandleAuctionSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(53)]
+		#[pallet::weight(
+			<T as pallet::Config<I>>::WeightInfo::set_candle_auction(source.len() as u32)
+		)]
+		pub fn set_candle_auction(
+			origin: OriginFor<T>,
+			source: Bundle<T::CollectionId, T::ItemId>,
+			maybe_price: Option<BalanceOf<T, I>>,
+			start_block: BlockNumberFor<T>,
+			early_end: BlockNumberFor<T>,
+			end_block: BlockNumberFor<T>,
+			maybe_asset: Option<T::AssetId>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let trade = Self::get_trade_id();
+			Self::set_trade_asset(&trade, maybe_asset)?;
+			Self::do_set_candle_auction(
+				&trade,
+				&sender,
+				source,
+				maybe_price,
+				start_block,
+				early_end,
+				end_block,
+			)?;
+			Ok(())
 		}
-		<T as pallet::Config<I>>::Currency::unreserve(&payload.miner, payload.miner_reserve);
-		Ok(())
+
+		/// Make a bid for the candle auction.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `trade`: The auction id.
+		/// - `bid`: The bid, `bid` must be higher than the minimum bid and higher than the previous
+		///   bid.
+		///
+		/// Emits `Bid`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(54)]
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::bid_candle_auction())]
+		pub fn bid_candle_auction(
+			origin: OriginFor<T>,
+			trade: T::TradeId,
+			bid: BalanceOf<T, I>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::do_bid_candle_auction(&trade, &sender, bid)?;
+			Ok(())
+		}
+
+		/// Handling a candle auction after it's over.
+		///
+		/// The highest bidder at the random closing block wins the auction, other bids are
+		/// refunded. If there is no winning bid, the NFT in the auction will be refunded.
+		/// The closing block is drawn at the start of `end_block`, so it cannot be picked by
+		/// choosing when to claim.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `trade`: The auction id.
+		///
+		/// Emits `CandleAuctionClaimed`.
+		///
+		/// Weight: `O(b)` where `b = MaxCandleBid`
+		#[pallet::call_index(55)]
+		#[pallet::weight(
+			<T as pallet::Config<I>>::WeightInfo::close_candle_auction(T::MaxCandleBid::get())
+		)]
+		pub fn close_candle_auction(origin: OriginFor<T>, trade: T::TradeId) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+			Self::do_claim_candle_auction(&trade)?;
+			Ok(())
+		}
+
+		/// Create a dutch auction for `source`.
+		///
+		/// Origin must be Signed and signer must be the owner of the `source`.
+		/// The price decreases linearly from `start_price` to `floor_price` over `duration`
+		/// blocks, the first buyer gets the bundle at the current price.
+		///
+		/// - `source`: The bundle for auction.
+		/// - `start_price`: The price when the auction starts.
+		/// - `floor_price`: The lowest price of the auction.
+		/// - `start_block`: The block to start the auction, default is the current block.
+		/// - `duration`: The number of blocks for the price to reach `floor_price`, the auction
+		///   expires afterwards.
+		/// - `maybe_asset`: Maybe the asset the trade is paid in, the native currency if `None`.
+		///
+		/// Emits `DutchAuctionSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(56)]
+		#[pallet::weight(
+			<T as pallet::Config<I>>::WeightInfo::set_dutch_auction(source.len() as u32)
+		)]
+		pub fn set_dutch_auction(
+			origin: OriginFor<T>,
+			source: Bundle<T::CollectionId, T::ItemId>,
+			start_price: BalanceOf<T, I>,
+			floor_price: BalanceOf<T, I>,
+			start_block: Option<BlockNumberFor<T>>,
+			duration: BlockNumberFor<T>,
+			maybe_asset: Option<T::AssetId>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let trade = Self::get_trade_id();
+			Self::set_trade_asset(&trade, maybe_asset)?;
+			Self::do_set_dutch_auction(
+				&trade,
+				&sender,
+				source,
+				start_price,
+				floor_price,
+				start_block,
+				duration,
+			)?;
+			Ok(())
+		}
+
+		/// Buy the bundle of a dutch auction at the current price.
+		///
+		/// Origin must be Signed and signer must not be the owner of the auction.
+		///
+		/// - `trade`: The auction id.
+		/// - `bid_price`: The highest price the buyer accepts, must be no less than the current
+		///   price.
+		///
+		/// Emits `DutchAuctionBought`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(57)]
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::buy_dutch_auction())]
+		pub fn buy_dutch_auction(
+			origin: OriginFor<T>,
+			trade: T::TradeId,
+			bid_price: BalanceOf<T, I>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::do_buy_dutch_auction(&trade, &sender, bid_price)?;
+			Ok(())
+		}
+
+		/// Create a sealed-bid auction for `source`.
+		///
+		/// Origin must be Signed and signer must be the owner of the `source`.
+		/// Bids are committed as hashes until `reveal_block` and revealed until `end_block`.
+		/// The highest revealed bid wins and pays the second-highest price.
+		///
+		/// - `source`: The bundle for auction.
+		/// - `maybe_price`: Maybe a minimum bid.
+		/// - `start_block`: The block to start the auction, default is the current block.
+		/// - `reveal_block`: The block to start the reveal period.
+		/// - `end_block`: The block to end the auction.
+		/// - `maybe_asset`: Maybe the asset the trade is paid in, the native currency if `None`.
+		///
+		/// Emits `SealedAuctionSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(58)]
+		#[pallet::weight(
+			<T as pallet::Config<I>>::WeightInfo::set_sealed_auction(source.len() as u32)
+		)]
+		pub fn set_sealed_auction(
+			origin: OriginFor<T>,
+			source: Bundle<T::CollectionId, T::ItemId>,
+			maybe_price: Option<BalanceOf<T, I>>,
+			start_block: Option<BlockNumberFor<T>>,
+			reveal_block: BlockNumberFor<T>,
+			end_block: BlockNumberFor<T>,
+			maybe_asset: Option<T::AssetId>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let trade = Self::get_trade_id();
+			Self::set_trade_asset(&trade, maybe_asset)?;
+			Self::do_set_sealed_auction(
+				&trade,
+				&sender,
+				source,
+				maybe_price,
+				start_block,
+				reveal_block,
+				end_block,
+			)?;
+			Ok(())
+		}
+
+		/// Commit a sealed bid for the auction.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `trade`: The auction id.
+		/// - `commitment`: The hash of `(sender, bid, salt)`.
+		/// - `deposit`: The amount reserved, must be no less than the bid.
+		///
+		/// Emits `SealedBidCommitted`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(59)]
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::commit_sealed_bid())]
+		pub fn commit_sealed_bid(
+			origin: OriginFor<T>,
+			trade: T::TradeId,
+			commitment: T::Hash,
+			deposit: BalanceOf<T, I>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::do_commit_sealed_bid(&trade, &sender, commitment, deposit)?;
+			Ok(())
+		}
+
+		/// Reveal a committed bid in the reveal period.
+		///
+		/// Origin must be Signed and signer must have committed a bid.
+		///
+		/// - `trade`: The auction id.
+		/// - `bid`: The committed bid.
+		/// - `salt`: The committed salt.
+		///
+		/// Emits `SealedBidRevealed`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(60)]
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::reveal_sealed_bid())]
+		pub fn reveal_sealed_bid(
+			origin: OriginFor<T>,
+			trade: T::TradeId,
+			bid: BalanceOf<T, I>,
+			salt: [u8; 32],
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::do_reveal_sealed_bid(&trade, &sender, bid, salt)?;
+			Ok(())
+		}
+
+		/// Handling a sealed-bid auction after it's over.
+		///
+		/// The highest revealed bid wins and pays the second-highest price, other revealed bids
+		/// are refunded and deposits of unrevealed bids are slashed to the seller.
+		/// If there is no winning bid, the NFT in the auction will be refunded.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `trade`: The auction id.
+		///
+		/// Emits `SealedAuctionClaimed`.
+		///
+		/// Weight: `O(b)` where `b = MaxSealedBid`
+		#[pallet::call_index(61)]
+		#[pallet::weight(
+			<T as pallet::Config<I>>::WeightInfo::close_sealed_auction(T::MaxSealedBid::get())
+		)]
+		pub fn close_sealed_auction(origin: OriginFor<T>, trade: T::TradeId) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+			Self::do_claim_sealed_auction(&trade)?;
+			Ok(())
+		}
+
+		/// Set a royalty for a collection or an item.
+		///
+		/// Origin must be Signed and signer should be the Owner of `collection`, a `TradeOperator`
+		/// of its game could only change the rate of the current `beneficiary`.
+		/// The royalty is paid to `beneficiary` from the price whenever the items are sold in a
+		/// trade, the royalty of an item overrides the royalty of its collection.
+		///
+		/// - `collection`: The collection of the royalty.
+		/// - `maybe_item`: Maybe an item, `None` for the whole collection.
+		/// - `beneficiary`: The account receiving the royalty.
+		/// - `rate`: The royalty rate in basis points, must not exceed `MaxRoyalty`.
+		///
+		/// Emits `RoyaltySet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(62)]
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::set_royalty())]
+		pub fn set_royalty(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			maybe_item: Option<T::ItemId>,
+			beneficiary: AccountIdLookupOf<T>,
+			rate: u16,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let beneficiary = T::Lookup::lookup(beneficiary)?;
+			Self::do_set_royalty(&sender, &collection, maybe_item, &beneficiary, rate)?;
+			Ok(())
+		}
+
+		/// Remove the royalty of a collection or an item.
+		///
+		/// Origin must be Signed and signer should be the Owner of `collection`.
+		///
+		/// - `collection`: The collection of the royalty.
+		/// - `maybe_item`: Maybe an item, `None` for the whole collection.
+		///
+		/// Emits `RoyaltyCleared`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(63)]
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::clear_royalty())]
+		pub fn clear_royalty(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			maybe_item: Option<T::ItemId>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::do_clear_royalty(&sender, &collection, maybe_item)?;
+			Ok(())
+		}
+
+		/// Set the fee taken from each trade settlement of the game items.
+		///
+		/// Origin must be Signed and signer should be the Owner of `game`, a `TradeOperator` could
+		/// only change the fee paid to the current `fee_account`.
+		/// The fee of an item is paid to the game that created its collection.
+		///
+		/// - `game`: The game id.
+		/// - `fee`: The fee in basis points of the price, must not exceed `MaxTradeFee`.
+		/// - `fee_account`: Maybe an account receiving the fees, the owner or treasury if `None`.
+		///
+		/// Emits `TradeFeeSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(64)]
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::set_trade_fee())]
+		pub fn set_trade_fee(
+			origin: OriginFor<T>,
+			game: T::GameId,
+			fee: u16,
+			fee_account: Option<AccountIdLookupOf<T>>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let fee_account = fee_account.map(T::Lookup::lookup).transpose()?;
+			Self::do_set_trade_fee(&sender, &game, fee, fee_account)?;
+			Ok(())
+		}
+
+		/// Set the price for NFTs within a collection in the order book.
+		///
+		/// Origin must be Signed and must be the owner of the `item`.
+		///
+		/// The items are sold to the purchases in the order book with a price equal to or higher
+		/// than `unit_price`, highest price first and then oldest first, at the purchase price.
+		/// The unsold items stay in the order book as a `set_price` trade.
+		///
+		/// - `package`: a number of an item in a collection to set the price for.
+		/// - `unit_price`: The price for each item.
+		/// - `maybe_asset`: Maybe the asset the trade is paid in, the native currency if `None`.
+		///
+		/// Emits `PriceSet` and `OrderMatched` for each filled purchase.
+		///
+		/// Weight: `O(m)` where `m = MaxBookOrders`
+		#[pallet::call_index(65)]
+		#[pallet::weight(
+			<T as pallet::Config<I>>::WeightInfo::set_book_price(T::MaxBookOrders::get())
+				.saturating_add(Pallet::<T, I>::book_scan_weight(T::MaxBookOrders::get()))
+		)]
+		pub fn set_book_price(
+			origin: OriginFor<T>,
+			package: Package<T::CollectionId, T::ItemId>,
+			unit_price: BalanceOf<T, I>,
+			maybe_asset: Option<T::AssetId>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let trade = Self::get_trade_id();
+			Self::set_trade_asset(&trade, maybe_asset)?;
+			let (fills, scanned) = Self::do_set_book_price(&trade, &sender, package, unit_price)?;
+			Ok(Some(
+				<T as pallet::Config<I>>::WeightInfo::set_book_price(fills)
+					.saturating_add(Self::book_scan_weight(scanned)),
+			)
+			.into())
+		}
+
+		/// Set up a purchase for `package` in the order book.
+		///
+		/// Origin must be Signed.
+		///
+		/// The items are bought from the listings in the order book with a price equal to or lower
+		/// than `unit_price`, lowest price first and then oldest first, at the listing price.
+		/// The remaining purchase stays in the order book as a `set_order` trade.
+		///
+		/// - `package`: A number of an item in a collection want to buy.
+		/// - `unit_price`: The price of each item the sender is willing to pay.
+		/// - `maybe_asset`: Maybe the asset the trade is paid in, the native currency if `None`.
+		///
+		/// Emits `BuySet` and `OrderMatched` for each filled listing.
+		///
+		/// Weight: `O(m)` where `m = MaxBookOrders`
+		#[pallet::call_index(66)]
+		#[pallet::weight(
+			<T as pallet::Config<I>>::WeightInfo::set_book_order(T::MaxBookOrders::get())
+				.saturating_add(Pallet::<T, I>::book_scan_weight(T::MaxBookOrders::get()))
+		)]
+		pub fn set_book_order(
+			origin: OriginFor<T>,
+			package: Package<T::CollectionId, T::ItemId>,
+			unit_price: BalanceOf<T, I>,
+			maybe_asset: Option<T::AssetId>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let trade = Self::get_trade_id();
+			Self::set_trade_asset(&trade, maybe_asset)?;
+			let (fills, scanned) = Self::do_set_book_order(&trade, &sender, package, unit_price)?;
+			Ok(Some(
+				<T as pallet::Config<I>>::WeightInfo::set_book_order(fills)
+					.saturating_add(Self::book_scan_weight(scanned)),
+			)
+			.into())
+		}
+
+		/// Set the asset the game is paid in.
+		///
+		/// Origin must be Signed and signer should be the Owner of `game`.
+		/// Upgrades, crafting fees and match stakes of the game are priced in this asset, trades
+		/// and pools are paid in the asset chosen by their creator.
+		///
+		/// - `game`: The game id.
+		/// - `maybe_asset`: Maybe an asset id, the native currency if `None`.
+		///
+		/// Emits `PaymentAssetSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(67)]
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::set_payment_asset())]
+		pub fn set_payment_asset(
+			origin: OriginFor<T>,
+			game: T::GameId,
+			maybe_asset: Option<T::AssetId>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::do_set_payment_asset(&sender, &game, maybe_asset)?;
+			Ok(())
+		}
+
+		/// Create a crafting recipe of `game`.
+		///
+		/// Origin must be Signed and signer should be the Admin, the owner or an `UpgradeDesigner`
+		/// of the game that created each `output` collection.
+		/// All items of the recipe must belong to collections of `game`.
+		///
+		/// - `game`: The game id.
+		/// - `input`: Items burned by each craft.
+		/// - `maybe_fee`: Maybe a fee paid to the signer by each craft, or to the game treasury if
+		///   the signer is the owner or admin of the game.
+		/// - `output`: Items produced by each craft.
+		/// - `maybe_crafts`: Maybe the number of times the recipe can be crafted, unlimited if
+		///   `None`. Output items with a finite supply are reserved from the signer for each craft,
+		///   they require a limit.
+		///
+		/// Emits `RecipeCreated`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(68)]
+		#[pallet::weight(
+			<T as pallet::Config<I>>::WeightInfo::create_recipe(
+				input.len() as u32,
+				output.len() as u32
+			)
+		)]
+		pub fn create_recipe(
+			origin: OriginFor<T>,
+			game: T::GameId,
+			input: Bundle<T::CollectionId, T::ItemId>,
+			maybe_fee: Option<BalanceOf<T, I>>,
+			output: Bundle<T::CollectionId, T::ItemId>,
+			maybe_crafts: Option<Amount>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let recipe = Self::get_recipe_id();
+
+			let input_len = input.len() as u32;
+			let output_len = output.len() as u32;
+
+			Self::do_create_recipe(
+				&recipe,
+				&sender,
+				&game,
+				input,
+				maybe_fee,
+				output,
+				maybe_crafts,
+			)?;
+			Ok(Some(<T as pallet::Config<I>>::WeightInfo::create_recipe(
+				input_len, output_len,
+			))
+			.into())
+		}
+
+		/// Remove a crafting recipe.
+		///
+		/// Origin must be Signed and signer should be the owner of the `recipe`, or the owner of
+		/// its game once the game is being destroyed.
+		///
+		/// The deposit and the output items reserved for the remaining crafts are returned to the
+		/// recipe owner.
+		///
+		/// - `recipe`: The recipe id.
+		///
+		/// Emits `RecipeRemoved`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(69)]
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::remove_recipe())]
+		pub fn remove_recipe(origin: OriginFor<T>, recipe: T::RecipeId) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::do_remove_recipe(&recipe, &sender)?;
+			Ok(())
+		}
+
+		/// Craft items with a recipe.
+		///
+		/// Origin must be Signed and signer should have the recipe input `times` over.
+		///
+		/// The input is burned and the output is received `times` times. Output items with a
+		/// finite supply are taken from the items reserved by the recipe owner.
+		///
+		/// - `recipe`: The recipe id.
+		/// - `times`: The number of crafts.
+		///
+		/// Emits `Crafted`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(70)]
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::craft())]
+		pub fn craft(origin: OriginFor<T>, recipe: T::RecipeId, times: Amount) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::do_craft(&sender, &recipe, times)?;
+			Ok(())
+		}
+
+		/// Set the chance of an upgrade level and the outcome of failed attempts.
+		///
+		/// Origin must be Signed and signer should be the Admin of `collection` or an
+		/// `UpgradeDesigner` of its game.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The item having the upgrade level.
+		/// - `level`: Upgrade level.
+		/// - `success_rate`: Chance of success in basis points, at most 10_000.
+		/// - `on_failure`: Keep, downgrade or destroy the items of failed attempts.
+		///
+		/// Emits `UpgradeChanceSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(71)]
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::set_upgrade_chance())]
+		pub fn set_upgrade_chance(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			level: Level,
+			success_rate: u16,
+			on_failure: UpgradeFailure,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::do_set_upgrade_chance(
+				&sender,
+				&collection,
+				&item,
+				level,
+				success_rate,
+				on_failure,
+			)?;
+			Ok(())
+		}
+
+		/// Set the items received when salvaging `item`.
+		///
+		/// Origin must be Signed and signer should be the Admin, or an `UpgradeDesigner` of the
+		/// game, of `collection` and of the output collections. Items of a loot table must have an
+		/// infinite supply.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The item to be salvaged.
+		/// - `output`: A fixed bundle or a loot table rolled for each salvaged item.
+		///
+		/// Emits `SalvageSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(72)]
+		#[pallet::weight(
+			<T as pallet::Config<I>>::WeightInfo::set_salvage(match &output {
+				SalvageOutput::Fixed(bundle) => bundle.len() as u32,
+				SalvageOutput::Random(table) => table.len() as u32,
+			})
+		)]
+		pub fn set_salvage(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			output: SalvageOutput<
+				Bundle<T::CollectionId, T::ItemId>,
+				LootTable<T::CollectionId, T::ItemId>,
+			>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::do_set_salvage(&sender, &collection, &item, output)?;
+			Ok(())
+		}
+
+		/// Remove the salvage of `item`.
+		///
+		/// Origin must be Signed and signer should be the owner of the salvage.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The salvaged item.
+		///
+		/// Emits `SalvageCleared`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(73)]
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::clear_salvage())]
+		pub fn clear_salvage(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::do_clear_salvage(&sender, &collection, &item)?;
+			Ok(())
+		}
+
+		/// Burn `amount` of `item` to receive its salvage output.
+		///
+		/// Origin must be Signed and signer should have a sufficient `amount` of `item`.
+		///
+		/// A loot table is rolled once per item after `MintInterval` blocks, `amount` must not
+		/// exceed `MaxMintItem`. The items are burned when the salvage is submitted.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The item to be salvaged.
+		/// - `amount`: The amount of `item` to be salvaged.
+		///
+		/// Emits `Salvaged` or `SalvageRequested`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(74)]
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::salvage())]
+		pub fn salvage(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			amount: Amount,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::do_salvage(&sender, &collection, &item, amount)?;
+			Ok(())
+		}
+
+		/// List `source` for rent at `price_per_block`.
+		///
+		/// Origin must be Signed and signer must be the owner of the `source`.
+		/// The items stay reserved by the owner, renters only gain their use right.
+		///
+		/// - `source`: The bundle for rent.
+		/// - `price_per_block`: The price paid by the renter for each rented block.
+		/// - `max_duration`: The longest rental term in blocks.
+		/// - `maybe_asset`: Maybe the asset the trade is paid in, the native currency if `None`.
+		///
+		/// Emits `RentalSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(75)]
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::set_rental(source.len() as u32))]
+		pub fn set_rental(
+			origin: OriginFor<T>,
+			source: Bundle<T::CollectionId, T::ItemId>,
+			price_per_block: BalanceOf<T, I>,
+			max_duration: BlockNumberFor<T>,
+			maybe_asset: Option<T::AssetId>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let trade = Self::get_trade_id();
+			Self::set_trade_asset(&trade, maybe_asset)?;
+			Self::do_set_rental(&trade, &sender, source, price_per_block, max_duration)?;
+			Ok(())
+		}
+
+		/// Rent the bundle of `trade` for `duration` blocks.
+		///
+		/// Origin must be Signed.
+		///
+		/// The renter pays `price_per_block` for each block upfront and gains the use right of
+		/// the items, which return to the owner once the term ends.
+		///
+		/// - `trade`: The rental id.
+		/// - `duration`: The number of rented blocks.
+		///
+		/// Emits `Rented`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(76)]
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::rent())]
+		pub fn rent(
+			origin: OriginFor<T>,
+			trade: T::TradeId,
+			duration: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::do_rent(&trade, &sender, duration)?;
+			Ok(())
+		}
+
+		/// Return the rented items of `trade` once the term ended.
+		///
+		/// Origin must be Signed, any account can call.
+		///
+		/// Rentals are also returned automatically when their term ends.
+		///
+		/// - `trade`: The rental id.
+		///
+		/// Emits `RentalReturned`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(77)]
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::return_rental())]
+		pub fn return_rental(origin: OriginFor<T>, trade: T::TradeId) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+			Self::do_return_rental(&trade)?;
+			Ok(())
+		}
+
+		/// Bind `item` to its holders.
+		///
+		/// Origin must be Signed and signer should be the Admin of `collection` or a `Moderator` of
+		/// its game.
+		///
+		/// Soulbound items can be minted, upgraded and burned but never transferred or listed in
+		/// any trade, upgraded items follow their original item. Only the owner, admin and
+		/// `PoolManager`s of `collection` can put soulbound items in a minting pool.
+		///
+		/// Binding does not affect the items already reserved: open trades, matches and pools
+		/// still settle them, cancel the trades first to keep the items bound.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The item to be bound.
+		/// - `soulbound`: Whether the item is soulbound.
+		///
+		/// Emits `SoulboundSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(78)]
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::set_soulbound())]
+		pub fn set_soulbound(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			soulbound: bool,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::do_set_soulbound(&sender, &collection, &item, soulbound)?;
+			Ok(())
+		}
+
+		/// Approve `operator` to transfer items on behalf of the signer.
+		///
+		/// Origin must be Signed.
+		///
+		/// Any existing approval of `operator` in the same scope is replaced.
+		///
+		/// - `operator`: The account allowed to transfer the items.
+		/// - `maybe_collection`: The only collection the operator can transfer, `None` for any
+		///   collection.
+		/// - `maybe_deadline`: The last block of the approval, `None` for no expiry.
+		///
+		/// Emits `OperatorApproved`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(79)]
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::approve_operator())]
+		pub fn approve_operator(
+			origin: OriginFor<T>,
+			operator: AccountIdLookupOf<T>,
+			maybe_collection: Option<T::CollectionId>,
+			maybe_deadline: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let operator = T::Lookup::lookup(operator)?;
+			Self::do_approve_operator(&sender, &operator, maybe_collection, maybe_deadline)?;
+			Ok(())
+		}
+
+		/// Revoke an approval of `operator`.
+		///
+		/// Origin must be Signed and signer should have approved the `operator`.
+		///
+		/// - `operator`: The approved account.
+		/// - `maybe_collection`: The collection of the approval, `None` for the approval of any
+		///   collection.
+		///
+		/// Emits `OperatorRevoked`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(80)]
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::revoke_operator())]
+		pub fn revoke_operator(
+			origin: OriginFor<T>,
+			operator: AccountIdLookupOf<T>,
+			maybe_collection: Option<T::CollectionId>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let operator = T::Lookup::lookup(operator)?;
+			Self::do_revoke_operator(&sender, &operator, maybe_collection)?;
+			Ok(())
+		}
+
+		/// Transfer `amount` of `item` from `from` to `dest` on behalf of `from`.
+		///
+		/// Origin must be Signed and signer should be an operator approved by `from` for
+		/// `collection` or for any collection.
+		///
+		/// - `from`: The owner of the items.
+		/// - `collection`: The collection of the item.
+		/// - `item`: The item to be transferred.
+		/// - `dest`: The destination account.
+		/// - `amount`: The amount of `item` to be transferred.
+		///
+		/// Emits `Transferred`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(81)]
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::transfer_from())]
+		pub fn transfer_from(
+			origin: OriginFor<T>,
+			from: AccountIdLookupOf<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			dest: AccountIdLookupOf<T>,
+			amount: Amount,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let from = T::Lookup::lookup(from)?;
+			let destination = T::Lookup::lookup(dest)?;
+			Self::do_transfer_from(&sender, &from, &collection, &item, &destination, amount)?;
+			Ok(())
+		}
+
+		/// Create a match escrow of `game`.
+		///
+		/// Origin must be Signed and signer should be the Owner, Admin or a `Moderator` of `game`.
+		///
+		/// - `game`: The game of the match.
+		/// - `stake`: The amount staked by each player, paid in the asset of `game`.
+		/// - `maybe_server`: The server account allowed to submit the result along with the game
+		///   owner and admin.
+		/// - `end_block`: The result must be submitted before `end_block`, the stakes can be
+		///   refunded afterwards.
+		///
+		/// Emits `MatchCreated`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(82)]
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::create_match())]
+		pub fn create_match(
+			origin: OriginFor<T>,
+			game: T::GameId,
+			stake: BalanceOf<T, I>,
+			maybe_server: Option<AccountIdLookupOf<T>>,
+			end_block: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let maybe_server = maybe_server.map(T::Lookup::lookup).transpose()?;
+			let match_id = Self::get_match_id();
+			Self::do_create_match(&match_id, &sender, &game, stake, maybe_server, end_block)?;
+			Ok(())
+		}
+
+		/// Join a match, staking its `stake` and `items`.
+		///
+		/// Origin must be Signed and signer should own the `items`.
+		///
+		/// - `match_id`: The match id.
+		/// - `items`: The items of the game put at stake.
+		///
+		/// Emits `MatchJoined`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(83)]
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::join_match(items.len() as u32))]
+		pub fn join_match(
+			origin: OriginFor<T>,
+			match_id: T::MatchId,
+			items: Bundle<T::CollectionId, T::ItemId>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::do_join_match(&match_id, &sender, items)?;
+			Ok(())
+		}
+
+		/// Submit the result of a match.
+		///
+		/// Origin must be Signed and signer should be the Owner, Admin or a `Moderator` of the
+		/// game, or the server of the match.
+		///
+		/// The stakes are shared by the winners, the losers' items are given to another player
+		/// or burned, the other items are returned.
+		///
+		/// - `match_id`: The match id.
+		/// - `result`: The result of the match.
+		///
+		/// Emits `MatchSettled`.
+		///
+		/// Weight: `O(p)` where `p = MaxMatchPlayers`
+		#[pallet::call_index(84)]
+		#[pallet::weight(
+			<T as pallet::Config<I>>::WeightInfo::settle_match(T::MaxMatchPlayers::get())
+		)]
+		pub fn settle_match(
+			origin: OriginFor<T>,
+			match_id: T::MatchId,
+			result: MatchResult<T::AccountId>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::do_settle_match(&match_id, &sender, result)?;
+			Ok(())
+		}
+
+		/// Refund the stakes and items of a match which timed out.
+		///
+		/// Origin must be Signed, any account can call.
+		///
+		/// - `match_id`: The match id.
+		///
+		/// Emits `MatchRefunded`.
+		///
+		/// Weight: `O(p)` where `p = MaxMatchPlayers`
+		#[pallet::call_index(85)]
+		#[pallet::weight(
+			<T as pallet::Config<I>>::WeightInfo::refund_match(T::MaxMatchPlayers::get())
+		)]
+		pub fn refund_match(origin: OriginFor<T>, match_id: T::MatchId) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+			Self::do_refund_match(&match_id)?;
+			Ok(())
+		}
+
+		/// Route the revenue of `game` to its treasury account.
+		///
+		/// Origin must be Signed and signer should be the Owner of `game`.
+		/// Mint payments, trade fees without a fee account and crafting fees are paid to the
+		/// treasury instead of the owners of the pools and recipes. Only the pools and recipes
+		/// created by the owner or admin of the game are routed.
+		///
+		/// - `game`: The game id.
+		/// - `enabled`: Whether the revenue is paid to the treasury.
+		///
+		/// Emits `TreasuryRoutingSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(86)]
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::set_treasury_routing())]
+		pub fn set_treasury_routing(
+			origin: OriginFor<T>,
+			game: T::GameId,
+			enabled: bool,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::do_set_treasury_routing(&sender, &game, enabled)?;
+			Ok(())
+		}
+
+		/// Pay from the treasury account of `game`.
+		///
+		/// Origin must be Signed and signer should be the Owner of `game`. The treasury may be
+		/// emptied.
+		///
+		/// - `game`: The game id.
+		/// - `maybe_asset`: Maybe an asset id, the native currency if `None`.
+		/// - `dest`: The account receiving the payment.
+		/// - `amount`: The amount paid.
+		///
+		/// Emits `TreasurySpent`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(87)]
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::spend_treasury())]
+		pub fn spend_treasury(
+			origin: OriginFor<T>,
+			game: T::GameId,
+			maybe_asset: Option<T::AssetId>,
+			dest: AccountIdLookupOf<T>,
+			amount: BalanceOf<T, I>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let dest = T::Lookup::lookup(dest)?;
+			Self::do_spend_treasury(&sender, &game, maybe_asset, &dest, amount)?;
+			Ok(())
+		}
+
+		/// Set or clear the game ownership the signer accepts to receive.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `maybe_game`: Maybe the game id, clear the acceptance if `None`.
+		///
+		/// Emits `GameOwnershipAcceptanceChanged`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(88)]
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::set_accept_game_ownership())]
+		pub fn set_accept_game_ownership(
+			origin: OriginFor<T>,
+			maybe_game: Option<T::GameId>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::do_set_accept_game_ownership(&sender, maybe_game)?;
+			Ok(())
+		}
+
+		/// Transfer the ownership of `game` to `new_owner`.
+		///
+		/// Origin must be Signed and signer should be the Owner of `game`.
+		/// `new_owner` must have accepted the ownership with `set_accept_game_ownership`, the game
+		/// deposit is moved to the new owner along with the game collections of the owner.
+		///
+		/// - `game`: The game id.
+		/// - `new_owner`: The new owner of the game.
+		///
+		/// Emits `GameOwnershipTransferred`.
+		///
+		/// Weight: `O(c)` where `c = MaxGameCollection`
+		#[pallet::call_index(89)]
+		#[pallet::weight(
+			<T as pallet::Config<I>>::WeightInfo::transfer_game_ownership(
+				T::MaxGameCollection::get(),
+			)
+		)]
+		pub fn transfer_game_ownership(
+			origin: OriginFor<T>,
+			game: T::GameId,
+			new_owner: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let new_owner = T::Lookup::lookup(new_owner)?;
+			Self::do_transfer_game_ownership(&sender, &game, &new_owner)?;
+			Ok(())
+		}
+
+		/// Set the admin of `game`.
+		///
+		/// Origin must be Signed and signer should be the Owner of `game`.
+		/// The roles of the previous admin are moved to the new admin, including the team of the
+		/// game collections of the owner.
+		///
+		/// - `game`: The game id.
+		/// - `admin`: The new admin of the game.
+		///
+		/// Emits `GameAdminSet`.
+		///
+		/// Weight: `O(c)` where `c = MaxGameCollection`
+		#[pallet::call_index(90)]
+		#[pallet::weight(
+			<T as pallet::Config<I>>::WeightInfo::set_game_admin(T::MaxGameCollection::get())
+		)]
+		pub fn set_game_admin(
+			origin: OriginFor<T>,
+			game: T::GameId,
+			admin: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let admin = T::Lookup::lookup(admin)?;
+			Self::do_set_game_admin(&sender, &game, &admin)?;
+			Ok(())
+		}
+
+		/// Grant a game-scoped role to an account.
+		///
+		/// Origin must be Signed and signer should be the Owner of `game`.
+		/// The role allows the account to make the matching calls of the game without the admin
+		/// key.
+		///
+		/// - `game`: The game id.
+		/// - `who`: The account receiving the role.
+		/// - `role`: The role granted.
+		///
+		/// Emits `RoleGranted`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(91)]
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::grant_role())]
+		pub fn grant_role(
+			origin: OriginFor<T>,
+			game: T::GameId,
+			who: AccountIdLookupOf<T>,
+			role: GameRole,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			Self::do_grant_role(&sender, &game, &who, role)?;
+			Ok(())
+		}
+
+		/// Revoke a game-scoped role from an account.
+		///
+		/// Origin must be Signed and signer should be the Owner of `game`.
+		///
+		/// - `game`: The game id.
+		/// - `who`: The account losing the role.
+		/// - `role`: The role revoked.
+		///
+		/// Emits `RoleRevoked`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(92)]
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::revoke_role())]
+		pub fn revoke_role(
+			origin: OriginFor<T>,
+			game: T::GameId,
+			who: AccountIdLookupOf<T>,
+			role: GameRole,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			Self::do_revoke_role(&sender, &game, &who, role)?;
+			Ok(())
+		}
+
+		/// Start the process of destroying a game.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the `game`.
+		///
+		/// No collection can be created in or added to the game afterwards.
+		///
+		/// - `game`: The identifier of the game to be destroyed.
+		///
+		/// Emits `GameDestructionStarted`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(50)]
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::start_game_destroy())]
+		pub fn start_game_destroy(origin: OriginFor<T>, game: T::GameId) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::do_start_game_destroy(&sender, &game)?;
+			Ok(())
+		}
+
+		/// Remove up to `RemoveRolesLimit` roles and pending acceptances of a game being destroyed.
+		///
+		/// Origin must be Signed.
+		///
+		/// The deposits of the acceptances to add collections are returned. Should be called until
+		/// no roles or acceptances are left before `finish_game_destroy`.
+		///
+		/// - `game`: The identifier of the game being destroyed.
+		///
+		/// Emits `GameRolesDestroyed`.
+		///
+		/// Weight: `O(r)` where `r = RemoveRolesLimit`
+		#[pallet::call_index(51)]
+		#[pallet::weight(
+			<T as pallet::Config<I>>::WeightInfo::destroy_game_roles(T::RemoveRolesLimit::get())
+		)]
+		pub fn destroy_game_roles(
+			origin: OriginFor<T>,
+			game: T::GameId,
+		) -> DispatchResultWithPostInfo {
+			let _ = ensure_signed(origin)?;
+			let removed = Self::do_destroy_game_roles(&game, T::RemoveRolesLimit::get())?;
+			Ok(Some(<T as pallet::Config<I>>::WeightInfo::destroy_game_roles(removed)).into())
+		}
+
+		/// Complete the destruction of a game.
+		///
+		/// Origin must be Signed.
+		///
+		/// The game must be in destruction and have no roles, acceptances or recipes left, and its
+		/// treasury must hold no assets. Its collections are released, and the deposit and the
+		/// treasury balance are returned to the owner.
+		///
+		/// - `game`: The identifier of the game being destroyed.
+		/// - `witness`: Information on the collections of the game. This must be correct.
+		///
+		/// Emits `GameDestroyed`.
+		///
+		/// Weight: `O(c)` where `c = witness.collections`
+		#[pallet::call_index(52)]
+		#[pallet::weight(
+			<T as pallet::Config<I>>::WeightInfo::finish_game_destroy(witness.collections)
+		)]
+		pub fn finish_game_destroy(
+			origin: OriginFor<T>,
+			game: T::GameId,
+			witness: GameDestroyWitness,
+		) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+			Self::do_finish_game_destroy(&game, &witness)?;
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	pub fn processing_mint_request(payload: MintRequestFor<T, I>) -> DispatchResult {
+		if let Some(pool_details) = PoolOf::<T, I>::get(payload.pool) {
+			if let Ok(_) = match pool_details.pool_type {
+				PoolType::Dynamic => Self::do_mint_dynamic_pool(
+					&payload.pool,
+					&payload.miner,
+					&payload.target,
+					payload.amount,
+				),
+				PoolType::Stable => Self::do_mint_stable_pool(
+					&payload.pool,
+					&payload.miner,
+					&payload.target,
+					payload.amount,
+				),
+			} {
+				let receiver = match PoolTreasuryOf::<T, I>::get(payload.pool) {
+					Some(game) => Self::revenue_account(&game, pool_details.owner),
+					None => pool_details.owner,
+				};
+				Self::pay_reserved(
+					PoolAssetOf::<T, I>::get(payload.pool),
+					&payload.miner,
+					&receiver,
+					payload.miner_reserve,
+				)?;
+				return Ok(())
+			}
+		}
+		Self::release_mint(&payload);
+		Self::unreserve_payment(
+			PoolAssetOf::<T, I>::get(payload.pool),
+			&payload.miner,
+			payload.miner_reserve,
+		)
 	}
 
 	pub fn remove_mint_request(block_number: BlockNumberFor<T>) -> DispatchResult {
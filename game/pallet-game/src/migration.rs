@@ -0,0 +1,232 @@
+use crate::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+use gafi_support::game::{Amount, MintSettings, MintType, UpgradeFailure, NFT};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+/// Layouts of the storage items before version 1.
+pub mod v0 {
+	use super::*;
+
+	#[derive(Decode)]
+	pub struct GameDetails<AccountId, DepositBalance> {
+		pub owner: AccountId,
+		pub owner_deposit: DepositBalance,
+		pub collections: u32,
+		pub admin: AccountId,
+	}
+
+	#[derive(Decode)]
+	pub struct UpgradeItemConfig<ItemId, Price> {
+		pub item: ItemId,
+		pub fee: Price,
+	}
+
+	#[derive(Decode)]
+	pub struct MintSettings<AccountId, Price, BlockNumber, CollectionId> {
+		/// The restrictions added later are new variants, the existing ones decode as before.
+		pub mint_type: MintType<AccountId, CollectionId>,
+		pub price: Price,
+		pub start_block: Option<BlockNumber>,
+		pub end_block: Option<BlockNumber>,
+	}
+
+	#[derive(Decode)]
+	pub struct PoolDetails<AccountId, Balance, BlockNumber, CollectionId> {
+		pub pool_type: PoolType,
+		pub owner: AccountId,
+		pub owner_deposit: Balance,
+		pub admin: AccountId,
+		pub mint_settings: MintSettings<AccountId, Balance, BlockNumber, CollectionId>,
+	}
+
+	#[derive(Decode)]
+	pub struct Loot<CollectionId, ItemId> {
+		pub maybe_nft: Option<NFT<CollectionId, ItemId>>,
+		pub weight: u32,
+	}
+
+	#[derive(Decode)]
+	pub struct MintRequest<AccountId, PoolId, Balance, BlockNumber> {
+		pub miner: AccountId,
+		pub pool: PoolId,
+		pub target: AccountId,
+		pub amount: Amount,
+		pub mining_fee: Balance,
+		pub miner_reserve: Balance,
+		pub block_number: BlockNumber,
+	}
+}
+
+pub mod v1 {
+	use super::*;
+
+	/// Migrate the game, upgrade and pool details to the layouts of storage version 1.
+	///
+	/// - Games count their collections, so their destroy witness is correct, and take no trade fee,
+	///   payment asset or treasury routing.
+	/// - Accepts to add collections are indexed by game, with the deposit of the collection owner.
+	/// - Collections owned by the owner of the first game they belong to were created by it.
+	/// - Upgrades need no materials and always succeed, as they did before.
+	/// - Pools take no mint limits, and pending mint requests used none.
+	/// - Pools count no pity and their loots have no tier.
+	pub struct MigrateToV1<T, I = ()>(PhantomData<(T, I)>);
+
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV1<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = Pallet::<T, I>::on_chain_storage_version();
+			if on_chain_version >= 1 {
+				log::info!(target: "PalletGame", "Migration to v1 skipped");
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut reads = 1u64;
+			let mut writes = 1u64;
+
+			Game::<T, I>::translate::<v0::GameDetails<T::AccountId, BalanceOf<T, I>>, _>(
+				|game, old| {
+					reads.saturating_accrue(2);
+					writes.saturating_inc();
+					let collections = CollectionsOf::<T, I>::decode_len(game).unwrap_or(0) as u32;
+					Some(GameDetails {
+						owner: old.owner,
+						owner_deposit: old.owner_deposit,
+						collections: collections.max(old.collections),
+						admin: old.admin,
+						is_destroying: false,
+						trade_fee: 0,
+						fee_account: None,
+						payment_asset: None,
+						treasury_routing: false,
+						recipes: 0,
+					})
+				},
+			);
+
+			for (collection, game) in AddingAcceptance::<T, I>::iter() {
+				reads.saturating_accrue(2);
+				if let Some(owner) = T::Nfts::collection_owner(&collection) {
+					writes.saturating_inc();
+					AddingAcceptanceOf::<T, I>::insert(
+						game,
+						collection,
+						(owner, T::GameDeposit::get()),
+					);
+				}
+			}
+
+			for (collection, games) in GamesOf::<T, I>::iter() {
+				reads.saturating_accrue(3);
+				if let Some(game) = games.first() {
+					let owner = Game::<T, I>::get(game).map(|details| details.owner);
+					if owner.is_some() && owner == T::Nfts::collection_owner(&collection) {
+						writes.saturating_inc();
+						CollectionGameOf::<T, I>::insert(collection, game);
+					}
+				}
+			}
+
+			UpgradeConfigOf::<T, I>::translate::<
+				v0::UpgradeItemConfig<T::ItemId, BalanceOf<T, I>>,
+				_,
+			>(|_, old| {
+				reads.saturating_inc();
+				writes.saturating_inc();
+				Some(UpgradeItemConfig {
+					item: old.item,
+					fee: old.fee,
+					materials: Default::default(),
+					success_rate: 10_000,
+					on_failure: UpgradeFailure::Keep,
+				})
+			});
+
+			PoolOf::<T, I>::translate::<
+				v0::PoolDetails<T::AccountId, BalanceOf<T, I>, BlockNumberFor<T>, T::CollectionId>,
+				_,
+			>(|_, old| {
+				reads.saturating_inc();
+				writes.saturating_inc();
+				Some(PoolDetails {
+					pool_type: old.pool_type,
+					owner: old.owner,
+					owner_deposit: old.owner_deposit,
+					admin: old.admin,
+					mint_settings: MintSettings {
+						mint_type: old.mint_settings.mint_type,
+						price: old.mint_settings.price,
+						start_block: old.mint_settings.start_block,
+						end_block: old.mint_settings.end_block,
+						max_per_account: None,
+						max_per_window: None,
+						cooldown: None,
+						pity: None,
+					},
+				})
+			});
+
+			LootTableOf::<T, I>::translate::<Vec<v0::Loot<T::CollectionId, T::ItemId>>, _>(
+				|_, old| {
+					reads.saturating_inc();
+					writes.saturating_inc();
+					let table: Vec<_> = old
+						.into_iter()
+						.map(|loot| Loot {
+							maybe_nft: loot.maybe_nft,
+							weight: loot.weight,
+							tier: None,
+						})
+						.collect();
+					Some(BoundedVec::truncate_from(table))
+				},
+			);
+
+			MintRequestOf::<T, I>::translate::<
+				Vec<v0::MintRequest<T::AccountId, T::PoolId, BalanceOf<T, I>, BlockNumberFor<T>>>,
+				_,
+			>(|_, old| {
+				reads.saturating_inc();
+				writes.saturating_inc();
+				let requests: Vec<_> = old
+					.into_iter()
+					.map(|request| MintRequest {
+						miner: request.miner,
+						pool: request.pool,
+						target: request.target,
+						amount: request.amount,
+						mining_fee: request.mining_fee,
+						miner_reserve: request.miner_reserve,
+						block_number: request.block_number,
+						maybe_usage: None,
+						maybe_nonce: None,
+					})
+					.collect();
+				Some(BoundedVec::truncate_from(requests))
+			});
+
+			StorageVersion::new(1).put::<Pallet<T, I>>();
+			log::info!(target: "PalletGame", "Migrated to v1");
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			ensure!(
+				Pallet::<T, I>::on_chain_storage_version() >= 1,
+				"v1 migration failed to bump the storage version"
+			);
+			for (game, details) in Game::<T, I>::iter() {
+				ensure!(
+					details.collections as usize >=
+						CollectionsOf::<T, I>::decode_len(game).unwrap_or(0),
+					"game collections are not counted"
+				);
+			}
+			Ok(())
+		}
+	}
+}
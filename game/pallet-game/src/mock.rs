@@ -28,6 +28,7 @@ frame_support::construct_runtime!(
 		PalletGame: pallet_game,
 		Balances: pallet_balances,
 		Nfts: pallet_nfts,
+		Assets: pallet_assets,
 	}
 );
 
@@ -153,6 +154,29 @@ where
 	}
 }
 
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = u128;
+	type AssetId = u32;
+	type AssetIdParameter = u32;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<Self::AccountId>>;
+	type ForceOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type AssetDeposit = ConstU128<1>;
+	type AssetAccountDeposit = ConstU128<1>;
+	type MetadataDepositBase = ConstU128<1>;
+	type MetadataDepositPerByte = ConstU128<1>;
+	type ApprovalDeposit = ConstU128<1>;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = ();
+	type RemoveItemsLimit = ConstU32<10>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
 pub const GAME_DEPOSIT_VAL: u128 = 5_000_000_000;
 pub const UPGRADE_DEPOSIT_VAL: u128 = 3_000_000_000;
 pub const BUNDLE_DEPOSIT_VAL: u128 = 3_000_000_000;
@@ -164,6 +188,14 @@ pub const MAX_BUNDLE_VAL: u32 = 5;
 pub const MAX_LOOT: u32 = 10;
 pub const MAX_MIN_REQUEST_VAL: u32 = 10;
 pub const MIN_INTERVAL_VAL: u32 = 1;
+pub const REMOVE_ROLES_LIMIT_VAL: u32 = 10;
+pub const MAX_CANDLE_BID_VAL: u32 = 10;
+pub const MAX_SEALED_BID_VAL: u32 = 10;
+pub const MAX_ROYALTY_VAL: u16 = 1000;
+pub const MAX_TRADE_FEE_VAL: u16 = 1000;
+pub const MAX_BOOK_ORDERS_VAL: u32 = 10;
+pub const MAX_EXPIRING_TRADES_VAL: u32 = 10;
+pub const MAX_MATCH_PLAYERS_VAL: u32 = 4;
 
 parameter_types! {
 	pub GameDeposit: u128 = GAME_DEPOSIT_VAL;
@@ -181,6 +213,14 @@ parameter_types! {
 	pub MaxLoot: u32 = MAX_LOOT;
 	pub MaxMintRequest: u32 = MAX_MIN_REQUEST_VAL;
 	pub MintInterval: u32 = MIN_INTERVAL_VAL;
+	pub RemoveRolesLimit: u32 = REMOVE_ROLES_LIMIT_VAL;
+	pub MaxCandleBid: u32 = MAX_CANDLE_BID_VAL;
+	pub MaxSealedBid: u32 = MAX_SEALED_BID_VAL;
+	pub MaxRoyalty: u16 = MAX_ROYALTY_VAL;
+	pub MaxTradeFee: u16 = MAX_TRADE_FEE_VAL;
+	pub MaxBookOrders: u32 = MAX_BOOK_ORDERS_VAL;
+	pub MaxExpiringTrades: u32 = MAX_EXPIRING_TRADES_VAL;
+	pub MaxMatchPlayers: u32 = MAX_MATCH_PLAYERS_VAL;
 }
 
 impl pallet_game::Config for Test {
@@ -188,10 +228,15 @@ impl pallet_game::Config for Test {
 	type WeightInfo = ();
 	type NftsWeightInfo = ();
 	type Currency = Balances;
+	type AssetId = u32;
+	type Assets = Assets;
+	type PalletId = PalletGameId;
 	type Nfts = Nfts;
 	type GameId = u32;
 	type TradeId = u32;
 	type PoolId = u32;
+	type RecipeId = u32;
+	type MatchId = u32;
 	type MiningPoolDeposit = MiningPoolDeposit;
 	type GameDeposit = GameDeposit;
 	type MaxGameCollection = MaxGameCollection;
@@ -205,6 +250,14 @@ impl pallet_game::Config for Test {
 	type GameRandomness = ();
 	type MaxMintRequest = MaxMintRequest;
 	type MintInterval = MintInterval;
+	type RemoveRolesLimit = RemoveRolesLimit;
+	type MaxCandleBid = MaxCandleBid;
+	type MaxSealedBid = MaxSealedBid;
+	type MaxRoyalty = MaxRoyalty;
+	type MaxTradeFee = MaxTradeFee;
+	type MaxBookOrders = MaxBookOrders;
+	type MaxExpiringTrades = MaxExpiringTrades;
+	type MaxMatchPlayers = MaxMatchPlayers;
 	#[cfg(feature = "runtime-benchmarks")]
 	type Helper = ();
 }
@@ -1,14 +1,26 @@
 use crate::{mock::*, types::*, Error, *};
-use sp_core::sr25519;
-
-use frame_support::{assert_err, assert_ok, traits::Currency};
+use codec::Encode;
+use sp_core::{sr25519, Pair, H256};
+
+use frame_support::{
+	assert_err, assert_err_ignore_postinfo, assert_ok,
+	traits::{
+		Currency, GetStorageVersion, OnIdle, OnInitialize, OnRuntimeUpgrade, ReservableCurrency,
+		StorageVersion,
+	},
+	weights::Weight,
+};
 use gafi_support::{
 	common::{unit, NativeToken::GAKI},
-	game::{Loot, MintSettings, MintType, Package, NFT},
+	game::{
+		GameRole, Loot, MatchResult, MintProof, MintSettings, MintType, MintVoucher, MintWindow,
+		Package, Pity, SalvageOutput, UpgradeFailure, NFT,
+	},
 };
 use pallet_nfts::{CollectionRole, CollectionRoles};
+use sp_io::hashing::blake2_256;
 use sp_keystore::{testing::MemoryKeystore, KeystoreExt};
-use sp_runtime::TokenError;
+use sp_runtime::{traits::Hash, TokenError};
 
 type PackageFor<T> =
 	Package<<T as pallet_nfts::Config>::CollectionId, <T as pallet_nfts::Config>::ItemId>;
@@ -29,6 +41,10 @@ fn new_account(account: u32, balance: u128) -> sr25519::Public {
 	return acc
 }
 
+fn sealed_commitment(who: &sr25519::Public, bid: u128, salt: [u8; 32]) -> H256 {
+	<Test as frame_system::Config>::Hashing::hash_of(&(who, bid, salt))
+}
+
 fn default_item_config() -> ItemConfig {
 	ItemConfig::default()
 }
@@ -39,6 +55,10 @@ fn default_mint_config() -> MintSettingsFor<Test> {
 		price: 10 * unit(GAKI),
 		start_block: None,
 		end_block: None,
+		max_per_account: None,
+		max_per_window: None,
+		cooldown: None,
+		pity: None,
 	}
 }
 
@@ -99,6 +119,7 @@ const TEST_TABLE: [LootFor<Test>; 3] = [
 			item: 0,
 		}),
 		weight: 10,
+		tier: None,
 	},
 	Loot {
 		maybe_nft: Some(NFT {
@@ -106,6 +127,7 @@ const TEST_TABLE: [LootFor<Test>; 3] = [
 			item: 1,
 		}),
 		weight: 10,
+		tier: None,
 	},
 	Loot {
 		maybe_nft: Some(NFT {
@@ -113,6 +135,7 @@ const TEST_TABLE: [LootFor<Test>; 3] = [
 			item: 2,
 		}),
 		weight: 10,
+		tier: None,
 	},
 ];
 
@@ -149,6 +172,7 @@ fn do_all_create_dynamic_pool(
 		TEST_TABLE.clone().to_vec(),
 		admin.clone(),
 		mint_settings,
+		None,
 	));
 	(owner, admin)
 }
@@ -172,7 +196,8 @@ fn do_all_create_stable_pool(
 		RuntimeOrigin::signed(owner.clone()),
 		TEST_TABLE.clone().to_vec(),
 		admin.clone(),
-		default_mint_config(),
+		mint_settings,
+		None,
 	));
 
 	(owner, admin)
@@ -203,6 +228,22 @@ fn create_account_with_item(
 	(player, owner, admin)
 }
 
+fn do_transfer_item(
+	from: &sr25519::Public,
+	to: &sr25519::Public,
+	collection: u32,
+	item: u32,
+	amount: Amount,
+) {
+	assert_ok!(PalletGame::transfer(
+		RuntimeOrigin::signed(from.clone()),
+		collection,
+		item,
+		to.clone(),
+		amount
+	));
+}
+
 fn funded_account_with_item(source: [PackageFor<Test>; 3]) -> sr25519::Public {
 	let player = new_account(3, 1000 * unit(GAKI));
 	for pack in source.clone() {
@@ -220,6 +261,7 @@ fn do_all_set_price(package: PackageFor<Test>, price: u128) -> sr25519::Public {
 		price,
 		None,
 		None,
+		None,
 	));
 	who
 }
@@ -233,10 +275,38 @@ fn do_all_set_bundle(package: Vec<PackageFor<Test>>, price: u128) -> sr25519::Pu
 		price,
 		None,
 		None,
+		None,
 	));
 	player
 }
 
+const PAYMENT_ASSET: u32 = 0;
+
+fn create_payment_asset(owner: &sr25519::Public, holder: &sr25519::Public, amount: u128) {
+	do_create_payment_asset(owner, holder, amount, true);
+}
+
+fn do_create_payment_asset(
+	owner: &sr25519::Public,
+	holder: &sr25519::Public,
+	amount: u128,
+	is_sufficient: bool,
+) {
+	assert_ok!(Assets::force_create(
+		RuntimeOrigin::root(),
+		PAYMENT_ASSET,
+		owner.clone(),
+		is_sufficient,
+		1
+	));
+	assert_ok!(Assets::mint(
+		RuntimeOrigin::signed(owner.clone()),
+		PAYMENT_ASSET,
+		holder.clone(),
+		amount
+	));
+}
+
 #[test]
 fn create_game_should_works() {
 	new_test_ext().execute_with(|| {
@@ -374,6 +444,200 @@ fn remove_collection_should_fails() {
 	})
 }
 
+#[test]
+fn destroy_game_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let (owner, admin) = do_create_game();
+		do_create_collection(0, &admin);
+		do_create_collection(0, &admin);
+		assert_ok!(PalletGame::set_game_metadata(
+			RuntimeOrigin::signed(owner.clone()),
+			bvec![0u8; 50],
+			0
+		));
+
+		let witness = Game::<Test>::get(0).unwrap().destroy_witness();
+		assert_eq!(witness.collections, 2);
+		let owner_reserved = Balances::reserved_balance(owner.clone());
+
+		assert_ok!(PalletGame::start_game_destroy(
+			RuntimeOrigin::signed(owner.clone()),
+			0
+		));
+		assert_ok!(PalletGame::destroy_game_roles(
+			RuntimeOrigin::signed(admin.clone()),
+			0
+		));
+		assert_eq!(GameRoleOf::<Test>::get(0, admin.clone()), None);
+
+		assert_ok!(PalletGame::finish_game_destroy(
+			RuntimeOrigin::signed(admin.clone()),
+			0,
+			witness
+		));
+
+		assert_eq!(Game::<Test>::get(0), None);
+		assert_eq!(GameAccount::<Test>::get(owner.clone(), 0), None);
+		assert_eq!(GameMetadataOf::<Test>::get(0), None);
+		assert_eq!(CollectionsOf::<Test>::get(0), [].to_vec());
+		assert_eq!(GamesOf::<Test>::get(0), [].to_vec());
+		assert_eq!(GamesOf::<Test>::get(1), [].to_vec());
+		assert_eq!(
+			Balances::reserved_balance(owner.clone()),
+			owner_reserved - GAME_DEPOSIT_VAL
+		);
+	})
+}
+
+#[test]
+fn destroy_game_should_fails() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let (owner, admin) = do_create_game();
+		do_create_collection(0, &admin);
+
+		assert_err!(
+			PalletGame::start_game_destroy(RuntimeOrigin::signed(admin.clone()), 0),
+			Error::<Test>::NoPermission
+		);
+		assert_err!(
+			PalletGame::destroy_game_roles(RuntimeOrigin::signed(owner.clone()), 0),
+			Error::<Test>::GameNotDestroying
+		);
+
+		assert_ok!(PalletGame::start_game_destroy(
+			RuntimeOrigin::signed(owner.clone()),
+			0
+		));
+		assert_err!(
+			PalletGame::start_game_destroy(RuntimeOrigin::signed(owner.clone()), 0),
+			Error::<Test>::GameDestroying
+		);
+		assert_err!(
+			PalletGame::create_game_collection(RuntimeOrigin::signed(admin.clone()), 0),
+			Error::<Test>::GameDestroying
+		);
+		assert_err!(
+			PalletGame::finish_game_destroy(
+				RuntimeOrigin::signed(owner.clone()),
+				0,
+				GameDestroyWitness { collections: 1 }
+			),
+			Error::<Test>::RolesRemaining
+		);
+
+		assert_ok!(PalletGame::destroy_game_roles(
+			RuntimeOrigin::signed(owner.clone()),
+			0
+		));
+		assert_err!(
+			PalletGame::finish_game_destroy(
+				RuntimeOrigin::signed(owner.clone()),
+				0,
+				GameDestroyWitness { collections: 0 }
+			),
+			Error::<Test>::BadWitness
+		);
+	})
+}
+
+#[test]
+fn destroy_game_should_clear_acceptances_recipes_and_treasury() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let (owner, admin) = do_create_game();
+
+		// a pending acceptance to add a collection
+		let other = new_account(2, 1000 * unit(GAKI));
+		assert_ok!(PalletGame::create_collection(
+			RuntimeOrigin::signed(other.clone()),
+			other.clone(),
+		));
+		let other_reserved = Balances::reserved_balance(&other);
+		assert_ok!(PalletGame::set_accept_adding(
+			RuntimeOrigin::signed(other.clone()),
+			0,
+			0
+		));
+		assert_eq!(
+			Balances::reserved_balance(&other),
+			other_reserved + GAME_DEPOSIT_VAL
+		);
+
+		// a pending acceptance of the game ownership
+		let acceptor = new_account(3, 1000 * unit(GAKI));
+		assert_ok!(PalletGame::set_accept_game_ownership(
+			RuntimeOrigin::signed(acceptor.clone()),
+			Some(0),
+		));
+
+		// a recipe of the game
+		do_create_collection(0, &admin);
+		for item in 0..2 {
+			assert_ok!(PalletGame::create_item(
+				RuntimeOrigin::signed(admin.clone()),
+				1,
+				item,
+				None
+			));
+		}
+		assert_ok!(PalletGame::create_recipe(
+			RuntimeOrigin::signed(admin.clone()),
+			0,
+			vec![Package::new(1, 0, 1)],
+			None,
+			vec![Package::new(1, 1, 1)],
+			None,
+		));
+		assert_eq!(Game::<Test>::get(0).unwrap().recipes, 1);
+
+		// the game treasury
+		let treasury = PalletGame::game_account(&0);
+		make_deposit(&treasury, 100 * unit(GAKI));
+
+		let witness = Game::<Test>::get(0).unwrap().destroy_witness();
+		assert_ok!(PalletGame::start_game_destroy(
+			RuntimeOrigin::signed(owner.clone()),
+			0
+		));
+		assert_err!(
+			PalletGame::set_accept_game_ownership(RuntimeOrigin::signed(other.clone()), Some(0)),
+			Error::<Test>::GameDestroying
+		);
+
+		assert_ok!(PalletGame::destroy_game_roles(
+			RuntimeOrigin::signed(owner.clone()),
+			0
+		));
+		assert_eq!(AddingAcceptance::<Test>::get(0), None);
+		assert_eq!(Balances::reserved_balance(&other), other_reserved);
+		assert_eq!(GameOwnershipAcceptance::<Test>::get(&acceptor), None);
+
+		assert_err!(
+			PalletGame::finish_game_destroy(RuntimeOrigin::signed(owner.clone()), 0, witness),
+			Error::<Test>::RecipesRemaining
+		);
+		// the game owner removes the recipes left
+		assert_ok!(PalletGame::remove_recipe(
+			RuntimeOrigin::signed(owner.clone()),
+			0
+		));
+
+		let before_balance = Balances::free_balance(&owner);
+		assert_ok!(PalletGame::finish_game_destroy(
+			RuntimeOrigin::signed(owner.clone()),
+			0,
+			witness
+		));
+		assert_eq!(Balances::free_balance(&treasury), 0);
+		assert_eq!(
+			Balances::free_balance(&owner),
+			before_balance + 100 * unit(GAKI) + GAME_DEPOSIT_VAL
+		);
+	})
+}
+
 #[test]
 fn create_collection_should_works() {
 	new_test_ext().execute_with(|| {
@@ -391,21 +655,42 @@ fn create_collection_should_works() {
 fn set_accept_adding_should_works() {
 	new_test_ext().execute_with(|| {
 		run_to_block(1);
-		let who = new_account(0, 3_000 * unit(GAKI));
+		do_create_game();
+		let who = new_account(2, 3_000 * unit(GAKI));
 		assert_ok!(PalletGame::create_collection(
 			RuntimeOrigin::signed(who.clone()),
 			who.clone(),
 		));
+		let who_reserved = Balances::reserved_balance(&who);
 
 		assert_ok!(PalletGame::set_accept_adding(
 			RuntimeOrigin::signed(who.clone()),
 			0,
 			0
 		));
+		assert_eq!(
+			Balances::reserved_balance(&who),
+			who_reserved + GAME_DEPOSIT_VAL
+		);
 		assert_err!(
 			PalletGame::set_accept_adding(RuntimeOrigin::signed(who.clone()), 0, 1),
 			Error::<Test>::UnknownCollection
 		);
+		assert_err!(
+			PalletGame::set_accept_adding(RuntimeOrigin::signed(who.clone()), 1, 0),
+			Error::<Test>::UnknownGame
+		);
+
+		// accepting again replaces the acceptance and its deposit
+		assert_ok!(PalletGame::set_accept_adding(
+			RuntimeOrigin::signed(who.clone()),
+			0,
+			0
+		));
+		assert_eq!(
+			Balances::reserved_balance(&who),
+			who_reserved + GAME_DEPOSIT_VAL
+		);
 	})
 }
 
@@ -602,9 +887,12 @@ pub fn set_upgrade_item_should_works() {
 
 		let byte = 50;
 
-		let input: UpgradeItemConfig<u32, u128> = UpgradeItemConfig {
+		let input: ItemUpgradeConfigFor<Test> = UpgradeItemConfig {
 			item: 100,
 			fee: 3 * unit(GAKI),
+			materials: bvec![],
+			success_rate: 10_000,
+			on_failure: UpgradeFailure::Keep,
 		};
 
 		let before_balance = Balances::free_balance(&owner);
@@ -618,6 +906,7 @@ pub fn set_upgrade_item_should_works() {
 			bvec![0u8; byte],
 			1,
 			input.fee,
+			vec![],
 		));
 
 		assert_eq!(LevelOf::<Test>::get(0, 0), 0);
@@ -641,9 +930,12 @@ pub fn upgrade_item_shoud_works() {
 		let (player, owner, admin) = create_account_with_item(TEST_BUNDLE);
 		let byte = 50;
 
-		let input: UpgradeItemConfig<u32, u128> = UpgradeItemConfig {
+		let input: ItemUpgradeConfigFor<Test> = UpgradeItemConfig {
 			item: 100,
 			fee: 3 * unit(GAKI),
+			materials: bvec![],
+			success_rate: 10_000,
+			on_failure: UpgradeFailure::Keep,
 		};
 
 		assert_ok!(PalletGame::set_upgrade_item(
@@ -655,11 +947,22 @@ pub fn upgrade_item_shoud_works() {
 			bvec![0u8; byte],
 			1,
 			input.fee,
+			vec![],
 		));
 
 		let player_before_balance = Balances::free_balance(&player);
 		let owner_before_balance = Balances::free_balance(&owner);
 
+		assert_err!(
+			PalletGame::upgrade_item(
+				RuntimeOrigin::signed(player.clone()),
+				0,
+				0,
+				MAX_ITEM_MINT_VAL + 1
+			),
+			Error::<Test>::ExceedAllowedAmount
+		);
+
 		assert_ok!(PalletGame::upgrade_item(
 			RuntimeOrigin::signed(player.clone()),
 			0,
@@ -681,899 +984,3148 @@ pub fn upgrade_item_shoud_works() {
 }
 
 #[test]
-pub fn set_price_should_works() {
-	new_test_ext().execute_with(|| {
-		run_to_block(1);
-		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
-		let price = 3 * unit(GAKI);
-
-		let before_balance = Balances::free_balance(&player);
-		assert_ok!(PalletGame::set_price(
-			RuntimeOrigin::signed(player.clone()),
-			TEST_BUNDLE[0].clone(),
-			price,
-			None,
-			None,
-		));
-		assert_eq!(
-			Balances::free_balance(&player),
-			before_balance - BUNDLE_DEPOSIT_VAL
-		);
-		assert_eq!(BundleOf::<Test>::get(0), [TEST_BUNDLE[0].clone()].to_vec());
-		assert_eq!(
-			TradeConfigOf::<Test>::get(0).unwrap(),
-			TradeConfig {
-				trade: TradeType::SetPrice,
-				owner: player.clone(),
-				maybe_price: Some(price),
-				maybe_required: None,
-				start_block: None,
-				end_block: None,
-			}
-		);
-	})
-}
-
-#[test]
-pub fn set_price_should_fails() {
+pub fn upgrade_item_with_materials_should_works() {
 	new_test_ext().execute_with(|| {
 		run_to_block(1);
 		let (player, _, admin) = create_account_with_item(TEST_BUNDLE);
 
-		let price = 3 * unit(GAKI);
-
-		let mut fail_bundle = TEST_BUNDLE[0].clone();
-		fail_bundle.amount += 10;
 		assert_err!(
-			PalletGame::set_price(
-				RuntimeOrigin::signed(player.clone()),
-				fail_bundle.clone(),
-				price,
-				None,
-				None,
+			PalletGame::set_upgrade_item(
+				RuntimeOrigin::signed(admin.clone()),
+				0,
+				0,
+				100,
+				default_item_config(),
+				bvec![0u8; 10],
+				1,
+				unit(GAKI),
+				vec![Package::new(0, 5, 1)],
 			),
-			Error::<Test>::InsufficientItemBalance
+			Error::<Test>::UnknownItem
 		);
 
-		let mut item_config = ItemConfig::default();
-		item_config.disable_setting(pallet_nfts::ItemSetting::Transferable);
-
-		assert_ok!(pallet_nfts::pallet::Pallet::<Test>::lock_item_transfer(
-			RuntimeOrigin::signed(admin),
+		assert_ok!(PalletGame::set_upgrade_item(
+			RuntimeOrigin::signed(admin.clone()),
 			0,
-			0
+			0,
+			100,
+			default_item_config(),
+			bvec![0u8; 10],
+			1,
+			unit(GAKI),
+			vec![Package::new(0, 1, 2), Package::new(0, 2, 1)],
 		));
 
+		// not enough materials
 		assert_err!(
-			PalletGame::set_price(
-				RuntimeOrigin::signed(player.clone()),
-				TEST_BUNDLE[0].clone(),
-				price,
-				None,
-				None,
-			),
-			Error::<Test>::ItemLocked
+			PalletGame::upgrade_item(RuntimeOrigin::signed(player.clone()), 0, 0, 6),
+			Error::<Test>::InsufficientItemBalance
 		);
+
+		assert_ok!(PalletGame::upgrade_item(
+			RuntimeOrigin::signed(player.clone()),
+			0,
+			0,
+			4
+		));
+
+		assert_eq!(ItemBalanceOf::<Test>::get((player.clone(), 0, 0)), 6);
+		assert_eq!(ItemBalanceOf::<Test>::get((player.clone(), 0, 100)), 4);
+		assert_eq!(ItemBalanceOf::<Test>::get((player.clone(), 0, 1)), 2);
+		assert_eq!(ItemBalanceOf::<Test>::get((player.clone(), 0, 2)), 6);
+		assert_eq!(SupplyOf::<Test>::get(0, 1), Some(Some(992)));
+		assert_eq!(SupplyOf::<Test>::get(0, 2), Some(Some(996)));
 	})
 }
 
+fn do_set_upgrade_levels(admin: &sr25519::Public) {
+	for (level, new_item) in [(1, 100), (2, 200)] {
+		assert_ok!(PalletGame::set_upgrade_item(
+			RuntimeOrigin::signed(admin.clone()),
+			0,
+			0,
+			new_item,
+			default_item_config(),
+			bvec![0u8; 10],
+			level,
+			unit(GAKI),
+			vec![],
+		));
+	}
+}
+
 #[test]
-pub fn buy_item_should_works() {
+pub fn set_upgrade_chance_should_works() {
 	new_test_ext().execute_with(|| {
 		run_to_block(1);
+		let (_, _, admin) = create_account_with_item(TEST_BUNDLE);
+		do_set_upgrade_levels(&admin);
 
-		let price = 1 * unit(GAKI);
-		let seller = do_all_set_price(TEST_BUNDLE[0].clone(), price);
-
-		let buyer = new_account(4, 10000 * unit(GAKI));
-
-		let seller_before_balance = Balances::free_balance(&seller);
-		let buyer_before_balance = Balances::free_balance(&buyer);
-
-		assert_ok!(PalletGame::buy_item(
-			RuntimeOrigin::signed(buyer.clone()),
+		assert_ok!(PalletGame::set_upgrade_chance(
+			RuntimeOrigin::signed(admin.clone()),
 			0,
-			5,
-			price,
+			0,
+			2,
+			2_500,
+			UpgradeFailure::Destroy,
 		));
 
-		assert_eq!(ItemBalanceOf::<Test>::get((&seller, 0, 0)), 0);
-		assert_eq!(ReservedBalanceOf::<Test>::get((&seller, 0, 0)), 5);
-		assert_eq!(ItemBalanceOf::<Test>::get((&buyer, 0, 0)), 5);
-
-		assert_eq!(
-			Balances::free_balance(&seller),
-			seller_before_balance + (price * 5)
-		);
-		assert_eq!(
-			Balances::free_balance(&buyer),
-			buyer_before_balance - (price * 5)
-		);
+		let config = UpgradeConfigOf::<Test>::get((0, 0, 2)).unwrap();
+		assert_eq!(config.success_rate, 2_500);
+		assert_eq!(config.on_failure, UpgradeFailure::Destroy);
 	})
 }
 
 #[test]
-pub fn buy_item_should_fails() {
+pub fn set_upgrade_chance_should_fails() {
 	new_test_ext().execute_with(|| {
 		run_to_block(1);
-
-		let price = 1 * unit(GAKI);
-		do_all_set_price(TEST_BUNDLE[0].clone(), price);
-
-		let buyer = new_account(4, 10000 * unit(GAKI));
+		let (player, _, admin) = create_account_with_item(TEST_BUNDLE);
+		do_set_upgrade_levels(&admin);
 
 		assert_err!(
-			PalletGame::buy_item(
-				RuntimeOrigin::signed(buyer.clone()),
+			PalletGame::set_upgrade_chance(
+				RuntimeOrigin::signed(player.clone()),
+				0,
 				0,
 				1,
-				price - (1 * unit(GAKI)),
+				5_000,
+				UpgradeFailure::Keep,
 			),
-			Error::<Test>::BidTooLow
+			Error::<Test>::NoPermission
 		);
 
-		assert_ok!(PalletGame::buy_item(
-			RuntimeOrigin::signed(buyer.clone()),
-			0,
-			8,
-			price,
-		));
+		assert_err!(
+			PalletGame::set_upgrade_chance(
+				RuntimeOrigin::signed(admin.clone()),
+				0,
+				0,
+				1,
+				10_001,
+				UpgradeFailure::Keep,
+			),
+			Error::<Test>::InvalidSuccessRate
+		);
 
 		assert_err!(
-			PalletGame::buy_item(RuntimeOrigin::signed(buyer.clone()), 0, 4, price),
-			Error::<Test>::SoldOut
+			PalletGame::set_upgrade_chance(
+				RuntimeOrigin::signed(admin.clone()),
+				0,
+				0,
+				3,
+				5_000,
+				UpgradeFailure::Keep,
+			),
+			Error::<Test>::UnknownUpgrade
 		);
 	})
 }
 
 #[test]
-pub fn add_set_price_should_works() {
+pub fn failed_upgrade_should_works() {
 	new_test_ext().execute_with(|| {
 		run_to_block(1);
+		let (player, owner, admin) = create_account_with_item(TEST_BUNDLE);
+		do_set_upgrade_levels(&admin);
 
-		let total = TEST_BUNDLE[0].clone();
-		let supply = Package {
-			collection: total.collection,
-			item: total.item,
-			amount: (total.amount / 2),
-		};
-		let player = do_all_set_price(supply.clone(), 10 * unit(GAKI));
+		assert_ok!(PalletGame::upgrade_item(
+			RuntimeOrigin::signed(player.clone()),
+			0,
+			0,
+			6
+		));
+		assert_eq!(ItemBalanceOf::<Test>::get((player.clone(), 0, 100)), 6);
+
+		// keep
+		assert_ok!(PalletGame::set_upgrade_chance(
+			RuntimeOrigin::signed(admin.clone()),
+			0,
+			0,
+			2,
+			0,
+			UpgradeFailure::Keep,
+		));
+
+		let owner_before_balance = Balances::free_balance(&owner);
+		assert_ok!(PalletGame::upgrade_item(
+			RuntimeOrigin::signed(player.clone()),
+			0,
+			100,
+			2
+		));
+		assert_eq!(ItemBalanceOf::<Test>::get((player.clone(), 0, 100)), 6);
+		assert_eq!(ItemBalanceOf::<Test>::get((player.clone(), 0, 200)), 0);
 		assert_eq!(
-			ReservedBalanceOf::<Test>::get((player.clone(), supply.collection, supply.item)),
-			supply.amount
+			Balances::free_balance(&owner),
+			owner_before_balance + unit(GAKI) * 2
 		);
-		assert_ok!(PalletGame::add_set_price(
+
+		// downgrade
+		assert_ok!(PalletGame::set_upgrade_chance(
+			RuntimeOrigin::signed(admin.clone()),
+			0,
+			0,
+			2,
+			0,
+			UpgradeFailure::Downgrade,
+		));
+		assert_ok!(PalletGame::upgrade_item(
 			RuntimeOrigin::signed(player.clone()),
 			0,
-			supply
+			100,
+			2
+		));
+		assert_eq!(ItemBalanceOf::<Test>::get((player.clone(), 0, 100)), 4);
+		assert_eq!(ItemBalanceOf::<Test>::get((player.clone(), 0, 0)), 6);
+
+		// destroy
+		assert_ok!(PalletGame::set_upgrade_chance(
+			RuntimeOrigin::signed(admin.clone()),
+			0,
+			0,
+			2,
+			0,
+			UpgradeFailure::Destroy,
+		));
+		assert_ok!(PalletGame::upgrade_item(
+			RuntimeOrigin::signed(player.clone()),
+			0,
+			100,
+			2
+		));
+		assert_eq!(ItemBalanceOf::<Test>::get((player.clone(), 0, 100)), 2);
+
+		// always succeeds once the rate is back to 100%
+		assert_ok!(PalletGame::set_upgrade_chance(
+			RuntimeOrigin::signed(admin.clone()),
+			0,
+			0,
+			2,
+			10_000,
+			UpgradeFailure::Destroy,
+		));
+		assert_ok!(PalletGame::upgrade_item(
+			RuntimeOrigin::signed(player.clone()),
+			0,
+			100,
+			2
+		));
+		assert_eq!(ItemBalanceOf::<Test>::get((player.clone(), 0, 200)), 2);
+	})
+}
+
+#[test]
+pub fn upgrade_with_chance_should_be_resolved_later() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let (player, _, admin) = create_account_with_item(TEST_BUNDLE);
+		do_set_upgrade_levels(&admin);
+
+		assert_ok!(PalletGame::upgrade_item(
+			RuntimeOrigin::signed(player.clone()),
+			0,
+			0,
+			6
+		));
+		assert_ok!(PalletGame::set_upgrade_chance(
+			RuntimeOrigin::signed(admin.clone()),
+			0,
+			0,
+			2,
+			5_000,
+			UpgradeFailure::Keep,
+		));
+
+		assert_ok!(PalletGame::upgrade_item(
+			RuntimeOrigin::signed(player.clone()),
+			0,
+			100,
+			2
+		));
+		let block: u64 = (1 + MIN_INTERVAL_VAL) as u64;
+		System::assert_last_event(RuntimeEvent::PalletGame(crate::Event::UpgradeRequested {
+			who: player.clone(),
+			collection: 0,
+			item: 100,
+			amount: 2,
+			block_number: block,
+		}));
+
+		// the items are reserved until the attempt is rolled
+		assert_eq!(ItemBalanceOf::<Test>::get((player.clone(), 0, 100)), 4);
+		assert_eq!(ReservedBalanceOf::<Test>::get((player.clone(), 0, 100)), 2);
+		assert_eq!(UpgradeRequestOf::<Test>::get(block).len(), 1);
+
+		// the rolls are weighed when the request is processed
+		System::set_block_number(block);
+		let weight = PalletGame::on_initialize(block);
+		assert!(weight.all_gte(<Test as Config>::WeightInfo::process_upgrade_request(2)));
+		assert_eq!(ReservedBalanceOf::<Test>::get((player.clone(), 0, 100)), 0);
+		assert_eq!(ItemBalanceOf::<Test>::get((player.clone(), 0, 100)), 4);
+		assert_eq!(ItemBalanceOf::<Test>::get((player.clone(), 0, 200)), 2);
+
+		run_to_block(block + 1);
+		assert!(UpgradeRequestOf::<Test>::get(block).is_empty());
+	})
+}
+
+#[test]
+pub fn create_recipe_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let (_, owner, admin) = create_account_with_item(TEST_BUNDLE);
+		let game = NextGameId::<Test>::get().unwrap() - 1;
+		do_transfer_item(&owner, &admin, 0, 2, 10);
+
+		let input = vec![Package::new(0, 0, 2), Package::new(0, 1, 1)];
+		let output = vec![Package::new(0, 2, 1)];
+		let before_balance = Balances::free_balance(&admin);
+
+		assert_ok!(PalletGame::create_recipe(
+			RuntimeOrigin::signed(admin.clone()),
+			game,
+			input.clone(),
+			Some(unit(GAKI)),
+			output.clone(),
+			Some(10),
 		));
+
+		let recipe = RecipeOf::<Test>::get(0).unwrap();
+		assert_eq!(recipe.owner, admin);
+		assert_eq!(recipe.game, game);
+		assert_eq!(recipe.input.into_inner(), input);
+		assert_eq!(recipe.output.into_inner(), output);
+		assert_eq!(recipe.maybe_crafts, Some(10));
 		assert_eq!(
-			ReservedBalanceOf::<Test>::get((player.clone(), total.collection, total.item)),
-			total.amount
+			Balances::free_balance(&admin),
+			before_balance - BUNDLE_DEPOSIT_VAL
 		);
+
+		// the finite output of every craft is reserved
+		assert_eq!(ItemBalanceOf::<Test>::get((admin.clone(), 0, 2)), 0);
+		assert_eq!(ReservedBalanceOf::<Test>::get((admin.clone(), 0, 2)), 10);
+
+		assert_ok!(PalletGame::remove_recipe(
+			RuntimeOrigin::signed(admin.clone()),
+			0
+		));
+		assert_eq!(RecipeOf::<Test>::get(0), None);
+		assert_eq!(Balances::free_balance(&admin), before_balance);
+		assert_eq!(ItemBalanceOf::<Test>::get((admin.clone(), 0, 2)), 10);
+		assert_eq!(ReservedBalanceOf::<Test>::get((admin.clone(), 0, 2)), 0);
 	})
 }
 
 #[test]
-pub fn add_set_price_should_failss() {
+pub fn create_recipe_should_fails() {
 	new_test_ext().execute_with(|| {
 		run_to_block(1);
+		let (player, owner, admin) = create_account_with_item(TEST_BUNDLE);
+		let game = NextGameId::<Test>::get().unwrap() - 1;
 
-		let player = do_all_set_price(TEST_BUNDLE[0].clone(), 10 * unit(GAKI));
-		let player1 = new_account(1, 1000 * unit(GAKI));
+		let input = vec![Package::new(0, 0, 2)];
+		let output = vec![Package::new(0, 2, 1)];
 
 		assert_err!(
-			PalletGame::add_set_price(
-				RuntimeOrigin::signed(player1.clone()),
-				0,
-				TEST_BUNDLE[0].clone()
+			PalletGame::create_recipe(
+				RuntimeOrigin::signed(admin.clone()),
+				game,
+				vec![],
+				None,
+				output.clone(),
+				Some(1),
 			),
-			Error::<Test>::NoPermission
+			Error::<Test>::InvalidRecipe
 		);
 
 		assert_err!(
-			PalletGame::add_set_price(
-				RuntimeOrigin::signed(player.clone()),
-				0,
-				TEST_BUNDLE1[1].clone()
+			PalletGame::create_recipe(
+				RuntimeOrigin::signed(admin.clone()),
+				game,
+				vec![Package::new(0, 0, 0)],
+				None,
+				output.clone(),
+				Some(1),
 			),
-			Error::<Test>::IncorrectCollection
+			Error::<Test>::InvalidRecipe
 		);
 
 		assert_err!(
-			PalletGame::add_set_price(
-				RuntimeOrigin::signed(player.clone()),
-				0,
-				TEST_BUNDLE[1].clone()
+			PalletGame::create_recipe(
+				RuntimeOrigin::signed(admin.clone()),
+				game,
+				input.clone(),
+				None,
+				vec![Package::new(0, 100, 1)],
+				Some(1),
 			),
-			Error::<Test>::IncorrectItem
+			Error::<Test>::UnknownItem
 		);
 
 		assert_err!(
-			PalletGame::add_set_price(
+			PalletGame::create_recipe(
 				RuntimeOrigin::signed(player.clone()),
-				0,
-				TEST_BUNDLE[0].clone()
+				game,
+				input.clone(),
+				None,
+				output.clone(),
+				Some(1),
+			),
+			Error::<Test>::NoPermission
+		);
+
+		// finite outputs need a limit of crafts held by the signer
+		assert_err!(
+			PalletGame::create_recipe(
+				RuntimeOrigin::signed(admin.clone()),
+				game,
+				input.clone(),
+				None,
+				output.clone(),
+				None,
+			),
+			Error::<Test>::InvalidRecipe
+		);
+		assert_err!(
+			PalletGame::create_recipe(
+				RuntimeOrigin::signed(admin.clone()),
+				game,
+				input.clone(),
+				None,
+				output.clone(),
+				Some(1),
 			),
 			Error::<Test>::InsufficientItemBalance
 		);
+
+		do_transfer_item(&owner, &admin, 0, 2, 1);
+		assert_ok!(PalletGame::create_recipe(
+			RuntimeOrigin::signed(admin.clone()),
+			game,
+			input,
+			None,
+			output,
+			Some(1),
+		));
+
+		assert_err!(
+			PalletGame::remove_recipe(RuntimeOrigin::signed(player.clone()), 0),
+			Error::<Test>::NoPermission
+		);
 	})
 }
 
 #[test]
-pub fn set_bundle_should_works() {
+pub fn craft_should_works() {
 	new_test_ext().execute_with(|| {
 		run_to_block(1);
+		let (player, owner, admin) = create_account_with_item(TEST_BUNDLE);
+		let game = NextGameId::<Test>::get().unwrap() - 1;
 
-		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
+		// infinite output item
+		assert_ok!(PalletGame::create_item(
+			RuntimeOrigin::signed(admin.clone()),
+			0,
+			3,
+			None
+		));
 
-		let price = 100 * unit(GAKI);
+		let fee = unit(GAKI);
+		do_transfer_item(&owner, &admin, 0, 2, 5);
+		assert_ok!(PalletGame::create_recipe(
+			RuntimeOrigin::signed(admin.clone()),
+			game,
+			vec![Package::new(0, 0, 2), Package::new(0, 1, 1)],
+			Some(fee),
+			vec![Package::new(0, 2, 1), Package::new(0, 3, 5)],
+			Some(5),
+		));
 
-		assert_ok!(PalletGame::set_bundle(
+		let player_before_balance = Balances::free_balance(&player);
+		let admin_before_balance = Balances::free_balance(&admin);
+
+		assert_ok!(PalletGame::craft(
 			RuntimeOrigin::signed(player.clone()),
-			TEST_BUNDLE.clone().to_vec(),
-			price,
-			None,
-			None,
+			0,
+			3
 		));
 
-		assert_eq!(BundleOf::<Test>::get(0), TEST_BUNDLE.clone().to_vec());
+		// input is burned
+		assert_eq!(ItemBalanceOf::<Test>::get((player.clone(), 0, 0)), 4);
+		assert_eq!(ItemBalanceOf::<Test>::get((player.clone(), 0, 1)), 7);
+		assert_eq!(SupplyOf::<Test>::get(0, 0), Some(Some(994)));
+		assert_eq!(SupplyOf::<Test>::get(0, 1), Some(Some(997)));
+
+		// finite output is taken from the recipe owner
+		assert_eq!(ItemBalanceOf::<Test>::get((player.clone(), 0, 2)), 13);
+		assert_eq!(ReservedBalanceOf::<Test>::get((admin.clone(), 0, 2)), 2);
+		assert_eq!(RecipeOf::<Test>::get(0).unwrap().maybe_crafts, Some(2));
+		assert_eq!(SupplyOf::<Test>::get(0, 2), Some(Some(1000)));
+		assert_eq!(ItemBalanceOf::<Test>::get((player.clone(), 0, 3)), 15);
+
+		assert_eq!(
+			Balances::free_balance(&player),
+			player_before_balance - fee * 3
+		);
+		assert_eq!(
+			Balances::free_balance(&admin),
+			admin_before_balance + fee * 3
+		);
 	})
 }
 
 #[test]
-pub fn set_bundle_should_fails() {
+pub fn craft_should_fails() {
 	new_test_ext().execute_with(|| {
 		run_to_block(1);
-		let seller = new_account(0, 10_000 * unit(GAKI));
+		let (player, owner, admin) = create_account_with_item(TEST_BUNDLE);
+		let game = NextGameId::<Test>::get().unwrap() - 1;
 
-		for i in 0..(MAX_BUNDLE_VAL + 5) {
-			ItemBalanceOf::<Test>::insert((&seller, 0, i), 10);
-		}
+		assert_err!(
+			PalletGame::craft(RuntimeOrigin::signed(player.clone()), 0, 1),
+			Error::<Test>::UnknownRecipe
+		);
 
-		{
-			let packages: Vec<PackageFor<Test>> = vec![Package::new(0, 0, 11)];
-			assert_err!(
-				PalletGame::set_bundle(
-					RuntimeOrigin::signed(seller.clone()),
-					packages.clone(),
-					100 * unit(GAKI),
-					None,
-					None,
-				),
-				Error::<Test>::InsufficientItemBalance
-			);
-		}
+		do_transfer_item(&owner, &admin, 0, 2, 500);
+		assert_ok!(PalletGame::create_recipe(
+			RuntimeOrigin::signed(admin.clone()),
+			game,
+			vec![Package::new(0, 0, 3)],
+			None,
+			vec![Package::new(0, 2, 100)],
+			Some(5),
+		));
 
-		{
-			let mut packages: Vec<PackageFor<Test>> = vec![];
+		assert_err!(
+			PalletGame::craft(RuntimeOrigin::signed(player.clone()), 0, 0),
+			Error::<Test>::InvalidAmount
+		);
 
-			for i in 0..(MAX_BUNDLE_VAL + 5) {
-				packages.push(Package::new(0, i, 1));
-			}
-			assert_err!(
-				PalletGame::set_bundle(
-					RuntimeOrigin::signed(seller.clone()),
-					packages.clone(),
-					100 * unit(GAKI),
-					None,
-					None,
-				),
-				Error::<Test>::ExceedMaxBundle
-			);
-		}
+		assert_err!(
+			PalletGame::craft(RuntimeOrigin::signed(player.clone()), 0, 4),
+			Error::<Test>::InsufficientItemBalance
+		);
+
+		// more than the remaining crafts
+		assert_err!(
+			PalletGame::craft(RuntimeOrigin::signed(player.clone()), 0, 6),
+			Error::<Test>::ExceedTotalAmount
+		);
+
+		// the game is being destroyed
+		assert_ok!(PalletGame::start_game_destroy(
+			RuntimeOrigin::signed(owner.clone()),
+			game
+		));
+		assert_err!(
+			PalletGame::craft(RuntimeOrigin::signed(player.clone()), 0, 1),
+			Error::<Test>::GameDestroying
+		);
 	})
 }
 
 #[test]
-pub fn buy_bundle_should_works() {
+pub fn set_salvage_should_works() {
 	new_test_ext().execute_with(|| {
 		run_to_block(1);
-		let price = 10 * unit(GAKI);
-		let seller = do_all_set_bundle(TEST_BUNDLE.clone().to_vec(), price);
-		let buyer = new_account(1, 10_000 * unit(GAKI));
+		let (_, _, admin) = create_account_with_item(TEST_BUNDLE);
+		let before_balance = Balances::free_balance(&admin);
 
-		let seller_before_balance = Balances::free_balance(&seller);
-		let buyer_before_balance = Balances::free_balance(&buyer);
+		assert_ok!(PalletGame::set_salvage(
+			RuntimeOrigin::signed(admin.clone()),
+			0,
+			0,
+			SalvageOutput::Fixed(vec![Package::new(0, 1, 3)]),
+		));
 
-		assert_ok!(PalletGame::buy_bundle(
-			RuntimeOrigin::signed(buyer.clone()),
+		let salvage = SalvageOf::<Test>::get(0, 0).unwrap();
+		assert_eq!(salvage.owner, admin);
+		assert_eq!(
+			salvage.output,
+			SalvageOutput::Fixed(bvec![Package::new(0, 1, 3)])
+		);
+		assert_eq!(
+			Balances::free_balance(&admin),
+			before_balance - BUNDLE_DEPOSIT_VAL
+		);
+
+		// replace the salvage
+		assert_ok!(PalletGame::set_salvage(
+			RuntimeOrigin::signed(admin.clone()),
 			0,
-			price
+			0,
+			SalvageOutput::Fixed(vec![Package::new(0, 2, 1)]),
 		));
-		for i in 0..TEST_BUNDLE.len() as u32 {
-			assert_eq!(ItemBalanceOf::<Test>::get((buyer.clone(), 0, i)), 10);
-		}
-		for i in 0..TEST_BUNDLE.len() as u32 {
-			assert_eq!(ItemBalanceOf::<Test>::get((seller.clone(), 0, i)), 0);
-		}
 		assert_eq!(
-			Balances::free_balance(&seller),
-			seller_before_balance + price + BUNDLE_DEPOSIT_VAL
+			Balances::free_balance(&admin),
+			before_balance - BUNDLE_DEPOSIT_VAL
 		);
-		assert_eq!(Balances::free_balance(&buyer), buyer_before_balance - price);
+
+		assert_ok!(PalletGame::clear_salvage(
+			RuntimeOrigin::signed(admin.clone()),
+			0,
+			0
+		));
+		assert_eq!(SalvageOf::<Test>::get(0, 0), None);
+		assert_eq!(Balances::free_balance(&admin), before_balance);
 	})
 }
 
 #[test]
-pub fn buy_bundle_should_fails() {
+pub fn set_salvage_should_fails() {
 	new_test_ext().execute_with(|| {
 		run_to_block(1);
+		let (player, _, admin) = create_account_with_item(TEST_BUNDLE);
 
-		let price = 100 * unit(GAKI);
-		let _ = do_all_set_bundle(TEST_BUNDLE.clone().to_vec(), price);
+		assert_err!(
+			PalletGame::set_salvage(
+				RuntimeOrigin::signed(player.clone()),
+				0,
+				0,
+				SalvageOutput::Fixed(vec![Package::new(0, 1, 3)]),
+			),
+			Error::<Test>::NoPermission
+		);
 
-		let buyer = new_account(1, 1 * unit(GAKI));
+		assert_err!(
+			PalletGame::set_salvage(
+				RuntimeOrigin::signed(admin.clone()),
+				0,
+				0,
+				SalvageOutput::Fixed(vec![]),
+			),
+			Error::<Test>::InvalidSalvage
+		);
 
 		assert_err!(
-			PalletGame::buy_bundle(RuntimeOrigin::signed(buyer.clone()), 0, price * unit(GAKI)),
-			TokenError::FundsUnavailable,
+			PalletGame::set_salvage(
+				RuntimeOrigin::signed(admin.clone()),
+				0,
+				0,
+				SalvageOutput::Fixed(vec![Package::new(0, 10, 1)]),
+			),
+			Error::<Test>::UnknownItem
 		);
 
-		let buyer = new_account(1, 1000 * unit(GAKI));
 		assert_err!(
-			PalletGame::buy_bundle(
-				RuntimeOrigin::signed(buyer.clone()),
+			PalletGame::set_salvage(
+				RuntimeOrigin::signed(admin.clone()),
 				0,
-				price - 1 * unit(GAKI)
+				0,
+				SalvageOutput::Random(TEST_TABLE.to_vec()),
 			),
-			Error::<Test>::BidTooLow
+			Error::<Test>::NotInfiniteSupply
+		);
+
+		assert_err!(
+			PalletGame::clear_salvage(RuntimeOrigin::signed(admin.clone()), 0, 0),
+			Error::<Test>::UnknownSalvage
 		);
 	})
 }
 
 #[test]
-pub fn do_cancel_set_price_should_works() {
+pub fn salvage_should_works() {
 	new_test_ext().execute_with(|| {
 		run_to_block(1);
+		let (player, owner, admin) = create_account_with_item(TEST_BUNDLE);
 
-		let player = do_all_set_price(TEST_BUNDLE[0].clone(), unit(GAKI));
-
-		let before_balance = Balances::free_balance(&player);
-
-		assert_ok!(PalletGame::do_cancel_price(&0, &player.clone(),));
+		assert_ok!(PalletGame::set_salvage(
+			RuntimeOrigin::signed(admin.clone()),
+			0,
+			0,
+			SalvageOutput::Fixed(vec![Package::new(0, 1, 3)]),
+		));
 
-		assert_eq!(BundleOf::<Test>::get(0), [].to_vec());
-		assert_eq!(TradeConfigOf::<Test>::get(0), None);
+		assert_ok!(PalletGame::salvage(
+			RuntimeOrigin::signed(player.clone()),
+			0,
+			0,
+			2
+		));
 
-		assert_eq!(ItemBalanceOf::<Test>::get((player.clone(), 0, 0)), 10);
-		assert_eq!(ReservedBalanceOf::<Test>::get((player.clone(), 0, 0)), 0);
-		assert_eq!(
-			Balances::free_balance(&player),
-			before_balance + BUNDLE_DEPOSIT_VAL
-		);
-	});
+		assert_eq!(ItemBalanceOf::<Test>::get((player.clone(), 0, 0)), 8);
+		assert_eq!(SupplyOf::<Test>::get(0, 0), Some(Some(998)));
+		assert_eq!(ItemBalanceOf::<Test>::get((player.clone(), 0, 1)), 16);
+		assert_eq!(ItemBalanceOf::<Test>::get((owner.clone(), 0, 1)), 984);
+	})
 }
 
 #[test]
-pub fn do_cancel_set_bundle_should_works() {
+pub fn salvage_with_loot_table_should_works() {
 	new_test_ext().execute_with(|| {
 		run_to_block(1);
+		let (player, _, admin) = create_account_with_item(TEST_BUNDLE);
 
-		let player = do_all_set_bundle(TEST_BUNDLE.clone().to_vec(), unit(GAKI));
-
-		let before_balance = Balances::free_balance(&player);
-		assert_ok!(PalletGame::do_cancel_bundle(&0, &player.clone(),));
+		assert_ok!(PalletGame::create_item(
+			RuntimeOrigin::signed(admin.clone()),
+			0,
+			3,
+			None
+		));
 
-		assert_eq!(BundleOf::<Test>::get(0), [].to_vec());
-		assert_eq!(TradeConfigOf::<Test>::get(0), None);
+		assert_ok!(PalletGame::set_salvage(
+			RuntimeOrigin::signed(admin.clone()),
+			0,
+			0,
+			SalvageOutput::Random(vec![
+				Loot {
+					maybe_nft: Some(NFT {
+						collection: 0,
+						item: 3,
+					}),
+					weight: 1,
+					tier: None,
+				},
+				Loot {
+					maybe_nft: None,
+					weight: 9,
+					tier: None,
+				},
+			]),
+		));
 
-		for i in 0..TEST_BUNDLE.len() as u32 {
-			assert_eq!(ItemBalanceOf::<Test>::get((player.clone(), 0, i)), 10);
-			assert_eq!(ReservedBalanceOf::<Test>::get((player.clone(), 0, i)), 0);
-		}
-		assert_eq!(
-			Balances::free_balance(&player),
-			before_balance + BUNDLE_DEPOSIT_VAL
+		assert_err!(
+			PalletGame::salvage(
+				RuntimeOrigin::signed(player.clone()),
+				0,
+				0,
+				MAX_ITEM_MINT_VAL + 1
+			),
+			Error::<Test>::ExceedAllowedAmount
 		);
-	});
-}
-
-#[test]
-pub fn cancel_set_wishlist_should_works() {
-	new_test_ext().execute_with(|| {
-		run_to_block(1);
 
-		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
-		let price = 3 * unit(GAKI);
-
-		assert_ok!(PalletGame::order_bundle(
+		assert_ok!(PalletGame::salvage(
 			RuntimeOrigin::signed(player.clone()),
-			TEST_BUNDLE.clone().to_vec(),
-			price,
-			None,
-			None,
+			0,
+			0,
+			4
 		));
+		let block: u64 = (1 + MIN_INTERVAL_VAL) as u64;
+		System::assert_last_event(RuntimeEvent::PalletGame(crate::Event::SalvageRequested {
+			who: player.clone(),
+			collection: 0,
+			item: 0,
+			amount: 4,
+			block_number: block,
+		}));
 
-		let before_balance = Balances::free_balance(&player);
-		assert_ok!(PalletGame::do_cancel_wishlist(&0, &player.clone(),));
+		// the items are burned, the loot is rolled in a later block
+		assert_eq!(ItemBalanceOf::<Test>::get((player.clone(), 0, 0)), 6);
+		assert_eq!(ItemBalanceOf::<Test>::get((player.clone(), 0, 3)), 0);
+		assert_eq!(SalvageRequestOf::<Test>::get(block).len(), 1);
 
-		assert_eq!(BundleOf::<Test>::get(0), [].to_vec());
-		assert_eq!(TradeConfigOf::<Test>::get(0), None);
+		run_to_block(block);
+		assert_eq!(ItemBalanceOf::<Test>::get((player.clone(), 0, 3)), 4);
 
-		assert_eq!(
-			Balances::free_balance(&player),
-			before_balance + BUNDLE_DEPOSIT_VAL
-		);
-	});
+		run_to_block(block + 1);
+		assert!(SalvageRequestOf::<Test>::get(block).is_empty());
+	})
 }
 
 #[test]
-pub fn do_cancel_set_swap_should_works() {
+pub fn set_price_should_works() {
 	new_test_ext().execute_with(|| {
 		run_to_block(1);
-
 		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
+		let price = 3 * unit(GAKI);
 
-		let price = 100 * unit(GAKI);
-
-		assert_ok!(PalletGame::create_swap(
+		let before_balance = Balances::free_balance(&player);
+		assert_ok!(PalletGame::set_price(
 			RuntimeOrigin::signed(player.clone()),
-			TEST_BUNDLE.clone().to_vec(),
-			TEST_BUNDLE1.clone().to_vec(),
-			Some(price),
+			TEST_BUNDLE[0].clone(),
+			price,
+			None,
 			None,
 			None,
 		));
-
-		let before_balance = Balances::free_balance(&player);
-		assert_ok!(PalletGame::do_cancel_swap(&0, &player.clone(),));
-		assert_eq!(BundleOf::<Test>::get(0), [].to_vec());
-		assert_eq!(TradeConfigOf::<Test>::get(0), None);
-
 		assert_eq!(
 			Balances::free_balance(&player),
-			before_balance + BUNDLE_DEPOSIT_VAL
+			before_balance - BUNDLE_DEPOSIT_VAL
 		);
-	});
-}
-
-#[test]
-pub fn set_wishlist_should_works() {
-	new_test_ext().execute_with(|| {
-		run_to_block(1);
-		let buyer = new_account(3, 1000 * unit(GAKI));
-		let price = 3 * unit(GAKI);
-		let before_balance = Balances::free_balance(&buyer);
-		assert_ok!(PalletGame::order_bundle(
-			RuntimeOrigin::signed(buyer.clone()),
-			TEST_BUNDLE.clone().to_vec(),
-			price,
-			None,
-			None,
-		));
-
-		assert_eq!(BundleOf::<Test>::get(0), TEST_BUNDLE.clone().to_vec());
+		assert_eq!(BundleOf::<Test>::get(0), [TEST_BUNDLE[0].clone()].to_vec());
 		assert_eq!(
 			TradeConfigOf::<Test>::get(0).unwrap(),
 			TradeConfig {
-				trade: TradeType::Wishlist,
-				owner: buyer.clone(),
+				trade: TradeType::SetPrice,
+				owner: player.clone(),
 				maybe_price: Some(price),
 				maybe_required: None,
 				start_block: None,
 				end_block: None,
 			}
 		);
-		assert_eq!(
-			Balances::free_balance(&buyer),
-			before_balance - (BUNDLE_DEPOSIT_VAL + price)
-		);
 	})
 }
 
 #[test]
-pub fn fill_wishlist_should_works() {
+pub fn set_price_should_fails() {
 	new_test_ext().execute_with(|| {
 		run_to_block(1);
-		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
-		let price = 3 * unit(GAKI);
+		let (player, _, admin) = create_account_with_item(TEST_BUNDLE);
 
-		let buyer = new_account(3, 1000 * unit(GAKI));
-		assert_ok!(PalletGame::order_bundle(
-			RuntimeOrigin::signed(buyer.clone()),
-			TEST_BUNDLE.clone().to_vec(),
-			price,
-			None,
-			None,
-		));
+		let price = 3 * unit(GAKI);
 
-		let before_player_balance = Balances::free_balance(&player);
-		assert_ok!(PalletGame::sell_bundle(
-			RuntimeOrigin::signed(player.clone()),
+		let mut fail_bundle = TEST_BUNDLE[0].clone();
+		fail_bundle.amount += 10;
+		assert_err!(
+			PalletGame::set_price(
+				RuntimeOrigin::signed(player.clone()),
+				fail_bundle.clone(),
+				price,
+				None,
+				None,
+				None,
+			),
+			Error::<Test>::InsufficientItemBalance
+		);
+
+		let mut item_config = ItemConfig::default();
+		item_config.disable_setting(pallet_nfts::ItemSetting::Transferable);
+
+		assert_ok!(pallet_nfts::pallet::Pallet::<Test>::lock_item_transfer(
+			RuntimeOrigin::signed(admin),
 			0,
-			price
+			0
 		));
 
-		assert_eq!(BundleOf::<Test>::get(0), [].to_vec());
-		assert_eq!(TradeConfigOf::<Test>::get(0), None);
-
-		for i in 0..TEST_BUNDLE.len() as u32 {
-			assert_eq!(ItemBalanceOf::<Test>::get((&player, 0, i)), 0);
-			assert_eq!(ItemBalanceOf::<Test>::get((&buyer, 0, i)), 10);
-		}
-		assert_eq!(Balances::reserved_balance(&buyer), 0);
-		assert_eq!(
-			Balances::free_balance(&player),
-			before_player_balance + price
+		assert_err!(
+			PalletGame::set_price(
+				RuntimeOrigin::signed(player.clone()),
+				TEST_BUNDLE[0].clone(),
+				price,
+				None,
+				None,
+				None,
+			),
+			Error::<Test>::ItemLocked
 		);
 	})
 }
 
 #[test]
-pub fn set_swap_should_works() {
+pub fn buy_item_should_works() {
 	new_test_ext().execute_with(|| {
 		run_to_block(1);
 
-		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
+		let price = 1 * unit(GAKI);
+		let seller = do_all_set_price(TEST_BUNDLE[0].clone(), price);
 
-		let price = 100 * unit(GAKI);
+		let buyer = new_account(4, 10000 * unit(GAKI));
 
-		let player_balance = Balances::free_balance(&player);
-		assert_ok!(PalletGame::create_swap(
-			RuntimeOrigin::signed(player.clone()),
-			TEST_BUNDLE.clone().to_vec(),
-			TEST_BUNDLE1.clone().to_vec(),
-			Some(price),
-			None,
-			None,
+		let seller_before_balance = Balances::free_balance(&seller);
+		let buyer_before_balance = Balances::free_balance(&buyer);
+
+		assert_ok!(PalletGame::buy_item(
+			RuntimeOrigin::signed(buyer.clone()),
+			0,
+			5,
+			price,
 		));
 
+		assert_eq!(ItemBalanceOf::<Test>::get((&seller, 0, 0)), 0);
+		assert_eq!(ReservedBalanceOf::<Test>::get((&seller, 0, 0)), 5);
+		assert_eq!(ItemBalanceOf::<Test>::get((&buyer, 0, 0)), 5);
+
 		assert_eq!(
-			Balances::free_balance(&player),
-			player_balance - BUNDLE_DEPOSIT_VAL
+			Balances::free_balance(&seller),
+			seller_before_balance + (price * 5)
 		);
-		assert_eq!(BundleOf::<Test>::get(0), TEST_BUNDLE.clone().to_vec());
-
-		for i in 0..TEST_BUNDLE.len() as u32 {
-			assert_eq!(ItemBalanceOf::<Test>::get((&player, 0, i)), 0);
-		}
-
 		assert_eq!(
-			TradeConfigOf::<Test>::get(0).unwrap(),
-			TradeConfig {
-				trade: TradeType::Swap,
-				owner: player.clone(),
-				maybe_price: Some(price),
-				maybe_required: Some(
-					BundleFor::<Test>::try_from(TEST_BUNDLE1.clone().to_vec()).unwrap()
-				),
-				start_block: None,
-				end_block: None,
-			}
+			Balances::free_balance(&buyer),
+			buyer_before_balance - (price * 5)
 		);
 	})
 }
 
 #[test]
-pub fn claim_swap_should_works() {
+pub fn buy_item_should_fails() {
 	new_test_ext().execute_with(|| {
 		run_to_block(1);
 
-		let (player1, _, _) = create_account_with_item(TEST_BUNDLE);
-		let (player2, _, _) = create_account_with_item(TEST_BUNDLE1);
-
-		let price = 100 * unit(GAKI);
+		let price = 1 * unit(GAKI);
+		do_all_set_price(TEST_BUNDLE[0].clone(), price);
 
-		assert_ok!(PalletGame::create_swap(
-			RuntimeOrigin::signed(player1.clone()),
-			TEST_BUNDLE.clone().to_vec(),
-			TEST_BUNDLE1.clone().to_vec(),
-			Some(price),
-			None,
-			None,
-		));
+		let buyer = new_account(4, 10000 * unit(GAKI));
 
-		let player1_balance = Balances::free_balance(&player1);
-		let player2_balance = Balances::free_balance(&player2);
+		assert_err!(
+			PalletGame::buy_item(
+				RuntimeOrigin::signed(buyer.clone()),
+				0,
+				1,
+				price - (1 * unit(GAKI)),
+			),
+			Error::<Test>::BidTooLow
+		);
 
-		assert_ok!(PalletGame::make_swap(
-			RuntimeOrigin::signed(player2.clone()),
+		assert_ok!(PalletGame::buy_item(
+			RuntimeOrigin::signed(buyer.clone()),
 			0,
-			Some(price)
+			8,
+			price,
 		));
 
-		assert_eq!(
-			Balances::free_balance(&player1),
-			player1_balance + price + BUNDLE_DEPOSIT_VAL
+		assert_err!(
+			PalletGame::buy_item(RuntimeOrigin::signed(buyer.clone()), 0, 4, price),
+			Error::<Test>::SoldOut
 		);
-		assert_eq!(Balances::free_balance(&player2), player2_balance - price);
-
-		for i in 0..TEST_BUNDLE.len() as u32 {
-			assert_eq!(ItemBalanceOf::<Test>::get((&player1, 1, i)), 10);
-			assert_eq!(ItemBalanceOf::<Test>::get((&player2, 0, i)), 10);
-		}
 	})
 }
 
 #[test]
-pub fn set_auction_should_works() {
+pub fn add_set_price_should_works() {
 	new_test_ext().execute_with(|| {
 		run_to_block(1);
 
-		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
-
-		let price = 100 * unit(GAKI);
-
-		let player_balance = Balances::free_balance(&player);
-
-		assert_ok!(PalletGame::set_auction(
+		let total = TEST_BUNDLE[0].clone();
+		let supply = Package {
+			collection: total.collection,
+			item: total.item,
+			amount: (total.amount / 2),
+		};
+		let player = do_all_set_price(supply.clone(), 10 * unit(GAKI));
+		assert_eq!(
+			ReservedBalanceOf::<Test>::get((player.clone(), supply.collection, supply.item)),
+			supply.amount
+		);
+		assert_ok!(PalletGame::add_set_price(
 			RuntimeOrigin::signed(player.clone()),
-			TEST_BUNDLE.clone().to_vec(),
-			Some(price),
-			Some(1),
-			1,
+			0,
+			supply
 		));
 		assert_eq!(
-			Balances::free_balance(&player),
-			player_balance - BUNDLE_DEPOSIT_VAL
+			ReservedBalanceOf::<Test>::get((player.clone(), total.collection, total.item)),
+			total.amount
 		);
-		for i in 0..TEST_BUNDLE.len() as u32 {
-			assert_eq!(ItemBalanceOf::<Test>::get((&player, 0, i)), 0);
-			assert_eq!(ReservedBalanceOf::<Test>::get((&player, 0, i)), 10);
-		}
 	})
 }
 
 #[test]
-pub fn bid_auction_should_works() {
+pub fn add_set_price_should_failss() {
 	new_test_ext().execute_with(|| {
 		run_to_block(1);
 
-		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
-		assert_ok!(PalletGame::set_auction(
-			RuntimeOrigin::signed(player.clone()),
-			TEST_BUNDLE.clone().to_vec(),
-			Some(100 * unit(GAKI)),
-			Some(1),
-			10,
-		));
+		let player = do_all_set_price(TEST_BUNDLE[0].clone(), 10 * unit(GAKI));
+		let player1 = new_account(1, 1000 * unit(GAKI));
 
-		let bidder = new_account(1, 1000 * unit(GAKI));
-		let bid = 200 * unit(GAKI);
-		let bidder_balance = Balances::free_balance(&bidder);
-		assert_ok!(PalletGame::bid_auction(
-			RuntimeOrigin::signed(bidder.clone()),
-			0,
-			bid
-		));
-		assert_eq!(Balances::free_balance(&bidder), bidder_balance - bid);
+		assert_err!(
+			PalletGame::add_set_price(
+				RuntimeOrigin::signed(player1.clone()),
+				0,
+				TEST_BUNDLE[0].clone()
+			),
+			Error::<Test>::NoPermission
+		);
 
-		run_to_block(2);
+		assert_err!(
+			PalletGame::add_set_price(
+				RuntimeOrigin::signed(player.clone()),
+				0,
+				TEST_BUNDLE1[1].clone()
+			),
+			Error::<Test>::IncorrectCollection
+		);
 
-		assert_ok!(PalletGame::bid_auction(
-			RuntimeOrigin::signed(bidder.clone()),
-			0,
-			bid * 2
-		));
+		assert_err!(
+			PalletGame::add_set_price(
+				RuntimeOrigin::signed(player.clone()),
+				0,
+				TEST_BUNDLE[1].clone()
+			),
+			Error::<Test>::IncorrectItem
+		);
 
-		assert_eq!(Balances::free_balance(&bidder), bidder_balance - (bid * 2));
+		assert_err!(
+			PalletGame::add_set_price(
+				RuntimeOrigin::signed(player.clone()),
+				0,
+				TEST_BUNDLE[0].clone()
+			),
+			Error::<Test>::InsufficientItemBalance
+		);
 	})
 }
 
 #[test]
-pub fn bid_auction_should_fails() {
+pub fn set_bundle_should_works() {
 	new_test_ext().execute_with(|| {
 		run_to_block(1);
 
 		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
-		assert_ok!(PalletGame::set_auction(
+
+		let price = 100 * unit(GAKI);
+
+		assert_ok!(PalletGame::set_bundle(
 			RuntimeOrigin::signed(player.clone()),
 			TEST_BUNDLE.clone().to_vec(),
-			Some(100 * unit(GAKI)),
-			Some(2),
-			10,
-		));
-
-		let bidder = new_account(1, 1000 * unit(GAKI));
-		assert_err!(
-			PalletGame::bid_auction(RuntimeOrigin::signed(bidder.clone()), 0, 50,),
-			Error::<Test>::AuctionNotStarted
-		);
-		run_to_block(2);
-		assert_err!(
-			PalletGame::bid_auction(RuntimeOrigin::signed(bidder.clone()), 0, 50,),
-			Error::<Test>::BidTooLow
-		);
-
-		run_to_block(11);
-		assert_ok!(PalletGame::bid_auction(
-			RuntimeOrigin::signed(bidder.clone()),
-			0,
-			200 * unit(GAKI)
+			price,
+			None,
+			None,
+			None,
 		));
 
-		let bidder1 = new_account(2, 1000 * unit(GAKI));
-		assert_err!(
-			PalletGame::bid_auction(RuntimeOrigin::signed(bidder1.clone()), 0, 200 * unit(GAKI)),
-			Error::<Test>::BidTooLow
-		);
-
-		run_to_block(12);
-		assert_err!(
-			PalletGame::bid_auction(RuntimeOrigin::signed(bidder1.clone()), 0, 300 * unit(GAKI)),
-			Error::<Test>::AuctionEnded
-		);
+		assert_eq!(BundleOf::<Test>::get(0), TEST_BUNDLE.clone().to_vec());
 	})
 }
 
 #[test]
-pub fn claim_auction_should_works() {
+pub fn set_bundle_should_fails() {
 	new_test_ext().execute_with(|| {
 		run_to_block(1);
+		let seller = new_account(0, 10_000 * unit(GAKI));
 
-		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
-		assert_ok!(PalletGame::set_auction(
-			RuntimeOrigin::signed(player.clone()),
-			TEST_BUNDLE.clone().to_vec(),
-			Some(100 * unit(GAKI)),
-			Some(1),
-			10,
-		));
+		for i in 0..(MAX_BUNDLE_VAL + 5) {
+			ItemBalanceOf::<Test>::insert((&seller, 0, i), 10);
+		}
 
-		run_to_block(2);
-		let bids = [
-			(new_account(1, 1000 * unit(GAKI)), 100 * unit(GAKI)),
-			(new_account(2, 1000 * unit(GAKI)), 200 * unit(GAKI)),
-			(new_account(3, 1000 * unit(GAKI)), 400 * unit(GAKI)),
-			(new_account(4, 1000 * unit(GAKI)), 500 * unit(GAKI)),
-		];
+		{
+			let packages: Vec<PackageFor<Test>> = vec![Package::new(0, 0, 11)];
+			assert_err!(
+				PalletGame::set_bundle(
+					RuntimeOrigin::signed(seller.clone()),
+					packages.clone(),
+					100 * unit(GAKI),
+					None,
+					None,
+					None,
+				),
+				Error::<Test>::InsufficientItemBalance
+			);
+		}
 
-		for i in 0..bids.len() {
-			run_to_block(2 + i as u64);
-			assert_ok!(PalletGame::bid_auction(
-				RuntimeOrigin::signed(bids[i].0.clone()),
-				0,
-				bids[i].1,
-			));
+		{
+			let mut packages: Vec<PackageFor<Test>> = vec![];
+
+			for i in 0..(MAX_BUNDLE_VAL + 5) {
+				packages.push(Package::new(0, i, 1));
+			}
+			assert_err!(
+				PalletGame::set_bundle(
+					RuntimeOrigin::signed(seller.clone()),
+					packages.clone(),
+					100 * unit(GAKI),
+					None,
+					None,
+					None,
+				),
+				Error::<Test>::ExceedMaxBundle
+			);
 		}
+	})
+}
 
-		let player_balance = Balances::free_balance(&player);
-		run_to_block(11);
-		assert_ok!(PalletGame::close_auction(
-			RuntimeOrigin::signed(player.clone()),
-			0
-		));
+#[test]
+pub fn buy_bundle_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let price = 10 * unit(GAKI);
+		let seller = do_all_set_bundle(TEST_BUNDLE.clone().to_vec(), price);
+		let buyer = new_account(1, 10_000 * unit(GAKI));
 
-		let winner = bids[3].clone();
+		let seller_before_balance = Balances::free_balance(&seller);
+		let buyer_before_balance = Balances::free_balance(&buyer);
 
+		assert_ok!(PalletGame::buy_bundle(
+			RuntimeOrigin::signed(buyer.clone()),
+			0,
+			price
+		));
 		for i in 0..TEST_BUNDLE.len() as u32 {
-			assert_eq!(ItemBalanceOf::<Test>::get((&winner.0.clone(), 0, i)), 10);
+			assert_eq!(ItemBalanceOf::<Test>::get((buyer.clone(), 0, i)), 10);
+		}
+		for i in 0..TEST_BUNDLE.len() as u32 {
+			assert_eq!(ItemBalanceOf::<Test>::get((seller.clone(), 0, i)), 0);
 		}
-
 		assert_eq!(
-			Balances::free_balance(&player),
-			player_balance + winner.1 + BUNDLE_DEPOSIT_VAL
+			Balances::free_balance(&seller),
+			seller_before_balance + price + BUNDLE_DEPOSIT_VAL
 		);
-
-		for i in 0..(bids.len() - 1) {
-			assert_eq!(
-				Balances::free_balance(&bids[i].0.clone()),
-				1000 * unit(GAKI)
-			);
-		}
+		assert_eq!(Balances::free_balance(&buyer), buyer_before_balance - price);
 	})
 }
 
 #[test]
-pub fn claim_auction_should_fails() {
+pub fn buy_bundle_should_fails() {
 	new_test_ext().execute_with(|| {
 		run_to_block(1);
 
-		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
+		let price = 100 * unit(GAKI);
+		let _ = do_all_set_bundle(TEST_BUNDLE.clone().to_vec(), price);
+
+		let buyer = new_account(1, 1 * unit(GAKI));
 
 		assert_err!(
-			PalletGame::close_auction(RuntimeOrigin::signed(player.clone()), 0),
-			Error::<Test>::UnknownAuction
+			PalletGame::buy_bundle(RuntimeOrigin::signed(buyer.clone()), 0, price * unit(GAKI)),
+			TokenError::FundsUnavailable,
 		);
 
-		assert_ok!(PalletGame::set_auction(
-			RuntimeOrigin::signed(player.clone()),
-			TEST_BUNDLE.clone().to_vec(),
-			Some(100 * unit(GAKI)),
-			Some(1),
-			10,
-		));
-
+		let buyer = new_account(1, 1000 * unit(GAKI));
 		assert_err!(
-			PalletGame::close_auction(RuntimeOrigin::signed(player.clone()), 0),
-			Error::<Test>::AuctionInProgress
+			PalletGame::buy_bundle(
+				RuntimeOrigin::signed(buyer.clone()),
+				0,
+				price - 1 * unit(GAKI)
+			),
+			Error::<Test>::BidTooLow
 		);
 	})
 }
 
 #[test]
-pub fn set_buy_should_works() {
+pub fn do_cancel_set_price_should_works() {
 	new_test_ext().execute_with(|| {
 		run_to_block(1);
 
-		let player = new_account(0, 1000 * unit(GAKI));
-		let player_balance = Balances::free_balance(&player);
+		let player = do_all_set_price(TEST_BUNDLE[0].clone(), unit(GAKI));
 
-		let unit_price = 5 * unit(GAKI);
-		assert_ok!(PalletGame::set_order(
-			RuntimeOrigin::signed(player.clone()),
-			TEST_BUNDLE[0].clone(),
-			unit_price,
-			None,
-			None,
-		));
+		let before_balance = Balances::free_balance(&player);
+
+		assert_ok!(PalletGame::do_cancel_price(&0, &player.clone(),));
+
+		assert_eq!(BundleOf::<Test>::get(0), [].to_vec());
+		assert_eq!(TradeConfigOf::<Test>::get(0), None);
 
+		assert_eq!(ItemBalanceOf::<Test>::get((player.clone(), 0, 0)), 10);
+		assert_eq!(ReservedBalanceOf::<Test>::get((player.clone(), 0, 0)), 0);
 		assert_eq!(
 			Balances::free_balance(&player),
-			player_balance -
-				BUNDLE_DEPOSIT_VAL -
-				(unit_price * TEST_BUNDLE[0].clone().amount as u128)
+			before_balance + BUNDLE_DEPOSIT_VAL
 		);
-	})
+	});
 }
 
 #[test]
-pub fn claim_set_buy_should_works() {
+pub fn do_cancel_set_bundle_should_works() {
 	new_test_ext().execute_with(|| {
 		run_to_block(1);
-		let player = new_account(0, 1000 * unit(GAKI));
-
-		let unit_price = 5 * unit(GAKI);
-		assert_ok!(PalletGame::set_order(
-			RuntimeOrigin::signed(player.clone()),
-			TEST_BUNDLE[0].clone(),
-			unit_price,
-			None,
-			None,
-		));
 
-		let (seller, _, _) = create_account_with_item(TEST_BUNDLE);
-		let amount = TEST_BUNDLE[0].clone().amount / 2;
-		let seller_balance = Balances::free_balance(&seller);
+		let player = do_all_set_bundle(TEST_BUNDLE.clone().to_vec(), unit(GAKI));
 
-		assert_ok!(PalletGame::sell_item(
-			RuntimeOrigin::signed(seller.clone()),
-			0,
-			amount,
-			unit_price
-		));
+		let before_balance = Balances::free_balance(&player);
+		assert_ok!(PalletGame::do_cancel_bundle(&0, &player.clone(),));
 
-		assert_eq!(
-			Balances::free_balance(&seller),
-			seller_balance + (unit_price * amount as u128)
-		);
-		assert_eq!(
-			ItemBalanceOf::<Test>::get((
-				player.clone(),
-				TEST_BUNDLE[0].clone().collection,
-				TEST_BUNDLE[0].clone().item
-			)),
-			amount
-		);
+		assert_eq!(BundleOf::<Test>::get(0), [].to_vec());
+		assert_eq!(TradeConfigOf::<Test>::get(0), None);
 
+		for i in 0..TEST_BUNDLE.len() as u32 {
+			assert_eq!(ItemBalanceOf::<Test>::get((player.clone(), 0, i)), 10);
+			assert_eq!(ReservedBalanceOf::<Test>::get((player.clone(), 0, i)), 0);
+		}
 		assert_eq!(
-			ItemBalanceOf::<Test>::get((
-				seller.clone(),
-				TEST_BUNDLE[0].clone().collection,
-				TEST_BUNDLE[0].clone().item
-			)),
-			amount
+			Balances::free_balance(&player),
+			before_balance + BUNDLE_DEPOSIT_VAL
 		);
-	})
+	});
 }
 
 #[test]
-fn cancel_trade_should_works() {
+pub fn cancel_set_wishlist_should_works() {
 	new_test_ext().execute_with(|| {
 		run_to_block(1);
 
-		// set price
-		{
-			let player = do_all_set_price(TEST_BUNDLE[0].clone(), unit(GAKI));
-			assert_ok!(PalletGame::cancel_trade(
-				RuntimeOrigin::signed(player.clone()),
-				0,
-				TradeType::SetPrice,
-			));
-		}
+		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
+		let price = 3 * unit(GAKI);
 
-		// set buy
+		assert_ok!(PalletGame::order_bundle(
+			RuntimeOrigin::signed(player.clone()),
+			TEST_BUNDLE.clone().to_vec(),
+			price,
+			None,
+			None,
+			None,
+		));
+
+		let before_balance = Balances::free_balance(&player);
+		assert_ok!(PalletGame::do_cancel_wishlist(&0, &player.clone(),));
+
+		assert_eq!(BundleOf::<Test>::get(0), [].to_vec());
+		assert_eq!(TradeConfigOf::<Test>::get(0), None);
+
+		assert_eq!(
+			Balances::free_balance(&player),
+			before_balance + price + BUNDLE_DEPOSIT_VAL
+		);
+	});
+}
+
+#[test]
+pub fn do_cancel_set_swap_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
+
+		let price = 100 * unit(GAKI);
+
+		assert_ok!(PalletGame::create_swap(
+			RuntimeOrigin::signed(player.clone()),
+			TEST_BUNDLE.clone().to_vec(),
+			TEST_BUNDLE1.clone().to_vec(),
+			Some(price),
+			None,
+			None,
+			None,
+		));
+
+		let before_balance = Balances::free_balance(&player);
+		assert_ok!(PalletGame::do_cancel_swap(&0, &player.clone(),));
+		assert_eq!(BundleOf::<Test>::get(0), [].to_vec());
+		assert_eq!(TradeConfigOf::<Test>::get(0), None);
+
+		assert_eq!(
+			Balances::free_balance(&player),
+			before_balance + BUNDLE_DEPOSIT_VAL
+		);
+	});
+}
+
+#[test]
+pub fn set_wishlist_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let buyer = new_account(3, 1000 * unit(GAKI));
+		let price = 3 * unit(GAKI);
+		let before_balance = Balances::free_balance(&buyer);
+		assert_ok!(PalletGame::order_bundle(
+			RuntimeOrigin::signed(buyer.clone()),
+			TEST_BUNDLE.clone().to_vec(),
+			price,
+			None,
+			None,
+			None,
+		));
+
+		assert_eq!(BundleOf::<Test>::get(0), TEST_BUNDLE.clone().to_vec());
+		assert_eq!(
+			TradeConfigOf::<Test>::get(0).unwrap(),
+			TradeConfig {
+				trade: TradeType::Wishlist,
+				owner: buyer.clone(),
+				maybe_price: Some(price),
+				maybe_required: None,
+				start_block: None,
+				end_block: None,
+			}
+		);
+		assert_eq!(
+			Balances::free_balance(&buyer),
+			before_balance - (BUNDLE_DEPOSIT_VAL + price)
+		);
+	})
+}
+
+#[test]
+pub fn fill_wishlist_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
+		let price = 3 * unit(GAKI);
+
+		let buyer = new_account(3, 1000 * unit(GAKI));
+		assert_ok!(PalletGame::order_bundle(
+			RuntimeOrigin::signed(buyer.clone()),
+			TEST_BUNDLE.clone().to_vec(),
+			price,
+			None,
+			None,
+			None,
+		));
+
+		let before_player_balance = Balances::free_balance(&player);
+		assert_ok!(PalletGame::sell_bundle(
+			RuntimeOrigin::signed(player.clone()),
+			0,
+			price
+		));
+
+		assert_eq!(BundleOf::<Test>::get(0), [].to_vec());
+		assert_eq!(TradeConfigOf::<Test>::get(0), None);
+
+		for i in 0..TEST_BUNDLE.len() as u32 {
+			assert_eq!(ItemBalanceOf::<Test>::get((&player, 0, i)), 0);
+			assert_eq!(ItemBalanceOf::<Test>::get((&buyer, 0, i)), 10);
+		}
+		assert_eq!(Balances::reserved_balance(&buyer), 0);
+		assert_eq!(
+			Balances::free_balance(&player),
+			before_player_balance + price
+		);
+	})
+}
+
+#[test]
+pub fn set_swap_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
+
+		let price = 100 * unit(GAKI);
+
+		let player_balance = Balances::free_balance(&player);
+		assert_ok!(PalletGame::create_swap(
+			RuntimeOrigin::signed(player.clone()),
+			TEST_BUNDLE.clone().to_vec(),
+			TEST_BUNDLE1.clone().to_vec(),
+			Some(price),
+			None,
+			None,
+			None,
+		));
+
+		assert_eq!(
+			Balances::free_balance(&player),
+			player_balance - BUNDLE_DEPOSIT_VAL
+		);
+		assert_eq!(BundleOf::<Test>::get(0), TEST_BUNDLE.clone().to_vec());
+
+		for i in 0..TEST_BUNDLE.len() as u32 {
+			assert_eq!(ItemBalanceOf::<Test>::get((&player, 0, i)), 0);
+		}
+
+		assert_eq!(
+			TradeConfigOf::<Test>::get(0).unwrap(),
+			TradeConfig {
+				trade: TradeType::Swap,
+				owner: player.clone(),
+				maybe_price: Some(price),
+				maybe_required: Some(
+					BundleFor::<Test>::try_from(TEST_BUNDLE1.clone().to_vec()).unwrap()
+				),
+				start_block: None,
+				end_block: None,
+			}
+		);
+	})
+}
+
+#[test]
+pub fn claim_swap_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (player1, _, _) = create_account_with_item(TEST_BUNDLE);
+		let (player2, _, _) = create_account_with_item(TEST_BUNDLE1);
+
+		let price = 100 * unit(GAKI);
+
+		assert_ok!(PalletGame::create_swap(
+			RuntimeOrigin::signed(player1.clone()),
+			TEST_BUNDLE.clone().to_vec(),
+			TEST_BUNDLE1.clone().to_vec(),
+			Some(price),
+			None,
+			None,
+			None,
+		));
+
+		let player1_balance = Balances::free_balance(&player1);
+		let player2_balance = Balances::free_balance(&player2);
+
+		assert_ok!(PalletGame::make_swap(
+			RuntimeOrigin::signed(player2.clone()),
+			0,
+			Some(price)
+		));
+
+		assert_eq!(
+			Balances::free_balance(&player1),
+			player1_balance + price + BUNDLE_DEPOSIT_VAL
+		);
+		assert_eq!(Balances::free_balance(&player2), player2_balance - price);
+
+		for i in 0..TEST_BUNDLE.len() as u32 {
+			assert_eq!(ItemBalanceOf::<Test>::get((&player1, 1, i)), 10);
+			assert_eq!(ItemBalanceOf::<Test>::get((&player2, 0, i)), 10);
+		}
+	})
+}
+
+#[test]
+pub fn set_auction_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
+
+		let price = 100 * unit(GAKI);
+
+		let player_balance = Balances::free_balance(&player);
+
+		assert_ok!(PalletGame::set_auction(
+			RuntimeOrigin::signed(player.clone()),
+			TEST_BUNDLE.clone().to_vec(),
+			Some(price),
+			Some(1),
+			1,
+			None,
+		));
+		assert_eq!(
+			Balances::free_balance(&player),
+			player_balance - BUNDLE_DEPOSIT_VAL
+		);
+		for i in 0..TEST_BUNDLE.len() as u32 {
+			assert_eq!(ItemBalanceOf::<Test>::get((&player, 0, i)), 0);
+			assert_eq!(ReservedBalanceOf::<Test>::get((&player, 0, i)), 10);
+		}
+	})
+}
+
+#[test]
+pub fn bid_auction_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
+		assert_ok!(PalletGame::set_auction(
+			RuntimeOrigin::signed(player.clone()),
+			TEST_BUNDLE.clone().to_vec(),
+			Some(100 * unit(GAKI)),
+			Some(1),
+			10,
+			None,
+		));
+
+		let bidder = new_account(1, 1000 * unit(GAKI));
+		let bid = 200 * unit(GAKI);
+		let bidder_balance = Balances::free_balance(&bidder);
+		assert_ok!(PalletGame::bid_auction(
+			RuntimeOrigin::signed(bidder.clone()),
+			0,
+			bid
+		));
+		assert_eq!(Balances::free_balance(&bidder), bidder_balance - bid);
+
+		run_to_block(2);
+
+		assert_ok!(PalletGame::bid_auction(
+			RuntimeOrigin::signed(bidder.clone()),
+			0,
+			bid * 2
+		));
+
+		assert_eq!(Balances::free_balance(&bidder), bidder_balance - (bid * 2));
+	})
+}
+
+#[test]
+pub fn bid_auction_should_fails() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
+		assert_ok!(PalletGame::set_auction(
+			RuntimeOrigin::signed(player.clone()),
+			TEST_BUNDLE.clone().to_vec(),
+			Some(100 * unit(GAKI)),
+			Some(2),
+			10,
+			None,
+		));
+
+		let bidder = new_account(1, 1000 * unit(GAKI));
+		assert_err!(
+			PalletGame::bid_auction(RuntimeOrigin::signed(bidder.clone()), 0, 50,),
+			Error::<Test>::AuctionNotStarted
+		);
+		run_to_block(2);
+		assert_err!(
+			PalletGame::bid_auction(RuntimeOrigin::signed(bidder.clone()), 0, 50,),
+			Error::<Test>::BidTooLow
+		);
+
+		run_to_block(11);
+		assert_ok!(PalletGame::bid_auction(
+			RuntimeOrigin::signed(bidder.clone()),
+			0,
+			200 * unit(GAKI)
+		));
+
+		let bidder1 = new_account(2, 1000 * unit(GAKI));
+		assert_err!(
+			PalletGame::bid_auction(RuntimeOrigin::signed(bidder1.clone()), 0, 200 * unit(GAKI)),
+			Error::<Test>::BidTooLow
+		);
+
+		run_to_block(12);
+		assert_err!(
+			PalletGame::bid_auction(RuntimeOrigin::signed(bidder1.clone()), 0, 300 * unit(GAKI)),
+			Error::<Test>::AuctionEnded
+		);
+	})
+}
+
+#[test]
+pub fn claim_auction_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
+		assert_ok!(PalletGame::set_auction(
+			RuntimeOrigin::signed(player.clone()),
+			TEST_BUNDLE.clone().to_vec(),
+			Some(100 * unit(GAKI)),
+			Some(1),
+			10,
+			None,
+		));
+
+		run_to_block(2);
+		let bids = [
+			(new_account(1, 1000 * unit(GAKI)), 100 * unit(GAKI)),
+			(new_account(2, 1000 * unit(GAKI)), 200 * unit(GAKI)),
+			(new_account(3, 1000 * unit(GAKI)), 400 * unit(GAKI)),
+			(new_account(4, 1000 * unit(GAKI)), 500 * unit(GAKI)),
+		];
+
+		for i in 0..bids.len() {
+			run_to_block(2 + i as u64);
+			assert_ok!(PalletGame::bid_auction(
+				RuntimeOrigin::signed(bids[i].0.clone()),
+				0,
+				bids[i].1,
+			));
+		}
+
+		let player_balance = Balances::free_balance(&player);
+		run_to_block(11);
+		assert_ok!(PalletGame::close_auction(
+			RuntimeOrigin::signed(player.clone()),
+			0
+		));
+
+		let winner = bids[3].clone();
+
+		for i in 0..TEST_BUNDLE.len() as u32 {
+			assert_eq!(ItemBalanceOf::<Test>::get((&winner.0.clone(), 0, i)), 10);
+		}
+
+		assert_eq!(
+			Balances::free_balance(&player),
+			player_balance + winner.1 + BUNDLE_DEPOSIT_VAL
+		);
+
+		for i in 0..(bids.len() - 1) {
+			assert_eq!(
+				Balances::free_balance(&bids[i].0.clone()),
+				1000 * unit(GAKI)
+			);
+		}
+	})
+}
+
+#[test]
+pub fn claim_auction_should_fails() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
+
+		assert_err!(
+			PalletGame::close_auction(RuntimeOrigin::signed(player.clone()), 0),
+			Error::<Test>::UnknownAuction
+		);
+
+		assert_ok!(PalletGame::set_auction(
+			RuntimeOrigin::signed(player.clone()),
+			TEST_BUNDLE.clone().to_vec(),
+			Some(100 * unit(GAKI)),
+			Some(1),
+			10,
+			None,
+		));
+
+		assert_err!(
+			PalletGame::close_auction(RuntimeOrigin::signed(player.clone()), 0),
+			Error::<Test>::AuctionInProgress
+		);
+	})
+}
+
+#[test]
+pub fn set_candle_auction_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
+		let player_balance = Balances::free_balance(&player);
+
+		assert_ok!(PalletGame::set_candle_auction(
+			RuntimeOrigin::signed(player.clone()),
+			TEST_BUNDLE.clone().to_vec(),
+			Some(100 * unit(GAKI)),
+			1,
+			5,
+			10,
+			None,
+		));
+		assert_eq!(
+			Balances::free_balance(&player),
+			player_balance - BUNDLE_DEPOSIT_VAL
+		);
+		for i in 0..TEST_BUNDLE.len() as u32 {
+			assert_eq!(ItemBalanceOf::<Test>::get((&player, 0, i)), 0);
+			assert_eq!(ReservedBalanceOf::<Test>::get((&player, 0, i)), 10);
+		}
+
+		assert_err!(
+			PalletGame::set_candle_auction(
+				RuntimeOrigin::signed(player.clone()),
+				TEST_BUNDLE.clone().to_vec(),
+				None,
+				1,
+				10,
+				10,
+				None,
+			),
+			Error::<Test>::InvalidAuctionPeriod
+		);
+	})
+}
+
+#[test]
+pub fn bid_candle_auction_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
+		assert_ok!(PalletGame::set_candle_auction(
+			RuntimeOrigin::signed(player.clone()),
+			TEST_BUNDLE.clone().to_vec(),
+			Some(100 * unit(GAKI)),
+			1,
+			5,
+			10,
+			None,
+		));
+
+		let bidder = new_account(1, 1000 * unit(GAKI));
+		let bidder1 = new_account(2, 1000 * unit(GAKI));
+		let bidder_balance = Balances::free_balance(&bidder);
+		assert_ok!(PalletGame::bid_candle_auction(
+			RuntimeOrigin::signed(bidder.clone()),
+			0,
+			200 * unit(GAKI)
+		));
+		assert_ok!(PalletGame::bid_candle_auction(
+			RuntimeOrigin::signed(bidder1.clone()),
+			0,
+			300 * unit(GAKI)
+		));
+
+		// the earlier bid is still reserved, only the difference is reserved when raising
+		assert_ok!(PalletGame::bid_candle_auction(
+			RuntimeOrigin::signed(bidder.clone()),
+			0,
+			400 * unit(GAKI)
+		));
+		assert_eq!(
+			Balances::free_balance(&bidder),
+			bidder_balance - 400 * unit(GAKI)
+		);
+		assert_eq!(CandleBidsOf::<Test>::get(0).len(), 3);
+	})
+}
+
+#[test]
+pub fn bid_candle_auction_should_fails() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
+		assert_ok!(PalletGame::set_candle_auction(
+			RuntimeOrigin::signed(player.clone()),
+			TEST_BUNDLE.clone().to_vec(),
+			Some(100 * unit(GAKI)),
+			2,
+			5,
+			10,
+			None,
+		));
+
+		let bidder = new_account(1, 1000 * unit(GAKI));
+		assert_err!(
+			PalletGame::bid_candle_auction(
+				RuntimeOrigin::signed(bidder.clone()),
+				0,
+				200 * unit(GAKI)
+			),
+			Error::<Test>::AuctionNotStarted
+		);
+
+		run_to_block(2);
+		assert_err!(
+			PalletGame::bid_candle_auction(RuntimeOrigin::signed(bidder.clone()), 0, 50),
+			Error::<Test>::BidTooLow
+		);
+		assert_ok!(PalletGame::bid_candle_auction(
+			RuntimeOrigin::signed(bidder.clone()),
+			0,
+			200 * unit(GAKI)
+		));
+		assert_err!(
+			PalletGame::bid_candle_auction(
+				RuntimeOrigin::signed(bidder.clone()),
+				0,
+				200 * unit(GAKI)
+			),
+			Error::<Test>::BidTooLow
+		);
+
+		run_to_block(10);
+		assert_err!(
+			PalletGame::bid_candle_auction(
+				RuntimeOrigin::signed(bidder.clone()),
+				0,
+				300 * unit(GAKI)
+			),
+			Error::<Test>::AuctionEnded
+		);
+	})
+}
+
+#[test]
+pub fn close_candle_auction_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
+		assert_ok!(PalletGame::set_candle_auction(
+			RuntimeOrigin::signed(player.clone()),
+			TEST_BUNDLE.clone().to_vec(),
+			Some(100 * unit(GAKI)),
+			1,
+			5,
+			10,
+			None,
+		));
+
+		let bids = [
+			(new_account(1, 1000 * unit(GAKI)), 100 * unit(GAKI), 2),
+			(new_account(2, 1000 * unit(GAKI)), 200 * unit(GAKI), 5),
+			(new_account(4, 1000 * unit(GAKI)), 500 * unit(GAKI), 8),
+		];
+		for bid in bids.clone() {
+			run_to_block(bid.2);
+			assert_ok!(PalletGame::bid_candle_auction(
+				RuntimeOrigin::signed(bid.0.clone()),
+				0,
+				bid.1,
+			));
+		}
+
+		assert_err!(
+			PalletGame::close_candle_auction(RuntimeOrigin::signed(player.clone()), 0),
+			Error::<Test>::AuctionInProgress
+		);
+
+		// the closing block is unknown while bidding
+		assert_eq!(CandleAuctionOf::<Test>::get(0).unwrap().closing_block, None);
+
+		let player_balance = Balances::free_balance(&player);
+		run_to_block(10);
+		assert_eq!(
+			CandleAuctionOf::<Test>::get(0).unwrap().closing_block,
+			Some(5)
+		);
+		assert_ok!(PalletGame::close_candle_auction(
+			RuntimeOrigin::signed(player.clone()),
+			0
+		));
+
+		// the mock randomness closes the auction at `early_end`, the later bid loses
+		let winner = bids[1].clone();
+		for i in 0..TEST_BUNDLE.len() as u32 {
+			assert_eq!(ItemBalanceOf::<Test>::get((&winner.0, 0, i)), 10);
+			assert_eq!(ReservedBalanceOf::<Test>::get((&player, 0, i)), 0);
+		}
+		assert_eq!(
+			Balances::free_balance(&player),
+			player_balance + winner.1 + BUNDLE_DEPOSIT_VAL
+		);
+		assert_eq!(
+			Balances::free_balance(&winner.0),
+			1000 * unit(GAKI) - winner.1
+		);
+		for i in [0, 2] {
+			assert_eq!(Balances::free_balance(&bids[i].0), 1000 * unit(GAKI));
+		}
+
+		assert_eq!(CandleAuctionOf::<Test>::get(0), None);
+		assert_eq!(CandleBidsOf::<Test>::get(0).len(), 0);
+		System::assert_last_event(RuntimeEvent::PalletGame(
+			crate::Event::CandleAuctionClaimed {
+				trade: 0,
+				closing_block: 5,
+				maybe_bid: Some((winner.0, winner.1)),
+			},
+		));
+	})
+}
+
+#[test]
+pub fn close_candle_auction_without_bid_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
+		let player_balance = Balances::free_balance(&player);
+		assert_ok!(PalletGame::set_candle_auction(
+			RuntimeOrigin::signed(player.clone()),
+			TEST_BUNDLE.clone().to_vec(),
+			None,
+			1,
+			5,
+			10,
+			None,
+		));
+
+		run_to_block(10);
+		assert_ok!(PalletGame::close_candle_auction(
+			RuntimeOrigin::signed(player.clone()),
+			0
+		));
+
+		for i in 0..TEST_BUNDLE.len() as u32 {
+			assert_eq!(ItemBalanceOf::<Test>::get((&player, 0, i)), 10);
+			assert_eq!(ReservedBalanceOf::<Test>::get((&player, 0, i)), 0);
+		}
+		assert_eq!(Balances::free_balance(&player), player_balance);
+	})
+}
+
+#[test]
+pub fn set_dutch_auction_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
+		let player_balance = Balances::free_balance(&player);
+
+		assert_ok!(PalletGame::set_dutch_auction(
+			RuntimeOrigin::signed(player.clone()),
+			TEST_BUNDLE.clone().to_vec(),
+			1000 * unit(GAKI),
+			100 * unit(GAKI),
+			None,
+			10,
+			None,
+		));
+		assert_eq!(
+			Balances::free_balance(&player),
+			player_balance - BUNDLE_DEPOSIT_VAL
+		);
+		for i in 0..TEST_BUNDLE.len() as u32 {
+			assert_eq!(ItemBalanceOf::<Test>::get((&player, 0, i)), 0);
+			assert_eq!(ReservedBalanceOf::<Test>::get((&player, 0, i)), 10);
+		}
+	})
+}
+
+#[test]
+pub fn set_dutch_auction_should_fails() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
+
+		assert_err!(
+			PalletGame::set_dutch_auction(
+				RuntimeOrigin::signed(player.clone()),
+				TEST_BUNDLE.clone().to_vec(),
+				100 * unit(GAKI),
+				1000 * unit(GAKI),
+				None,
+				10,
+				None,
+			),
+			Error::<Test>::InvalidAuctionPrice
+		);
+
+		assert_err!(
+			PalletGame::set_dutch_auction(
+				RuntimeOrigin::signed(player.clone()),
+				TEST_BUNDLE.clone().to_vec(),
+				1000 * unit(GAKI),
+				100 * unit(GAKI),
+				None,
+				0,
+				None,
+			),
+			Error::<Test>::InvalidAuctionPeriod
+		);
+	})
+}
+
+#[test]
+pub fn buy_dutch_auction_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
+		assert_ok!(PalletGame::set_dutch_auction(
+			RuntimeOrigin::signed(player.clone()),
+			TEST_BUNDLE.clone().to_vec(),
+			1000 * unit(GAKI),
+			100 * unit(GAKI),
+			Some(1),
+			10,
+			None,
+		));
+		let player_balance = Balances::free_balance(&player);
+
+		// half of the duration has passed
+		run_to_block(6);
+		let buyer = new_account(1, 1000 * unit(GAKI));
+		assert_ok!(PalletGame::buy_dutch_auction(
+			RuntimeOrigin::signed(buyer.clone()),
+			0,
+			600 * unit(GAKI)
+		));
+
+		let price = 550 * unit(GAKI);
+		assert_eq!(Balances::free_balance(&buyer), 1000 * unit(GAKI) - price);
+		assert_eq!(
+			Balances::free_balance(&player),
+			player_balance + price + BUNDLE_DEPOSIT_VAL
+		);
+		for i in 0..TEST_BUNDLE.len() as u32 {
+			assert_eq!(ItemBalanceOf::<Test>::get((&buyer, 0, i)), 10);
+			assert_eq!(ReservedBalanceOf::<Test>::get((&player, 0, i)), 0);
+		}
+
+		assert_eq!(DutchAuctionOf::<Test>::get(0), None);
+		System::assert_last_event(RuntimeEvent::PalletGame(crate::Event::DutchAuctionBought {
+			trade: 0,
+			who: buyer,
+			price,
+		}));
+	})
+}
+
+#[test]
+pub fn buy_dutch_auction_should_fails() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
+		assert_ok!(PalletGame::set_dutch_auction(
+			RuntimeOrigin::signed(player.clone()),
+			TEST_BUNDLE.clone().to_vec(),
+			1000 * unit(GAKI),
+			100 * unit(GAKI),
+			Some(2),
+			10,
+			None,
+		));
+
+		let buyer = new_account(1, 2000 * unit(GAKI));
+		assert_err!(
+			PalletGame::buy_dutch_auction(
+				RuntimeOrigin::signed(buyer.clone()),
+				0,
+				1000 * unit(GAKI)
+			),
+			Error::<Test>::AuctionNotStarted
+		);
+
+		// the price reaches the floor price at the end of the duration
+		run_to_block(12);
+		assert_err!(
+			PalletGame::buy_dutch_auction(RuntimeOrigin::signed(buyer.clone()), 0, 99 * unit(GAKI)),
+			Error::<Test>::BidTooLow
+		);
+
+		// the owner can not buy the auction
+		assert_err!(
+			PalletGame::buy_dutch_auction(
+				RuntimeOrigin::signed(player.clone()),
+				0,
+				100 * unit(GAKI)
+			),
+			Error::<Test>::NoPermission
+		);
+
+		run_to_block(13);
+		assert_err!(
+			PalletGame::buy_dutch_auction(
+				RuntimeOrigin::signed(buyer.clone()),
+				0,
+				100 * unit(GAKI)
+			),
+			Error::<Test>::AuctionEnded
+		);
+
+		assert_err!(
+			PalletGame::cancel_trade(
+				RuntimeOrigin::signed(buyer.clone()),
+				0,
+				TradeType::DutchAuction
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(PalletGame::cancel_trade(
+			RuntimeOrigin::signed(player.clone()),
+			0,
+			TradeType::DutchAuction
+		));
+		for i in 0..TEST_BUNDLE.len() as u32 {
+			assert_eq!(ItemBalanceOf::<Test>::get((&player, 0, i)), 10);
+			assert_eq!(ReservedBalanceOf::<Test>::get((&player, 0, i)), 0);
+		}
+
+		assert_err!(
+			PalletGame::buy_dutch_auction(
+				RuntimeOrigin::signed(buyer.clone()),
+				0,
+				100 * unit(GAKI)
+			),
+			Error::<Test>::UnknownAuction
+		);
+	})
+}
+
+#[test]
+pub fn dutch_auction_should_expire() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
+		let player_balance = Balances::free_balance(&player);
+		assert_ok!(PalletGame::set_dutch_auction(
+			RuntimeOrigin::signed(player.clone()),
+			TEST_BUNDLE.clone().to_vec(),
+			1000 * unit(GAKI),
+			100 * unit(GAKI),
+			Some(2),
+			10,
+			None,
+		));
+		assert_eq!(
+			TradeExpiryOf::<Test>::get(13).into_inner(),
+			vec![(0, TradeType::DutchAuction)]
+		);
+
+		run_to_block(13);
+		PalletGame::on_idle(13, Weight::MAX);
+
+		assert_eq!(DutchAuctionOf::<Test>::get(0), None);
+		for i in 0..TEST_BUNDLE.len() as u32 {
+			assert_eq!(ItemBalanceOf::<Test>::get((&player, 0, i)), 10);
+			assert_eq!(ReservedBalanceOf::<Test>::get((&player, 0, i)), 0);
+		}
+		assert_eq!(Balances::free_balance(&player), player_balance);
+		System::assert_last_event(RuntimeEvent::PalletGame(crate::Event::TradeExpired {
+			trade: 0,
+		}));
+	})
+}
+
+#[test]
+pub fn set_sealed_auction_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
+		let player_balance = Balances::free_balance(&player);
+
+		assert_ok!(PalletGame::set_sealed_auction(
+			RuntimeOrigin::signed(player.clone()),
+			TEST_BUNDLE.clone().to_vec(),
+			Some(100 * unit(GAKI)),
+			None,
+			5,
+			10,
+			None,
+		));
+		assert_eq!(
+			Balances::free_balance(&player),
+			player_balance - BUNDLE_DEPOSIT_VAL
+		);
+		for i in 0..TEST_BUNDLE.len() as u32 {
+			assert_eq!(ItemBalanceOf::<Test>::get((&player, 0, i)), 0);
+			assert_eq!(ReservedBalanceOf::<Test>::get((&player, 0, i)), 10);
+		}
+
+		assert_err!(
+			PalletGame::set_sealed_auction(
+				RuntimeOrigin::signed(player.clone()),
+				TEST_BUNDLE.clone().to_vec(),
+				None,
+				Some(5),
+				5,
+				10,
+				None,
+			),
+			Error::<Test>::InvalidAuctionPeriod
+		);
+	})
+}
+
+#[test]
+pub fn commit_sealed_bid_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
+		assert_ok!(PalletGame::set_sealed_auction(
+			RuntimeOrigin::signed(player.clone()),
+			TEST_BUNDLE.clone().to_vec(),
+			Some(100 * unit(GAKI)),
+			None,
+			5,
+			10,
+			None,
+		));
+
+		let bidder = new_account(1, 1000 * unit(GAKI));
+		assert_ok!(PalletGame::commit_sealed_bid(
+			RuntimeOrigin::signed(bidder.clone()),
+			0,
+			sealed_commitment(&bidder, 200 * unit(GAKI), [1; 32]),
+			300 * unit(GAKI)
+		));
+		assert_eq!(Balances::free_balance(&bidder), 700 * unit(GAKI));
+		assert_eq!(SealedBidsOf::<Test>::get(0).len(), 1);
+	})
+}
+
+#[test]
+pub fn commit_sealed_bid_should_fails() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
+		assert_ok!(PalletGame::set_sealed_auction(
+			RuntimeOrigin::signed(player.clone()),
+			TEST_BUNDLE.clone().to_vec(),
+			Some(100 * unit(GAKI)),
+			None,
+			5,
+			10,
+			None,
+		));
+
+		let bidder = new_account(1, 1000 * unit(GAKI));
+		let commitment = sealed_commitment(&bidder, 200 * unit(GAKI), [1; 32]);
+		assert_err!(
+			PalletGame::commit_sealed_bid(
+				RuntimeOrigin::signed(bidder.clone()),
+				0,
+				commitment,
+				50 * unit(GAKI)
+			),
+			Error::<Test>::BidTooLow
+		);
+
+		assert_ok!(PalletGame::commit_sealed_bid(
+			RuntimeOrigin::signed(bidder.clone()),
+			0,
+			commitment,
+			300 * unit(GAKI)
+		));
+		assert_err!(
+			PalletGame::commit_sealed_bid(
+				RuntimeOrigin::signed(bidder.clone()),
+				0,
+				commitment,
+				300 * unit(GAKI)
+			),
+			Error::<Test>::BidCommitted
+		);
+
+		run_to_block(5);
+		let bidder1 = new_account(2, 1000 * unit(GAKI));
+		assert_err!(
+			PalletGame::commit_sealed_bid(
+				RuntimeOrigin::signed(bidder1.clone()),
+				0,
+				sealed_commitment(&bidder1, 200 * unit(GAKI), [1; 32]),
+				300 * unit(GAKI)
+			),
+			Error::<Test>::AuctionEnded
+		);
+	})
+}
+
+#[test]
+pub fn reveal_sealed_bid_should_fails() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
+		assert_ok!(PalletGame::set_sealed_auction(
+			RuntimeOrigin::signed(player.clone()),
+			TEST_BUNDLE.clone().to_vec(),
+			Some(100 * unit(GAKI)),
+			None,
+			5,
+			10,
+			None,
+		));
+
+		let bidder = new_account(1, 1000 * unit(GAKI));
+		let bid = 400 * unit(GAKI);
+		assert_ok!(PalletGame::commit_sealed_bid(
+			RuntimeOrigin::signed(bidder.clone()),
+			0,
+			sealed_commitment(&bidder, bid, [1; 32]),
+			300 * unit(GAKI)
+		));
+		assert_err!(
+			PalletGame::reveal_sealed_bid(RuntimeOrigin::signed(bidder.clone()), 0, bid, [1; 32]),
+			Error::<Test>::RevealNotStarted
+		);
+
+		run_to_block(5);
+		assert_err!(
+			PalletGame::reveal_sealed_bid(RuntimeOrigin::signed(bidder.clone()), 0, bid, [2; 32]),
+			Error::<Test>::InvalidReveal
+		);
+		assert_err!(
+			PalletGame::reveal_sealed_bid(RuntimeOrigin::signed(bidder.clone()), 0, bid, [1; 32]),
+			Error::<Test>::InsufficientDeposit
+		);
+		assert_err!(
+			PalletGame::reveal_sealed_bid(RuntimeOrigin::signed(player.clone()), 0, bid, [1; 32]),
+			Error::<Test>::BidNotCommitted
+		);
+	})
+}
+
+#[test]
+pub fn close_sealed_auction_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
+		assert_ok!(PalletGame::set_sealed_auction(
+			RuntimeOrigin::signed(player.clone()),
+			TEST_BUNDLE.clone().to_vec(),
+			Some(100 * unit(GAKI)),
+			None,
+			5,
+			10,
+			None,
+		));
+
+		// (bidder, bid, deposit)
+		let bids = [
+			(
+				new_account(1, 1000 * unit(GAKI)),
+				300 * unit(GAKI),
+				500 * unit(GAKI),
+			),
+			(
+				new_account(2, 1000 * unit(GAKI)),
+				200 * unit(GAKI),
+				400 * unit(GAKI),
+			),
+			(
+				new_account(3, 1000 * unit(GAKI)),
+				400 * unit(GAKI),
+				400 * unit(GAKI),
+			),
+		];
+		for bid in bids.clone() {
+			assert_ok!(PalletGame::commit_sealed_bid(
+				RuntimeOrigin::signed(bid.0.clone()),
+				0,
+				sealed_commitment(&bid.0, bid.1, [1; 32]),
+				bid.2
+			));
+		}
+
+		// the last bidder does not reveal
+		run_to_block(5);
+		for bid in bids[..2].iter() {
+			assert_ok!(PalletGame::reveal_sealed_bid(
+				RuntimeOrigin::signed(bid.0.clone()),
+				0,
+				bid.1,
+				[1; 32]
+			));
+		}
+
+		assert_err!(
+			PalletGame::close_sealed_auction(RuntimeOrigin::signed(player.clone()), 0),
+			Error::<Test>::AuctionInProgress
+		);
+
+		let player_balance = Balances::free_balance(&player);
+		run_to_block(10);
+		assert_ok!(PalletGame::close_sealed_auction(
+			RuntimeOrigin::signed(player.clone()),
+			0
+		));
+
+		// the winner pays the second-highest price
+		let (winner, _, _) = bids[0].clone();
+		let price = bids[1].1;
+		let slashed = bids[2].2;
+		for i in 0..TEST_BUNDLE.len() as u32 {
+			assert_eq!(ItemBalanceOf::<Test>::get((&winner, 0, i)), 10);
+			assert_eq!(ReservedBalanceOf::<Test>::get((&player, 0, i)), 0);
+		}
+		assert_eq!(Balances::free_balance(&winner), 1000 * unit(GAKI) - price);
+		assert_eq!(Balances::free_balance(&bids[1].0), 1000 * unit(GAKI));
+		assert_eq!(
+			Balances::free_balance(&bids[2].0),
+			1000 * unit(GAKI) - slashed
+		);
+		assert_eq!(
+			Balances::free_balance(&player),
+			player_balance + price + slashed + BUNDLE_DEPOSIT_VAL
+		);
+
+		assert_eq!(SealedAuctionOf::<Test>::get(0), None);
+		assert_eq!(SealedBidsOf::<Test>::get(0).len(), 0);
+		System::assert_last_event(RuntimeEvent::PalletGame(
+			crate::Event::SealedAuctionClaimed {
+				trade: 0,
+				maybe_bid: Some((winner, price)),
+				slashed,
+			},
+		));
+	})
+}
+
+#[test]
+pub fn set_royalty_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (_, owner, _) = create_account_with_item(TEST_BUNDLE);
+		let beneficiary = new_account(5, 1000 * unit(GAKI));
+
+		assert_ok!(PalletGame::set_royalty(
+			RuntimeOrigin::signed(owner.clone()),
+			0,
+			None,
+			beneficiary.clone(),
+			500,
+		));
+		assert_ok!(PalletGame::set_royalty(
+			RuntimeOrigin::signed(owner.clone()),
+			0,
+			Some(0),
+			beneficiary.clone(),
+			1000,
+		));
+
+		// the item royalty overrides the collection royalty
+		assert_eq!(PalletGame::royalty_of(&0, &0).unwrap().rate, 1000);
+		assert_eq!(PalletGame::royalty_of(&0, &1).unwrap().rate, 500);
+
+		assert_ok!(PalletGame::clear_royalty(
+			RuntimeOrigin::signed(owner.clone()),
+			0,
+			Some(0)
+		));
+		assert_eq!(PalletGame::royalty_of(&0, &0).unwrap().rate, 500);
+		System::assert_last_event(RuntimeEvent::PalletGame(crate::Event::RoyaltyCleared {
+			collection: 0,
+			maybe_item: Some(0),
+		}));
+	})
+}
+
+#[test]
+pub fn set_royalty_should_fails() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (player, owner, _) = create_account_with_item(TEST_BUNDLE);
+		let beneficiary = new_account(5, 1000 * unit(GAKI));
+
+		assert_err!(
+			PalletGame::set_royalty(
+				RuntimeOrigin::signed(player.clone()),
+				0,
+				None,
+				beneficiary.clone(),
+				500,
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_err!(
+			PalletGame::set_royalty(
+				RuntimeOrigin::signed(owner.clone()),
+				0,
+				None,
+				beneficiary.clone(),
+				MAX_ROYALTY_VAL + 1,
+			),
+			Error::<Test>::RoyaltyTooHigh
+		);
+		assert_err!(
+			PalletGame::clear_royalty(RuntimeOrigin::signed(owner.clone()), 0, None),
+			Error::<Test>::UnknownRoyalty
+		);
+	})
+}
+
+#[test]
+pub fn buy_item_with_royalty_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (seller, owner, _) = create_account_with_item(TEST_BUNDLE);
+		let beneficiary = new_account(5, 1000 * unit(GAKI));
+		assert_ok!(PalletGame::set_royalty(
+			RuntimeOrigin::signed(owner.clone()),
+			0,
+			None,
+			beneficiary.clone(),
+			1000,
+		));
+
+		let price = 10 * unit(GAKI);
+		assert_ok!(PalletGame::set_price(
+			RuntimeOrigin::signed(seller.clone()),
+			TEST_BUNDLE[0].clone(),
+			price,
+			None,
+			None,
+			None,
+		));
+
+		let buyer = new_account(4, 10000 * unit(GAKI));
+		let seller_before_balance = Balances::free_balance(&seller);
+		assert_ok!(PalletGame::buy_item(
+			RuntimeOrigin::signed(buyer.clone()),
+			0,
+			5,
+			price,
+		));
+
+		// 10% of the price goes to the beneficiary
+		let royalty = 5 * unit(GAKI);
+		assert_eq!(
+			Balances::free_balance(&buyer),
+			10000 * unit(GAKI) - price * 5
+		);
+		assert_eq!(
+			Balances::free_balance(&seller),
+			seller_before_balance + price * 5 - royalty
+		);
+		assert_eq!(
+			Balances::free_balance(&beneficiary),
+			1000 * unit(GAKI) + royalty
+		);
+		System::assert_has_event(RuntimeEvent::PalletGame(crate::Event::RoyaltyPaid {
+			trade: 0,
+			collection: 0,
+			item: 0,
+			beneficiary,
+			amount: royalty,
+		}));
+	})
+}
+
+#[test]
+pub fn sell_bundle_with_royalty_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (seller, owner, _) = create_account_with_item(TEST_BUNDLE);
+		let beneficiary = new_account(5, 1000 * unit(GAKI));
+		assert_ok!(PalletGame::set_royalty(
+			RuntimeOrigin::signed(owner.clone()),
+			0,
+			Some(0),
+			beneficiary.clone(),
+			1000,
+		));
+
+		let buyer = new_account(4, 10000 * unit(GAKI));
+		let price = 300 * unit(GAKI);
+		assert_ok!(PalletGame::order_bundle(
+			RuntimeOrigin::signed(buyer.clone()),
+			TEST_BUNDLE.clone().to_vec(),
+			price,
+			None,
+			None,
+			None,
+		));
+
+		let seller_before_balance = Balances::free_balance(&seller);
+		assert_ok!(PalletGame::sell_bundle(
+			RuntimeOrigin::signed(seller.clone()),
+			0,
+			price,
+		));
+
+		// only item 0 has a royalty, its share is a third of the price
+		let royalty = 10 * unit(GAKI);
+		assert_eq!(
+			Balances::free_balance(&seller),
+			seller_before_balance + price - royalty
+		);
+		assert_eq!(
+			Balances::free_balance(&beneficiary),
+			1000 * unit(GAKI) + royalty
+		);
+		for i in 0..TEST_BUNDLE.len() as u32 {
+			assert_eq!(ItemBalanceOf::<Test>::get((&buyer, 0, i)), 10);
+		}
+	})
+}
+
+#[test]
+pub fn set_trade_fee_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (owner, _) = do_create_game();
+		let game = NextGameId::<Test>::get().unwrap() - 1;
+		let fee_account = new_account(5, 1000 * unit(GAKI));
+
+		assert_ok!(PalletGame::set_trade_fee(
+			RuntimeOrigin::signed(owner.clone()),
+			game,
+			MAX_TRADE_FEE_VAL,
+			Some(fee_account.clone()),
+		));
+
+		let details = Game::<Test>::get(game).unwrap();
+		assert_eq!(details.trade_fee, MAX_TRADE_FEE_VAL);
+		assert_eq!(details.fee_account, Some(fee_account.clone()));
+		System::assert_last_event(RuntimeEvent::PalletGame(crate::Event::TradeFeeSet {
+			game,
+			fee: MAX_TRADE_FEE_VAL,
+			fee_account: Some(fee_account),
+		}));
+	})
+}
+
+#[test]
+pub fn set_trade_fee_should_fails() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (owner, admin) = do_create_game();
+		let game = NextGameId::<Test>::get().unwrap() - 1;
+
+		assert_err!(
+			PalletGame::set_trade_fee(RuntimeOrigin::signed(admin.clone()), game, 100, None),
+			Error::<Test>::NoPermission
+		);
+
+		assert_err!(
+			PalletGame::set_trade_fee(
+				RuntimeOrigin::signed(owner.clone()),
+				game,
+				MAX_TRADE_FEE_VAL + 1,
+				None
+			),
+			Error::<Test>::TradeFeeTooHigh
+		);
+
+		assert_err!(
+			PalletGame::set_trade_fee(RuntimeOrigin::signed(owner.clone()), game + 1, 100, None),
+			Error::<Test>::UnknownGame
+		);
+	})
+}
+
+#[test]
+pub fn buy_item_with_trade_fee_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (seller, owner, _) = create_account_with_item(TEST_BUNDLE);
+		let game = NextGameId::<Test>::get().unwrap() - 1;
+		let fee_account = new_account(5, 1000 * unit(GAKI));
+		assert_ok!(PalletGame::set_trade_fee(
+			RuntimeOrigin::signed(owner.clone()),
+			game,
+			500,
+			Some(fee_account.clone()),
+		));
+
+		let price = 10 * unit(GAKI);
+		assert_ok!(PalletGame::set_price(
+			RuntimeOrigin::signed(seller.clone()),
+			TEST_BUNDLE[0].clone(),
+			price,
+			None,
+			None,
+			None,
+		));
+
+		let buyer = new_account(4, 10000 * unit(GAKI));
+		let seller_before_balance = Balances::free_balance(&seller);
+		assert_ok!(PalletGame::buy_item(
+			RuntimeOrigin::signed(buyer.clone()),
+			0,
+			4,
+			price,
+		));
+
+		// 5% of the price goes to the fee account
+		let fee = 2 * unit(GAKI);
+		assert_eq!(
+			Balances::free_balance(&seller),
+			seller_before_balance + price * 4 - fee
+		);
+		assert_eq!(
+			Balances::free_balance(&fee_account),
+			1000 * unit(GAKI) + fee
+		);
+		System::assert_has_event(RuntimeEvent::PalletGame(crate::Event::TradeFeePaid {
+			trade: 0,
+			game,
+			fee_account,
+			amount: fee,
+		}));
+	})
+}
+
+#[test]
+pub fn set_payment_asset_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (owner, _) = do_create_game();
+		let game = NextGameId::<Test>::get().unwrap() - 1;
+		create_payment_asset(&owner, &owner, 1000 * unit(GAKI));
+
+		assert_ok!(PalletGame::set_payment_asset(
+			RuntimeOrigin::signed(owner.clone()),
+			game,
+			Some(PAYMENT_ASSET),
+		));
+
+		assert_eq!(
+			Game::<Test>::get(game).unwrap().payment_asset,
+			Some(PAYMENT_ASSET)
+		);
+		System::assert_last_event(RuntimeEvent::PalletGame(crate::Event::PaymentAssetSet {
+			game,
+			maybe_asset: Some(PAYMENT_ASSET),
+		}));
+
+		assert_ok!(PalletGame::set_payment_asset(
+			RuntimeOrigin::signed(owner.clone()),
+			game,
+			None,
+		));
+		assert_eq!(Game::<Test>::get(game).unwrap().payment_asset, None);
+	})
+}
+
+#[test]
+pub fn set_payment_asset_should_fails() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (owner, admin) = do_create_game();
+		let game = NextGameId::<Test>::get().unwrap() - 1;
+
+		assert_err!(
+			PalletGame::set_payment_asset(
+				RuntimeOrigin::signed(admin.clone()),
+				game,
+				Some(PAYMENT_ASSET)
+			),
+			Error::<Test>::NoPermission
+		);
+
+		assert_err!(
+			PalletGame::set_payment_asset(
+				RuntimeOrigin::signed(owner.clone()),
+				game + 1,
+				Some(PAYMENT_ASSET)
+			),
+			Error::<Test>::UnknownGame
+		);
+
+		assert_err!(
+			PalletGame::set_payment_asset(
+				RuntimeOrigin::signed(owner.clone()),
+				game,
+				Some(PAYMENT_ASSET)
+			),
+			Error::<Test>::UnknownAsset
+		);
+	})
+}
+
+#[test]
+pub fn buy_item_with_payment_asset_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (seller, owner, _) = create_account_with_item(TEST_BUNDLE);
+		let buyer = new_account(4, 10000 * unit(GAKI));
+		create_payment_asset(&owner, &buyer, 1000 * unit(GAKI));
+
+		let price = 10 * unit(GAKI);
+		assert_ok!(PalletGame::set_price(
+			RuntimeOrigin::signed(seller.clone()),
+			TEST_BUNDLE[0].clone(),
+			price,
+			None,
+			None,
+			Some(PAYMENT_ASSET),
+		));
+		assert_eq!(TradeAssetOf::<Test>::get(0), Some(PAYMENT_ASSET));
+
+		let buyer_before_balance = Balances::free_balance(&buyer);
+		assert_ok!(PalletGame::buy_item(
+			RuntimeOrigin::signed(buyer.clone()),
+			0,
+			4,
+			price,
+		));
+
+		// the price is paid in the asset
+		assert_eq!(Assets::balance(PAYMENT_ASSET, &seller), price * 4);
+		assert_eq!(
+			Assets::balance(PAYMENT_ASSET, &buyer),
+			1000 * unit(GAKI) - price * 4
+		);
+		assert_eq!(Balances::free_balance(&buyer), buyer_before_balance);
+		assert_eq!(ItemBalanceOf::<Test>::get((buyer.clone(), 0, 0)), 4);
+
+		assert_ok!(PalletGame::cancel_trade(
+			RuntimeOrigin::signed(seller.clone()),
+			0,
+			TradeType::SetPrice
+		));
+		assert_eq!(TradeAssetOf::<Test>::get(0), None);
+	})
+}
+
+#[test]
+pub fn set_buy_with_payment_asset_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (_, owner, _) = create_account_with_item(TEST_BUNDLE);
+		let buyer = new_account(4, 10000 * unit(GAKI));
+		create_payment_asset(&owner, &buyer, 1000 * unit(GAKI));
+
+		let unit_price = 5 * unit(GAKI);
+		assert_ok!(PalletGame::set_order(
+			RuntimeOrigin::signed(buyer.clone()),
+			TEST_BUNDLE[0].clone(),
+			unit_price,
+			None,
+			None,
+			Some(PAYMENT_ASSET),
+		));
+
+		// the price is held by the escrow account
+		let escrow = PalletGame::escrow_account();
+		let deposit = unit_price * TEST_BUNDLE[0].amount as u128;
+		assert_eq!(Assets::balance(PAYMENT_ASSET, &escrow), deposit);
+		assert_eq!(
+			Assets::balance(PAYMENT_ASSET, &buyer),
+			1000 * unit(GAKI) - deposit
+		);
+
+		assert_ok!(PalletGame::cancel_trade(
+			RuntimeOrigin::signed(buyer.clone()),
+			0,
+			TradeType::SetBuy
+		));
+		assert_eq!(Assets::balance(PAYMENT_ASSET, &escrow), 0);
+		assert_eq!(Assets::balance(PAYMENT_ASSET, &buyer), 1000 * unit(GAKI));
+	})
+}
+
+#[test]
+pub fn set_buy_with_non_sufficient_asset_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (_, owner, _) = create_account_with_item(TEST_BUNDLE);
+		let buyer = new_account(4, 10000 * unit(GAKI));
+		do_create_payment_asset(&owner, &buyer, 1000 * unit(GAKI), false);
+
+		let unit_price = 5 * unit(GAKI);
+		assert_ok!(PalletGame::set_order(
+			RuntimeOrigin::signed(buyer.clone()),
+			TEST_BUNDLE[0].clone(),
+			unit_price,
+			None,
+			None,
+			Some(PAYMENT_ASSET),
+		));
+
+		// the price is held by the escrow account
+		let escrow = PalletGame::escrow_account();
+		let deposit = unit_price * TEST_BUNDLE[0].amount as u128;
+		assert_eq!(Assets::balance(PAYMENT_ASSET, &escrow), deposit);
+		assert_eq!(
+			Assets::balance(PAYMENT_ASSET, &buyer),
+			1000 * unit(GAKI) - deposit
+		);
+
+		assert_ok!(PalletGame::cancel_trade(
+			RuntimeOrigin::signed(buyer.clone()),
+			0,
+			TradeType::SetBuy
+		));
+		assert_eq!(Assets::balance(PAYMENT_ASSET, &escrow), 0);
+		assert_eq!(Assets::balance(PAYMENT_ASSET, &buyer), 1000 * unit(GAKI));
+	})
+}
+
+#[test]
+pub fn set_price_with_unknown_asset_should_fails() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (seller, _, _) = create_account_with_item(TEST_BUNDLE);
+
+		assert_err!(
+			PalletGame::set_price(
+				RuntimeOrigin::signed(seller.clone()),
+				TEST_BUNDLE[0].clone(),
+				10 * unit(GAKI),
+				None,
+				None,
+				Some(PAYMENT_ASSET),
+			),
+			Error::<Test>::UnknownAsset
+		);
+	})
+}
+
+#[test]
+pub fn set_buy_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let player = new_account(0, 1000 * unit(GAKI));
+		let player_balance = Balances::free_balance(&player);
+
+		let unit_price = 5 * unit(GAKI);
+		assert_ok!(PalletGame::set_order(
+			RuntimeOrigin::signed(player.clone()),
+			TEST_BUNDLE[0].clone(),
+			unit_price,
+			None,
+			None,
+			None,
+		));
+
+		assert_eq!(
+			Balances::free_balance(&player),
+			player_balance -
+				BUNDLE_DEPOSIT_VAL -
+				(unit_price * TEST_BUNDLE[0].clone().amount as u128)
+		);
+	})
+}
+
+#[test]
+pub fn claim_set_buy_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let player = new_account(0, 1000 * unit(GAKI));
+
+		let unit_price = 5 * unit(GAKI);
+		assert_ok!(PalletGame::set_order(
+			RuntimeOrigin::signed(player.clone()),
+			TEST_BUNDLE[0].clone(),
+			unit_price,
+			None,
+			None,
+			None,
+		));
+
+		let (seller, _, _) = create_account_with_item(TEST_BUNDLE);
+		let amount = TEST_BUNDLE[0].clone().amount / 2;
+		let seller_balance = Balances::free_balance(&seller);
+
+		assert_ok!(PalletGame::sell_item(
+			RuntimeOrigin::signed(seller.clone()),
+			0,
+			amount,
+			unit_price
+		));
+
+		assert_eq!(
+			Balances::free_balance(&seller),
+			seller_balance + (unit_price * amount as u128)
+		);
+		assert_eq!(
+			ItemBalanceOf::<Test>::get((
+				player.clone(),
+				TEST_BUNDLE[0].clone().collection,
+				TEST_BUNDLE[0].clone().item
+			)),
+			amount
+		);
+
+		assert_eq!(
+			ItemBalanceOf::<Test>::get((
+				seller.clone(),
+				TEST_BUNDLE[0].clone().collection,
+				TEST_BUNDLE[0].clone().item
+			)),
+			amount
+		);
+	})
+}
+
+#[test]
+pub fn set_book_price_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let buyers = [
+			(new_account(4, 1000 * unit(GAKI)), 3, 5 * unit(GAKI)),
+			(new_account(5, 1000 * unit(GAKI)), 2, 6 * unit(GAKI)),
+			(new_account(6, 1000 * unit(GAKI)), 2, 5 * unit(GAKI)),
+		];
+		for (buyer, amount, unit_price) in buyers.clone() {
+			assert_ok!(PalletGame::set_book_order(
+				RuntimeOrigin::signed(buyer),
+				Package::new(0, 0, amount),
+				unit_price,
+				None,
+			));
+		}
+		// highest price first, then oldest first
+		assert_eq!(
+			BidBookOf::<Test>::get((0, 0, None)).into_inner(),
+			vec![
+				(1, 6 * unit(GAKI)),
+				(0, 5 * unit(GAKI)),
+				(2, 5 * unit(GAKI))
+			]
+		);
+
+		let (seller, _, _) = create_account_with_item(TEST_BUNDLE);
+		let seller_balance = Balances::free_balance(&seller);
+		assert_ok!(PalletGame::set_book_price(
+			RuntimeOrigin::signed(seller.clone()),
+			Package::new(0, 0, 6),
+			5 * unit(GAKI),
+			None,
+		));
+
+		System::assert_has_event(RuntimeEvent::PalletGame(crate::Event::OrderMatched {
+			ask: 3,
+			bid: 1,
+			seller: seller.clone(),
+			buyer: buyers[1].0.clone(),
+			collection: 0,
+			item: 0,
+			amount: 2,
+			unit_price: 6 * unit(GAKI),
+		}));
+		System::assert_last_event(RuntimeEvent::PalletGame(crate::Event::OrderMatched {
+			ask: 3,
+			bid: 2,
+			seller: seller.clone(),
+			buyer: buyers[2].0.clone(),
+			collection: 0,
+			item: 0,
+			amount: 1,
+			unit_price: 5 * unit(GAKI),
+		}));
+
+		assert_eq!(
+			Balances::free_balance(&seller),
+			seller_balance + 32 * unit(GAKI)
+		);
+		assert_eq!(ItemBalanceOf::<Test>::get((&seller, 0, 0)), 4);
+		assert_eq!(
+			Balances::free_balance(&buyers[0].0),
+			1000 * unit(GAKI) - 15 * unit(GAKI)
+		);
+		assert_eq!(ItemBalanceOf::<Test>::get((&buyers[0].0, 0, 0)), 3);
+		assert_eq!(
+			Balances::free_balance(&buyers[1].0),
+			1000 * unit(GAKI) - 12 * unit(GAKI)
+		);
+		assert_eq!(ItemBalanceOf::<Test>::get((&buyers[1].0, 0, 0)), 2);
+		assert_eq!(ItemBalanceOf::<Test>::get((&buyers[2].0, 0, 0)), 1);
+
+		// the partly filled purchase stays in the book
+		assert_eq!(
+			BidBookOf::<Test>::get((0, 0, None)).into_inner(),
+			vec![(2, 5 * unit(GAKI))]
+		);
+		assert_eq!(
+			BundleOf::<Test>::get(2).into_inner(),
+			vec![Package::new(0, 0, 1)]
+		);
+		assert!(AskBookOf::<Test>::get((0, 0, None)).is_empty());
+		assert!(TradeConfigOf::<Test>::get(3).is_none());
+	})
+}
+
+#[test]
+pub fn set_book_order_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (seller, _, _) = create_account_with_item(TEST_BUNDLE);
+		assert_ok!(PalletGame::set_book_price(
+			RuntimeOrigin::signed(seller.clone()),
+			Package::new(0, 0, 3),
+			7 * unit(GAKI),
+			None,
+		));
+		assert_ok!(PalletGame::set_book_price(
+			RuntimeOrigin::signed(seller.clone()),
+			Package::new(0, 0, 2),
+			5 * unit(GAKI),
+			None,
+		));
+		// lowest price first
+		assert_eq!(
+			AskBookOf::<Test>::get((0, 0, None)).into_inner(),
+			vec![(1, 5 * unit(GAKI)), (0, 7 * unit(GAKI))]
+		);
+
+		// not crossing the listings
+		let buyer = new_account(4, 1000 * unit(GAKI));
+		assert_ok!(PalletGame::set_book_order(
+			RuntimeOrigin::signed(buyer.clone()),
+			Package::new(0, 0, 1),
+			4 * unit(GAKI),
+			None,
+		));
+		assert_eq!(
+			BidBookOf::<Test>::get((0, 0, None)).into_inner(),
+			vec![(2, 4 * unit(GAKI))]
+		);
+
+		let seller_balance = Balances::free_balance(&seller);
+		let buyer_balance = Balances::free_balance(&buyer);
+		assert_ok!(PalletGame::set_book_order(
+			RuntimeOrigin::signed(buyer.clone()),
+			Package::new(0, 0, 4),
+			8 * unit(GAKI),
+			None,
+		));
+
+		// filled at the listing prices
+		System::assert_has_event(RuntimeEvent::PalletGame(crate::Event::OrderMatched {
+			ask: 1,
+			bid: 3,
+			seller: seller.clone(),
+			buyer: buyer.clone(),
+			collection: 0,
+			item: 0,
+			amount: 2,
+			unit_price: 5 * unit(GAKI),
+		}));
+		System::assert_last_event(RuntimeEvent::PalletGame(crate::Event::OrderMatched {
+			ask: 0,
+			bid: 3,
+			seller: seller.clone(),
+			buyer: buyer.clone(),
+			collection: 0,
+			item: 0,
+			amount: 2,
+			unit_price: 7 * unit(GAKI),
+		}));
+
+		assert_eq!(
+			Balances::free_balance(&buyer),
+			buyer_balance - 24 * unit(GAKI)
+		);
+		assert_eq!(ItemBalanceOf::<Test>::get((&buyer, 0, 0)), 4);
+		assert_eq!(
+			Balances::free_balance(&seller),
+			seller_balance + 24 * unit(GAKI) + BUNDLE_DEPOSIT_VAL
+		);
+		assert_eq!(
+			AskBookOf::<Test>::get((0, 0, None)).into_inner(),
+			vec![(0, 7 * unit(GAKI))]
+		);
+		assert_eq!(
+			BidBookOf::<Test>::get((0, 0, None)).into_inner(),
+			vec![(2, 4 * unit(GAKI))]
+		);
+
+		// the purchases scanned for closed orders are charged
+		let post_info = PalletGame::set_book_order(
+			RuntimeOrigin::signed(buyer.clone()),
+			Package::new(0, 0, 1),
+			3 * unit(GAKI),
+			None,
+		)
+		.unwrap();
+		assert_eq!(
+			post_info.actual_weight,
+			Some(
+				<Test as Config>::WeightInfo::set_book_order(0)
+					.saturating_add(PalletGame::book_scan_weight(1))
+			)
+		);
+	})
+}
+
+#[test]
+pub fn set_book_order_should_fails() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let buyer = new_account(4, 1000 * unit(GAKI));
+		for _ in 0..MAX_BOOK_ORDERS_VAL {
+			assert_ok!(PalletGame::set_book_order(
+				RuntimeOrigin::signed(buyer.clone()),
+				Package::new(0, 0, 1),
+				unit(GAKI),
+				None,
+			));
+		}
+
+		assert_err!(
+			PalletGame::set_book_order(
+				RuntimeOrigin::signed(buyer.clone()),
+				Package::new(0, 0, 1),
+				unit(GAKI),
+				None,
+			),
+			Error::<Test>::OrderBookFull
+		);
+
+		// canceled purchases leave the book
+		assert_ok!(PalletGame::cancel_trade(
+			RuntimeOrigin::signed(buyer.clone()),
+			0,
+			TradeType::SetBuy
+		));
+		assert_eq!(
+			BidBookOf::<Test>::get((0, 0, None)).len() as u32,
+			MAX_BOOK_ORDERS_VAL - 1
+		);
+		assert_ok!(PalletGame::set_book_order(
+			RuntimeOrigin::signed(buyer.clone()),
+			Package::new(0, 0, 1),
+			unit(GAKI),
+			None,
+		));
+	})
+}
+
+#[test]
+fn cancel_trade_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		// set price
+		{
+			let player = do_all_set_price(TEST_BUNDLE[0].clone(), unit(GAKI));
+			assert_ok!(PalletGame::cancel_trade(
+				RuntimeOrigin::signed(player.clone()),
+				0,
+				TradeType::SetPrice,
+			));
+		}
+
+		// set buy
 		{
 			let player = new_account(0, 1000 * unit(GAKI));
 			assert_ok!(PalletGame::set_order(
@@ -1582,138 +4134,2933 @@ fn cancel_trade_should_works() {
 				unit(GAKI),
 				None,
 				None,
+				None,
+			));
+			assert_ok!(PalletGame::cancel_trade(
+				RuntimeOrigin::signed(player.clone()),
+				1,
+				TradeType::SetBuy,
+			));
+		}
+
+		// set price bundle
+		{
+			let player = funded_account_with_item(TEST_BUNDLE.clone());
+			assert_ok!(PalletGame::set_bundle(
+				RuntimeOrigin::signed(player.clone()),
+				TEST_BUNDLE.clone().to_vec(),
+				unit(GAKI),
+				None,
+				None,
+				None,
+			));
+
+			assert_ok!(PalletGame::cancel_trade(
+				RuntimeOrigin::signed(player.clone()),
+				2,
+				TradeType::Bundle,
+			));
+		}
+
+		// wishlist
+		{
+			let player = new_account(0, 1000 * unit(GAKI));
+			assert_ok!(PalletGame::order_bundle(
+				RuntimeOrigin::signed(player.clone()),
+				TEST_BUNDLE.clone().to_vec(),
+				unit(GAKI),
+				None,
+				None,
+				None,
+			));
+			assert_ok!(PalletGame::cancel_trade(
+				RuntimeOrigin::signed(player.clone()),
+				3,
+				TradeType::Wishlist,
+			));
+		}
+
+		// swap
+		{
+			let player = funded_account_with_item(TEST_BUNDLE.clone());
+			assert_ok!(PalletGame::create_swap(
+				RuntimeOrigin::signed(player.clone()),
+				TEST_BUNDLE.clone().to_vec(),
+				TEST_BUNDLE1.clone().to_vec(),
+				Some(unit(GAKI)),
+				None,
+				None,
+				None,
+			));
+
+			assert_ok!(PalletGame::cancel_trade(
+				RuntimeOrigin::signed(player.clone()),
+				4,
+				TradeType::Swap,
+			));
+		}
+
+		// auction
+		{
+			let player = funded_account_with_item(TEST_BUNDLE.clone());
+			assert_ok!(PalletGame::set_auction(
+				RuntimeOrigin::signed(player.clone()),
+				TEST_BUNDLE.clone().to_vec(),
+				Some(unit(GAKI)),
+				Some(1),
+				1,
+				None,
+			));
+
+			run_to_block(2);
+			assert_ok!(PalletGame::cancel_trade(
+				RuntimeOrigin::signed(player.clone()),
+				5,
+				TradeType::Auction,
+			));
+		}
+	})
+}
+
+#[test]
+pub fn expire_trades_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
+		let player_balance = Balances::free_balance(&player);
+		assert_ok!(PalletGame::set_price(
+			RuntimeOrigin::signed(player.clone()),
+			TEST_BUNDLE[0].clone(),
+			unit(GAKI),
+			None,
+			Some(5),
+			None,
+		));
+		assert_ok!(PalletGame::set_price(
+			RuntimeOrigin::signed(player.clone()),
+			TEST_BUNDLE[1].clone(),
+			unit(GAKI),
+			None,
+			Some(5),
+			None,
+		));
+		assert_ok!(PalletGame::set_auction(
+			RuntimeOrigin::signed(player.clone()),
+			vec![TEST_BUNDLE[2].clone()],
+			None,
+			Some(1),
+			5,
+			None,
+		));
+		assert_eq!(
+			TradeExpiryOf::<Test>::get(6).into_inner(),
+			vec![
+				(0, TradeType::SetPrice),
+				(1, TradeType::SetPrice),
+				(2, TradeType::Auction)
+			]
+		);
+
+		// canceled trades are skipped
+		assert_ok!(PalletGame::cancel_trade(
+			RuntimeOrigin::signed(player.clone()),
+			1,
+			TradeType::SetPrice,
+		));
+
+		run_to_block(6);
+		PalletGame::on_idle(6, Weight::MAX);
+
+		assert!(TradeConfigOf::<Test>::get(0).is_none());
+		assert!(AuctionConfigOf::<Test>::get(2).is_none());
+		assert!(TradeExpiryOf::<Test>::get(6).is_empty());
+		assert!(TradeExpiryOf::<Test>::get(7).is_empty());
+		assert_eq!(NextExpiryBlock::<Test>::get(), 7);
+		for i in 0..TEST_BUNDLE.len() as u32 {
+			assert_eq!(ItemBalanceOf::<Test>::get((&player, 0, i)), 10);
+			assert_eq!(ReservedBalanceOf::<Test>::get((&player, 0, i)), 0);
+		}
+		assert_eq!(Balances::free_balance(&player), player_balance);
+
+		System::assert_has_event(RuntimeEvent::PalletGame(crate::Event::TradeExpired {
+			trade: 0,
+		}));
+		System::assert_last_event(RuntimeEvent::PalletGame(crate::Event::TradeExpired {
+			trade: 2,
+		}));
+	})
+}
+
+#[test]
+pub fn expire_trades_within_weight_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
+		for package in TEST_BUNDLE.clone() {
+			assert_ok!(PalletGame::set_price(
+				RuntimeOrigin::signed(player.clone()),
+				package,
+				unit(GAKI),
+				None,
+				Some(2),
+				None,
+			));
+		}
+
+		// the trades are not expired yet
+		run_to_block(2);
+		PalletGame::on_idle(2, Weight::MAX);
+		assert_eq!(TradeExpiryOf::<Test>::get(3).len(), 3);
+
+		run_to_block(3);
+		let weight = PalletGame::on_idle(3, <() as crate::WeightInfo>::expire_trade());
+		assert_eq!(weight, <() as crate::WeightInfo>::expire_trade());
+		assert!(TradeConfigOf::<Test>::get(0).is_none());
+		assert_eq!(
+			TradeExpiryOf::<Test>::get(3).into_inner(),
+			vec![(1, TradeType::SetPrice), (2, TradeType::SetPrice)]
+		);
+		assert_eq!(NextExpiryBlock::<Test>::get(), 3);
+
+		// the rest expires in the following blocks
+		run_to_block(4);
+		PalletGame::on_idle(4, Weight::MAX);
+		assert!(TradeExpiryOf::<Test>::get(3).is_empty());
+		assert!(TradeConfigOf::<Test>::get(1).is_none());
+		assert!(TradeConfigOf::<Test>::get(2).is_none());
+		assert_eq!(NextExpiryBlock::<Test>::get(), 5);
+	})
+}
+
+#[test]
+pub fn expire_trades_should_spill_over() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
+		for _ in 0..MAX_EXPIRING_TRADES_VAL {
+			assert_ok!(PalletGame::set_price(
+				RuntimeOrigin::signed(player.clone()),
+				Package::new(0, 0, 1),
+				unit(GAKI),
+				None,
+				Some(5),
+				None,
+			));
+		}
+
+		// the full block spills over to the next one
+		assert_ok!(PalletGame::set_price(
+			RuntimeOrigin::signed(player.clone()),
+			Package::new(0, 1, 1),
+			unit(GAKI),
+			None,
+			Some(5),
+			None,
+		));
+		assert_eq!(
+			TradeExpiryOf::<Test>::get(6).len() as u32,
+			MAX_EXPIRING_TRADES_VAL
+		);
+		let trade = MAX_EXPIRING_TRADES_VAL;
+		assert_eq!(
+			TradeExpiryOf::<Test>::get(7).into_inner(),
+			vec![(trade, TradeType::SetPrice)]
+		);
+
+		run_to_block(7);
+		PalletGame::on_idle(7, Weight::MAX);
+		assert!(TradeConfigOf::<Test>::get(trade).is_none());
+		assert_eq!(ItemBalanceOf::<Test>::get((player.clone(), 0, 1)), 10);
+	})
+}
+
+#[test]
+fn create_stable_pool_should_works() {
+	new_test_ext().execute_with(|| {
+		let (owner, admin) = do_create_game();
+		do_create_collection(0, &admin);
+
+		assert_ok!(PalletGame::create_item(
+			RuntimeOrigin::signed(admin.clone()),
+			0,
+			0,
+			None
+		));
+
+		assert_ok!(PalletGame::create_item(
+			RuntimeOrigin::signed(admin.clone()),
+			0,
+			1,
+			None
+		));
+
+		assert_ok!(PalletGame::create_item(
+			RuntimeOrigin::signed(admin.clone()),
+			0,
+			2,
+			None
+		));
+
+		let owner_balance = Balances::free_balance(owner.clone());
+		assert_ok!(PalletGame::create_stable_pool(
+			RuntimeOrigin::signed(owner.clone()),
+			TEST_TABLE.clone().to_vec(),
+			admin.clone(),
+			default_mint_config(),
+			None,
+		));
+
+		assert_eq!(
+			Balances::free_balance(owner.clone()),
+			owner_balance - MINING_DEPOSIT_VAL
+		);
+		assert_eq!(LootTableOf::<Test>::get(0), TEST_TABLE.to_vec());
+	})
+}
+
+#[test]
+fn create_stable_pool_should_fails() {
+	new_test_ext().execute_with(|| {
+		let (owner, admin) = do_create_game();
+		do_create_collection(0, &admin);
+
+		assert_ok!(PalletGame::create_item(
+			RuntimeOrigin::signed(admin.clone()),
+			0,
+			0,
+			None
+		));
+
+		assert_ok!(PalletGame::create_item(
+			RuntimeOrigin::signed(admin.clone()),
+			0,
+			1,
+			None
+		));
+
+		assert_err!(
+			PalletGame::create_stable_pool(
+				RuntimeOrigin::signed(admin.clone()),
+				TEST_TABLE.clone()[0..1].to_vec(),
+				admin.clone(),
+				default_mint_config(),
+				None,
+			),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(PalletGame::create_item(
+			RuntimeOrigin::signed(admin.clone()),
+			0,
+			2,
+			Some(1000)
+		));
+
+		assert_err!(
+			PalletGame::create_stable_pool(
+				RuntimeOrigin::signed(owner.clone()),
+				TEST_TABLE.clone().to_vec(),
+				admin.clone(),
+				default_mint_config(),
+				None,
+			),
+			Error::<Test>::NotInfiniteSupply
+		);
+	})
+}
+
+#[test]
+fn create_dynamic_pool_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let (owner, admin) = do_create_game();
+		do_create_collection(0, &admin);
+
+		for package in TEST_BUNDLE.clone() {
+			do_create_item(&admin, package.collection, package.item, package.amount);
+		}
+
+		let owner_balance = Balances::free_balance(owner.clone());
+
+		assert_ok!(PalletGame::create_dynamic_pool(
+			RuntimeOrigin::signed(owner.clone()),
+			TEST_TABLE.clone().to_vec(),
+			admin.clone(),
+			default_mint_config(),
+			None,
+		));
+
+		assert_eq!(
+			Balances::free_balance(owner.clone()),
+			owner_balance - MINING_DEPOSIT_VAL
+		);
+		assert_eq!(LootTableOf::<Test>::get(0).to_vec(), TEST_TABLE.clone());
+
+		for package in TEST_BUNDLE.clone() {
+			assert_eq!(
+				ItemBalanceOf::<Test>::get((owner.clone(), package.collection, package.item)),
+				0
+			);
+
+			assert_eq!(
+				ReservedBalanceOf::<Test>::get((owner.clone(), package.collection, package.item)),
+				package.amount
+			);
+		}
+	})
+}
+
+#[test]
+fn request_mint_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let (_, _) = do_all_create_stable_pool(default_mint_config());
+		let player = new_account(2, 1000_000 * unit(GAKI));
+
+		let amount = 10;
+		let block: u64 = (1 + MIN_INTERVAL_VAL) as u64;
+
+		assert_ok!(PalletGame::request_mint(
+			RuntimeOrigin::signed(player.clone()),
+			0,
+			player.clone(),
+			amount,
+			None,
+		));
+
+		let request = MintRequest {
+			miner: player.clone(),
+			pool: 0,
+			target: player.clone(),
+			amount,
+			mining_fee: default_mint_config().price,
+			miner_reserve: default_mint_config().price * amount as u128,
+			block_number: block,
+			maybe_usage: None,
+			maybe_nonce: None,
+		};
+
+		assert_eq!(MintRequestOf::<Test>::get(block).to_vec(), [request]);
+
+		assert_eq!(
+			Balances::reserved_balance(player.clone()),
+			(default_mint_config().price * amount as u128 as u128)
+		);
+	})
+}
+
+#[test]
+fn request_mint_with_payment_asset_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let (owner, admin) = do_create_game();
+		do_create_collection(0, &admin);
+		for package in TEST_BUNDLE.clone() {
+			assert_ok!(PalletGame::create_item(
+				RuntimeOrigin::signed(admin.clone()),
+				package.collection,
+				package.item,
+				None
+			));
+		}
+		assert_ok!(PalletGame::create_stable_pool(
+			RuntimeOrigin::signed(owner.clone()),
+			TEST_TABLE.clone().to_vec(),
+			admin.clone(),
+			default_mint_config(),
+			Some(PAYMENT_ASSET),
+		));
+		assert_eq!(PoolAssetOf::<Test>::get(0), Some(PAYMENT_ASSET));
+
+		let player = new_account(2, 1000_000 * unit(GAKI));
+		create_payment_asset(&owner, &player, 1000 * unit(GAKI));
+
+		let amount = 10;
+		assert_ok!(PalletGame::request_mint(
+			RuntimeOrigin::signed(player.clone()),
+			0,
+			player.clone(),
+			amount,
+			None,
+		));
+
+		// the mint price is held by the escrow account
+		let reserve = default_mint_config().price * amount as u128;
+		assert_eq!(
+			Assets::balance(PAYMENT_ASSET, &PalletGame::escrow_account()),
+			reserve
+		);
+		assert_eq!(
+			Assets::balance(PAYMENT_ASSET, &player),
+			1000 * unit(GAKI) - reserve
+		);
+		assert_eq!(Balances::reserved_balance(player.clone()), 0);
+	})
+}
+
+#[test]
+fn execute_mint_stable_pool_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let (owner, _) = do_all_create_stable_pool(default_mint_config());
+		let player = new_account(2, 1000_000 * unit(GAKI));
+
+		let amount = 10;
+		let block: u64 = (1 + MIN_INTERVAL_VAL) as u64;
+
+		assert_ok!(PalletGame::request_mint(
+			RuntimeOrigin::signed(player.clone()),
+			0,
+			player.clone(),
+			amount,
+			None,
+		));
+
+		let request = MintRequest {
+			miner: player.clone(),
+			pool: 0,
+			target: player.clone(),
+			amount,
+			mining_fee: default_mint_config().price,
+			miner_reserve: default_mint_config().price * amount as u128,
+			block_number: block,
+			maybe_usage: None,
+			maybe_nonce: None,
+		};
+
+		let owner_balance = Balances::free_balance(owner.clone());
+
+		assert_ok!(PalletGame::processing_mint_request(request));
+
+		let mut nfts = 0;
+		for package in TEST_BUNDLE.clone() {
+			nfts += ItemBalanceOf::<Test>::get((player.clone(), package.collection, package.item));
+		}
+		assert_eq!(nfts, amount);
+
+		assert_eq!(Balances::reserved_balance(player.clone()), 0);
+		assert_eq!(
+			Balances::free_balance(owner.clone()),
+			owner_balance + (default_mint_config().price * amount as u128 as u128)
+		);
+	})
+}
+
+#[test]
+fn execute_mint_dynamic_pool_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let (owner, _) = do_all_create_dynamic_pool(default_mint_config());
+		let player = new_account(2, 1000_000 * unit(GAKI));
+
+		let amount = 10;
+		let block: u64 = (1 + MIN_INTERVAL_VAL) as u64;
+
+		for _ in TEST_BUNDLE.clone() {
+			assert_ok!(PalletGame::request_mint(
+				RuntimeOrigin::signed(player.clone()),
+				0,
+				player.clone(),
+				amount,
+				None,
+			));
+		}
+
+		let request = MintRequest {
+			miner: player.clone(),
+			pool: 0,
+			target: player.clone(),
+			amount,
+			mining_fee: default_mint_config().price,
+			miner_reserve: default_mint_config().price * amount as u128,
+			block_number: block,
+			maybe_usage: None,
+			maybe_nonce: None,
+		};
+
+		let owner_balance = Balances::free_balance(owner.clone());
+		for _ in TEST_BUNDLE.clone() {
+			assert_ok!(PalletGame::processing_mint_request(request.clone()));
+		}
+		for pack in TEST_BUNDLE.clone() {
+			assert_eq!(
+				ItemBalanceOf::<Test>::get((player.clone(), pack.collection, pack.item)),
+				amount
+			);
+			assert_eq!(
+				ReservedBalanceOf::<Test>::get((owner.clone(), pack.collection, pack.item)),
+				pack.amount - amount
+			);
+		}
+
+		assert_eq!(Balances::reserved_balance(player.clone()), 0);
+		assert_eq!(
+			Balances::free_balance(owner.clone()),
+			owner_balance +
+				(default_mint_config().price * amount as u128 * TEST_BUNDLE.len() as u128)
+		);
+	})
+}
+
+#[test]
+fn mint_flow_should_works() {
+	new_test_ext().execute_with(|| {
+		let block = 10_u64;
+		let execute_block = block + MIN_INTERVAL_VAL as u64;
+
+		run_to_block(block);
+		let (owner, _) = do_all_create_stable_pool(default_mint_config());
+		let player = new_account(2, 1000_000 * unit(GAKI));
+
+		let amount = 10;
+
+		let owner_balance = Balances::free_balance(owner.clone());
+		assert_ok!(PalletGame::request_mint(
+			RuntimeOrigin::signed(player.clone()),
+			0,
+			player.clone(),
+			amount,
+			None,
+		));
+
+		fn count_nft(who: &sr25519::Public) -> u32 {
+			let mut nfts = 0;
+			for package in TEST_BUNDLE.clone() {
+				nfts += ItemBalanceOf::<Test>::get((who.clone(), package.collection, package.item));
+			}
+			nfts
+		}
+
+		for b in block..execute_block {
+			// before processing mint request
+			run_to_block(b);
+			assert_eq!(count_nft(&player), 0);
+			assert_eq!(Balances::free_balance(owner.clone()), owner_balance);
+		}
+
+		// after processing mint request
+		run_to_block(execute_block);
+		assert_eq!(count_nft(&player), amount);
+		assert_eq!(
+			Balances::free_balance(owner.clone()),
+			owner_balance + (default_mint_config().price * amount as u128 as u128)
+		);
+
+		run_to_block(execute_block + 1);
+		assert_eq!(MintRequestOf::<Test>::get(execute_block).is_empty(), true);
+	})
+}
+
+#[test]
+fn request_mint_with_limits_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		do_all_create_stable_pool(MintSettings {
+			max_per_account: Some(10),
+			max_per_window: Some(MintWindow {
+				length: 5,
+				max_mints: 5,
+			}),
+			cooldown: Some(2),
+			..default_mint_config()
+		});
+		let player = new_account(2, 1000_000 * unit(GAKI));
+
+		assert_ok!(PalletGame::request_mint(
+			RuntimeOrigin::signed(player.clone()),
+			0,
+			player.clone(),
+			5,
+			None,
+		));
+		assert_eq!(
+			MintRecordOf::<Test>::get(0, player.clone()),
+			MintRecord {
+				total: 5,
+				window_start: 1,
+				window_mints: 5,
+				last_block: Some(1),
+			}
+		);
+
+		// a new window starts after the previous one has passed
+		run_to_block(6);
+		assert_ok!(PalletGame::request_mint(
+			RuntimeOrigin::signed(player.clone()),
+			0,
+			player.clone(),
+			5,
+			None,
+		));
+		assert_eq!(
+			MintRecordOf::<Test>::get(0, player.clone()),
+			MintRecord {
+				total: 10,
+				window_start: 6,
+				window_mints: 5,
+				last_block: Some(6),
+			}
+		);
+	})
+}
+
+#[test]
+fn refunded_mint_request_should_release_limits() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		do_all_create_stable_pool(MintSettings {
+			max_per_account: Some(5),
+			max_per_window: Some(MintWindow {
+				length: 10,
+				max_mints: 5,
+			}),
+			cooldown: Some(5),
+			..default_mint_config()
+		});
+		let player = new_account(2, 1000_000 * unit(GAKI));
+		let player_balance = Balances::free_balance(&player);
+
+		assert_ok!(PalletGame::request_mint(
+			RuntimeOrigin::signed(player.clone()),
+			0,
+			player.clone(),
+			5,
+			None,
+		));
+
+		// the pool is gone before the request is processed
+		PoolOf::<Test>::remove(0);
+		run_to_block(2);
+
+		assert_eq!(Balances::free_balance(&player), player_balance);
+		assert_eq!(
+			MintRecordOf::<Test>::get(0, player.clone()),
+			MintRecord {
+				total: 0,
+				window_start: 1,
+				window_mints: 0,
+				last_block: None,
+			}
+		);
+	})
+}
+
+#[test]
+fn request_mint_should_fails() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		do_all_create_stable_pool(MintSettings {
+			max_per_account: Some(6),
+			max_per_window: Some(MintWindow {
+				length: 10,
+				max_mints: 4,
+			}),
+			cooldown: Some(2),
+			..default_mint_config()
+		});
+		let player = new_account(2, 1000_000 * unit(GAKI));
+
+		assert_err!(
+			PalletGame::request_mint(
+				RuntimeOrigin::signed(player.clone()),
+				0,
+				player.clone(),
+				7,
+				None
+			),
+			Error::<Test>::ExceedMintLimit
+		);
+
+		assert_err!(
+			PalletGame::request_mint(
+				RuntimeOrigin::signed(player.clone()),
+				0,
+				player.clone(),
+				5,
+				None
+			),
+			Error::<Test>::ExceedMintWindowLimit
+		);
+
+		assert_ok!(PalletGame::request_mint(
+			RuntimeOrigin::signed(player.clone()),
+			0,
+			player.clone(),
+			3,
+			None,
+		));
+
+		run_to_block(2);
+		assert_err!(
+			PalletGame::request_mint(
+				RuntimeOrigin::signed(player.clone()),
+				0,
+				player.clone(),
+				1,
+				None
+			),
+			Error::<Test>::MintCooldown
+		);
+
+		run_to_block(3);
+		assert_err!(
+			PalletGame::request_mint(
+				RuntimeOrigin::signed(player.clone()),
+				0,
+				player.clone(),
+				2,
+				None
+			),
+			Error::<Test>::ExceedMintWindowLimit
+		);
+		assert_ok!(PalletGame::request_mint(
+			RuntimeOrigin::signed(player.clone()),
+			0,
+			player.clone(),
+			1,
+			None,
+		));
+
+		// the account limit still applies in a new window
+		run_to_block(11);
+		assert_err!(
+			PalletGame::request_mint(
+				RuntimeOrigin::signed(player.clone()),
+				0,
+				player.clone(),
+				3,
+				None
+			),
+			Error::<Test>::ExceedMintLimit
+		);
+	})
+}
+
+fn allowlist_leaf(who: &sr25519::Public, max_amount: Amount) -> [u8; 32] {
+	blake2_256(&(who, max_amount).encode())
+}
+
+fn allowlist_node(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+	if a <= b {
+		blake2_256(&[a, b].concat())
+	} else {
+		blake2_256(&[b, a].concat())
+	}
+}
+
+#[test]
+fn request_mint_allowlist_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let player = new_account(2, 1000_000 * unit(GAKI));
+		let other = new_account(4, 1000_000 * unit(GAKI));
+
+		let player_leaf = allowlist_leaf(&player, 5);
+		let other_leaf = allowlist_leaf(&other, 10);
+		let root = H256(allowlist_node(player_leaf, other_leaf));
+		do_all_create_stable_pool(MintSettings {
+			mint_type: MintType::MerkleAllowlist(root),
+			..default_mint_config()
+		});
+
+		assert_ok!(PalletGame::request_mint(
+			RuntimeOrigin::signed(player.clone()),
+			0,
+			player.clone(),
+			3,
+			Some(MintProof::Allowlist {
+				max_amount: 5,
+				proof: vec![H256(other_leaf)],
+			}),
+		));
+		assert_eq!(MintRecordOf::<Test>::get(0, player.clone()).total, 3);
+
+		assert_ok!(PalletGame::request_mint(
+			RuntimeOrigin::signed(other.clone()),
+			0,
+			other.clone(),
+			10,
+			Some(MintProof::Allowlist {
+				max_amount: 10,
+				proof: vec![H256(player_leaf)],
+			}),
+		));
+		assert_eq!(MintRecordOf::<Test>::get(0, other.clone()).total, 10);
+	})
+}
+
+#[test]
+fn request_mint_allowlist_should_fails() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let player = new_account(2, 1000_000 * unit(GAKI));
+		let other = new_account(4, 1000_000 * unit(GAKI));
+
+		let other_leaf = allowlist_leaf(&other, 10);
+		let root = H256(allowlist_node(allowlist_leaf(&player, 5), other_leaf));
+		do_all_create_stable_pool(MintSettings {
+			mint_type: MintType::MerkleAllowlist(root),
+			..default_mint_config()
+		});
+
+		assert_err!(
+			PalletGame::request_mint(
+				RuntimeOrigin::signed(player.clone()),
+				0,
+				player.clone(),
+				3,
+				None
+			),
+			Error::<Test>::InvalidMintProof
+		);
+
+		assert_err!(
+			PalletGame::request_mint(
+				RuntimeOrigin::signed(player.clone()),
+				0,
+				player.clone(),
+				3,
+				Some(MintProof::Allowlist {
+					max_amount: 10,
+					proof: vec![H256(other_leaf)],
+				}),
+			),
+			Error::<Test>::NotWhitelisted
+		);
+
+		assert_ok!(PalletGame::request_mint(
+			RuntimeOrigin::signed(player.clone()),
+			0,
+			player.clone(),
+			3,
+			Some(MintProof::Allowlist {
+				max_amount: 5,
+				proof: vec![H256(other_leaf)],
+			}),
+		));
+
+		assert_err!(
+			PalletGame::request_mint(
+				RuntimeOrigin::signed(player.clone()),
+				0,
+				player.clone(),
+				3,
+				Some(MintProof::Allowlist {
+					max_amount: 5,
+					proof: vec![H256(other_leaf)],
+				}),
+			),
+			Error::<Test>::ExceedMintAllowance
+		);
+	})
+}
+
+fn sign_voucher(
+	signer: &sr25519::Pair,
+	who: &sr25519::Public,
+	max_amount: Amount,
+	nonce: u32,
+	expiry: u64,
+) -> MintProofFor<Test> {
+	let voucher = MintVoucher {
+		pool: 0_u32,
+		who: who.clone(),
+		max_amount,
+		nonce,
+		expiry,
+	};
+	MintProof::Voucher {
+		max_amount,
+		nonce,
+		expiry,
+		signature: signer.sign(&(System::block_hash(0), "PalletGame", voucher).encode()),
+	}
+}
+
+#[test]
+fn request_mint_voucher_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let signer = sr25519::Pair::from_seed(&[1; 32]);
+		do_all_create_stable_pool(MintSettings {
+			mint_type: MintType::SignedVoucher(signer.public()),
+			..default_mint_config()
+		});
+		let player = new_account(2, 1000_000 * unit(GAKI));
+
+		assert_ok!(PalletGame::request_mint(
+			RuntimeOrigin::signed(player.clone()),
+			0,
+			player.clone(),
+			5,
+			Some(sign_voucher(&signer, &player, 5, 0, 10)),
+		));
+		assert!(UsedVoucherOf::<Test>::contains_key(0, 0));
+
+		assert_ok!(PalletGame::request_mint(
+			RuntimeOrigin::signed(player.clone()),
+			0,
+			player.clone(),
+			2,
+			Some(sign_voucher(&signer, &player, 5, 1, 10)),
+		));
+		assert!(UsedVoucherOf::<Test>::contains_key(0, 1));
+	})
+}
+
+#[test]
+fn request_mint_voucher_should_fails() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let signer = sr25519::Pair::from_seed(&[1; 32]);
+		do_all_create_stable_pool(MintSettings {
+			mint_type: MintType::SignedVoucher(signer.public()),
+			..default_mint_config()
+		});
+		let player = new_account(2, 1000_000 * unit(GAKI));
+		let other = new_account(4, 1000_000 * unit(GAKI));
+
+		assert_err!(
+			PalletGame::request_mint(
+				RuntimeOrigin::signed(player.clone()),
+				0,
+				player.clone(),
+				5,
+				None
+			),
+			Error::<Test>::InvalidMintProof
+		);
+
+		// signed by another account
+		let forger = sr25519::Pair::from_seed(&[2; 32]);
+		assert_err!(
+			PalletGame::request_mint(
+				RuntimeOrigin::signed(player.clone()),
+				0,
+				player.clone(),
+				5,
+				Some(sign_voucher(&forger, &player, 5, 0, 10)),
+			),
+			Error::<Test>::NotWhitelisted
+		);
+
+		// issued to another account
+		assert_err!(
+			PalletGame::request_mint(
+				RuntimeOrigin::signed(player.clone()),
+				0,
+				player.clone(),
+				5,
+				Some(sign_voucher(&signer, &other, 5, 0, 10)),
+			),
+			Error::<Test>::NotWhitelisted
+		);
+
+		// signed without the chain and pallet of the pool
+		let voucher = MintVoucher {
+			pool: 0_u32,
+			who: player.clone(),
+			max_amount: 5,
+			nonce: 0,
+			expiry: 10_u64,
+		};
+		assert_err!(
+			PalletGame::request_mint(
+				RuntimeOrigin::signed(player.clone()),
+				0,
+				player.clone(),
+				5,
+				Some(MintProof::Voucher {
+					max_amount: 5,
+					nonce: 0,
+					expiry: 10,
+					signature: signer.sign(&voucher.encode()),
+				}),
+			),
+			Error::<Test>::NotWhitelisted
+		);
+
+		assert_err!(
+			PalletGame::request_mint(
+				RuntimeOrigin::signed(player.clone()),
+				0,
+				player.clone(),
+				6,
+				Some(sign_voucher(&signer, &player, 5, 0, 10)),
+			),
+			Error::<Test>::ExceedMintAllowance
+		);
+
+		assert_ok!(PalletGame::request_mint(
+			RuntimeOrigin::signed(player.clone()),
+			0,
+			player.clone(),
+			5,
+			Some(sign_voucher(&signer, &player, 5, 0, 10)),
+		));
+
+		assert_err!(
+			PalletGame::request_mint(
+				RuntimeOrigin::signed(player.clone()),
+				0,
+				player.clone(),
+				5,
+				Some(sign_voucher(&signer, &player, 5, 0, 10)),
+			),
+			Error::<Test>::VoucherUsed
+		);
+
+		run_to_block(11);
+		assert_err!(
+			PalletGame::request_mint(
+				RuntimeOrigin::signed(player.clone()),
+				0,
+				player.clone(),
+				5,
+				Some(sign_voucher(&signer, &player, 5, 1, 10)),
+			),
+			Error::<Test>::VoucherExpired
+		);
+	})
+}
+
+fn tiered_table(rare_weight: u32) -> Vec<LootFor<Test>> {
+	vec![
+		Loot {
+			maybe_nft: Some(NFT {
+				collection: 0,
+				item: 0,
+			}),
+			weight: 10,
+			tier: Some(0),
+		},
+		Loot {
+			maybe_nft: Some(NFT {
+				collection: 0,
+				item: 1,
+			}),
+			weight: rare_weight,
+			tier: Some(1),
+		},
+	]
+}
+
+fn pity_config() -> MintSettingsFor<Test> {
+	MintSettings {
+		pity: Some(Pity {
+			tier: 1,
+			threshold: 2,
+		}),
+		..default_mint_config()
+	}
+}
+
+#[test]
+fn mint_stable_pool_with_pity_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let (owner, admin) = do_create_game();
+		do_create_collection(0, &admin);
+		for package in TEST_BUNDLE.clone() {
+			assert_ok!(PalletGame::create_item(
+				RuntimeOrigin::signed(admin.clone()),
+				package.collection,
+				package.item,
+				None
+			));
+		}
+		assert_ok!(PalletGame::create_stable_pool(
+			RuntimeOrigin::signed(owner.clone()),
+			tiered_table(10),
+			admin.clone(),
+			pity_config(),
+			None,
+		));
+		let player = new_account(2, 1000_000 * unit(GAKI));
+
+		// the mock randomness always rolls the first loot
+		assert_ok!(PalletGame::request_mint(
+			RuntimeOrigin::signed(player.clone()),
+			0,
+			player.clone(),
+			3,
+			None,
+		));
+		run_to_block(1 + MIN_INTERVAL_VAL as u64);
+		assert_eq!(ItemBalanceOf::<Test>::get((player.clone(), 0, 0)), 2);
+		assert_eq!(ItemBalanceOf::<Test>::get((player.clone(), 0, 1)), 1);
+		assert_eq!(PityCounterOf::<Test>::get(0, player.clone()), 0);
+
+		assert_ok!(PalletGame::request_mint(
+			RuntimeOrigin::signed(player.clone()),
+			0,
+			player.clone(),
+			2,
+			None,
+		));
+		run_to_block(2 + 2 * MIN_INTERVAL_VAL as u64);
+		assert_eq!(ItemBalanceOf::<Test>::get((player.clone(), 0, 0)), 4);
+		assert_eq!(ItemBalanceOf::<Test>::get((player.clone(), 0, 1)), 1);
+		assert_eq!(PityCounterOf::<Test>::get(0, player.clone()), 2);
+	})
+}
+
+#[test]
+fn mint_dynamic_pool_with_pity_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let (owner, admin) = do_create_game();
+		do_create_collection(0, &admin);
+		for package in TEST_BUNDLE.clone() {
+			do_create_item(&admin, package.collection, package.item, package.amount);
+		}
+		assert_ok!(PalletGame::create_dynamic_pool(
+			RuntimeOrigin::signed(owner.clone()),
+			tiered_table(1),
+			admin.clone(),
+			pity_config(),
+			None,
+		));
+		let player = new_account(2, 1000_000 * unit(GAKI));
+
+		// the pity stays due once the tier is sold out
+		assert_ok!(PalletGame::request_mint(
+			RuntimeOrigin::signed(player.clone()),
+			0,
+			player.clone(),
+			6,
+			None,
+		));
+		run_to_block(1 + MIN_INTERVAL_VAL as u64);
+		assert_eq!(ItemBalanceOf::<Test>::get((player.clone(), 0, 0)), 5);
+		assert_eq!(ItemBalanceOf::<Test>::get((player.clone(), 0, 1)), 1);
+		assert_eq!(PityCounterOf::<Test>::get(0, player.clone()), 3);
+		assert_eq!(
+			LootTableOf::<Test>::get(0).into_inner(),
+			vec![
+				Loot {
+					maybe_nft: Some(NFT {
+						collection: 0,
+						item: 0,
+					}),
+					weight: 5,
+					tier: Some(0),
+				},
+				Loot {
+					maybe_nft: Some(NFT {
+						collection: 0,
+						item: 1,
+					}),
+					weight: 0,
+					tier: Some(1),
+				},
+			]
+		);
+	})
+}
+
+#[test]
+fn create_pool_with_pity_should_fails() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let (owner, admin) = do_create_game();
+		do_create_collection(0, &admin);
+		for package in TEST_BUNDLE.clone() {
+			do_create_item(&admin, package.collection, package.item, package.amount);
+		}
+
+		assert_err!(
+			PalletGame::create_dynamic_pool(
+				RuntimeOrigin::signed(owner.clone()),
+				tiered_table(10),
+				admin.clone(),
+				MintSettings {
+					pity: Some(Pity {
+						tier: 2,
+						threshold: 2,
+					}),
+					..default_mint_config()
+				},
+				None,
+			),
+			Error::<Test>::InvalidPity
+		);
+
+		assert_err!(
+			PalletGame::create_stable_pool(
+				RuntimeOrigin::signed(owner.clone()),
+				tiered_table(10),
+				admin.clone(),
+				MintSettings {
+					pity: Some(Pity {
+						tier: 1,
+						threshold: 0,
+					}),
+					..default_mint_config()
+				},
+				None,
+			),
+			Error::<Test>::InvalidPity
+		);
+	})
+}
+
+#[test]
+fn inventory_of_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let player = do_all_set_price(TEST_BUNDLE[0].clone(), 10 * unit(GAKI));
+
+		let inventory = PalletGame::inventory_of(&player);
+		assert_eq!(inventory.len(), TEST_BUNDLE.len());
+		for package in TEST_BUNDLE.clone() {
+			let balance = inventory
+				.iter()
+				.find(|b| b.collection == package.collection && b.item == package.item)
+				.unwrap();
+			if package == TEST_BUNDLE[0] {
+				assert_eq!(balance.free, 0);
+				assert_eq!(balance.reserved, package.amount);
+			} else {
+				assert_eq!(balance.free, package.amount);
+				assert_eq!(balance.reserved, 0);
+			}
+		}
+	})
+}
+
+#[test]
+fn active_trades_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let player = create_account_with_item(TEST_BUNDLE).0;
+
+		assert_ok!(PalletGame::set_price(
+			RuntimeOrigin::signed(player.clone()),
+			TEST_BUNDLE[0].clone(),
+			10 * unit(GAKI),
+			None,
+			Some(10),
+			None,
+		));
+		assert_ok!(PalletGame::set_auction(
+			RuntimeOrigin::signed(player.clone()),
+			vec![TEST_BUNDLE[1].clone()],
+			None,
+			None,
+			5,
+			None,
+		));
+
+		let trades = PalletGame::active_trades(&0, &0);
+		assert_eq!(trades.len(), 1);
+		assert_eq!(trades[0].trade, 0);
+		assert_eq!(trades[0].trade_type, TradeType::SetPrice);
+		assert_eq!(trades[0].owner, player);
+
+		let trades = PalletGame::active_trades(&0, &1);
+		assert_eq!(trades.len(), 1);
+		assert_eq!(trades[0].trade_type, TradeType::Auction);
+		assert_eq!(trades[0].end_block, Some(6));
+
+		assert_eq!(PalletGame::active_trades(&0, &2).len(), 0);
+
+		// ended trades are skipped
+		run_to_block(11);
+		assert_eq!(PalletGame::active_trades(&0, &0).len(), 0);
+		assert_eq!(PalletGame::active_trades(&0, &1).len(), 0);
+	})
+}
+
+#[test]
+fn pool_info_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let (owner, admin) = do_all_create_stable_pool(default_mint_config());
+
+		let info = PalletGame::pool_info(&0).unwrap();
+		assert_eq!(info.pool_type, PoolType::Stable);
+		assert_eq!(info.owner, owner);
+		assert_eq!(info.admin, admin);
+		assert_eq!(info.mint_settings, default_mint_config());
+		assert_eq!(info.table, TEST_TABLE.to_vec());
+		assert_eq!(info.total_weight, 30);
+
+		assert_eq!(PalletGame::pool_info(&1), None);
+	})
+}
+
+#[test]
+fn upgrade_path_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let (_, admin) = do_create_game();
+		do_create_collection(0, &admin);
+		do_create_item(&admin, 0, 0, 1000);
+
+		for level in 1..=2 {
+			assert_ok!(PalletGame::set_upgrade_item(
+				RuntimeOrigin::signed(admin.clone()),
+				0,
+				0,
+				100 + level,
+				default_item_config(),
+				bvec![0u8; 50],
+				level,
+				level as u128 * unit(GAKI),
+				vec![],
+			));
+		}
+
+		let path = PalletGame::upgrade_path(&0, &0);
+		assert_eq!(path.len(), 2);
+		assert_eq!(path[0].level, 1);
+		assert_eq!(path[0].item, 101);
+		assert_eq!(path[1].level, 2);
+		assert_eq!(path[1].item, 102);
+		assert_eq!(path[1].fee, 2 * unit(GAKI));
+
+		// upgraded items resolve to the same path
+		assert_eq!(PalletGame::upgrade_path(&0, &101), path);
+		assert_eq!(PalletGame::upgrade_path(&0, &1).len(), 0);
+	})
+}
+
+#[test]
+pub fn set_rental_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
+		let player_balance = Balances::free_balance(&player);
+
+		assert_ok!(PalletGame::set_rental(
+			RuntimeOrigin::signed(player.clone()),
+			TEST_BUNDLE.clone().to_vec(),
+			10 * unit(GAKI),
+			10,
+			None,
+		));
+		assert_eq!(
+			Balances::free_balance(&player),
+			player_balance - BUNDLE_DEPOSIT_VAL
+		);
+		for i in 0..TEST_BUNDLE.len() as u32 {
+			assert_eq!(ItemBalanceOf::<Test>::get((&player, 0, i)), 0);
+			assert_eq!(ReservedBalanceOf::<Test>::get((&player, 0, i)), 10);
+		}
+		assert_eq!(
+			RentalOf::<Test>::get(0),
+			Some(RentalConfig {
+				owner: player,
+				price_per_block: 10 * unit(GAKI),
+				max_duration: 10,
+				maybe_renter: None,
+			})
+		);
+	})
+}
+
+#[test]
+pub fn rent_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
+		assert_ok!(PalletGame::set_rental(
+			RuntimeOrigin::signed(player.clone()),
+			TEST_BUNDLE.clone().to_vec(),
+			10 * unit(GAKI),
+			10,
+			None,
+		));
+		let player_balance = Balances::free_balance(&player);
+
+		let renter = new_account(1, 1000 * unit(GAKI));
+		assert_ok!(PalletGame::rent(
+			RuntimeOrigin::signed(renter.clone()),
+			0,
+			5
+		));
+
+		let price = 50 * unit(GAKI);
+		assert_eq!(Balances::free_balance(&renter), 1000 * unit(GAKI) - price);
+		assert_eq!(Balances::free_balance(&player), player_balance + price);
+		for i in 0..TEST_BUNDLE.len() as u32 {
+			assert_eq!(PalletGame::use_right_of(&renter, &0, &i), 10);
+			assert_eq!(ItemBalanceOf::<Test>::get((&renter, 0, i)), 0);
+			assert_eq!(ReservedBalanceOf::<Test>::get((&player, 0, i)), 10);
+		}
+		let inventory = PalletGame::inventory_of(&renter);
+		assert_eq!(inventory.len(), TEST_BUNDLE.len());
+		assert!(inventory.iter().all(|balance| balance.free == 0 && balance.rented == 10));
+
+		// rented items can not be moved by the renter
+		assert_err!(
+			PalletGame::transfer(
+				RuntimeOrigin::signed(renter.clone()),
+				0,
+				0,
+				player.clone(),
+				1
+			),
+			Error::<Test>::InsufficientItemBalance
+		);
+		// nor by the lender, they stay reserved until returned
+		assert_err!(
+			PalletGame::transfer(
+				RuntimeOrigin::signed(player.clone()),
+				0,
+				0,
+				renter.clone(),
+				1
+			),
+			Error::<Test>::InsufficientItemBalance
+		);
+
+		assert_eq!(
+			RentalOf::<Test>::get(0).unwrap().maybe_renter,
+			Some((renter.clone(), 6))
+		);
+		assert_eq!(
+			TradeExpiryOf::<Test>::get(6).into_inner(),
+			vec![(0, TradeType::Rental)]
+		);
+		System::assert_last_event(RuntimeEvent::PalletGame(crate::Event::Rented {
+			trade: 0,
+			who: renter,
+			duration: 5,
+			price,
+			end_block: 6,
+		}));
+	})
+}
+
+#[test]
+pub fn rent_should_fails() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
+		assert_ok!(PalletGame::set_rental(
+			RuntimeOrigin::signed(player.clone()),
+			TEST_BUNDLE.clone().to_vec(),
+			10 * unit(GAKI),
+			10,
+			None,
+		));
+
+		let renter = new_account(1, 1000 * unit(GAKI));
+		assert_err!(
+			PalletGame::rent(RuntimeOrigin::signed(renter.clone()), 1, 5),
+			Error::<Test>::UnknownRental
+		);
+		assert_err!(
+			PalletGame::rent(RuntimeOrigin::signed(renter.clone()), 0, 0),
+			Error::<Test>::InvalidRentalDuration
+		);
+		assert_err!(
+			PalletGame::rent(RuntimeOrigin::signed(renter.clone()), 0, 11),
+			Error::<Test>::InvalidRentalDuration
+		);
+		assert_err!(
+			PalletGame::return_rental(RuntimeOrigin::signed(renter.clone()), 0),
+			Error::<Test>::NotRented
+		);
+
+		assert_ok!(PalletGame::rent(
+			RuntimeOrigin::signed(renter.clone()),
+			0,
+			5
+		));
+		let other = new_account(2, 1000 * unit(GAKI));
+		assert_err!(
+			PalletGame::rent(RuntimeOrigin::signed(other.clone()), 0, 5),
+			Error::<Test>::ItemRented
+		);
+		assert_err!(
+			PalletGame::return_rental(RuntimeOrigin::signed(other.clone()), 0),
+			Error::<Test>::RentalNotEnded
+		);
+		assert_err!(
+			PalletGame::cancel_trade(RuntimeOrigin::signed(player.clone()), 0, TradeType::Rental),
+			Error::<Test>::ItemRented
+		);
+	})
+}
+
+#[test]
+pub fn rental_should_return_on_expiry() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
+		let player_balance = Balances::free_balance(&player);
+		assert_ok!(PalletGame::set_rental(
+			RuntimeOrigin::signed(player.clone()),
+			TEST_BUNDLE.clone().to_vec(),
+			10 * unit(GAKI),
+			10,
+			None,
+		));
+
+		let renter = new_account(1, 1000 * unit(GAKI));
+		assert_ok!(PalletGame::rent(
+			RuntimeOrigin::signed(renter.clone()),
+			0,
+			5
+		));
+
+		run_to_block(6);
+		PalletGame::on_idle(6, Weight::MAX);
+
+		for i in 0..TEST_BUNDLE.len() as u32 {
+			assert_eq!(PalletGame::use_right_of(&renter, &0, &i), 0);
+		}
+		// the items stay listed for rent
+		assert_eq!(RentalOf::<Test>::get(0).unwrap().maybe_renter, None);
+		System::assert_has_event(RuntimeEvent::PalletGame(crate::Event::RentalReturned {
+			trade: 0,
+			who: renter,
+		}));
+		System::assert_last_event(RuntimeEvent::PalletGame(crate::Event::TradeExpired {
+			trade: 0,
+		}));
+
+		assert_ok!(PalletGame::cancel_trade(
+			RuntimeOrigin::signed(player.clone()),
+			0,
+			TradeType::Rental
+		));
+		for i in 0..TEST_BUNDLE.len() as u32 {
+			assert_eq!(ItemBalanceOf::<Test>::get((&player, 0, i)), 10);
+			assert_eq!(ReservedBalanceOf::<Test>::get((&player, 0, i)), 0);
+		}
+		assert_eq!(
+			Balances::free_balance(&player),
+			player_balance + 50 * unit(GAKI)
+		);
+		assert_eq!(RentalOf::<Test>::get(0), None);
+	})
+}
+
+#[test]
+pub fn set_soulbound_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let (_, _, admin) = create_account_with_item(TEST_BUNDLE);
+
+		assert_ok!(PalletGame::set_soulbound(
+			RuntimeOrigin::signed(admin.clone()),
+			0,
+			0,
+			true
+		));
+		assert!(SoulboundOf::<Test>::get(0, 0));
+		System::assert_last_event(RuntimeEvent::PalletGame(crate::Event::SoulboundSet {
+			collection: 0,
+			item: 0,
+			soulbound: true,
+		}));
+
+		assert_ok!(PalletGame::set_soulbound(
+			RuntimeOrigin::signed(admin.clone()),
+			0,
+			0,
+			false
+		));
+		assert!(!SoulboundOf::<Test>::contains_key(0, 0));
+	})
+}
+
+#[test]
+pub fn set_soulbound_should_fails() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let (player, _, admin) = create_account_with_item(TEST_BUNDLE);
+
+		assert_err!(
+			PalletGame::set_soulbound(RuntimeOrigin::signed(player.clone()), 0, 0, true),
+			Error::<Test>::NoPermission
+		);
+		assert_err!(
+			PalletGame::set_soulbound(RuntimeOrigin::signed(admin.clone()), 0, 10, true),
+			Error::<Test>::UnknownItem
+		);
+	})
+}
+
+#[test]
+pub fn soulbound_item_should_not_be_traded() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let (player, _, admin) = create_account_with_item(TEST_BUNDLE);
+		assert_ok!(PalletGame::set_soulbound(
+			RuntimeOrigin::signed(admin.clone()),
+			0,
+			0,
+			true
+		));
+
+		let dest = new_account(1, 1000 * unit(GAKI));
+		assert_err!(
+			PalletGame::transfer(RuntimeOrigin::signed(player.clone()), 0, 0, dest.clone(), 1),
+			Error::<Test>::SoulboundItem
+		);
+		assert_err!(
+			PalletGame::set_price(
+				RuntimeOrigin::signed(player.clone()),
+				TEST_BUNDLE[0].clone(),
+				unit(GAKI),
+				None,
+				None,
+				None,
+			),
+			Error::<Test>::SoulboundItem
+		);
+		assert_err!(
+			PalletGame::set_order(
+				RuntimeOrigin::signed(dest.clone()),
+				TEST_BUNDLE[0].clone(),
+				unit(GAKI),
+				None,
+				None,
+				None,
+			),
+			Error::<Test>::SoulboundItem
+		);
+		assert_err_ignore_postinfo!(
+			PalletGame::create_swap(
+				RuntimeOrigin::signed(player.clone()),
+				vec![TEST_BUNDLE[1].clone()],
+				vec![TEST_BUNDLE[0].clone()],
+				None,
+				None,
+				None,
+				None,
+			),
+			Error::<Test>::SoulboundItem
+		);
+		assert_err!(
+			PalletGame::set_rental(
+				RuntimeOrigin::signed(player.clone()),
+				TEST_BUNDLE.clone().to_vec(),
+				unit(GAKI),
+				10,
+				None,
+			),
+			Error::<Test>::SoulboundItem
+		);
+
+		// upgraded items stay soulbound, burning is still allowed
+		do_set_upgrade_levels(&admin);
+		assert_ok!(PalletGame::upgrade_item(
+			RuntimeOrigin::signed(player.clone()),
+			0,
+			0,
+			1
+		));
+		assert!(PalletGame::is_soulbound(&0, &100));
+		assert_err!(
+			PalletGame::transfer(
+				RuntimeOrigin::signed(player.clone()),
+				0,
+				100,
+				dest.clone(),
+				1
+			),
+			Error::<Test>::SoulboundItem
+		);
+		assert_ok!(PalletGame::burn(
+			RuntimeOrigin::signed(player.clone()),
+			0,
+			0,
+			1
+		));
+		assert_eq!(ItemBalanceOf::<Test>::get((&player, 0, 0)), 8);
+
+		// other items are not affected
+		assert_ok!(PalletGame::transfer(
+			RuntimeOrigin::signed(player.clone()),
+			0,
+			1,
+			dest.clone(),
+			1
+		));
+	})
+}
+
+#[test]
+pub fn soulbound_item_should_be_minted() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let (owner, admin) = do_create_game();
+		do_create_collection(0, &admin);
+		for package in TEST_BUNDLE.clone() {
+			do_create_item(&admin, package.collection, package.item, package.amount);
+			assert_ok!(PalletGame::set_soulbound(
+				RuntimeOrigin::signed(admin.clone()),
+				package.collection,
+				package.item,
+				true
+			));
+		}
+
+		// the supply of soulbound items can still be reserved for minting
+		assert_ok!(PalletGame::create_dynamic_pool(
+			RuntimeOrigin::signed(owner.clone()),
+			TEST_TABLE.clone().to_vec(),
+			admin.clone(),
+			default_mint_config(),
+			None,
+		));
+		for package in TEST_BUNDLE.clone() {
+			assert_eq!(
+				ReservedBalanceOf::<Test>::get((&owner, package.collection, package.item)),
+				package.amount
+			);
+		}
+	})
+}
+
+#[test]
+pub fn soulbound_item_should_not_be_pooled_by_players() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let (player, _, admin) = create_account_with_item(TEST_BUNDLE);
+		assert_ok!(PalletGame::set_soulbound(
+			RuntimeOrigin::signed(admin.clone()),
+			0,
+			0,
+			true
+		));
+
+		assert_err_ignore_postinfo!(
+			PalletGame::create_dynamic_pool(
+				RuntimeOrigin::signed(player.clone()),
+				TEST_TABLE.clone().to_vec(),
+				player.clone(),
+				default_mint_config(),
+				None,
+			),
+			Error::<Test>::SoulboundItem
+		);
+	})
+}
+
+#[test]
+pub fn approve_operator_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
+		let operator = new_account(1, 1000 * unit(GAKI));
+
+		assert_ok!(PalletGame::approve_operator(
+			RuntimeOrigin::signed(player.clone()),
+			operator.clone(),
+			Some(0),
+			Some(10),
+		));
+		assert_eq!(
+			OperatorApprovalOf::<Test>::get((&player, &operator, Some(0))),
+			Some(Some(10))
+		);
+		System::assert_last_event(RuntimeEvent::PalletGame(crate::Event::OperatorApproved {
+			who: player.clone(),
+			operator: operator.clone(),
+			maybe_collection: Some(0),
+			maybe_deadline: Some(10),
+		}));
+
+		assert_ok!(PalletGame::revoke_operator(
+			RuntimeOrigin::signed(player.clone()),
+			operator.clone(),
+			Some(0),
+		));
+		assert_eq!(
+			OperatorApprovalOf::<Test>::get((&player, &operator, Some(0))),
+			None
+		);
+		assert_err!(
+			PalletGame::revoke_operator(RuntimeOrigin::signed(player.clone()), operator, Some(0)),
+			Error::<Test>::UnknownApproval
+		);
+	})
+}
+
+#[test]
+pub fn transfer_from_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
+		let operator = new_account(1, 1000 * unit(GAKI));
+		let dest = new_account(2, 1000 * unit(GAKI));
+
+		assert_ok!(PalletGame::approve_operator(
+			RuntimeOrigin::signed(player.clone()),
+			operator.clone(),
+			None,
+			None,
+		));
+		assert_ok!(PalletGame::transfer_from(
+			RuntimeOrigin::signed(operator.clone()),
+			player.clone(),
+			0,
+			0,
+			dest.clone(),
+			4
+		));
+
+		assert_eq!(ItemBalanceOf::<Test>::get((&player, 0, 0)), 6);
+		assert_eq!(ItemBalanceOf::<Test>::get((&dest, 0, 0)), 4);
+		System::assert_last_event(RuntimeEvent::PalletGame(crate::Event::Transferred {
+			from: player,
+			collection: 0,
+			item: 0,
+			dest,
+			amount: 4,
+		}));
+	})
+}
+
+#[test]
+pub fn transfer_from_should_fails() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let (player, _, _) = create_account_with_item(TEST_BUNDLE);
+		let operator = new_account(1, 1000 * unit(GAKI));
+		let dest = new_account(2, 1000 * unit(GAKI));
+
+		assert_err!(
+			PalletGame::transfer_from(
+				RuntimeOrigin::signed(operator.clone()),
+				player.clone(),
+				0,
+				0,
+				dest.clone(),
+				1
+			),
+			Error::<Test>::NoPermission
+		);
+
+		// approved for another collection only
+		assert_ok!(PalletGame::approve_operator(
+			RuntimeOrigin::signed(player.clone()),
+			operator.clone(),
+			Some(1),
+			None,
+		));
+		assert_err!(
+			PalletGame::transfer_from(
+				RuntimeOrigin::signed(operator.clone()),
+				player.clone(),
+				0,
+				0,
+				dest.clone(),
+				1
+			),
+			Error::<Test>::NoPermission
+		);
+
+		assert_err!(
+			PalletGame::approve_operator(
+				RuntimeOrigin::signed(player.clone()),
+				operator.clone(),
+				Some(0),
+				Some(0),
+			),
+			Error::<Test>::ApprovalExpired
+		);
+		assert_ok!(PalletGame::approve_operator(
+			RuntimeOrigin::signed(player.clone()),
+			operator.clone(),
+			Some(0),
+			Some(5),
+		));
+		run_to_block(6);
+		assert_err!(
+			PalletGame::transfer_from(
+				RuntimeOrigin::signed(operator.clone()),
+				player.clone(),
+				0,
+				0,
+				dest.clone(),
+				1
+			),
+			Error::<Test>::ApprovalExpired
+		);
+	})
+}
+
+#[test]
+pub fn join_match_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let (player, _, admin) = create_account_with_item(TEST_BUNDLE);
+		let game = NextGameId::<Test>::get().unwrap() - 1;
+		let server = new_account(5, 1000 * unit(GAKI));
+		let before_balance = Balances::free_balance(&admin);
+
+		assert_ok!(PalletGame::create_match(
+			RuntimeOrigin::signed(admin.clone()),
+			game,
+			unit(GAKI),
+			Some(server.clone()),
+			10
+		));
+		assert_eq!(
+			Balances::free_balance(&admin),
+			before_balance - BUNDLE_DEPOSIT_VAL
+		);
+
+		let items = vec![Package::new(0, 0, 2), Package::new(0, 1, 1)];
+		assert_ok!(PalletGame::join_match(
+			RuntimeOrigin::signed(player.clone()),
+			0,
+			items.clone()
+		));
+
+		assert_eq!(Balances::reserved_balance(&player), unit(GAKI));
+		assert_eq!(ItemBalanceOf::<Test>::get((&player, 0, 0)), 8);
+		assert_eq!(ReservedBalanceOf::<Test>::get((&player, 0, 0)), 2);
+		assert_eq!(ReservedBalanceOf::<Test>::get((&player, 0, 1)), 1);
+		assert_eq!(MatchOf::<Test>::get(0).unwrap().players.len(), 1);
+		System::assert_last_event(RuntimeEvent::PalletGame(crate::Event::MatchJoined {
+			match_id: 0,
+			who: player,
+			items,
+		}));
+	})
+}
+
+#[test]
+pub fn join_match_should_fails() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let (player, _, admin) = create_account_with_item(TEST_BUNDLE);
+		let game = NextGameId::<Test>::get().unwrap() - 1;
+
+		assert_err!(
+			PalletGame::create_match(
+				RuntimeOrigin::signed(player.clone()),
+				game,
+				unit(GAKI),
+				None,
+				10
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(PalletGame::create_match(
+			RuntimeOrigin::signed(admin.clone()),
+			game,
+			unit(GAKI),
+			None,
+			10
+		));
+
+		// items of another game
+		let other_admin = new_account(6, 1000 * unit(GAKI));
+		assert_ok!(PalletGame::create_game(
+			RuntimeOrigin::signed(other_admin.clone()),
+			other_admin.clone()
+		));
+		do_create_collection(game + 1, &other_admin);
+		do_create_item(&other_admin, 1, 0, 1000);
+		assert_err!(
+			PalletGame::join_match(
+				RuntimeOrigin::signed(other_admin.clone()),
+				0,
+				vec![Package::new(1, 0, 1)]
+			),
+			Error::<Test>::IncorrectCollection
+		);
+
+		assert_ok!(PalletGame::join_match(
+			RuntimeOrigin::signed(player.clone()),
+			0,
+			vec![Package::new(0, 0, 1)]
+		));
+		assert_err!(
+			PalletGame::join_match(
+				RuntimeOrigin::signed(player.clone()),
+				0,
+				vec![Package::new(0, 0, 1)]
+			),
+			Error::<Test>::AlreadyJoined
+		);
+
+		run_to_block(10);
+		assert_err!(
+			PalletGame::join_match(RuntimeOrigin::signed(admin.clone()), 0, vec![]),
+			Error::<Test>::MatchEnded
+		);
+	})
+}
+
+#[test]
+pub fn settle_match_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let (player, owner, admin) = create_account_with_item(TEST_BUNDLE);
+		let game = NextGameId::<Test>::get().unwrap() - 1;
+		let server = new_account(5, 1000 * unit(GAKI));
+		let before_balance = Balances::free_balance(&admin);
+
+		let opponent = new_account(6, 1000 * unit(GAKI));
+		let spectator = new_account(7, 1000 * unit(GAKI));
+		for account in [&opponent, &spectator] {
+			assert_ok!(PalletGame::transfer(
+				RuntimeOrigin::signed(owner.clone()),
+				0,
+				0,
+				account.clone(),
+				10
 			));
-			assert_ok!(PalletGame::cancel_trade(
-				RuntimeOrigin::signed(player.clone()),
-				1,
-				TradeType::SetBuy,
+		}
+
+		assert_ok!(PalletGame::create_match(
+			RuntimeOrigin::signed(admin.clone()),
+			game,
+			unit(GAKI),
+			Some(server.clone()),
+			10
+		));
+		for account in [&player, &opponent, &spectator] {
+			assert_ok!(PalletGame::join_match(
+				RuntimeOrigin::signed(account.clone()),
+				0,
+				vec![Package::new(0, 0, 2)]
 			));
 		}
+		let player_balance = Balances::free_balance(&player);
+		let opponent_balance = Balances::free_balance(&opponent);
+
+		// the winner takes the opponent items, the spectator items are burned
+		let result = MatchResult {
+			winners: vec![(player.clone(), 6_000), (spectator.clone(), 4_000)],
+			losers: vec![
+				(opponent.clone(), Some(player.clone())),
+				(spectator.clone(), None),
+			],
+		};
+		assert_err!(
+			PalletGame::settle_match(RuntimeOrigin::signed(player.clone()), 0, result.clone()),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(PalletGame::settle_match(
+			RuntimeOrigin::signed(server.clone()),
+			0,
+			result.clone()
+		));
+
+		// 3 stakes are shared by 60% and 40%
+		assert_eq!(
+			Balances::free_balance(&player),
+			player_balance + unit(GAKI) * 3 * 6 / 10
+		);
+		assert_eq!(Balances::free_balance(&opponent), opponent_balance);
+		assert_eq!(Balances::reserved_balance(&opponent), 0);
+
+		assert_eq!(ItemBalanceOf::<Test>::get((&player, 0, 0)), 12);
+		assert_eq!(ItemBalanceOf::<Test>::get((&opponent, 0, 0)), 8);
+		assert_eq!(ItemBalanceOf::<Test>::get((&spectator, 0, 0)), 8);
+		assert_eq!(ReservedBalanceOf::<Test>::get((&player, 0, 0)), 0);
+		assert_eq!(SupplyOf::<Test>::get(0, 0).unwrap().unwrap(), 998);
+
+		assert_eq!(MatchOf::<Test>::get(0), None);
+		assert_eq!(Balances::free_balance(&admin), before_balance);
+		System::assert_last_event(RuntimeEvent::PalletGame(crate::Event::MatchSettled {
+			match_id: 0,
+			result,
+		}));
+	})
+}
+
+#[test]
+pub fn refund_match_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let (player, _, admin) = create_account_with_item(TEST_BUNDLE);
+		let game = NextGameId::<Test>::get().unwrap() - 1;
+		let before_balance = Balances::free_balance(&player);
+
+		assert_ok!(PalletGame::create_match(
+			RuntimeOrigin::signed(admin.clone()),
+			game,
+			unit(GAKI),
+			None,
+			10
+		));
+		assert_ok!(PalletGame::join_match(
+			RuntimeOrigin::signed(player.clone()),
+			0,
+			vec![Package::new(0, 0, 2)]
+		));
+
+		assert_err!(
+			PalletGame::refund_match(RuntimeOrigin::signed(player.clone()), 0),
+			Error::<Test>::MatchNotEnded
+		);
+
+		run_to_block(10);
+		assert_err!(
+			PalletGame::settle_match(
+				RuntimeOrigin::signed(admin.clone()),
+				0,
+				MatchResult {
+					winners: vec![],
+					losers: vec![],
+				}
+			),
+			Error::<Test>::MatchEnded
+		);
+		assert_ok!(PalletGame::refund_match(
+			RuntimeOrigin::signed(player.clone()),
+			0
+		));
+
+		assert_eq!(Balances::free_balance(&player), before_balance);
+		assert_eq!(ItemBalanceOf::<Test>::get((&player, 0, 0)), 10);
+		assert_eq!(ReservedBalanceOf::<Test>::get((&player, 0, 0)), 0);
+		assert_eq!(MatchOf::<Test>::get(0), None);
+		System::assert_last_event(RuntimeEvent::PalletGame(crate::Event::MatchRefunded {
+			match_id: 0,
+		}));
+	})
+}
+
+#[test]
+pub fn settle_match_should_use_payment_asset_of_match() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let (player, owner, admin) = create_account_with_item(TEST_BUNDLE);
+		let game = NextGameId::<Test>::get().unwrap() - 1;
+
+		// other trades keep their assets in the escrow account
+		let escrow = PalletGame::escrow_account();
+		create_payment_asset(&owner, &escrow, 1000 * unit(GAKI));
+
+		assert_ok!(PalletGame::create_match(
+			RuntimeOrigin::signed(admin.clone()),
+			game,
+			unit(GAKI),
+			None,
+			10
+		));
+		assert_ok!(PalletGame::join_match(
+			RuntimeOrigin::signed(player.clone()),
+			0,
+			vec![]
+		));
+		let player_balance = Balances::free_balance(&player);
+
+		assert_ok!(PalletGame::set_payment_asset(
+			RuntimeOrigin::signed(owner.clone()),
+			game,
+			Some(PAYMENT_ASSET),
+		));
+		assert_ok!(PalletGame::settle_match(
+			RuntimeOrigin::signed(admin.clone()),
+			0,
+			MatchResult {
+				winners: vec![(player.clone(), 10_000)],
+				losers: vec![],
+			}
+		));
+
+		assert_eq!(Balances::free_balance(&player), player_balance + unit(GAKI));
+		assert_eq!(Assets::balance(PAYMENT_ASSET, &escrow), 1000 * unit(GAKI));
+		assert_eq!(Assets::balance(PAYMENT_ASSET, &player), 0);
+	})
+}
+
+#[test]
+pub fn set_treasury_routing_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (owner, _) = do_create_game();
+		let game = NextGameId::<Test>::get().unwrap() - 1;
+
+		assert_ok!(PalletGame::set_treasury_routing(
+			RuntimeOrigin::signed(owner.clone()),
+			game,
+			true,
+		));
+
+		assert_eq!(Game::<Test>::get(game).unwrap().treasury_routing, true);
+		System::assert_last_event(RuntimeEvent::PalletGame(crate::Event::TreasuryRoutingSet {
+			game,
+			enabled: true,
+		}));
+	})
+}
+
+#[test]
+pub fn set_treasury_routing_should_fails() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (owner, admin) = do_create_game();
+		let game = NextGameId::<Test>::get().unwrap() - 1;
+
+		assert_err!(
+			PalletGame::set_treasury_routing(RuntimeOrigin::signed(admin.clone()), game, true),
+			Error::<Test>::NoPermission
+		);
+
+		assert_err!(
+			PalletGame::set_treasury_routing(RuntimeOrigin::signed(owner.clone()), game + 1, true),
+			Error::<Test>::UnknownGame
+		);
+	})
+}
+
+#[test]
+pub fn mint_to_treasury_should_works() {
+	new_test_ext().execute_with(|| {
+		let block = 10_u64;
+		let execute_block = block + MIN_INTERVAL_VAL as u64;
+
+		run_to_block(block);
+		let (owner, _) = do_all_create_stable_pool(default_mint_config());
+		let game = NextGameId::<Test>::get().unwrap() - 1;
+		assert_eq!(PoolTreasuryOf::<Test>::get(0), Some(game));
+		assert_ok!(PalletGame::set_treasury_routing(
+			RuntimeOrigin::signed(owner.clone()),
+			game,
+			true,
+		));
+
+		let player = new_account(2, 1000_000 * unit(GAKI));
+		let amount = 10;
+		let owner_balance = Balances::free_balance(owner.clone());
+		assert_ok!(PalletGame::request_mint(
+			RuntimeOrigin::signed(player.clone()),
+			0,
+			player.clone(),
+			amount,
+			None,
+		));
+
+		run_to_block(execute_block);
+		assert_eq!(Balances::free_balance(owner.clone()), owner_balance);
+		assert_eq!(
+			Balances::free_balance(PalletGame::game_account(&game)),
+			default_mint_config().price * amount as u128
+		);
+	})
+}
+
+#[test]
+pub fn mint_from_player_pool_should_not_route_to_treasury() {
+	new_test_ext().execute_with(|| {
+		let block = 10_u64;
+		let execute_block = block + MIN_INTERVAL_VAL as u64;
+
+		run_to_block(block);
+		let (seller, owner, _) = create_account_with_item(TEST_BUNDLE);
+		let game = NextGameId::<Test>::get().unwrap() - 1;
+		assert_ok!(PalletGame::set_treasury_routing(
+			RuntimeOrigin::signed(owner.clone()),
+			game,
+			true,
+		));
+
+		// a pool created by a player of the game
+		assert_ok!(PalletGame::create_dynamic_pool(
+			RuntimeOrigin::signed(seller.clone()),
+			TEST_TABLE.clone().to_vec(),
+			seller.clone(),
+			default_mint_config(),
+			None,
+		));
+		assert_eq!(PoolTreasuryOf::<Test>::get(0), None);
+
+		let player = new_account(4, 1000_000 * unit(GAKI));
+		let amount = 1;
+		let seller_balance = Balances::free_balance(seller.clone());
+		assert_ok!(PalletGame::request_mint(
+			RuntimeOrigin::signed(player.clone()),
+			0,
+			player.clone(),
+			amount,
+			None,
+		));
+
+		run_to_block(execute_block);
+		assert_eq!(
+			Balances::free_balance(seller.clone()),
+			seller_balance + default_mint_config().price * amount as u128
+		);
+		assert_eq!(Balances::free_balance(PalletGame::game_account(&game)), 0);
+	})
+}
+
+#[test]
+pub fn craft_to_treasury_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let (player, owner, admin) = create_account_with_item(TEST_BUNDLE);
+		let game = NextGameId::<Test>::get().unwrap() - 1;
+		assert_ok!(PalletGame::set_treasury_routing(
+			RuntimeOrigin::signed(owner.clone()),
+			game,
+			true,
+		));
+
+		let fee = unit(GAKI);
+		do_transfer_item(&owner, &admin, 0, 2, 2);
+		assert_ok!(PalletGame::create_recipe(
+			RuntimeOrigin::signed(admin.clone()),
+			game,
+			vec![Package::new(0, 0, 1)],
+			Some(fee),
+			vec![Package::new(0, 2, 1)],
+			Some(2),
+		));
+
+		let admin_before_balance = Balances::free_balance(&admin);
+		assert_ok!(PalletGame::craft(
+			RuntimeOrigin::signed(player.clone()),
+			0,
+			2
+		));
+
+		assert_eq!(Balances::free_balance(&admin), admin_before_balance);
+		assert_eq!(
+			Balances::free_balance(PalletGame::game_account(&game)),
+			fee * 2
+		);
+	})
+}
+
+#[test]
+pub fn craft_from_designer_recipe_should_not_route_to_treasury() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let (player, owner, _) = create_account_with_item(TEST_BUNDLE);
+		let game = NextGameId::<Test>::get().unwrap() - 1;
+		assert_ok!(PalletGame::set_treasury_routing(
+			RuntimeOrigin::signed(owner.clone()),
+			game,
+			true,
+		));
+
+		// a recipe created by a designer of the game
+		let designer = new_account(5, 1000 * unit(GAKI));
+		assert_ok!(PalletGame::grant_role(
+			RuntimeOrigin::signed(owner.clone()),
+			game,
+			designer.clone(),
+			GameRole::UpgradeDesigner,
+		));
+		let fee = unit(GAKI);
+		do_transfer_item(&owner, &designer, 0, 2, 2);
+		assert_ok!(PalletGame::create_recipe(
+			RuntimeOrigin::signed(designer.clone()),
+			game,
+			vec![Package::new(0, 0, 1)],
+			Some(fee),
+			vec![Package::new(0, 2, 1)],
+			Some(2),
+		));
+
+		let designer_before_balance = Balances::free_balance(&designer);
+		assert_ok!(PalletGame::craft(
+			RuntimeOrigin::signed(player.clone()),
+			0,
+			2
+		));
+
+		assert_eq!(
+			Balances::free_balance(&designer),
+			designer_before_balance + fee * 2
+		);
+		assert_eq!(Balances::free_balance(PalletGame::game_account(&game)), 0);
+	})
+}
+
+#[test]
+pub fn spend_treasury_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (owner, _) = do_create_game();
+		let game = NextGameId::<Test>::get().unwrap() - 1;
+		let treasury = PalletGame::game_account(&game);
+		make_deposit(&treasury, 100 * unit(GAKI));
+
+		let dest = new_account(5, 1000 * unit(GAKI));
+		assert_ok!(PalletGame::spend_treasury(
+			RuntimeOrigin::signed(owner.clone()),
+			game,
+			None,
+			dest.clone(),
+			40 * unit(GAKI),
+		));
+
+		assert_eq!(Balances::free_balance(&treasury), 60 * unit(GAKI));
+		assert_eq!(Balances::free_balance(&dest), 1040 * unit(GAKI));
+		System::assert_last_event(RuntimeEvent::PalletGame(crate::Event::TreasurySpent {
+			game,
+			maybe_asset: None,
+			dest,
+			amount: 40 * unit(GAKI),
+		}));
+	})
+}
+
+#[test]
+pub fn spend_treasury_should_fails() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (owner, admin) = do_create_game();
+		let game = NextGameId::<Test>::get().unwrap() - 1;
+		make_deposit(&PalletGame::game_account(&game), 100 * unit(GAKI));
+
+		assert_err!(
+			PalletGame::spend_treasury(
+				RuntimeOrigin::signed(admin.clone()),
+				game,
+				None,
+				admin.clone(),
+				unit(GAKI),
+			),
+			Error::<Test>::NoPermission
+		);
+
+		assert_err!(
+			PalletGame::spend_treasury(
+				RuntimeOrigin::signed(owner.clone()),
+				game + 1,
+				None,
+				owner.clone(),
+				unit(GAKI),
+			),
+			Error::<Test>::UnknownGame
+		);
+	})
+}
+
+#[test]
+pub fn transfer_game_ownership_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (owner, admin) = do_create_game();
+		let game = NextGameId::<Test>::get().unwrap() - 1;
+		let new_owner = new_account(5, 1000 * unit(GAKI));
+		let owner_reserved = Balances::reserved_balance(&owner);
+		do_create_collection(game, &admin);
+		let collection_deposit = Balances::reserved_balance(&owner) - owner_reserved;
+
+		assert_ok!(PalletGame::set_accept_game_ownership(
+			RuntimeOrigin::signed(new_owner.clone()),
+			Some(game),
+		));
+		System::assert_last_event(RuntimeEvent::PalletGame(
+			crate::Event::GameOwnershipAcceptanceChanged {
+				who: new_owner.clone(),
+				maybe_game: Some(game),
+			},
+		));
+
+		assert_ok!(PalletGame::transfer_game_ownership(
+			RuntimeOrigin::signed(owner.clone()),
+			game,
+			new_owner.clone(),
+		));
+
+		assert_eq!(Game::<Test>::get(game).unwrap().owner, new_owner);
+		assert_eq!(GameAccount::<Test>::get(&owner, game), None);
+		assert_eq!(GameAccount::<Test>::get(&new_owner, game), Some(()));
+		assert_eq!(GameOwnershipAcceptance::<Test>::get(&new_owner), None);
+		assert_eq!(
+			Balances::reserved_balance(&owner),
+			owner_reserved - GAME_DEPOSIT_VAL
+		);
+		assert_eq!(
+			Balances::reserved_balance(&new_owner),
+			GAME_DEPOSIT_VAL + collection_deposit
+		);
+		// the game collections are transferred along
+		assert_eq!(
+			<Nfts as Inspect<sr25519::Public>>::collection_owner(&0),
+			Some(new_owner.clone())
+		);
+		System::assert_last_event(RuntimeEvent::PalletGame(
+			crate::Event::GameOwnershipTransferred {
+				game,
+				new_owner: new_owner.clone(),
+			},
+		));
 
-		// set price bundle
-		{
-			let player = funded_account_with_item(TEST_BUNDLE.clone());
-			assert_ok!(PalletGame::set_bundle(
-				RuntimeOrigin::signed(player.clone()),
-				TEST_BUNDLE.clone().to_vec(),
-				unit(GAKI),
-				None,
-				None,
-			));
+		// the previous owner lost the permissions
+		assert_err!(
+			PalletGame::set_trade_fee(RuntimeOrigin::signed(owner.clone()), game, 100, None),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(PalletGame::set_trade_fee(
+			RuntimeOrigin::signed(new_owner.clone()),
+			game,
+			100,
+			None
+		));
+	})
+}
 
-			assert_ok!(PalletGame::cancel_trade(
-				RuntimeOrigin::signed(player.clone()),
-				2,
-				TradeType::Bundle,
-			));
-		}
+#[test]
+pub fn transfer_game_ownership_should_fails() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
 
-		// wishlist
-		{
-			let player = new_account(0, 1000 * unit(GAKI));
-			assert_ok!(PalletGame::order_bundle(
-				RuntimeOrigin::signed(player.clone()),
-				TEST_BUNDLE.clone().to_vec(),
-				unit(GAKI),
-				None,
-				None,
-			));
-			assert_ok!(PalletGame::cancel_trade(
-				RuntimeOrigin::signed(player.clone()),
-				3,
-				TradeType::Wishlist,
-			));
-		}
+		let (owner, admin) = do_create_game();
+		let game = NextGameId::<Test>::get().unwrap() - 1;
+		let new_owner = new_account(5, 1000 * unit(GAKI));
 
-		// swap
-		{
-			let player = funded_account_with_item(TEST_BUNDLE.clone());
-			assert_ok!(PalletGame::create_swap(
-				RuntimeOrigin::signed(player.clone()),
-				TEST_BUNDLE.clone().to_vec(),
-				TEST_BUNDLE1.clone().to_vec(),
-				Some(unit(GAKI)),
-				None,
-				None,
-			));
+		// not accepted
+		assert_err!(
+			PalletGame::transfer_game_ownership(
+				RuntimeOrigin::signed(owner.clone()),
+				game,
+				new_owner.clone(),
+			),
+			Error::<Test>::UnknownAcceptance
+		);
 
-			assert_ok!(PalletGame::cancel_trade(
-				RuntimeOrigin::signed(player.clone()),
-				4,
-				TradeType::Swap,
-			));
-		}
+		assert_err!(
+			PalletGame::set_accept_game_ownership(
+				RuntimeOrigin::signed(new_owner.clone()),
+				Some(game + 1),
+			),
+			Error::<Test>::UnknownGame
+		);
 
-		// auction
-		{
-			let player = funded_account_with_item(TEST_BUNDLE.clone());
-			assert_ok!(PalletGame::set_auction(
-				RuntimeOrigin::signed(player.clone()),
-				TEST_BUNDLE.clone().to_vec(),
-				Some(unit(GAKI)),
-				Some(1),
-				1,
-			));
+		assert_ok!(PalletGame::set_accept_game_ownership(
+			RuntimeOrigin::signed(new_owner.clone()),
+			Some(game),
+		));
+		assert_err!(
+			PalletGame::transfer_game_ownership(
+				RuntimeOrigin::signed(admin.clone()),
+				game,
+				new_owner.clone(),
+			),
+			Error::<Test>::NoPermission
+		);
 
-			run_to_block(2);
-			assert_ok!(PalletGame::cancel_trade(
-				RuntimeOrigin::signed(player.clone()),
-				5,
-				TradeType::Auction,
-			));
-		}
+		// the game deposit is no longer reserved
+		Balances::unreserve(&owner, GAME_DEPOSIT_VAL);
+		assert_err!(
+			PalletGame::transfer_game_ownership(
+				RuntimeOrigin::signed(owner.clone()),
+				game,
+				new_owner.clone(),
+			),
+			Error::<Test>::InsufficientReservedBalance
+		);
+		assert_eq!(Balances::reserved_balance(&new_owner), 0);
+
+		// acceptance cleared
+		assert_ok!(PalletGame::set_accept_game_ownership(
+			RuntimeOrigin::signed(new_owner.clone()),
+			None,
+		));
+		assert_err!(
+			PalletGame::transfer_game_ownership(
+				RuntimeOrigin::signed(owner.clone()),
+				game,
+				new_owner.clone(),
+			),
+			Error::<Test>::UnknownAcceptance
+		);
 	})
 }
 
 #[test]
-fn create_stable_pool_should_works() {
+pub fn set_game_admin_should_works() {
 	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
 		let (owner, admin) = do_create_game();
-		do_create_collection(0, &admin);
+		let game = NextGameId::<Test>::get().unwrap() - 1;
+		let new_admin = new_account(5, 1000 * unit(GAKI));
+		do_create_collection(game, &admin);
+
+		assert_ok!(PalletGame::set_game_admin(
+			RuntimeOrigin::signed(owner.clone()),
+			game,
+			new_admin.clone(),
+		));
+
+		assert_eq!(Game::<Test>::get(game).unwrap().admin, new_admin);
+		assert_eq!(GameRoleOf::<Test>::get(game, &admin), None);
+		assert_eq!(
+			GameRoleOf::<Test>::get(game, &new_admin),
+			Some(CollectionRoles(
+				CollectionRole::Admin | CollectionRole::Freezer | CollectionRole::Issuer
+			))
+		);
+		System::assert_last_event(RuntimeEvent::PalletGame(crate::Event::GameAdminSet {
+			game,
+			admin: new_admin.clone(),
+		}));
+
+		// the previous admin lost the permissions
+		assert_err!(
+			PalletGame::set_game_metadata(
+				RuntimeOrigin::signed(admin.clone()),
+				bvec![0u8; 10],
+				game,
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_err!(
+			PalletGame::create_item(RuntimeOrigin::signed(admin.clone()), 0, 0, Some(10)),
+			Error::<Test>::NoPermission
+		);
+		do_create_item(&new_admin, 0, 0, 10);
+	})
+}
+
+#[test]
+pub fn set_game_admin_should_fails() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (owner, admin) = do_create_game();
+		let game = NextGameId::<Test>::get().unwrap() - 1;
+		let new_admin = new_account(5, 1000 * unit(GAKI));
+
+		assert_err!(
+			PalletGame::set_game_admin(
+				RuntimeOrigin::signed(admin.clone()),
+				game,
+				new_admin.clone()
+			),
+			Error::<Test>::NoPermission
+		);
+
+		assert_err!(
+			PalletGame::set_game_admin(
+				RuntimeOrigin::signed(owner.clone()),
+				game + 1,
+				new_admin.clone()
+			),
+			Error::<Test>::UnknownGame
+		);
+	})
+}
+
+#[test]
+pub fn grant_role_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (owner, admin) = do_create_game();
+		let game = NextGameId::<Test>::get().unwrap() - 1;
+		do_create_collection(game, &admin);
+		let minter = new_account(5, 1000 * unit(GAKI));
+
+		// no role
+		assert_err!(
+			PalletGame::create_item(RuntimeOrigin::signed(minter.clone()), 0, 0, Some(10)),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(PalletGame::grant_role(
+			RuntimeOrigin::signed(owner.clone()),
+			game,
+			minter.clone(),
+			GameRole::Minter,
+		));
+		assert_eq!(
+			GrantedRoleOf::<Test>::get((game, minter.clone(), GameRole::Minter)),
+			Some(())
+		);
+		System::assert_last_event(RuntimeEvent::PalletGame(crate::Event::RoleGranted {
+			game,
+			who: minter.clone(),
+			role: GameRole::Minter,
+		}));
 
 		assert_ok!(PalletGame::create_item(
-			RuntimeOrigin::signed(admin.clone()),
+			RuntimeOrigin::signed(minter.clone()),
 			0,
 			0,
-			None
+			Some(10)
 		));
-
-		assert_ok!(PalletGame::create_item(
-			RuntimeOrigin::signed(admin.clone()),
+		assert_ok!(PalletGame::add_supply(
+			RuntimeOrigin::signed(minter.clone()),
 			0,
-			1,
+			0,
+			10
+		));
+
+		// only the granted role is allowed
+		assert_err!(
+			PalletGame::set_trade_fee(RuntimeOrigin::signed(minter.clone()), game, 100, None),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(PalletGame::grant_role(
+			RuntimeOrigin::signed(owner.clone()),
+			game,
+			minter.clone(),
+			GameRole::TradeOperator,
+		));
+		assert_ok!(PalletGame::set_trade_fee(
+			RuntimeOrigin::signed(minter.clone()),
+			game,
+			100,
 			None
 		));
 
-		assert_ok!(PalletGame::create_item(
+		// the fee account and payment asset stay with the owner
+		assert_err!(
+			PalletGame::set_trade_fee(
+				RuntimeOrigin::signed(minter.clone()),
+				game,
+				100,
+				Some(minter.clone())
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_err!(
+			PalletGame::set_payment_asset(RuntimeOrigin::signed(minter.clone()), game, None),
+			Error::<Test>::NoPermission
+		);
+	})
+}
+
+#[test]
+pub fn grant_role_should_fails() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (owner, admin) = do_create_game();
+		let game = NextGameId::<Test>::get().unwrap() - 1;
+		let who = new_account(5, 1000 * unit(GAKI));
+
+		assert_err!(
+			PalletGame::grant_role(
+				RuntimeOrigin::signed(admin.clone()),
+				game,
+				who.clone(),
+				GameRole::Moderator,
+			),
+			Error::<Test>::NoPermission
+		);
+
+		assert_err!(
+			PalletGame::grant_role(
+				RuntimeOrigin::signed(owner.clone()),
+				game + 1,
+				who.clone(),
+				GameRole::Moderator,
+			),
+			Error::<Test>::UnknownGame
+		);
+	})
+}
+
+#[test]
+pub fn collection_role_should_be_scoped_to_its_game() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let (owner, admin) = do_create_game();
+		do_create_collection(0, &admin);
+
+		// the collection is added to another game
+		let other_owner = new_account(5, 10_000 * unit(GAKI));
+		assert_ok!(PalletGame::create_game(
+			RuntimeOrigin::signed(other_owner.clone()),
+			other_owner.clone()
+		));
+		assert_ok!(PalletGame::set_accept_adding(
 			RuntimeOrigin::signed(admin.clone()),
+			1,
+			0
+		));
+		assert_ok!(PalletGame::add_game_collection(
+			RuntimeOrigin::signed(other_owner.clone()),
+			1,
+			0
+		));
+		assert_eq!(GamesOf::<Test>::get(0), [0, 1].to_vec());
+		assert_eq!(CollectionGameOf::<Test>::get(0), Some(0));
+		assert_ok!(PalletGame::set_royalty(
+			RuntimeOrigin::signed(owner.clone()),
 			0,
-			2,
-			None
+			None,
+			owner.clone(),
+			50
 		));
 
-		let owner_balance = Balances::free_balance(owner.clone());
-		assert_ok!(PalletGame::create_stable_pool(
+		assert_ok!(PalletGame::grant_role(
+			RuntimeOrigin::signed(other_owner.clone()),
+			1,
+			other_owner.clone(),
+			GameRole::TradeOperator,
+		));
+		assert_err!(
+			PalletGame::set_royalty(
+				RuntimeOrigin::signed(other_owner.clone()),
+				0,
+				None,
+				owner.clone(),
+				100
+			),
+			Error::<Test>::NoPermission
+		);
+
+		// roles of the game that created the collection count
+		let operator = new_account(6, 1000 * unit(GAKI));
+		assert_ok!(PalletGame::grant_role(
 			RuntimeOrigin::signed(owner.clone()),
-			TEST_TABLE.clone().to_vec(),
-			admin.clone(),
-			default_mint_config(),
+			0,
+			operator.clone(),
+			GameRole::TradeOperator,
+		));
+		assert_ok!(PalletGame::set_royalty(
+			RuntimeOrigin::signed(operator.clone()),
+			0,
+			None,
+			owner.clone(),
+			100
 		));
 
-		assert_eq!(
-			Balances::free_balance(owner.clone()),
-			owner_balance - MINING_DEPOSIT_VAL
+		// the beneficiary stays with the owner
+		assert_err!(
+			PalletGame::set_royalty(
+				RuntimeOrigin::signed(operator.clone()),
+				0,
+				None,
+				operator.clone(),
+				100
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_err!(
+			PalletGame::clear_royalty(RuntimeOrigin::signed(operator.clone()), 0, None),
+			Error::<Test>::NoPermission
 		);
-		assert_eq!(LootTableOf::<Test>::get(0), TEST_TABLE.to_vec());
 	})
 }
 
 #[test]
-fn create_stable_pool_should_fails() {
+pub fn create_recipe_should_be_scoped_to_the_output_game() {
 	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
 		let (owner, admin) = do_create_game();
 		do_create_collection(0, &admin);
-
 		assert_ok!(PalletGame::create_item(
 			RuntimeOrigin::signed(admin.clone()),
 			0,
@@ -1721,260 +7068,226 @@ fn create_stable_pool_should_fails() {
 			None
 		));
 
-		assert_ok!(PalletGame::create_item(
+		// the collection is added to another game
+		let other_owner = new_account(5, 10_000 * unit(GAKI));
+		assert_ok!(PalletGame::create_game(
+			RuntimeOrigin::signed(other_owner.clone()),
+			other_owner.clone()
+		));
+		assert_ok!(PalletGame::set_accept_adding(
 			RuntimeOrigin::signed(admin.clone()),
-			0,
 			1,
-			None
+			0
+		));
+		assert_ok!(PalletGame::add_game_collection(
+			RuntimeOrigin::signed(other_owner.clone()),
+			1,
+			0
 		));
 
-		assert_err!(
-			PalletGame::create_stable_pool(
-				RuntimeOrigin::signed(admin.clone()),
-				TEST_TABLE.clone()[0..1].to_vec(),
-				admin.clone(),
-				default_mint_config(),
+		// a designer of the other game could not produce the items
+		let designer = new_account(6, 1000 * unit(GAKI));
+		assert_ok!(PalletGame::grant_role(
+			RuntimeOrigin::signed(other_owner.clone()),
+			1,
+			designer.clone(),
+			GameRole::UpgradeDesigner,
+		));
+		assert_err_ignore_postinfo!(
+			PalletGame::create_recipe(
+				RuntimeOrigin::signed(designer.clone()),
+				1,
+				vec![Package::new(0, 0, 1)],
+				None,
+				vec![Package::new(0, 0, 1)],
+				None,
 			),
 			Error::<Test>::NoPermission
 		);
 
-		assert_ok!(PalletGame::create_item(
-			RuntimeOrigin::signed(admin.clone()),
+		// a designer of the game that created the collection could
+		assert_ok!(PalletGame::grant_role(
+			RuntimeOrigin::signed(owner.clone()),
 			0,
-			2,
-			Some(1000)
+			designer.clone(),
+			GameRole::UpgradeDesigner,
+		));
+		assert_ok!(PalletGame::create_recipe(
+			RuntimeOrigin::signed(designer.clone()),
+			1,
+			vec![Package::new(0, 0, 1)],
+			None,
+			vec![Package::new(0, 0, 1)],
+			None,
 		));
-
-		assert_err!(
-			PalletGame::create_stable_pool(
-				RuntimeOrigin::signed(owner.clone()),
-				TEST_TABLE.clone().to_vec(),
-				admin.clone(),
-				default_mint_config(),
-			),
-			Error::<Test>::NotInfiniteSupply
-		);
 	})
 }
 
 #[test]
-fn create_dynamic_pool_should_works() {
+pub fn revoke_role_should_works() {
 	new_test_ext().execute_with(|| {
 		run_to_block(1);
-		let (owner, admin) = do_create_game();
-		do_create_collection(0, &admin);
-
-		for package in TEST_BUNDLE.clone() {
-			do_create_item(&admin, package.collection, package.item, package.amount);
-		}
 
-		let owner_balance = Balances::free_balance(owner.clone());
+		let (owner, _) = do_create_game();
+		let game = NextGameId::<Test>::get().unwrap() - 1;
+		let moderator = new_account(5, 1000 * unit(GAKI));
 
-		assert_ok!(PalletGame::create_dynamic_pool(
+		assert_ok!(PalletGame::grant_role(
 			RuntimeOrigin::signed(owner.clone()),
-			TEST_TABLE.clone().to_vec(),
-			admin.clone(),
-			default_mint_config(),
+			game,
+			moderator.clone(),
+			GameRole::Moderator,
+		));
+		assert_ok!(PalletGame::set_game_metadata(
+			RuntimeOrigin::signed(moderator.clone()),
+			bvec![0u8; 10],
+			game,
 		));
 
+		assert_ok!(PalletGame::revoke_role(
+			RuntimeOrigin::signed(owner.clone()),
+			game,
+			moderator.clone(),
+			GameRole::Moderator,
+		));
 		assert_eq!(
-			Balances::free_balance(owner.clone()),
-			owner_balance - MINING_DEPOSIT_VAL
+			GrantedRoleOf::<Test>::get((game, moderator.clone(), GameRole::Moderator)),
+			None
 		);
-		assert_eq!(LootTableOf::<Test>::get(0).to_vec(), TEST_TABLE.clone());
-
-		for package in TEST_BUNDLE.clone() {
-			assert_eq!(
-				ItemBalanceOf::<Test>::get((owner.clone(), package.collection, package.item)),
-				0
-			);
+		System::assert_last_event(RuntimeEvent::PalletGame(crate::Event::RoleRevoked {
+			game,
+			who: moderator.clone(),
+			role: GameRole::Moderator,
+		}));
 
-			assert_eq!(
-				ReservedBalanceOf::<Test>::get((owner.clone(), package.collection, package.item)),
-				package.amount
-			);
-		}
+		assert_err!(
+			PalletGame::set_game_metadata(
+				RuntimeOrigin::signed(moderator.clone()),
+				bvec![0u8; 10],
+				game,
+			),
+			Error::<Test>::NoPermission
+		);
 	})
 }
 
 #[test]
-fn request_mint_should_works() {
+pub fn revoke_role_should_fails() {
 	new_test_ext().execute_with(|| {
 		run_to_block(1);
-		let (_, _) = do_all_create_stable_pool(default_mint_config());
-		let player = new_account(2, 1000_000 * unit(GAKI));
-
-		let amount = 10;
-		let block: u64 = (1 + MIN_INTERVAL_VAL) as u64;
-
-		assert_ok!(PalletGame::request_mint(
-			RuntimeOrigin::signed(player.clone()),
-			0,
-			player.clone(),
-			amount,
-		));
 
-		let request = MintRequest {
-			miner: player.clone(),
-			pool: 0,
-			target: player.clone(),
-			amount,
-			mining_fee: default_mint_config().price,
-			miner_reserve: default_mint_config().price * amount as u128,
-			block_number: block,
-		};
+		let (owner, admin) = do_create_game();
+		let game = NextGameId::<Test>::get().unwrap() - 1;
+		let who = new_account(5, 1000 * unit(GAKI));
 
-		assert_eq!(MintRequestOf::<Test>::get(block).to_vec(), [request]);
+		assert_err!(
+			PalletGame::revoke_role(
+				RuntimeOrigin::signed(owner.clone()),
+				game,
+				who.clone(),
+				GameRole::Moderator,
+			),
+			Error::<Test>::UnknownRole
+		);
 
-		assert_eq!(
-			Balances::reserved_balance(player.clone()),
-			(default_mint_config().price * amount as u128 as u128)
+		assert_ok!(PalletGame::grant_role(
+			RuntimeOrigin::signed(owner.clone()),
+			game,
+			who.clone(),
+			GameRole::Moderator,
+		));
+		assert_err!(
+			PalletGame::revoke_role(
+				RuntimeOrigin::signed(admin.clone()),
+				game,
+				who.clone(),
+				GameRole::Moderator,
+			),
+			Error::<Test>::NoPermission
 		);
 	})
 }
 
 #[test]
-fn execute_mint_stable_pool_should_works() {
+pub fn destroy_game_should_remove_granted_roles() {
 	new_test_ext().execute_with(|| {
 		run_to_block(1);
-		let (owner, _) = do_all_create_stable_pool(default_mint_config());
-		let player = new_account(2, 1000_000 * unit(GAKI));
-
-		let amount = 10;
-		let block: u64 = (1 + MIN_INTERVAL_VAL) as u64;
 
-		assert_ok!(PalletGame::request_mint(
-			RuntimeOrigin::signed(player.clone()),
-			0,
-			player.clone(),
-			amount,
+		let (owner, admin) = do_create_game();
+		let game = NextGameId::<Test>::get().unwrap() - 1;
+		let who = new_account(5, 1000 * unit(GAKI));
+		assert_ok!(PalletGame::grant_role(
+			RuntimeOrigin::signed(owner.clone()),
+			game,
+			who.clone(),
+			GameRole::PoolManager,
 		));
 
-		let request = MintRequest {
-			miner: player.clone(),
-			pool: 0,
-			target: player.clone(),
-			amount,
-			mining_fee: default_mint_config().price,
-			miner_reserve: default_mint_config().price * amount as u128,
-			block_number: block,
-		};
-
-		let owner_balance = Balances::free_balance(owner.clone());
-
-		assert_ok!(PalletGame::processing_mint_request(request));
-
-		let mut nfts = 0;
-		for package in TEST_BUNDLE.clone() {
-			nfts += ItemBalanceOf::<Test>::get((player.clone(), package.collection, package.item));
-		}
-		assert_eq!(nfts, amount);
-
-		assert_eq!(Balances::reserved_balance(player.clone()), 0);
+		let witness = Game::<Test>::get(game).unwrap().destroy_witness();
+		assert_ok!(PalletGame::start_game_destroy(
+			RuntimeOrigin::signed(owner.clone()),
+			game
+		));
+		assert_ok!(PalletGame::destroy_game_roles(
+			RuntimeOrigin::signed(admin.clone()),
+			game
+		));
 		assert_eq!(
-			Balances::free_balance(owner.clone()),
-			owner_balance + (default_mint_config().price * amount as u128 as u128)
+			GrantedRoleOf::<Test>::get((game, who.clone(), GameRole::PoolManager)),
+			None
 		);
+
+		assert_ok!(PalletGame::finish_game_destroy(
+			RuntimeOrigin::signed(admin.clone()),
+			game,
+			witness
+		));
 	})
 }
 
 #[test]
-fn execute_mint_dynamic_pool_should_works() {
+pub fn migrate_to_v1_should_count_game_collections() {
 	new_test_ext().execute_with(|| {
 		run_to_block(1);
-		let (owner, _) = do_all_create_dynamic_pool(default_mint_config());
-		let player = new_account(2, 1000_000 * unit(GAKI));
-
-		let amount = 10;
-		let block: u64 = (1 + MIN_INTERVAL_VAL) as u64;
-
-		for _ in TEST_BUNDLE.clone() {
-			assert_ok!(PalletGame::request_mint(
-				RuntimeOrigin::signed(player.clone()),
-				0,
-				player.clone(),
-				amount,
-			));
-		}
+		let (owner, admin) = do_create_game();
+		do_create_collection(0, &admin);
+		do_create_collection(0, &admin);
 
-		let request = MintRequest {
-			miner: player.clone(),
-			pool: 0,
-			target: player.clone(),
-			amount,
-			mining_fee: default_mint_config().price,
-			miner_reserve: default_mint_config().price * amount as u128,
-			block_number: block,
-		};
+		// games created before v1 never counted their collections
+		frame_support::storage::unhashed::put(
+			&Game::<Test>::hashed_key_for(0),
+			&(owner.clone(), GAME_DEPOSIT_VAL, 0u32, admin.clone()),
+		);
+		// nor the game that created each collection
+		CollectionGameOf::<Test>::remove(0);
+		CollectionGameOf::<Test>::remove(1);
+		StorageVersion::new(0).put::<PalletGame>();
 
-		let owner_balance = Balances::free_balance(owner.clone());
-		for _ in TEST_BUNDLE.clone() {
-			assert_ok!(PalletGame::processing_mint_request(request.clone()));
-		}
-		for pack in TEST_BUNDLE.clone() {
-			assert_eq!(
-				ItemBalanceOf::<Test>::get((player.clone(), pack.collection, pack.item)),
-				amount
-			);
-			assert_eq!(
-				ReservedBalanceOf::<Test>::get((owner.clone(), pack.collection, pack.item)),
-				pack.amount - amount
-			);
-		}
+		migration::v1::MigrateToV1::<Test>::on_runtime_upgrade();
 
-		assert_eq!(Balances::reserved_balance(player.clone()), 0);
-		assert_eq!(
-			Balances::free_balance(owner.clone()),
-			owner_balance +
-				(default_mint_config().price * amount as u128 * TEST_BUNDLE.len() as u128)
-		);
+		assert_eq!(PalletGame::on_chain_storage_version(), 1);
+		let game = Game::<Test>::get(0).unwrap();
+		assert_eq!(game.owner, owner);
+		assert_eq!(game.admin, admin);
+		assert_eq!(game.collections, 2);
+		assert_eq!(game.trade_fee, 0);
+		assert_eq!(game.payment_asset, None);
+		assert_eq!(CollectionGameOf::<Test>::get(0), Some(0));
+		assert_eq!(CollectionGameOf::<Test>::get(1), Some(0));
 	})
 }
 
 #[test]
-fn mint_flow_should_works() {
+pub fn pay_reserved_should_fails_on_short_reserve() {
 	new_test_ext().execute_with(|| {
-		let block = 10_u64;
-		let execute_block = block + MIN_INTERVAL_VAL as u64;
-
-		run_to_block(block);
-		let (owner, _) = do_all_create_stable_pool(default_mint_config());
-		let player = new_account(2, 1000_000 * unit(GAKI));
-
-		let amount = 10;
-
-		let owner_balance = Balances::free_balance(owner.clone());
-		assert_ok!(PalletGame::request_mint(
-			RuntimeOrigin::signed(player.clone()),
-			0,
-			player.clone(),
-			amount,
-		));
-
-		fn count_nft(who: &sr25519::Public) -> u32 {
-			let mut nfts = 0;
-			for package in TEST_BUNDLE.clone() {
-				nfts += ItemBalanceOf::<Test>::get((who.clone(), package.collection, package.item));
-			}
-			nfts
-		}
-
-		for b in block..execute_block {
-			// before processing mint request
-			run_to_block(b);
-			assert_eq!(count_nft(&player), 0);
-			assert_eq!(Balances::free_balance(owner.clone()), owner_balance);
-		}
+		run_to_block(1);
+		let from = new_account(0, 1000 * unit(GAKI));
+		let to = new_account(1, 1000 * unit(GAKI));
+		assert_ok!(Balances::reserve(&from, 10 * unit(GAKI)));
 
-		// after processing mint request
-		run_to_block(execute_block);
-		assert_eq!(count_nft(&player), amount);
-		assert_eq!(
-			Balances::free_balance(owner.clone()),
-			owner_balance + (default_mint_config().price * amount as u128 as u128)
+		assert_err!(
+			PalletGame::pay_reserved(None, &from, &to, 20 * unit(GAKI)),
+			Error::<Test>::InsufficientReservedBalance
 		);
-
-		run_to_block(execute_block + 1);
-		assert_eq!(MintRequestOf::<Test>::get(execute_block).is_empty(), true);
 	})
 }
@@ -1,8 +1,11 @@
 use crate::*;
-use frame_support::{pallet_prelude::*, traits::BalanceStatus};
+use frame_support::pallet_prelude::*;
 use frame_system::pallet_prelude::BlockNumberFor;
 use gafi_support::game::{Auction, Bundle};
-use sp_runtime::Saturating;
+use sp_runtime::{
+	traits::{One, SaturatedConversion, Zero},
+	Perbill, Saturating,
+};
 
 impl<T: Config<I>, I: 'static>
 	Auction<
@@ -56,6 +59,7 @@ impl<T: Config<I>, I: 'static>
 				duration,
 			},
 		);
+		Self::schedule_expiry(trade, TradeType::Auction, start.saturating_add(duration));
 
 		Self::deposit_event(Event::<T, I>::AuctionSet {
 			trade: *trade,
@@ -90,13 +94,14 @@ impl<T: Config<I>, I: 'static>
 				ensure!(bid >= price, Error::<T, I>::BidTooLow);
 			}
 			// update winner
+			let maybe_asset = TradeAssetOf::<T, I>::get(trade);
 			if let Some(highest_bid) = HighestBidOf::<T, I>::get(trade) {
 				ensure!(bid > highest_bid.1, Error::<T, I>::BidTooLow);
-				<T as Config<I>>::Currency::unreserve(&highest_bid.0, highest_bid.1);
+				Self::unreserve_payment(maybe_asset, &highest_bid.0, highest_bid.1)?;
 			}
 
 			HighestBidOf::<T, I>::insert(trade, (who, bid));
-			<T as Config<I>>::Currency::reserve(&who, bid)?;
+			Self::reserve_payment(maybe_asset, who, bid)?;
 
 			Self::deposit_event(Event::<T, I>::Bid {
 				trade: *trade,
@@ -119,11 +124,12 @@ impl<T: Config<I>, I: 'static>
 			let maybe_bid = HighestBidOf::<T, I>::get(trade);
 			if let Some(highest_bid) = maybe_bid.clone() {
 				if let Some(auction) = AuctionConfigOf::<T, I>::get(trade) {
-					<T as pallet::Config<I>>::Currency::repatriate_reserved(
+					Self::repatriate_price(
+						trade,
 						&highest_bid.0,
 						&auction.owner,
+						&BundleOf::<T, I>::get(trade),
 						highest_bid.1,
-						BalanceStatus::Free,
 					)?;
 
 					for package in BundleOf::<T, I>::get(trade) {
@@ -139,9 +145,21 @@ impl<T: Config<I>, I: 'static>
 
 					<T as Config<I>>::Currency::unreserve(&auction.owner, T::BundleDeposit::get());
 				}
+			} else {
+				// return items to the owner
+				for package in BundleOf::<T, I>::get(trade) {
+					Self::unreserved_item(
+						&config.owner,
+						&package.collection,
+						&package.item,
+						package.amount,
+					)?;
+				}
+				<T as Config<I>>::Currency::unreserve(&config.owner, T::BundleDeposit::get());
 			}
 			AuctionConfigOf::<T, I>::remove(trade);
 			BundleOf::<T, I>::remove(trade);
+			TradeAssetOf::<T, I>::remove(trade);
 			HighestBidOf::<T, I>::remove(trade);
 
 			Self::deposit_event(Event::<T, I>::AuctionClaimed {
@@ -152,4 +170,396 @@ impl<T: Config<I>, I: 'static>
 		}
 		Err(Error::<T, I>::UnknownAuction.into())
 	}
+
+	fn do_set_candle_auction(
+		trade: &T::TradeId,
+		who: &T::AccountId,
+		source: Bundle<T::CollectionId, T::ItemId>,
+		maybe_price: Option<BalanceOf<T, I>>,
+		start_block: BlockNumberFor<T>,
+		early_end: BlockNumberFor<T>,
+		end_block: BlockNumberFor<T>,
+	) -> DispatchResult {
+		// ensure available trade
+		ensure!(
+			!BundleOf::<T, I>::contains_key(trade),
+			Error::<T, I>::TradeIdInUse,
+		);
+
+		ensure!(
+			start_block <= early_end && early_end < end_block,
+			Error::<T, I>::InvalidAuctionPeriod
+		);
+
+		<T as Config<I>>::Currency::reserve(&who, T::BundleDeposit::get())?;
+
+		// lock bundle
+		for package in source.clone() {
+			Self::reserved_item(who, &package.collection, &package.item, package.amount)?;
+		}
+
+		<BundleOf<T, I>>::try_mutate(trade, |package_vec| -> DispatchResult {
+			package_vec
+				.try_append(source.clone().into_mut())
+				.map_err(|_| Error::<T, I>::ExceedMaxBundle)?;
+			Ok(())
+		})?;
+
+		CandleAuctionOf::<T, I>::insert(
+			trade,
+			CandleAuctionConfig {
+				owner: who.clone(),
+				maybe_price,
+				start_block,
+				early_end,
+				end_block,
+				closing_block: None,
+			},
+		);
+		Self::schedule_candle_closing(trade, end_block);
+		Self::schedule_expiry(trade, TradeType::CandleAuction, end_block);
+
+		Self::deposit_event(Event::<T, I>::CandleAuctionSet {
+			trade: *trade,
+			who: who.clone(),
+			source,
+			maybe_price,
+			start_block,
+			early_end,
+			end_block,
+		});
+
+		Ok(())
+	}
+
+	fn do_bid_candle_auction(
+		trade: &T::TradeId,
+		who: &T::AccountId,
+		bid: BalanceOf<T, I>,
+	) -> DispatchResult {
+		if let Some(config) = CandleAuctionOf::<T, I>::get(trade) {
+			// make sure the auction is not over
+			let block_number = <frame_system::Pallet<T>>::block_number();
+			ensure!(
+				block_number >= config.start_block,
+				Error::<T, I>::AuctionNotStarted
+			);
+			ensure!(block_number < config.end_block, Error::<T, I>::AuctionEnded);
+
+			if let Some(price) = config.maybe_price {
+				ensure!(bid >= price, Error::<T, I>::BidTooLow);
+			}
+
+			CandleBidsOf::<T, I>::try_mutate(trade, |bids| -> DispatchResult {
+				if let Some(highest_bid) = bids.last() {
+					ensure!(bid > highest_bid.1, Error::<T, I>::BidTooLow);
+				}
+
+				// earlier bids may still win, only reserve the difference
+				let reserved = bids
+					.iter()
+					.rev()
+					.find(|(bidder, _, _)| bidder == who)
+					.map_or(Zero::zero(), |(_, price, _)| *price);
+				Self::reserve_payment(
+					TradeAssetOf::<T, I>::get(trade),
+					who,
+					bid.saturating_sub(reserved),
+				)?;
+
+				bids.try_push((who.clone(), bid, block_number))
+					.map_err(|_| Error::<T, I>::ExceedMaxCandleBid)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T, I>::Bid {
+				trade: *trade,
+				who: who.clone(),
+				bid,
+			});
+			return Ok(())
+		}
+		Err(Error::<T, I>::UnknownAuction.into())
+	}
+
+	fn do_claim_candle_auction(trade: &T::TradeId) -> DispatchResult {
+		if let Some(config) = CandleAuctionOf::<T, I>::get(trade) {
+			let block_number = <frame_system::Pallet<T>>::block_number();
+			ensure!(
+				block_number >= config.end_block,
+				Error::<T, I>::AuctionInProgress
+			);
+			let closing_block = config.closing_block.ok_or(Error::<T, I>::AuctionInProgress)?;
+
+			let bids = CandleBidsOf::<T, I>::take(trade);
+
+			let maybe_bid = bids
+				.iter()
+				.rev()
+				.find(|(_, _, block)| *block <= closing_block)
+				.map(|(bidder, price, _)| (bidder.clone(), *price));
+
+			// refund the bids, the latest bid of a bidder is the amount reserved
+			let mut refunded: Vec<T::AccountId> = Vec::new();
+			for (bidder, price, _) in bids.into_iter().rev() {
+				if refunded.contains(&bidder) {
+					continue
+				}
+				let refund = match &maybe_bid {
+					Some((winner, win_price)) if *winner == bidder =>
+						price.saturating_sub(*win_price),
+					_ => price,
+				};
+				Self::unreserve_payment(TradeAssetOf::<T, I>::get(trade), &bidder, refund)?;
+				refunded.push(bidder);
+			}
+
+			if let Some((winner, win_price)) = maybe_bid.clone() {
+				Self::repatriate_price(
+					trade,
+					&winner,
+					&config.owner,
+					&BundleOf::<T, I>::get(trade),
+					win_price,
+				)?;
+
+				for package in BundleOf::<T, I>::get(trade) {
+					Self::repatriate_reserved_item(
+						&config.owner,
+						&package.collection,
+						&package.item,
+						&winner,
+						package.amount,
+						ItemBalanceStatus::Free,
+					)?;
+				}
+			} else {
+				for package in BundleOf::<T, I>::get(trade) {
+					Self::unreserved_item(
+						&config.owner,
+						&package.collection,
+						&package.item,
+						package.amount,
+					)?;
+				}
+			}
+			<T as Config<I>>::Currency::unreserve(&config.owner, T::BundleDeposit::get());
+
+			CandleAuctionOf::<T, I>::remove(trade);
+			BundleOf::<T, I>::remove(trade);
+			TradeAssetOf::<T, I>::remove(trade);
+
+			Self::deposit_event(Event::<T, I>::CandleAuctionClaimed {
+				trade: *trade,
+				closing_block,
+				maybe_bid,
+			});
+			return Ok(())
+		}
+		Err(Error::<T, I>::UnknownAuction.into())
+	}
+
+	fn do_set_dutch_auction(
+		trade: &T::TradeId,
+		who: &T::AccountId,
+		source: Bundle<T::CollectionId, T::ItemId>,
+		start_price: BalanceOf<T, I>,
+		floor_price: BalanceOf<T, I>,
+		start_block: Option<BlockNumberFor<T>>,
+		duration: BlockNumberFor<T>,
+	) -> DispatchResult {
+		// ensure available trade
+		ensure!(
+			!BundleOf::<T, I>::contains_key(trade),
+			Error::<T, I>::TradeIdInUse,
+		);
+
+		ensure!(
+			floor_price <= start_price,
+			Error::<T, I>::InvalidAuctionPrice
+		);
+		ensure!(!duration.is_zero(), Error::<T, I>::InvalidAuctionPeriod);
+
+		<T as Config<I>>::Currency::reserve(&who, T::BundleDeposit::get())?;
+
+		// lock bundle
+		for package in source.clone() {
+			Self::reserved_item(who, &package.collection, &package.item, package.amount)?;
+		}
+
+		<BundleOf<T, I>>::try_mutate(trade, |package_vec| -> DispatchResult {
+			package_vec
+				.try_append(source.clone().into_mut())
+				.map_err(|_| Error::<T, I>::ExceedMaxBundle)?;
+			Ok(())
+		})?;
+
+		let start = match start_block {
+			Some(block) => block,
+			None => <frame_system::Pallet<T>>::block_number(),
+		};
+
+		DutchAuctionOf::<T, I>::insert(
+			trade,
+			DutchAuctionConfig {
+				owner: who.clone(),
+				start_price,
+				floor_price,
+				start_block: start,
+				duration,
+			},
+		);
+		Self::schedule_trade_expiry(
+			trade,
+			TradeType::DutchAuction,
+			Some(start.saturating_add(duration)),
+		);
+
+		Self::deposit_event(Event::<T, I>::DutchAuctionSet {
+			trade: *trade,
+			who: who.clone(),
+			source,
+			start_price,
+			floor_price,
+			start_block,
+			duration,
+		});
+
+		Ok(())
+	}
+
+	fn do_buy_dutch_auction(
+		trade: &T::TradeId,
+		who: &T::AccountId,
+		bid_price: BalanceOf<T, I>,
+	) -> DispatchResult {
+		if let Some(config) = DutchAuctionOf::<T, I>::get(trade) {
+			ensure!(!who.eq(&config.owner), Error::<T, I>::NoPermission);
+
+			let block_number = <frame_system::Pallet<T>>::block_number();
+			ensure!(
+				block_number >= config.start_block,
+				Error::<T, I>::AuctionNotStarted
+			);
+			ensure!(
+				block_number <= config.start_block.saturating_add(config.duration),
+				Error::<T, I>::AuctionEnded
+			);
+
+			let bundle = BundleOf::<T, I>::get(trade);
+			// ensure item can be transfer
+			for pack in bundle.clone() {
+				ensure!(
+					T::Nfts::can_transfer(&pack.collection, &pack.item),
+					Error::<T, I>::ItemLocked
+				);
+			}
+
+			// check price
+			let price = Self::dutch_auction_price(&config, block_number);
+			ensure!(bid_price >= price, Error::<T, I>::BidTooLow);
+
+			Self::transfer_price(trade, &who, &config.owner, &bundle, price)?;
+
+			// transfer items
+			for package in bundle {
+				Self::repatriate_reserved_item(
+					&config.owner,
+					&package.collection,
+					&package.item,
+					who,
+					package.amount,
+					ItemBalanceStatus::Free,
+				)?;
+			}
+
+			// end auction
+			<T as pallet::Config<I>>::Currency::unreserve(&config.owner, T::BundleDeposit::get());
+			BundleOf::<T, I>::remove(trade);
+			TradeAssetOf::<T, I>::remove(trade);
+			DutchAuctionOf::<T, I>::remove(trade);
+
+			Self::deposit_event(Event::<T, I>::DutchAuctionBought {
+				trade: *trade,
+				who: who.clone(),
+				price,
+			});
+			return Ok(())
+		}
+		Err(Error::<T, I>::UnknownAuction.into())
+	}
+
+	fn do_cancel_dutch_auction(trade: &T::TradeId, who: &T::AccountId) -> DispatchResult {
+		if let Some(config) = DutchAuctionOf::<T, I>::get(trade) {
+			// ensure owner
+			ensure!(who.eq(&config.owner), Error::<T, I>::NoPermission);
+
+			// unlock items
+			for package in BundleOf::<T, I>::get(trade) {
+				Self::unreserved_item(who, &package.collection, &package.item, package.amount)?;
+			}
+
+			// end auction
+			<T as pallet::Config<I>>::Currency::unreserve(&config.owner, T::BundleDeposit::get());
+			BundleOf::<T, I>::remove(trade);
+			TradeAssetOf::<T, I>::remove(trade);
+			DutchAuctionOf::<T, I>::remove(trade);
+			return Ok(())
+		}
+		Err(Error::<T, I>::UnknownAuction.into())
+	}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Price of a dutch auction at `block_number`.
+	///
+	/// The price decreases linearly from `start_price` to `floor_price` over `duration` blocks.
+	pub fn dutch_auction_price(
+		config: &DutchAuctionConfig<T::AccountId, BalanceOf<T, I>, BlockNumberFor<T>>,
+		block_number: BlockNumberFor<T>,
+	) -> BalanceOf<T, I> {
+		let elapsed = block_number.saturating_sub(config.start_block);
+		if elapsed >= config.duration {
+			return config.floor_price
+		}
+
+		let decay = Perbill::from_rational(
+			elapsed.saturated_into::<u32>(),
+			config.duration.saturated_into::<u32>(),
+		) * config.start_price.saturating_sub(config.floor_price);
+		config.start_price.saturating_sub(decay)
+	}
+
+	/// Queue the candle auction `trade` to draw its closing block at `end_block`, or at the next
+	/// block if `end_block` has passed.
+	///
+	/// The draw spills over to the next block with room once a block is full.
+	pub(crate) fn schedule_candle_closing(trade: &T::TradeId, end_block: BlockNumberFor<T>) {
+		let next_block = <frame_system::Pallet<T>>::block_number().saturating_add(One::one());
+		let mut block = end_block.max(next_block);
+		loop {
+			let mut trades = CandleClosingOf::<T, I>::get(block);
+			if trades.try_push(*trade).is_ok() {
+				CandleClosingOf::<T, I>::insert(block, trades);
+				return
+			}
+			block = block.saturating_add(One::one());
+		}
+	}
+
+	/// Retroactively pick the closing block of the candle auction `trade` in its ending period.
+	///
+	/// The randomness is drawn once bidding is over, before the auction can be claimed.
+	pub(crate) fn draw_candle_closing(trade: &T::TradeId, index: u32) {
+		CandleAuctionOf::<T, I>::mutate(trade, |maybe_config| {
+			if let Some(config) = maybe_config {
+				let ending_period: u32 =
+					config.end_block.saturating_sub(config.early_end).saturated_into();
+				let offset = T::GameRandomness::random_number(ending_period, index)
+					.unwrap_or(ending_period)
+					.saturating_sub(1);
+				config.closing_block = Some(config.early_end.saturating_add(offset.into()));
+			}
+		});
+	}
 }
@@ -0,0 +1,162 @@
+/// Expiry module closes the trades which passed their end block
+use crate::*;
+use frame_support::{pallet_prelude::*, storage::with_storage_layer};
+use frame_system::pallet_prelude::BlockNumberFor;
+use gafi_support::game::{
+	Auction, Rental, Retail, SealedAuction, Swap, TradeType, Wholesale, Wishlist,
+};
+use sp_runtime::{
+	traits::{One, Zero},
+	Saturating,
+};
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Queue `trade` to expire at `block`, or at the next block if `block` has passed.
+	///
+	/// The trade spills over to the next block with room once a block is full.
+	pub(crate) fn schedule_expiry(
+		trade: &T::TradeId,
+		trade_type: TradeType,
+		block: BlockNumberFor<T>,
+	) {
+		let block_number = <frame_system::Pallet<T>>::block_number();
+		if NextExpiryBlock::<T, I>::get().is_zero() {
+			NextExpiryBlock::<T, I>::put(block_number);
+		}
+
+		let mut block = block.max(block_number.saturating_add(One::one()));
+		loop {
+			let mut trades = TradeExpiryOf::<T, I>::get(block);
+			if trades.try_push((*trade, trade_type.clone())).is_ok() {
+				TradeExpiryOf::<T, I>::insert(block, trades);
+				return
+			}
+			block = block.saturating_add(One::one());
+		}
+	}
+
+	/// Queue `trade` to expire after `end_block` if any.
+	pub(crate) fn schedule_trade_expiry(
+		trade: &T::TradeId,
+		trade_type: TradeType,
+		end_block: Option<BlockNumberFor<T>>,
+	) {
+		if let Some(end_block) = end_block {
+			Self::schedule_expiry(trade, trade_type, end_block.saturating_add(One::one()));
+		}
+	}
+
+	/// Expire the queued trades up to `block_number` within `remaining_weight`.
+	///
+	/// Returns the consumed weight.
+	pub(crate) fn expire_trades(
+		block_number: BlockNumberFor<T>,
+		remaining_weight: Weight,
+	) -> Weight {
+		let db_weight = T::DbWeight::get();
+
+		// read and write the next expiry block
+		let mut weight = db_weight.reads_writes(1, 1);
+		if weight.any_gt(remaining_weight) {
+			return Weight::zero()
+		}
+
+		let mut next = NextExpiryBlock::<T, I>::get();
+		if next.is_zero() {
+			next = block_number;
+		}
+
+		while next <= block_number {
+			let queue_weight = weight.saturating_add(db_weight.reads_writes(1, 1));
+			if queue_weight.any_gt(remaining_weight) {
+				break
+			}
+			weight = queue_weight;
+
+			let trades = TradeExpiryOf::<T, I>::take(next).into_inner();
+			let mut expired = 0;
+			for (trade, trade_type) in trades.iter() {
+				let expire_weight = Self::expire_weight(trade_type);
+				if weight.saturating_add(expire_weight).any_gt(remaining_weight) {
+					break
+				}
+				weight = weight.saturating_add(expire_weight);
+				Self::expire_trade(trade, trade_type.clone());
+				expired += 1;
+			}
+
+			if expired < trades.len() {
+				TradeExpiryOf::<T, I>::insert(
+					next,
+					BoundedVec::truncate_from(trades[expired..].to_vec()),
+				);
+				break
+			}
+			next = next.saturating_add(One::one());
+		}
+
+		NextExpiryBlock::<T, I>::put(next);
+		weight
+	}
+
+	/// Weight of expiring a trade of `trade_type`, candle and sealed auctions are claimed over
+	/// all their bids.
+	fn expire_weight(trade_type: &TradeType) -> Weight {
+		let expire_weight = <T as pallet::Config<I>>::WeightInfo::expire_trade();
+		match trade_type {
+			TradeType::CandleAuction => expire_weight.max(
+				<T as pallet::Config<I>>::WeightInfo::close_candle_auction(T::MaxCandleBid::get()),
+			),
+			TradeType::SealedAuction => expire_weight.max(
+				<T as pallet::Config<I>>::WeightInfo::close_sealed_auction(T::MaxSealedBid::get()),
+			),
+			_ => expire_weight,
+		}
+	}
+
+	/// Close `trade` if it still exists, returning the reserved items and deposits.
+	///
+	/// Auctions are claimed by the highest bidder, rented items are returned to the listing.
+	pub(crate) fn expire_trade(trade: &T::TradeId, trade_type: TradeType) {
+		let maybe_owner = match trade_type {
+			TradeType::Auction => AuctionConfigOf::<T, I>::get(trade).map(|config| config.owner),
+			TradeType::CandleAuction =>
+				CandleAuctionOf::<T, I>::get(trade).map(|config| config.owner),
+			TradeType::SealedAuction =>
+				SealedAuctionOf::<T, I>::get(trade).map(|config| config.owner),
+			TradeType::DutchAuction =>
+				DutchAuctionOf::<T, I>::get(trade).map(|config| config.owner),
+			TradeType::Rental => RentalOf::<T, I>::get(trade)
+				.filter(|config| Self::rental_ended(config))
+				.map(|config| config.owner),
+			_ => TradeConfigOf::<T, I>::get(trade)
+				.filter(|config| config.trade == trade_type)
+				.map(|config| config.owner),
+		};
+
+		let owner = match maybe_owner {
+			Some(owner) => owner,
+			None => return,
+		};
+
+		let res = with_storage_layer(|| -> DispatchResult {
+			match trade_type {
+				TradeType::SetPrice => Self::do_cancel_price(trade, &owner),
+				TradeType::SetBuy => Self::do_cancel_set_buy(trade, &owner),
+				TradeType::Bundle => Self::do_cancel_bundle(trade, &owner),
+				TradeType::Wishlist => Self::do_cancel_wishlist(trade, &owner),
+				TradeType::Swap => Self::do_cancel_swap(trade, &owner),
+				TradeType::Auction => Self::do_claim_auction(trade),
+				TradeType::CandleAuction => Self::do_claim_candle_auction(trade),
+				TradeType::SealedAuction => Self::do_claim_sealed_auction(trade),
+				TradeType::DutchAuction => Self::do_cancel_dutch_auction(trade, &owner),
+				TradeType::Rental => Self::do_return_rental(trade),
+			}
+		});
+
+		match res {
+			Ok(()) => Self::deposit_event(Event::<T, I>::TradeExpired { trade: *trade }),
+			Err(e) => log::error!("Trade Expiry Failed: {:?}", e),
+		}
+	}
+}
@@ -15,3 +15,15 @@ pub use auction::*;
 
 pub mod wholesale;
 pub use wholesale::*;
+
+pub mod sealed_auction;
+pub use sealed_auction::*;
+
+pub mod order_book;
+pub use order_book::*;
+
+pub mod rental;
+pub use rental::*;
+
+pub mod expiry;
+pub use expiry::*;
@@ -0,0 +1,289 @@
+use crate::*;
+use frame_support::pallet_prelude::*;
+use gafi_support::game::{Amount, OrderBook, Package, Retail, TradeType};
+use sp_runtime::Saturating;
+
+impl<T: Config<I>, I: 'static>
+	OrderBook<T::AccountId, T::CollectionId, T::ItemId, T::TradeId, BalanceOf<T, I>>
+	for Pallet<T, I>
+{
+	fn do_set_book_price(
+		trade: &T::TradeId,
+		who: &T::AccountId,
+		package: Package<T::CollectionId, T::ItemId>,
+		unit_price: BalanceOf<T, I>,
+	) -> Result<(u32, u32), sp_runtime::DispatchError> {
+		Self::do_set_price(trade, who, package.clone(), unit_price, None, None)?;
+
+		let key = (
+			package.collection,
+			package.item,
+			TradeAssetOf::<T, I>::get(trade),
+		);
+		let mut remaining = package.amount;
+		let mut fills = 0;
+		let mut scanned = 0;
+
+		// fill the highest purchases first
+		let mut bids = BidBookOf::<T, I>::get(&key);
+		while remaining > 0 {
+			let (bid, bid_price) = match bids.first() {
+				Some((bid, bid_price)) if *bid_price >= unit_price => (*bid, *bid_price),
+				_ => break,
+			};
+			let (buyer, available) = match Self::book_order(&bid, TradeType::SetBuy) {
+				Some(order) => order,
+				None => {
+					bids.remove(0);
+					scanned += 1;
+					continue
+				},
+			};
+
+			let amount = remaining.min(available);
+			Self::match_orders(
+				trade,
+				who,
+				&bid,
+				&buyer,
+				&Package::new(package.collection, package.item, amount),
+				bid_price,
+				bid_price,
+			)?;
+
+			remaining = remaining.saturating_sub(amount);
+			fills += 1;
+			if amount == available {
+				bids.remove(0);
+			}
+		}
+		if bids.is_empty() {
+			BidBookOf::<T, I>::remove(&key);
+		} else {
+			BidBookOf::<T, I>::insert(&key, bids);
+		}
+
+		if remaining > 0 {
+			AskBookOf::<T, I>::try_mutate(&key, |asks| -> DispatchResult {
+				scanned += asks.len() as u32;
+				asks.retain(|(ask, _)| Self::book_order(ask, TradeType::SetPrice).is_some());
+				let index =
+					asks.iter().position(|(_, price)| *price > unit_price).unwrap_or(asks.len());
+				asks.try_insert(index, (*trade, unit_price))
+					.map_err(|_| Error::<T, I>::OrderBookFull)?;
+				Ok(())
+			})?;
+		}
+
+		Ok((fills, scanned))
+	}
+
+	fn do_set_book_order(
+		trade: &T::TradeId,
+		who: &T::AccountId,
+		package: Package<T::CollectionId, T::ItemId>,
+		unit_price: BalanceOf<T, I>,
+	) -> Result<(u32, u32), sp_runtime::DispatchError> {
+		Self::do_set_buy(trade, who, package.clone(), unit_price, None, None)?;
+
+		let key = (
+			package.collection,
+			package.item,
+			TradeAssetOf::<T, I>::get(trade),
+		);
+		let mut remaining = package.amount;
+		let mut fills = 0;
+		let mut scanned = 0;
+
+		// fill the lowest listings first
+		let mut asks = AskBookOf::<T, I>::get(&key);
+		while remaining > 0 {
+			let (ask, ask_price) = match asks.first() {
+				Some((ask, ask_price)) if *ask_price <= unit_price => (*ask, *ask_price),
+				_ => break,
+			};
+			let (seller, available) = match Self::book_order(&ask, TradeType::SetPrice) {
+				Some(order) => order,
+				None => {
+					asks.remove(0);
+					scanned += 1;
+					continue
+				},
+			};
+
+			let amount = remaining.min(available);
+			Self::match_orders(
+				&ask,
+				&seller,
+				trade,
+				who,
+				&Package::new(package.collection, package.item, amount),
+				ask_price,
+				unit_price,
+			)?;
+
+			remaining = remaining.saturating_sub(amount);
+			fills += 1;
+			if amount == available {
+				asks.remove(0);
+			}
+		}
+		if asks.is_empty() {
+			AskBookOf::<T, I>::remove(&key);
+		} else {
+			AskBookOf::<T, I>::insert(&key, asks);
+		}
+
+		if remaining > 0 {
+			BidBookOf::<T, I>::try_mutate(&key, |bids| -> DispatchResult {
+				scanned += bids.len() as u32;
+				bids.retain(|(bid, _)| Self::book_order(bid, TradeType::SetBuy).is_some());
+				let index =
+					bids.iter().position(|(_, price)| *price < unit_price).unwrap_or(bids.len());
+				bids.try_insert(index, (*trade, unit_price))
+					.map_err(|_| Error::<T, I>::OrderBookFull)?;
+				Ok(())
+			})?;
+		}
+
+		Ok((fills, scanned))
+	}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Owner and remaining amount of an open `trade_type` trade.
+	pub(crate) fn book_order(
+		trade: &T::TradeId,
+		trade_type: TradeType,
+	) -> Option<(T::AccountId, Amount)> {
+		let config = TradeConfigOf::<T, I>::get(trade)?;
+		if config.trade != trade_type {
+			return None
+		}
+		match BundleOf::<T, I>::get(trade).first() {
+			Some(package) if package.amount > 0 => Some((config.owner, package.amount)),
+			_ => None,
+		}
+	}
+
+	/// Weight of checking `scanned` orders of the order book are still open.
+	pub(crate) fn book_scan_weight(scanned: u32) -> Weight {
+		// the trade config and bundle of each order
+		T::DbWeight::get().reads(2_u64.saturating_mul(scanned.into()))
+	}
+
+	/// Remove `trade` from the order book of the item in `package`.
+	pub(crate) fn remove_book_order(
+		trade: &T::TradeId,
+		package: &Package<T::CollectionId, T::ItemId>,
+		trade_type: TradeType,
+	) {
+		let key = (
+			package.collection,
+			package.item,
+			TradeAssetOf::<T, I>::get(trade),
+		);
+		match trade_type {
+			TradeType::SetPrice => AskBookOf::<T, I>::mutate_exists(&key, |maybe_asks| {
+				if let Some(asks) = maybe_asks {
+					asks.retain(|(ask, _)| ask != trade);
+					if asks.is_empty() {
+						*maybe_asks = None;
+					}
+				}
+			}),
+			TradeType::SetBuy => BidBookOf::<T, I>::mutate_exists(&key, |maybe_bids| {
+				if let Some(bids) = maybe_bids {
+					bids.retain(|(bid, _)| bid != trade);
+					if bids.is_empty() {
+						*maybe_bids = None;
+					}
+				}
+			}),
+			_ => {},
+		}
+	}
+
+	/// Exchange `package` between the listing `ask` and the purchase `bid` at `unit_price`.
+	///
+	/// The purchase reserved `bid_price` for each item, the difference is returned to the buyer.
+	fn match_orders(
+		ask: &T::TradeId,
+		seller: &T::AccountId,
+		bid: &T::TradeId,
+		buyer: &T::AccountId,
+		package: &Package<T::CollectionId, T::ItemId>,
+		unit_price: BalanceOf<T, I>,
+		bid_price: BalanceOf<T, I>,
+	) -> DispatchResult {
+		// ensure item can be transfer
+		ensure!(
+			T::Nfts::can_transfer(&package.collection, &package.item),
+			Error::<T, I>::ItemLocked
+		);
+
+		// transfer item
+		Self::repatriate_reserved_item(
+			seller,
+			&package.collection,
+			&package.item,
+			buyer,
+			package.amount,
+			ItemBalanceStatus::Free,
+		)?;
+
+		// make deposit
+		Self::repatriate_price(
+			ask,
+			buyer,
+			seller,
+			&[package.clone()],
+			unit_price.saturating_mul(package.amount.into()),
+		)?;
+		Self::unreserve_payment(
+			TradeAssetOf::<T, I>::get(bid),
+			buyer,
+			bid_price.saturating_sub(unit_price).saturating_mul(package.amount.into()),
+		)?;
+
+		Self::fill_book_order(ask, seller, package.amount)?;
+		Self::fill_book_order(bid, buyer, package.amount)?;
+
+		Self::deposit_event(Event::<T, I>::OrderMatched {
+			ask: *ask,
+			bid: *bid,
+			seller: seller.clone(),
+			buyer: buyer.clone(),
+			collection: package.collection,
+			item: package.item,
+			amount: package.amount,
+			unit_price,
+		});
+		Ok(())
+	}
+
+	/// Take `amount` items from `trade`, ending the trade once it is filled.
+	fn fill_book_order(trade: &T::TradeId, owner: &T::AccountId, amount: Amount) -> DispatchResult {
+		let package = BundleOf::<T, I>::get(trade)
+			.first()
+			.cloned()
+			.ok_or(Error::<T, I>::UnknownTrade)?;
+		let remaining = package.amount.saturating_sub(amount);
+
+		if remaining == 0 {
+			// end trade
+			<T as pallet::Config<I>>::Currency::unreserve(owner, T::BundleDeposit::get());
+			BundleOf::<T, I>::remove(trade);
+			TradeAssetOf::<T, I>::remove(trade);
+			TradeConfigOf::<T, I>::remove(trade);
+			return Ok(())
+		}
+
+		let new_package = Package::new(package.collection, package.item, remaining);
+		<BundleOf<T, I>>::try_mutate(trade, |package_vec| -> DispatchResult {
+			*package_vec = BundleFor::<T, I>::try_from([new_package].to_vec())
+				.map_err(|_| Error::<T, I>::ExceedMaxBundle)?;
+			Ok(())
+		})
+	}
+}
@@ -0,0 +1,183 @@
+/// Rental module lends reserved items to renters for a number of blocks
+use crate::*;
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::BlockNumberFor;
+use gafi_support::game::{Amount, Bundle, Rental, TradeType};
+use sp_runtime::{
+	traits::{SaturatedConversion, Zero},
+	Saturating,
+};
+
+impl<T: Config<I>, I: 'static>
+	Rental<T::AccountId, T::CollectionId, T::ItemId, T::TradeId, BalanceOf<T, I>, BlockNumberFor<T>>
+	for Pallet<T, I>
+{
+	fn do_set_rental(
+		trade: &T::TradeId,
+		who: &T::AccountId,
+		source: Bundle<T::CollectionId, T::ItemId>,
+		price_per_block: BalanceOf<T, I>,
+		max_duration: BlockNumberFor<T>,
+	) -> DispatchResult {
+		// ensure available trade
+		ensure!(
+			!BundleOf::<T, I>::contains_key(trade),
+			Error::<T, I>::TradeIdInUse,
+		);
+		ensure!(
+			!max_duration.is_zero(),
+			Error::<T, I>::InvalidRentalDuration
+		);
+
+		<T as Config<I>>::Currency::reserve(who, T::BundleDeposit::get())?;
+
+		// lock bundle
+		for package in source.clone() {
+			Self::reserved_item(who, &package.collection, &package.item, package.amount)?;
+		}
+
+		<BundleOf<T, I>>::try_mutate(trade, |package_vec| -> DispatchResult {
+			package_vec
+				.try_append(source.clone().into_mut())
+				.map_err(|_| Error::<T, I>::ExceedMaxBundle)?;
+			Ok(())
+		})?;
+
+		RentalOf::<T, I>::insert(
+			trade,
+			RentalConfig {
+				owner: who.clone(),
+				price_per_block,
+				max_duration,
+				maybe_renter: None,
+			},
+		);
+
+		Self::deposit_event(Event::<T, I>::RentalSet {
+			trade: *trade,
+			who: who.clone(),
+			source,
+			price_per_block,
+			max_duration,
+		});
+
+		Ok(())
+	}
+
+	fn do_rent(
+		trade: &T::TradeId,
+		who: &T::AccountId,
+		duration: BlockNumberFor<T>,
+	) -> DispatchResult {
+		let mut config = RentalOf::<T, I>::get(trade).ok_or(Error::<T, I>::UnknownRental)?;
+		ensure!(config.maybe_renter.is_none(), Error::<T, I>::ItemRented);
+		ensure!(
+			!duration.is_zero() && duration <= config.max_duration,
+			Error::<T, I>::InvalidRentalDuration
+		);
+
+		let bundle = BundleOf::<T, I>::get(trade);
+		let price = config.price_per_block.saturating_mul(duration.saturated_into::<u32>().into());
+		Self::transfer_price(trade, who, &config.owner, &bundle, price)?;
+
+		for package in bundle.iter() {
+			Self::add_use_right(who, &package.collection, &package.item, package.amount);
+		}
+
+		let end_block = <frame_system::Pallet<T>>::block_number().saturating_add(duration);
+		Self::schedule_expiry(trade, TradeType::Rental, end_block);
+
+		config.maybe_renter = Some((who.clone(), end_block));
+		RentalOf::<T, I>::insert(trade, config);
+
+		Self::deposit_event(Event::<T, I>::Rented {
+			trade: *trade,
+			who: who.clone(),
+			duration,
+			price,
+			end_block,
+		});
+
+		Ok(())
+	}
+
+	fn do_return_rental(trade: &T::TradeId) -> DispatchResult {
+		let mut config = RentalOf::<T, I>::get(trade).ok_or(Error::<T, I>::UnknownRental)?;
+		let (renter, end_block) = config.maybe_renter.take().ok_or(Error::<T, I>::NotRented)?;
+		ensure!(
+			end_block <= <frame_system::Pallet<T>>::block_number(),
+			Error::<T, I>::RentalNotEnded
+		);
+
+		for package in BundleOf::<T, I>::get(trade) {
+			Self::sub_use_right(&renter, &package.collection, &package.item, package.amount);
+		}
+		RentalOf::<T, I>::insert(trade, config);
+
+		Self::deposit_event(Event::<T, I>::RentalReturned {
+			trade: *trade,
+			who: renter,
+		});
+
+		Ok(())
+	}
+
+	fn do_cancel_rental(trade: &T::TradeId, who: &T::AccountId) -> DispatchResult {
+		let config = RentalOf::<T, I>::get(trade).ok_or(Error::<T, I>::UnknownRental)?;
+		// ensure owner
+		ensure!(who.eq(&config.owner), Error::<T, I>::NoPermission);
+
+		// take back the items of an ended term
+		if config.maybe_renter.is_some() {
+			ensure!(Self::rental_ended(&config), Error::<T, I>::ItemRented);
+			Self::do_return_rental(trade)?;
+		}
+
+		// unlock items
+		for package in BundleOf::<T, I>::get(trade) {
+			Self::unreserved_item(who, &package.collection, &package.item, package.amount)?;
+		}
+
+		// end rental
+		<T as pallet::Config<I>>::Currency::unreserve(&config.owner, T::BundleDeposit::get());
+		BundleOf::<T, I>::remove(trade);
+		TradeAssetOf::<T, I>::remove(trade);
+		RentalOf::<T, I>::remove(trade);
+		Ok(())
+	}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Whether the term of the current renter has ended.
+	pub(crate) fn rental_ended(
+		config: &RentalConfig<T::AccountId, BalanceOf<T, I>, BlockNumberFor<T>>,
+	) -> bool {
+		match config.maybe_renter {
+			Some((_, end_block)) => end_block <= <frame_system::Pallet<T>>::block_number(),
+			None => false,
+		}
+	}
+
+	fn add_use_right(
+		who: &T::AccountId,
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+		amount: Amount,
+	) {
+		UseRightOf::<T, I>::mutate((who, collection, item), |balance| {
+			balance.saturating_accrue(amount)
+		});
+	}
+
+	fn sub_use_right(
+		who: &T::AccountId,
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+		amount: Amount,
+	) {
+		UseRightOf::<T, I>::mutate_exists((who, collection, item), |maybe_balance| {
+			let balance = maybe_balance.unwrap_or_default().saturating_sub(amount);
+			*maybe_balance = if balance == 0 { None } else { Some(balance) };
+		});
+	}
+}
@@ -1,8 +1,5 @@
 use crate::*;
-use frame_support::{
-	pallet_prelude::*,
-	traits::{BalanceStatus, ExistenceRequirement},
-};
+use frame_support::pallet_prelude::*;
 use frame_system::pallet_prelude::BlockNumberFor;
 use gafi_support::game::{Amount, Package, Retail, TradeType};
 use sp_runtime::Saturating;
@@ -55,6 +52,7 @@ impl<T: Config<I>, I: 'static>
 				end_block,
 			},
 		);
+		Self::schedule_trade_expiry(trade, TradeType::SetPrice, end_block);
 
 		Self::deposit_event(Event::<T, I>::PriceSet {
 			trade: *trade,
@@ -105,11 +103,12 @@ impl<T: Config<I>, I: 'static>
 				ensure!(bid_unit_price >= price, Error::<T, I>::BidTooLow);
 
 				// make deposit
-				<T as pallet::Config<I>>::Currency::transfer(
+				Self::transfer_price(
+					trade,
 					&who,
 					&config.owner,
+					&[Package::new(package.collection, package.item, amount)],
 					price.saturating_mul(amount.into()),
-					ExistenceRequirement::KeepAlive,
 				)?;
 
 				// transfer item
@@ -160,6 +159,7 @@ impl<T: Config<I>, I: 'static>
 
 				// unlock items
 				Self::unreserved_item(who, &package.collection, &package.item, package.amount)?;
+				Self::remove_book_order(trade, package, TradeType::SetPrice);
 
 				// end trade
 				<T as pallet::Config<I>>::Currency::unreserve(
@@ -167,6 +167,7 @@ impl<T: Config<I>, I: 'static>
 					T::BundleDeposit::get(),
 				);
 				BundleOf::<T, I>::remove(trade);
+				TradeAssetOf::<T, I>::remove(trade);
 				TradeConfigOf::<T, I>::remove(trade);
 
 				Self::deposit_event(Event::<T, I>::TradeCanceled {
@@ -242,11 +243,14 @@ impl<T: Config<I>, I: 'static>
 			Error::<T, I>::TradeIdInUse
 		);
 
+		Self::ensure_transferable(&package.collection, &package.item)?;
+
 		// ensure reserve deposit
 		<T as Config<I>>::Currency::reserve(&who, T::BundleDeposit::get())?;
 
+		let maybe_asset = TradeAssetOf::<T, I>::get(trade);
 		let deposit = unit_price.saturating_mul(package.amount.into());
-		<T as Config<I>>::Currency::reserve(&who, deposit)?;
+		Self::reserve_payment(maybe_asset, who, deposit)?;
 
 		<BundleOf<T, I>>::try_mutate(trade, |package_vec| -> DispatchResult {
 			package_vec
@@ -266,6 +270,7 @@ impl<T: Config<I>, I: 'static>
 				end_block,
 			},
 		);
+		Self::schedule_trade_expiry(trade, TradeType::SetBuy, end_block);
 
 		Self::deposit_event(Event::<T, I>::BuySet {
 			trade: *trade,
@@ -321,11 +326,12 @@ impl<T: Config<I>, I: 'static>
 				)?;
 
 				// make deposit
-				<T as pallet::Config<I>>::Currency::repatriate_reserved(
+				Self::repatriate_price(
+					trade,
 					&config.owner,
 					&who,
+					&[Package::new(package.collection, package.item, amount)],
 					price.saturating_mul(amount.into()),
-					BalanceStatus::Free,
 				)?;
 
 				let new_package = Package::new(
@@ -363,10 +369,12 @@ impl<T: Config<I>, I: 'static>
 				// unreserve deposit
 				let price = config.maybe_price.unwrap_or_default();
 
-				<T as pallet::Config<I>>::Currency::unreserve(
+				Self::unreserve_payment(
+					TradeAssetOf::<T, I>::get(trade),
 					&config.owner,
 					price.saturating_mul(package.amount.into()),
-				);
+				)?;
+				Self::remove_book_order(trade, package, TradeType::SetBuy);
 
 				// end trade
 				<T as pallet::Config<I>>::Currency::unreserve(
@@ -374,6 +382,7 @@ impl<T: Config<I>, I: 'static>
 					T::BundleDeposit::get(),
 				);
 				BundleOf::<T, I>::remove(trade);
+				TradeAssetOf::<T, I>::remove(trade);
 				TradeConfigOf::<T, I>::remove(trade);
 
 				return Ok(())
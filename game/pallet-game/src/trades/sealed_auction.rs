@@ -0,0 +1,269 @@
+use crate::*;
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::BlockNumberFor;
+use gafi_support::game::{Bundle, SealedAuction};
+use sp_runtime::{
+	traits::{Hash, Zero},
+	Saturating,
+};
+
+impl<T: Config<I>, I: 'static>
+	SealedAuction<
+		T::AccountId,
+		T::CollectionId,
+		T::ItemId,
+		T::TradeId,
+		BalanceOf<T, I>,
+		BlockNumberFor<T>,
+		T::Hash,
+	> for Pallet<T, I>
+{
+	fn do_set_sealed_auction(
+		trade: &T::TradeId,
+		who: &T::AccountId,
+		source: Bundle<T::CollectionId, T::ItemId>,
+		maybe_price: Option<BalanceOf<T, I>>,
+		start_block: Option<BlockNumberFor<T>>,
+		reveal_block: BlockNumberFor<T>,
+		end_block: BlockNumberFor<T>,
+	) -> DispatchResult {
+		// ensure available trade
+		ensure!(
+			!BundleOf::<T, I>::contains_key(trade),
+			Error::<T, I>::TradeIdInUse,
+		);
+
+		let start = match start_block {
+			Some(block) => block,
+			None => <frame_system::Pallet<T>>::block_number(),
+		};
+		ensure!(
+			start < reveal_block && reveal_block < end_block,
+			Error::<T, I>::InvalidAuctionPeriod
+		);
+
+		<T as Config<I>>::Currency::reserve(&who, T::BundleDeposit::get())?;
+
+		// lock bundle
+		for package in source.clone() {
+			Self::reserved_item(who, &package.collection, &package.item, package.amount)?;
+		}
+
+		<BundleOf<T, I>>::try_mutate(trade, |package_vec| -> DispatchResult {
+			package_vec
+				.try_append(source.clone().into_mut())
+				.map_err(|_| Error::<T, I>::ExceedMaxBundle)?;
+			Ok(())
+		})?;
+
+		SealedAuctionOf::<T, I>::insert(
+			trade,
+			SealedAuctionConfig {
+				owner: who.clone(),
+				maybe_price,
+				start_block: start,
+				reveal_block,
+				end_block,
+			},
+		);
+		Self::schedule_expiry(trade, TradeType::SealedAuction, end_block);
+
+		Self::deposit_event(Event::<T, I>::SealedAuctionSet {
+			trade: *trade,
+			who: who.clone(),
+			source,
+			maybe_price,
+			start_block,
+			reveal_block,
+			end_block,
+		});
+
+		Ok(())
+	}
+
+	fn do_commit_sealed_bid(
+		trade: &T::TradeId,
+		who: &T::AccountId,
+		commitment: T::Hash,
+		deposit: BalanceOf<T, I>,
+	) -> DispatchResult {
+		if let Some(config) = SealedAuctionOf::<T, I>::get(trade) {
+			// make sure the commit period is not over
+			let block_number = <frame_system::Pallet<T>>::block_number();
+			ensure!(
+				block_number >= config.start_block,
+				Error::<T, I>::AuctionNotStarted
+			);
+			ensure!(
+				block_number < config.reveal_block,
+				Error::<T, I>::AuctionEnded
+			);
+
+			if let Some(price) = config.maybe_price {
+				ensure!(deposit >= price, Error::<T, I>::BidTooLow);
+			}
+
+			SealedBidsOf::<T, I>::try_mutate(trade, |bids| -> DispatchResult {
+				ensure!(
+					!bids.iter().any(|(bidder, _)| bidder == who),
+					Error::<T, I>::BidCommitted
+				);
+
+				Self::reserve_payment(TradeAssetOf::<T, I>::get(trade), who, deposit)?;
+
+				bids.try_push((
+					who.clone(),
+					SealedBid {
+						commitment,
+						deposit,
+						maybe_bid: None,
+					},
+				))
+				.map_err(|_| Error::<T, I>::ExceedMaxSealedBid)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T, I>::SealedBidCommitted {
+				trade: *trade,
+				who: who.clone(),
+				deposit,
+			});
+			return Ok(())
+		}
+		Err(Error::<T, I>::UnknownAuction.into())
+	}
+
+	fn do_reveal_sealed_bid(
+		trade: &T::TradeId,
+		who: &T::AccountId,
+		bid: BalanceOf<T, I>,
+		salt: [u8; 32],
+	) -> DispatchResult {
+		if let Some(config) = SealedAuctionOf::<T, I>::get(trade) {
+			// make sure it is in the reveal period
+			let block_number = <frame_system::Pallet<T>>::block_number();
+			ensure!(
+				block_number >= config.reveal_block,
+				Error::<T, I>::RevealNotStarted
+			);
+			ensure!(block_number < config.end_block, Error::<T, I>::AuctionEnded);
+
+			SealedBidsOf::<T, I>::try_mutate(trade, |bids| -> DispatchResult {
+				let (_, sealed) = bids
+					.iter_mut()
+					.find(|(bidder, _)| bidder == who)
+					.ok_or(Error::<T, I>::BidNotCommitted)?;
+				ensure!(sealed.maybe_bid.is_none(), Error::<T, I>::BidRevealed);
+				ensure!(
+					T::Hashing::hash_of(&(who, bid, salt)) == sealed.commitment,
+					Error::<T, I>::InvalidReveal
+				);
+				ensure!(bid <= sealed.deposit, Error::<T, I>::InsufficientDeposit);
+
+				sealed.maybe_bid = Some(bid);
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T, I>::SealedBidRevealed {
+				trade: *trade,
+				who: who.clone(),
+				bid,
+			});
+			return Ok(())
+		}
+		Err(Error::<T, I>::UnknownAuction.into())
+	}
+
+	fn do_claim_sealed_auction(trade: &T::TradeId) -> DispatchResult {
+		if let Some(config) = SealedAuctionOf::<T, I>::get(trade) {
+			let block_number = <frame_system::Pallet<T>>::block_number();
+			ensure!(
+				block_number >= config.end_block,
+				Error::<T, I>::AuctionInProgress
+			);
+
+			let bids = SealedBidsOf::<T, I>::take(trade);
+			let min_price = config.maybe_price.unwrap_or_default();
+
+			// the highest revealed bid wins, the earlier bid wins a tie
+			let mut maybe_highest: Option<(T::AccountId, BalanceOf<T, I>)> = None;
+			let mut second_price = min_price;
+			for (bidder, sealed) in bids.iter() {
+				if let Some(bid) = sealed.maybe_bid.filter(|bid| *bid >= min_price) {
+					match &maybe_highest {
+						Some((_, highest)) if bid <= *highest => {
+							second_price = second_price.max(bid);
+						},
+						Some((_, highest)) => {
+							second_price = second_price.max(*highest);
+							maybe_highest = Some((bidder.clone(), bid));
+						},
+						None => maybe_highest = Some((bidder.clone(), bid)),
+					}
+				}
+			}
+			let maybe_bid = maybe_highest.map(|(winner, _)| (winner, second_price));
+
+			// refund revealed bids and slash unrevealed bids
+			let maybe_asset = TradeAssetOf::<T, I>::get(trade);
+			let mut slashed: BalanceOf<T, I> = Zero::zero();
+			for (bidder, sealed) in bids.into_iter() {
+				if sealed.maybe_bid.is_none() {
+					Self::pay_reserved(maybe_asset, &bidder, &config.owner, sealed.deposit)?;
+					slashed = slashed.saturating_add(sealed.deposit);
+					continue
+				}
+
+				let refund = match &maybe_bid {
+					Some((winner, price)) if *winner == bidder =>
+						sealed.deposit.saturating_sub(*price),
+					_ => sealed.deposit,
+				};
+				Self::unreserve_payment(maybe_asset, &bidder, refund)?;
+			}
+
+			if let Some((winner, price)) = maybe_bid.clone() {
+				Self::repatriate_price(
+					trade,
+					&winner,
+					&config.owner,
+					&BundleOf::<T, I>::get(trade),
+					price,
+				)?;
+
+				for package in BundleOf::<T, I>::get(trade) {
+					Self::repatriate_reserved_item(
+						&config.owner,
+						&package.collection,
+						&package.item,
+						&winner,
+						package.amount,
+						ItemBalanceStatus::Free,
+					)?;
+				}
+			} else {
+				for package in BundleOf::<T, I>::get(trade) {
+					Self::unreserved_item(
+						&config.owner,
+						&package.collection,
+						&package.item,
+						package.amount,
+					)?;
+				}
+			}
+			<T as Config<I>>::Currency::unreserve(&config.owner, T::BundleDeposit::get());
+
+			SealedAuctionOf::<T, I>::remove(trade);
+			BundleOf::<T, I>::remove(trade);
+			TradeAssetOf::<T, I>::remove(trade);
+
+			Self::deposit_event(Event::<T, I>::SealedAuctionClaimed {
+				trade: *trade,
+				maybe_bid,
+				slashed,
+			});
+			return Ok(())
+		}
+		Err(Error::<T, I>::UnknownAuction.into())
+	}
+}
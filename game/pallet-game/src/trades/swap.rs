@@ -1,5 +1,5 @@
 use crate::*;
-use frame_support::{pallet_prelude::*, traits::ExistenceRequirement};
+use frame_support::pallet_prelude::*;
 use frame_system::pallet_prelude::BlockNumberFor;
 use gafi_support::game::{Bundle, Swap, TradeType};
 
@@ -21,6 +21,7 @@ impl<T: Config<I>, I: 'static>
 			!BundleOf::<T, I>::contains_key(trade),
 			Error::<T, I>::TradeIdInUse,
 		);
+		Self::ensure_transferable_bundle(&required)?;
 
 		<T as Config<I>>::Currency::reserve(&who, T::BundleDeposit::get())?;
 
@@ -50,6 +51,7 @@ impl<T: Config<I>, I: 'static>
 				end_block,
 			},
 		);
+		Self::schedule_trade_expiry(trade, TradeType::Swap, end_block);
 
 		Self::deposit_event(Event::<T, I>::SwapSet {
 			trade: *trade,
@@ -88,11 +90,12 @@ impl<T: Config<I>, I: 'static>
 				);
 
 				// make deposit
-				<T as pallet::Config<I>>::Currency::transfer(
+				Self::transfer_price(
+					trade,
 					&who,
 					&config.owner,
+					&BundleOf::<T, I>::get(trade),
 					price,
-					ExistenceRequirement::KeepAlive,
 				)?;
 			}
 
@@ -123,6 +126,7 @@ impl<T: Config<I>, I: 'static>
 			// end trade
 			<T as pallet::Config<I>>::Currency::unreserve(&config.owner, T::BundleDeposit::get());
 			BundleOf::<T, I>::remove(trade);
+			TradeAssetOf::<T, I>::remove(trade);
 			TradeConfigOf::<T, I>::remove(trade);
 
 			Self::deposit_event(Event::<T, I>::SwapClaimed {
@@ -151,6 +155,7 @@ impl<T: Config<I>, I: 'static>
 			// end trade
 			<T as pallet::Config<I>>::Currency::unreserve(&config.owner, T::BundleDeposit::get());
 			BundleOf::<T, I>::remove(trade);
+			TradeAssetOf::<T, I>::remove(trade);
 			TradeConfigOf::<T, I>::remove(trade);
 
 			Self::deposit_event(Event::<T, I>::TradeCanceled {
@@ -1,6 +1,6 @@
 use crate::*;
 use frame_support::pallet_prelude::*;
-use gafi_support::game::{Retail, Trade, TradeType};
+use gafi_support::game::{Rental, Retail, Trade, TradeType};
 
 impl<T: Config<I>, I: 'static> Trade<T::AccountId, T::TradeId> for Pallet<T, I> {
 	fn do_cancel_trade(
@@ -21,12 +21,24 @@ impl<T: Config<I>, I: 'static> Trade<T::AccountId, T::TradeId> for Pallet<T, I>
 			TradeType::Auction => {
 				Self::do_claim_auction(trade)?;
 			},
+			TradeType::CandleAuction => {
+				Self::do_claim_candle_auction(trade)?;
+			},
+			TradeType::DutchAuction => {
+				Self::do_cancel_dutch_auction(trade, who)?;
+			},
+			TradeType::SealedAuction => {
+				Self::do_claim_sealed_auction(trade)?;
+			},
 			TradeType::Swap => {
 				Self::do_cancel_swap(trade, who)?;
 			},
 			TradeType::SetBuy => {
 				Self::do_cancel_set_buy(trade, who)?;
 			},
+			TradeType::Rental => {
+				Self::do_cancel_rental(trade, who)?;
+			},
 		};
 
 		Self::deposit_event(Event::<T, I>::TradeCanceled { trade: *trade, who: who.clone() });
@@ -1,5 +1,5 @@
 use crate::*;
-use frame_support::{pallet_prelude::*, traits::ExistenceRequirement};
+use frame_support::pallet_prelude::*;
 use frame_system::pallet_prelude::BlockNumberFor;
 use gafi_support::game::{Bundle, TradeType, Wholesale};
 
@@ -52,6 +52,7 @@ impl<T: Config<I>, I: 'static>
 				end_block,
 			},
 		);
+		Self::schedule_trade_expiry(trade, TradeType::Bundle, end_block);
 
 		Self::deposit_event(Event::<T, I>::BundleSet {
 			trade: *trade,
@@ -96,12 +97,7 @@ impl<T: Config<I>, I: 'static>
 			ensure!(bid_price >= price, Error::<T, I>::BidTooLow);
 
 			// make deposit
-			<T as pallet::Config<I>>::Currency::transfer(
-				&who,
-				&config.owner,
-				price,
-				ExistenceRequirement::KeepAlive,
-			)?;
+			Self::transfer_price(trade, &who, &config.owner, &bundle, price)?;
 
 			// transfer items
 			for package in bundle.clone() {
@@ -118,6 +114,7 @@ impl<T: Config<I>, I: 'static>
 			// end trade
 			<T as pallet::Config<I>>::Currency::unreserve(&config.owner, T::BundleDeposit::get());
 			BundleOf::<T, I>::remove(trade);
+			TradeAssetOf::<T, I>::remove(trade);
 			TradeConfigOf::<T, I>::remove(trade);
 
 			Self::deposit_event(Event::<T, I>::BundleBought {
@@ -147,6 +144,7 @@ impl<T: Config<I>, I: 'static>
 			// end trade
 			<T as pallet::Config<I>>::Currency::unreserve(&config.owner, T::BundleDeposit::get());
 			BundleOf::<T, I>::remove(trade);
+			TradeAssetOf::<T, I>::remove(trade);
 			TradeConfigOf::<T, I>::remove(trade);
 
 			Self::deposit_event(Event::<T, I>::TradeCanceled {
@@ -1,5 +1,5 @@
 use crate::*;
-use frame_support::{pallet_prelude::*, traits::BalanceStatus};
+use frame_support::pallet_prelude::*;
 use frame_system::pallet_prelude::BlockNumberFor;
 use gafi_support::game::{Bundle, Wishlist};
 
@@ -27,8 +27,11 @@ impl<T: Config<I>, I: 'static>
 			Error::<T, I>::TradeIdInUse
 		);
 
+		Self::ensure_transferable_bundle(&wishlist)?;
+
 		<T as Config<I>>::Currency::reserve(&who, T::BundleDeposit::get())?;
-		<T as Config<I>>::Currency::reserve(&who, price)?;
+		let maybe_asset = TradeAssetOf::<T, I>::get(trade);
+		Self::reserve_payment(maybe_asset, who, price)?;
 
 		<BundleOf<T, I>>::try_mutate(trade, |package_vec| -> DispatchResult {
 			package_vec
@@ -48,6 +51,7 @@ impl<T: Config<I>, I: 'static>
 				end_block,
 			},
 		);
+		Self::schedule_trade_expiry(trade, TradeType::Wishlist, end_block);
 
 		Self::deposit_event(Event::<T, I>::WishlistSet {
 			trade: *trade,
@@ -95,12 +99,7 @@ impl<T: Config<I>, I: 'static>
 			ensure!(ask_price <= price, Error::<T, I>::AskTooHigh);
 
 			// make deposit
-			<T as pallet::Config<I>>::Currency::repatriate_reserved(
-				&config.owner,
-				&who,
-				price,
-				BalanceStatus::Free,
-			)?;
+			Self::repatriate_price(trade, &config.owner, &who, &bundle, price)?;
 
 			// transfer items
 			for package in bundle.clone() {
@@ -116,6 +115,7 @@ impl<T: Config<I>, I: 'static>
 			// end trade
 			<T as pallet::Config<I>>::Currency::unreserve(&config.owner, T::BundleDeposit::get());
 			BundleOf::<T, I>::remove(trade);
+			TradeAssetOf::<T, I>::remove(trade);
 			TradeConfigOf::<T, I>::remove(trade);
 
 			Self::deposit_event(Event::<T, I>::WishlistFilled {
@@ -138,9 +138,17 @@ impl<T: Config<I>, I: 'static>
 			// ensure owner
 			ensure!(who.eq(&config.owner), Error::<T, I>::NoPermission);
 
+			// unreserve the price reserved by `order_bundle`
+			Self::unreserve_payment(
+				TradeAssetOf::<T, I>::get(trade),
+				&config.owner,
+				config.maybe_price.unwrap_or_default(),
+			)?;
+
 			// end trade
 			<T as pallet::Config<I>>::Currency::unreserve(&config.owner, T::BundleDeposit::get());
 			BundleOf::<T, I>::remove(trade);
+			TradeAssetOf::<T, I>::remove(trade);
 			TradeConfigOf::<T, I>::remove(trade);
 
 			Self::deposit_event(Event::<T, I>::TradeCanceled {
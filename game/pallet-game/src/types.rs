@@ -1,12 +1,12 @@
 use crate::*;
 use codec::{Decode, Encode};
-use frame_system::pallet_prelude::BlockNumberFor;
 use core::primitive::u32;
 use frame_support::{
 	pallet_prelude::{BoundedVec, MaxEncodedLen},
 	RuntimeDebug,
 };
-use gafi_support::game::{Loot, MintSettings};
+use frame_system::pallet_prelude::BlockNumberFor;
+use gafi_support::game::{Loot, MintProof, MintSettings, MintVoucher};
 
 use scale_info::TypeInfo;
 pub type BalanceOf<T, I = ()> =
@@ -14,10 +14,15 @@ pub type BalanceOf<T, I = ()> =
 
 pub type AccountIdLookupOf<T> = <<T as SystemConfig>::Lookup as StaticLookup>::Source;
 
-pub type GameDetailsFor<T, I> = GameDetails<<T as SystemConfig>::AccountId, BalanceOf<T, I>>;
+pub type GameDetailsFor<T, I> =
+	GameDetails<<T as SystemConfig>::AccountId, BalanceOf<T, I>, <T as Config<I>>::AssetId>;
 
-pub type TradeConfigFor<T, I> =
-	TradeConfig<<T as SystemConfig>::AccountId, BalanceOf<T, I>, BundleFor<T, I>, BlockNumberFor<T>>;
+pub type TradeConfigFor<T, I> = TradeConfig<
+	<T as SystemConfig>::AccountId,
+	BalanceOf<T, I>,
+	BundleFor<T, I>,
+	BlockNumberFor<T>,
+>;
 
 pub type MintRequestFor<T, I> = MintRequest<
 	<T as SystemConfig>::AccountId,
@@ -26,6 +31,19 @@ pub type MintRequestFor<T, I> = MintRequest<
 	BlockNumberFor<T>,
 >;
 
+pub type UpgradeRequestFor<T> = UpgradeRequest<
+	<T as SystemConfig>::AccountId,
+	<T as pallet_nfts::Config>::CollectionId,
+	<T as pallet_nfts::Config>::ItemId,
+>;
+
+pub type SalvageRequestFor<T, I> = SalvageRequest<
+	<T as SystemConfig>::AccountId,
+	<T as pallet_nfts::Config>::CollectionId,
+	<T as pallet_nfts::Config>::ItemId,
+	LootTableFor<T, I>,
+>;
+
 pub type PoolDetailsFor<T, I> = PoolDetails<
 	<T as SystemConfig>::AccountId,
 	BalanceOf<T, I>,
@@ -33,14 +51,53 @@ pub type PoolDetailsFor<T, I> = PoolDetails<
 	<T as pallet_nfts::Config>::CollectionId,
 >;
 
+pub type RecipeDetailsFor<T, I> = RecipeDetails<
+	<T as SystemConfig>::AccountId,
+	<T as Config<I>>::GameId,
+	BalanceOf<T, I>,
+	BundleFor<T, I>,
+>;
+
+pub type SalvageDetailsFor<T, I> = SalvageDetails<
+	<T as SystemConfig>::AccountId,
+	BalanceOf<T, I>,
+	BundleFor<T, I>,
+	LootTableFor<T, I>,
+>;
+
+pub type MatchDetailsFor<T, I> = MatchDetails<
+	<T as SystemConfig>::AccountId,
+	BalanceOf<T, I>,
+	<T as Config<I>>::AssetId,
+	<T as Config<I>>::GameId,
+	BlockNumberFor<T>,
+	BoundedVec<
+		(<T as SystemConfig>::AccountId, BundleFor<T, I>),
+		<T as Config<I>>::MaxMatchPlayers,
+	>,
+>;
+
 pub type CollectionConfigFor<T, I = ()> =
 	CollectionConfig<BalanceOf<T, I>, BlockNumberFor<T>, <T as pallet_nfts::Config>::CollectionId>;
 
 pub type ItemUpgradeConfigFor<T, I = ()> =
-	UpgradeItemConfig<<T as pallet_nfts::Config>::ItemId, BalanceOf<T, I>>;
+	UpgradeItemConfig<<T as pallet_nfts::Config>::ItemId, BalanceOf<T, I>, BundleFor<T, I>>;
+
+pub type MintSettingsFor<T, I = ()> = MintSettings<
+	<T as SystemConfig>::AccountId,
+	BalanceOf<T, I>,
+	BlockNumberFor<T>,
+	<T as pallet_nfts::Config>::CollectionId,
+>;
 
-pub type MintSettingsFor<T, I = ()> =
-	MintSettings<BalanceOf<T, I>, BlockNumberFor<T>, <T as pallet_nfts::Config>::CollectionId>;
+pub type MintProofFor<T> =
+	MintProof<BlockNumberFor<T>, <T as pallet_nfts::Config>::OffchainSignature>;
+
+pub type MintVoucherFor<T, I = ()> = MintVoucher<
+	<T as pallet::Config<I>>::PoolId,
+	<T as SystemConfig>::AccountId,
+	BlockNumberFor<T>,
+>;
 
 pub(crate) type BundleFor<T, I = ()> = BoundedVec<
 	Package<<T as pallet_nfts::Config>::CollectionId, <T as pallet_nfts::Config>::ItemId>,
@@ -54,7 +111,7 @@ pub(crate) type LootTableFor<T, I = ()> = BoundedVec<
 
 /// Information about a game.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-pub struct GameDetails<AccountId, DepositBalance> {
+pub struct GameDetails<AccountId, DepositBalance, AssetId> {
 	/// game's owner.
 	pub(super) owner: AccountId,
 	/// The total balance deposited by the owner for all the storage data associated with this
@@ -64,13 +121,48 @@ pub struct GameDetails<AccountId, DepositBalance> {
 	pub(super) collections: u32,
 	/// Can thaw tokens, force transfers and burn tokens from any account.
 	pub(super) admin: AccountId,
+	/// Whether the game is being destroyed.
+	pub(super) is_destroying: bool,
+	/// Fee taken from each trade settlement of the game items, in basis points.
+	pub(super) trade_fee: u16,
+	/// Account receiving the trade fees, the owner or the treasury if not set.
+	pub(super) fee_account: Option<AccountId>,
+	/// Asset the upgrades, crafting fees and match stakes are paid in, the native currency if not
+	/// set.
+	pub(super) payment_asset: Option<AssetId>,
+	/// Whether the mint, trade fee and crafting revenue is paid to the game treasury.
+	pub(super) treasury_routing: bool,
+	/// The total number of recipes of this game.
+	pub(super) recipes: u32,
+}
+
+impl<AccountId, DepositBalance, AssetId> GameDetails<AccountId, DepositBalance, AssetId> {
+	pub fn destroy_witness(&self) -> GameDestroyWitness {
+		GameDestroyWitness {
+			collections: self.collections,
+		}
+	}
+}
+
+/// Witness data for the destroy transactions.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct GameDestroyWitness {
+	/// The total number of outstanding collections of this game.
+	#[codec(compact)]
+	pub collections: u32,
 }
 
 /// Upgrade Item configuration.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-pub struct UpgradeItemConfig<ItemId, Price> {
+pub struct UpgradeItemConfig<ItemId, Price, Bundle> {
 	pub item: ItemId,
 	pub fee: Price,
+	/// Items burned per upgraded item.
+	pub materials: Bundle,
+	/// Chance of a successful upgrade in basis points.
+	pub success_rate: u16,
+	/// What happens to the items when the upgrade fails.
+	pub on_failure: UpgradeFailure,
 }
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -91,6 +183,61 @@ pub struct AuctionConfig<AccountId, Price, BlockNumber> {
 	pub duration: BlockNumber,
 }
 
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CandleAuctionConfig<AccountId, Price, BlockNumber> {
+	pub owner: AccountId,
+	pub maybe_price: Option<Price>,
+	pub start_block: BlockNumber,
+	/// The ending period starts, the auction may close at any block from here.
+	pub early_end: BlockNumber,
+	pub end_block: BlockNumber,
+	/// The closing block drawn once the auction ends, unknown while bidding.
+	pub closing_block: Option<BlockNumber>,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct DutchAuctionConfig<AccountId, Price, BlockNumber> {
+	pub owner: AccountId,
+	pub start_price: Price,
+	/// The price stays at `floor_price` once `duration` has passed.
+	pub floor_price: Price,
+	pub start_block: BlockNumber,
+	pub duration: BlockNumber,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RentalConfig<AccountId, Price, BlockNumber> {
+	pub owner: AccountId,
+	pub price_per_block: Price,
+	pub max_duration: BlockNumber,
+	/// The current renter and the block when the term ends.
+	pub maybe_renter: Option<(AccountId, BlockNumber)>,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct SealedAuctionConfig<AccountId, Price, BlockNumber> {
+	pub owner: AccountId,
+	pub maybe_price: Option<Price>,
+	pub start_block: BlockNumber,
+	/// Bids are committed before `reveal_block` and revealed from `reveal_block`.
+	pub reveal_block: BlockNumber,
+	pub end_block: BlockNumber,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct SealedBid<Hash, Price> {
+	pub commitment: Hash,
+	pub deposit: Price,
+	pub maybe_bid: Option<Price>,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RoyaltyConfig<AccountId> {
+	pub beneficiary: AccountId,
+	/// Royalty rate in basis points.
+	pub rate: u16,
+}
+
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub enum ItemBalanceStatus {
 	Reserved,
@@ -110,7 +257,62 @@ pub struct PoolDetails<AccountId, Balance, BlockNumber, CollectionId> {
 	/// Can create a new pool, add more resources.
 	pub(super) admin: AccountId,
 	/// mint settings
-	pub(super) mint_settings: MintSettings<Balance, BlockNumber, CollectionId>,
+	pub(super) mint_settings: MintSettings<AccountId, Balance, BlockNumber, CollectionId>,
+}
+
+/// Information about a match escrow.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct MatchDetails<AccountId, Balance, AssetId, GameId, BlockNumber, Players> {
+	/// match's owner.
+	pub(super) owner: AccountId,
+	/// The balance deposited by the owner for the match.
+	pub(super) owner_deposit: Balance,
+	/// The game the match belongs to.
+	pub(super) game: GameId,
+	/// Amount staked by each player.
+	pub(super) stake: Balance,
+	/// Asset the stakes are paid in, `None` for the native currency.
+	pub(super) maybe_asset: Option<AssetId>,
+	/// Server allowed to submit the result along with the game owner and admin.
+	pub(super) maybe_server: Option<AccountId>,
+	/// The result must be submitted before this block.
+	pub(super) end_block: BlockNumber,
+	/// Players and their staked items.
+	pub(super) players: Players,
+}
+
+/// Information about a crafting recipe.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RecipeDetails<AccountId, GameId, Balance, Bundle> {
+	/// recipe's owner.
+	pub(super) owner: AccountId,
+	/// The balance deposited by the owner for the recipe.
+	pub(super) owner_deposit: Balance,
+	/// The game the recipe belongs to.
+	pub(super) game: GameId,
+	/// Items burned by each craft.
+	pub(super) input: Bundle,
+	/// Fee paid to the owner, or the game treasury, by each craft.
+	pub(super) maybe_fee: Option<Balance>,
+	/// Whether the fees are revenue of the game, only for recipes created by the owner or admin
+	/// of the game.
+	pub(super) game_revenue: bool,
+	/// Items produced by each craft.
+	pub(super) output: Bundle,
+	/// Remaining crafts, unlimited if not set. The finite supply outputs of the remaining crafts
+	/// are reserved from the owner.
+	pub(super) maybe_crafts: Option<Amount>,
+}
+
+/// Information about the salvage of an item.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct SalvageDetails<AccountId, Balance, Bundle, LootTable> {
+	/// salvage's owner.
+	pub(super) owner: AccountId,
+	/// The balance deposited by the owner for the salvage.
+	pub(super) owner_deposit: Balance,
+	/// Items received for each salvaged item.
+	pub(super) output: SalvageOutput<Bundle, LootTable>,
 }
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -135,6 +337,66 @@ pub struct MintRequest<AccountId, PoolId, Balance, BlockNumber> {
 
 	/// block_number request
 	pub(super) block_number: BlockNumber,
+
+	/// Mint limits used by the request, given back if the request is refunded
+	pub(super) maybe_usage: Option<MintUsage<BlockNumber>>,
+
+	/// Nonce of the voucher used by the request, released if the request is refunded
+	pub(super) maybe_nonce: Option<u32>,
+}
+
+/// An upgrade attempt resolved in a later block, so its outcome is unknown when submitted.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct UpgradeRequest<AccountId, CollectionId, ItemId> {
+	/// Owner of the items being upgraded.
+	pub(super) who: AccountId,
+	pub(super) collection: CollectionId,
+	/// The item being upgraded, reserved until the attempt is resolved.
+	pub(super) item: ItemId,
+	/// The item of the next level.
+	pub(super) new_item: ItemId,
+	pub(super) amount: Amount,
+	/// Chance of each item to be upgraded in basis points.
+	pub(super) success_rate: u16,
+	/// What happens to the items failing the upgrade.
+	pub(super) on_failure: UpgradeFailure,
+}
+
+/// A salvage rolled in a later block, so its loot is unknown when submitted.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct SalvageRequest<AccountId, CollectionId, ItemId, LootTable> {
+	/// Receiver of the loot.
+	pub(super) who: AccountId,
+	pub(super) collection: CollectionId,
+	/// The item burned by the salvage.
+	pub(super) item: ItemId,
+	pub(super) amount: Amount,
+	/// Loot table of the salvage when it was submitted, rolled once per item.
+	pub(super) table: LootTable,
+}
+
+/// Items minted by an account from a pool, tracked to enforce the mint limits.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default, TypeInfo, MaxEncodedLen)]
+pub struct MintRecord<BlockNumber> {
+	/// Items minted over the pool lifetime.
+	pub(super) total: Amount,
+	/// First block of the current window.
+	pub(super) window_start: BlockNumber,
+	/// Items minted within the current window.
+	pub(super) window_mints: Amount,
+	/// Block of the last mint request.
+	pub(super) last_block: Option<BlockNumber>,
+}
+
+/// The part of a `MintRecord` used by a mint request.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct MintUsage<BlockNumber> {
+	/// Block of the mint request.
+	pub(super) block_number: BlockNumber,
+	/// Window the request was counted in.
+	pub(super) window_start: BlockNumber,
+	/// Block of the mint request before this one.
+	pub(super) prev_block: Option<BlockNumber>,
 }
 
 /// Information about the game's metadata.
@@ -46,8 +46,8 @@ pub trait WeightInfo {
 	fn add_supply() -> Weight;
 	fn burn() -> Weight;
 	fn transfer() -> Weight;
-	fn set_upgrade_item(s: u32, ) -> Weight;
-	fn upgrade_item() -> Weight;
+	fn set_upgrade_item(s: u32, m: u32, ) -> Weight;
+	fn upgrade_item(m: u32, ) -> Weight;
 	fn set_price() -> Weight;
 	fn buy_item() -> Weight;
 	fn set_bundle(s: u32, ) -> Weight;
@@ -79,6 +79,51 @@ pub trait WeightInfo {
 	fn create_stable_pool_with_data() -> Weight;
 	fn set_pool_metadata() -> Weight;
 	fn clear_pool_metadata() -> Weight;
+	fn start_game_destroy() -> Weight;
+	fn destroy_game_roles(r: u32, ) -> Weight;
+	fn finish_game_destroy(c: u32, ) -> Weight;
+	fn set_candle_auction(s: u32, ) -> Weight;
+	fn bid_candle_auction() -> Weight;
+	fn close_candle_auction(b: u32, ) -> Weight;
+	fn set_dutch_auction(s: u32, ) -> Weight;
+	fn buy_dutch_auction() -> Weight;
+	fn set_sealed_auction(s: u32, ) -> Weight;
+	fn commit_sealed_bid() -> Weight;
+	fn reveal_sealed_bid() -> Weight;
+	fn close_sealed_auction(b: u32, ) -> Weight;
+	fn set_royalty() -> Weight;
+	fn clear_royalty() -> Weight;
+	fn set_trade_fee() -> Weight;
+	fn set_book_price(m: u32, ) -> Weight;
+	fn set_book_order(m: u32, ) -> Weight;
+	fn expire_trade() -> Weight;
+	fn set_payment_asset() -> Weight;
+	fn create_recipe(i: u32, o: u32, ) -> Weight;
+	fn remove_recipe() -> Weight;
+	fn craft() -> Weight;
+	fn set_upgrade_chance() -> Weight;
+	fn set_salvage(m: u32, ) -> Weight;
+	fn clear_salvage() -> Weight;
+	fn salvage() -> Weight;
+	fn set_rental(s: u32, ) -> Weight;
+	fn rent() -> Weight;
+	fn return_rental() -> Weight;
+	fn set_soulbound() -> Weight;
+	fn approve_operator() -> Weight;
+	fn revoke_operator() -> Weight;
+	fn transfer_from() -> Weight;
+	fn create_match() -> Weight;
+	fn join_match(s: u32, ) -> Weight;
+	fn settle_match(p: u32, ) -> Weight;
+	fn refund_match(p: u32, ) -> Weight;
+	fn set_treasury_routing() -> Weight;
+	fn spend_treasury() -> Weight;
+	fn set_accept_game_ownership() -> Weight;
+	fn transfer_game_ownership(c: u32, ) -> Weight;
+	fn set_game_admin(c: u32, ) -> Weight;
+	fn grant_role() -> Weight;
+	fn revoke_role() -> Weight;
+	fn process_upgrade_request(a: u32, ) -> Weight;
 }
 
 /// Weights for pallet_game using the Substrate node and recommended hardware.
@@ -117,6 +162,8 @@ impl<T: frame_system::Config + pallet_nfts::Config> WeightInfo for SubstrateWeig
 	/// Proof: Game CollectionsOf (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
 	/// Storage: Game GamesOf (r:1 w:1)
 	/// Proof: Game GamesOf (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: Game CollectionGameOf (r:0 w:1)
+	/// Proof: Game CollectionGameOf (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionRoleOf (r:0 w:1)
 	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionConfigOf (r:0 w:1)
@@ -130,7 +177,7 @@ impl<T: frame_system::Config + pallet_nfts::Config> WeightInfo for SubstrateWeig
 		// Minimum execution time: 63_000_000 picoseconds.
 		Weight::from_parts(64_000_000, 3593)
 			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 	/// Storage: Nfts Collection (r:1 w:1)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
@@ -220,8 +267,11 @@ impl<T: frame_system::Config + pallet_nfts::Config> WeightInfo for SubstrateWeig
 	/// Proof: Game LevelOf (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
 	/// Storage: Nfts Account (r:0 w:1)
 	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Game SupplyOf (r:10 w:0)
+	/// Proof: Game SupplyOf (max_values: None, max_size: Some(45), added: 2520, mode: MaxEncodedLen)
 	/// The range of component `s` is `[0, 50]`.
-	fn set_upgrade_item(s: u32, ) -> Weight {
+	/// The range of component `m` is `[0, 10]`.
+	fn set_upgrade_item(s: u32, m: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `822`
 		//  Estimated: `6036`
@@ -229,8 +279,12 @@ impl<T: frame_system::Config + pallet_nfts::Config> WeightInfo for SubstrateWeig
 		Weight::from_parts(117_731_912, 6036)
 			// Standard Error: 2_741
 			.saturating_add(Weight::from_parts(6_528, 0).saturating_mul(s.into()))
+			// Standard Error: 13_706
+			.saturating_add(Weight::from_parts(3_108_420, 0).saturating_mul(m.into()))
 			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(m.into())))
 			.saturating_add(T::DbWeight::get().writes(9_u64))
+			.saturating_add(Weight::from_parts(0, 2520).saturating_mul(m.into()))
 	}
 	/// Storage: Game LevelOf (r:1 w:0)
 	/// Proof: Game LevelOf (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
@@ -240,16 +294,24 @@ impl<T: frame_system::Config + pallet_nfts::Config> WeightInfo for SubstrateWeig
 	/// Proof: Game UpgradeConfigOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	/// Storage: Nfts Collection (r:1 w:0)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
-	/// Storage: Game ItemBalanceOf (r:2 w:2)
+	/// Storage: Game ItemBalanceOf (r:12 w:12)
 	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
-	fn upgrade_item() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `720`
-		//  Estimated: `6108`
-		// Minimum execution time: 48_000_000 picoseconds.
-		Weight::from_parts(49_000_000, 6108)
+	/// Storage: Game SupplyOf (r:10 w:10)
+	/// Proof: Game SupplyOf (max_values: None, max_size: Some(45), added: 2520, mode: MaxEncodedLen)
+	/// The range of component `m` is `[0, 10]`.
+	fn upgrade_item(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1041 + m * (162 ±0)`
+		//  Estimated: `6118 + m * (5079 ±0)`
+		// Minimum execution time: 62_000_000 picoseconds.
+		Weight::from_parts(63_912_540, 6118)
+			// Standard Error: 21_930
+			.saturating_add(Weight::from_parts(7_008_746, 0).saturating_mul(m.into()))
 			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(m.into())))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 5079).saturating_mul(m.into()))
 	}
 	/// Storage: Game NextTradeId (r:1 w:1)
 	/// Proof: Game NextTradeId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
@@ -393,14 +455,16 @@ impl<T: frame_system::Config + pallet_nfts::Config> WeightInfo for SubstrateWeig
 	/// Proof: Game CollectionsOf (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
 	/// Storage: Game GamesOf (r:0 w:1)
 	/// Proof: Game GamesOf (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: Game CollectionGameOf (r:1 w:1)
+	/// Proof: Game CollectionGameOf (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
 	fn remove_collection() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `460`
 		//  Estimated: `3534`
 		// Minimum execution time: 25_000_000 picoseconds.
-		Weight::from_parts(26_000_000, 3534)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		Weight::from_parts(27_000_000, 3534)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: Game NextTradeId (r:1 w:1)
 	/// Proof: Game NextTradeId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
@@ -573,16 +637,20 @@ impl<T: frame_system::Config + pallet_nfts::Config> WeightInfo for SubstrateWeig
 	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	/// Storage: Game AddingAcceptance (r:0 w:1)
+	/// Storage: Game Game (r:1 w:0)
+	/// Proof: Game Game (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Game AddingAcceptance (r:1 w:1)
 	/// Proof: Game AddingAcceptance (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Game AddingAcceptanceOf (r:1 w:2)
+	/// Proof: Game AddingAcceptanceOf (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
 	fn set_accept_adding() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `401`
+		//  Measured:  `612`
 		//  Estimated: `3593`
-		// Minimum execution time: 37_000_000 picoseconds.
-		Weight::from_parts(38_000_000, 3593)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		// Minimum execution time: 45_000_000 picoseconds.
+		Weight::from_parts(46_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: Game GameRoleOf (r:1 w:0)
 	/// Proof: Game GameRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
@@ -693,16 +761,20 @@ impl<T: frame_system::Config + pallet_nfts::Config> WeightInfo for SubstrateWeig
 	/// Proof: Game PoolOf (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Game UsedVoucherOf (r:1 w:1)
+	/// Proof: Game UsedVoucherOf (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Game MintRecordOf (r:1 w:1)
+	/// Proof: Game MintRecordOf (max_values: None, max_size: Some(75), added: 2550, mode: MaxEncodedLen)
 	/// Storage: Game MintRequestOf (r:1 w:1)
 	/// Proof: Game MintRequestOf (max_values: None, max_size: Some(1093), added: 3568, mode: MaxEncodedLen)
 	fn request_mint() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `337`
 		//  Estimated: `4558`
-		// Minimum execution time: 37_000_000 picoseconds.
-		Weight::from_parts(38_000_000, 4558)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		// Minimum execution time: 88_000_000 picoseconds.
+		Weight::from_parts(94_000_000, 4558)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 
 	/// Storage: Game NextGameId (r:1 w:1)
@@ -890,222 +962,143 @@ impl<T: frame_system::Config + pallet_nfts::Config> WeightInfo for SubstrateWeig
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
-}
-
-// For backwards compatibility and tests
-impl WeightInfo for () {
-	/// Storage: Game NextGameId (r:1 w:1)
-	/// Proof: Game NextGameId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	/// Storage: Game GameAccount (r:0 w:1)
-	/// Proof: Game GameAccount (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
-	/// Storage: Game Game (r:0 w:1)
-	/// Proof: Game Game (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
-	/// Storage: Game GameRoleOf (r:0 w:1)
-	/// Proof: Game GameRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
-	fn create_game() -> Weight {
+	/// Storage: Game Game (r:1 w:1)
+	/// Proof: Game Game (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	fn start_game_destroy() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `109`
-		//  Estimated: `3593`
-		// Minimum execution time: 38_000_000 picoseconds.
-		Weight::from_parts(39_000_000, 3593)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+		//  Measured:  `312`
+		//  Estimated: `3562`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 3562)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-	/// Storage: Game GameRoleOf (r:1 w:0)
-	/// Proof: Game GameRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
 	/// Storage: Game Game (r:1 w:0)
-	/// Proof: Game Game (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
-	/// Storage: Nfts NextCollectionId (r:1 w:1)
-	/// Proof: Nfts NextCollectionId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	/// Storage: Nfts Collection (r:1 w:1)
-	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
+	/// Proof: Game Game (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Game GameRoleOf (r:1001 w:1000)
+	/// Proof: Game GameRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Game AddingAcceptanceOf (r:1001 w:1000)
+	/// Proof: Game AddingAcceptanceOf (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: Game AddingAcceptance (r:0 w:1000)
+	/// Proof: Game AddingAcceptance (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1000 w:1000)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `r` is `[0, 1000]`.
+	fn destroy_game_roles(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `344 + r * (231 ±0)`
+		//  Estimated: `3562 + r * (2603 ±0)`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(16_000_000, 3562)
+			// Standard Error: 8_472
+			.saturating_add(Weight::from_parts(15_112_384, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(r.into()))
+	}
+	/// Storage: Game Game (r:1 w:1)
+	/// Proof: Game Game (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Game GameRoleOf (r:1 w:0)
+	/// Proof: Game GameRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Game AddingAcceptanceOf (r:1 w:0)
+	/// Proof: Game AddingAcceptanceOf (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: Game OwnershipAcceptorOf (r:1 w:0)
+	/// Proof: Game OwnershipAcceptorOf (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	/// Storage: Game CollectionsOf (r:1 w:1)
 	/// Proof: Game CollectionsOf (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
-	/// Storage: Game GamesOf (r:1 w:1)
+	/// Storage: Game GamesOf (r:5 w:5)
 	/// Proof: Game GamesOf (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
-	/// Storage: Nfts CollectionRoleOf (r:0 w:1)
-	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
-	/// Storage: Nfts CollectionConfigOf (r:0 w:1)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
-	/// Storage: Nfts CollectionAccount (r:0 w:1)
-	/// Proof: Nfts CollectionAccount (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
-	fn create_game_collection() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `388`
-		//  Estimated: `3593`
-		// Minimum execution time: 63_000_000 picoseconds.
-		Weight::from_parts(64_000_000, 3593)
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(8_u64))
-	}
-	/// Storage: Nfts Collection (r:1 w:1)
-	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
-	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
-	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
-	/// Storage: Nfts Item (r:1 w:1)
-	/// Proof: Nfts Item (max_values: None, max_size: Some(491), added: 2966, mode: MaxEncodedLen)
-	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
-	/// Storage: Nfts ItemConfigOf (r:1 w:1)
-	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	/// Storage: Game ItemBalanceOf (r:1 w:1)
-	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
-	/// Storage: Game SupplyOf (r:0 w:1)
-	/// Proof: Game SupplyOf (max_values: None, max_size: Some(45), added: 2520, mode: MaxEncodedLen)
-	/// Storage: Nfts Account (r:0 w:1)
-	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
-	fn create_item() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `493`
-		//  Estimated: `3956`
-		// Minimum execution time: 75_000_000 picoseconds.
-		Weight::from_parts(77_000_000, 3956)
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(7_u64))
-	}
-	/// Storage: Nfts Collection (r:1 w:0)
-	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
-	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
-	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
-	/// Storage: Game SupplyOf (r:1 w:1)
-	/// Proof: Game SupplyOf (max_values: None, max_size: Some(45), added: 2520, mode: MaxEncodedLen)
-	/// Storage: Game ItemBalanceOf (r:1 w:1)
-	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
-	fn add_supply() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `513`
-		//  Estimated: `3549`
-		// Minimum execution time: 37_000_000 picoseconds.
-		Weight::from_parts(38_000_000, 3549)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
-	}
-	/// Storage: Game ItemBalanceOf (r:1 w:1)
-	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
-	fn burn() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `191`
-		//  Estimated: `3549`
-		// Minimum execution time: 21_000_000 picoseconds.
-		Weight::from_parts(22_000_000, 3549)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
-	}
-	/// Storage: Game ItemBalanceOf (r:1 w:1)
-	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
-	fn transfer() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `191`
-		//  Estimated: `3549`
-		// Minimum execution time: 28_000_000 picoseconds.
-		Weight::from_parts(29_000_000, 3549)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
-	}
-	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
-	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
-	/// Storage: Nfts Collection (r:1 w:1)
-	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
-	/// Storage: Nfts ItemConfigOf (r:2 w:1)
-	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
-	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
-	/// Storage: Nfts ItemMetadataOf (r:1 w:1)
-	/// Proof: Nfts ItemMetadataOf (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
+	/// Storage: Game CollectionGameOf (r:5 w:5)
+	/// Proof: Game CollectionGameOf (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	/// Storage: Game UpgradeConfigOf (r:1 w:1)
-	/// Proof: Game UpgradeConfigOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
-	/// Storage: Nfts Item (r:1 w:1)
-	/// Proof: Nfts Item (max_values: None, max_size: Some(491), added: 2966, mode: MaxEncodedLen)
-	/// Storage: Game OriginItemOf (r:0 w:1)
-	/// Proof: Game OriginItemOf (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
-	/// Storage: Game LevelOf (r:0 w:1)
-	/// Proof: Game LevelOf (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
-	/// Storage: Nfts Account (r:0 w:1)
-	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
-	/// The range of component `s` is `[0, 50]`.
-	fn set_upgrade_item(s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `822`
-		//  Estimated: `6036`
-		// Minimum execution time: 115_000_000 picoseconds.
-		Weight::from_parts(117_731_912, 6036)
-			// Standard Error: 2_741
-			.saturating_add(Weight::from_parts(6_528, 0).saturating_mul(s.into()))
-			.saturating_add(RocksDbWeight::get().reads(9_u64))
-			.saturating_add(RocksDbWeight::get().writes(9_u64))
-	}
-	/// Storage: Game LevelOf (r:1 w:0)
-	/// Proof: Game LevelOf (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
-	/// Storage: Game OriginItemOf (r:1 w:0)
-	/// Proof: Game OriginItemOf (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
-	/// Storage: Game UpgradeConfigOf (r:1 w:0)
-	/// Proof: Game UpgradeConfigOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
-	/// Storage: Nfts Collection (r:1 w:0)
-	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
-	/// Storage: Game ItemBalanceOf (r:2 w:2)
-	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
-	fn upgrade_item() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `720`
-		//  Estimated: `6108`
-		// Minimum execution time: 48_000_000 picoseconds.
-		Weight::from_parts(49_000_000, 6108)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	/// Storage: Game GameMetadataOf (r:0 w:1)
+	/// Proof: Game GameMetadataOf (max_values: None, max_size: Some(322), added: 2797, mode: MaxEncodedLen)
+	/// Storage: Game GameAccount (r:0 w:1)
+	/// Proof: Game GameAccount (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// The range of component `c` is `[0, 5]`.
+	fn finish_game_destroy(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `626 + c * (61 ±0)`
+		//  Estimated: `6196 + c * (2508 ±0)`
+		// Minimum execution time: 56_000_000 picoseconds.
+		Weight::from_parts(58_126_509, 6196)
+			// Standard Error: 41_262
+			.saturating_add(Weight::from_parts(4_187_233, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2508).saturating_mul(c.into()))
 	}
 	/// Storage: Game NextTradeId (r:1 w:1)
 	/// Proof: Game NextTradeId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
 	/// Storage: Game BundleOf (r:1 w:1)
 	/// Proof: Game BundleOf (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
-	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
-	/// Storage: Nfts ItemConfigOf (r:1 w:0)
-	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: Game ItemBalanceOf (r:1 w:1)
 	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
 	/// Storage: Game ReservedBalanceOf (r:1 w:1)
 	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
-	/// Storage: Game TradeConfigOf (r:0 w:1)
-	/// Proof: Game TradeConfigOf (max_values: None, max_size: Some(202), added: 2677, mode: MaxEncodedLen)
-	fn set_price() -> Weight {
+	/// Storage: Game CandleAuctionOf (r:0 w:1)
+	/// Proof: Game CandleAuctionOf (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// The range of component `s` is `[0, 10]`.
+	fn set_candle_auction(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `695`
+		//  Measured:  `294`
 		//  Estimated: `3606`
-		// Minimum execution time: 67_000_000 picoseconds.
-		Weight::from_parts(68_000_000, 3606)
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+		// Minimum execution time: 35_000_000 picoseconds.
+		Weight::from_parts(40_218_774, 3606)
+			// Standard Error: 27_913
+			.saturating_add(Weight::from_parts(15_402_651, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
-	/// Storage: Game TradeConfigOf (r:1 w:0)
-	/// Proof: Game TradeConfigOf (max_values: None, max_size: Some(202), added: 2677, mode: MaxEncodedLen)
+	/// Storage: Game CandleAuctionOf (r:1 w:0)
+	/// Proof: Game CandleAuctionOf (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Game CandleBidsOf (r:1 w:1)
+	/// Proof: Game CandleBidsOf (max_values: None, max_size: Some(6419), added: 8894, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn bid_candle_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6903`
+		//  Estimated: `9884`
+		// Minimum execution time: 52_000_000 picoseconds.
+		Weight::from_parts(54_000_000, 9884)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Game CandleAuctionOf (r:1 w:1)
+	/// Proof: Game CandleAuctionOf (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Game CandleBidsOf (r:1 w:1)
+	/// Proof: Game CandleBidsOf (max_values: None, max_size: Some(6419), added: 8894, mode: MaxEncodedLen)
+	/// Storage: Game RandomSeed (r:1 w:0)
+	/// Proof: Game RandomSeed (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:101 w:101)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: Game BundleOf (r:1 w:1)
 	/// Proof: Game BundleOf (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
-	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
-	/// Storage: Nfts ItemConfigOf (r:1 w:0)
-	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
 	/// Storage: Game ReservedBalanceOf (r:1 w:1)
 	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
 	/// Storage: Game ItemBalanceOf (r:1 w:1)
 	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
-	fn buy_item() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `874`
-		//  Estimated: `3667`
-		// Minimum execution time: 53_000_000 picoseconds.
-		Weight::from_parts(54_000_000, 3667)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	/// The range of component `b` is `[0, 100]`.
+	fn close_candle_auction(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `712 + b * (64 ±0)`
+		//  Estimated: `9884 + b * (2603 ±0)`
+		// Minimum execution time: 61_000_000 picoseconds.
+		Weight::from_parts(63_552_118, 9884)
+			// Standard Error: 19_084
+			.saturating_add(Weight::from_parts(12_604_377, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(b.into()))
 	}
 	/// Storage: Game NextTradeId (r:1 w:1)
 	/// Proof: Game NextTradeId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
@@ -1117,22 +1110,26 @@ impl WeightInfo for () {
 	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
 	/// Storage: Game ReservedBalanceOf (r:1 w:1)
 	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
-	/// Storage: Game TradeConfigOf (r:0 w:1)
-	/// Proof: Game TradeConfigOf (max_values: None, max_size: Some(202), added: 2677, mode: MaxEncodedLen)
+	/// Storage: Game NextExpiryBlock (r:1 w:1)
+	/// Proof: Game NextExpiryBlock (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Game TradeExpiryOf (r:1 w:1)
+	/// Proof: Game TradeExpiryOf (max_values: None, max_size: Some(514), added: 2989, mode: MaxEncodedLen)
+	/// Storage: Game DutchAuctionOf (r:0 w:1)
+	/// Proof: Game DutchAuctionOf (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
 	/// The range of component `s` is `[0, 10]`.
-	fn set_bundle(s: u32, ) -> Weight {
+	fn set_dutch_auction(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `294`
 		//  Estimated: `3606`
 		// Minimum execution time: 35_000_000 picoseconds.
-		Weight::from_parts(39_573_291, 3606)
-			// Standard Error: 30_111
-			.saturating_add(Weight::from_parts(15_637_767, 0).saturating_mul(s.into()))
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+		Weight::from_parts(39_817_402, 3606)
+			// Standard Error: 28_406
+			.saturating_add(Weight::from_parts(15_519_230, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
-	/// Storage: Game TradeConfigOf (r:1 w:1)
-	/// Proof: Game TradeConfigOf (max_values: None, max_size: Some(202), added: 2677, mode: MaxEncodedLen)
+	/// Storage: Game DutchAuctionOf (r:1 w:1)
+	/// Proof: Game DutchAuctionOf (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
 	/// Storage: Game BundleOf (r:1 w:1)
 	/// Proof: Game BundleOf (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
@@ -1145,14 +1142,14 @@ impl WeightInfo for () {
 	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	fn buy_bundle() -> Weight {
+	fn buy_dutch_auction() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1012`
 		//  Estimated: `6108`
-		// Minimum execution time: 93_000_000 picoseconds.
-		Weight::from_parts(98_000_000, 6108)
-			.saturating_add(RocksDbWeight::get().reads(10_u64))
-			.saturating_add(RocksDbWeight::get().writes(7_u64))
+		// Minimum execution time: 94_000_000 picoseconds.
+		Weight::from_parts(99_000_000, 6108)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: Game NextTradeId (r:1 w:1)
 	/// Proof: Game NextTradeId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
@@ -1160,142 +1157,185 @@ impl WeightInfo for () {
 	/// Proof: Game BundleOf (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	/// Storage: Game TradeConfigOf (r:0 w:1)
-	/// Proof: Game TradeConfigOf (max_values: None, max_size: Some(202), added: 2677, mode: MaxEncodedLen)
-	/// The range of component `s` is `[0, 10]`.
-	fn order_bundle(s: u32, ) -> Weight {
+	/// Storage: Game ItemBalanceOf (r:1 w:1)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game ReservedBalanceOf (r:1 w:1)
+	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game SealedAuctionOf (r:0 w:1)
+	/// Proof: Game SealedAuctionOf (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// The range of component `s` is `[0, 10]`.
+	fn set_sealed_auction(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `179`
+		//  Measured:  `294`
 		//  Estimated: `3606`
-		// Minimum execution time: 49_000_000 picoseconds.
-		Weight::from_parts(51_617_714, 3606)
-			// Standard Error: 7_852
-			.saturating_add(Weight::from_parts(147_850, 0).saturating_mul(s.into()))
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+		// Minimum execution time: 35_000_000 picoseconds.
+		Weight::from_parts(40_042_316, 3606)
+			// Standard Error: 28_115
+			.saturating_add(Weight::from_parts(15_447_820, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
-	/// Storage: Game TradeConfigOf (r:1 w:1)
-	/// Proof: Game TradeConfigOf (max_values: None, max_size: Some(202), added: 2677, mode: MaxEncodedLen)
-	/// Storage: Game BundleOf (r:1 w:1)
-	/// Proof: Game BundleOf (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
-	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
-	/// Storage: Nfts ItemConfigOf (r:2 w:0)
-	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
-	/// Storage: System Account (r:2 w:2)
+	/// Storage: Game SealedAuctionOf (r:1 w:0)
+	/// Proof: Game SealedAuctionOf (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Game SealedBidsOf (r:1 w:1)
+	/// Proof: Game SealedBidsOf (max_values: None, max_size: Some(9619), added: 12094, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	/// Storage: Game ItemBalanceOf (r:4 w:4)
-	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
-	fn sell_bundle() -> Weight {
+	fn commit_sealed_bid() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `986`
-		//  Estimated: `11226`
-		// Minimum execution time: 119_000_000 picoseconds.
-		Weight::from_parts(121_000_000, 11226)
-			.saturating_add(RocksDbWeight::get().reads(11_u64))
-			.saturating_add(RocksDbWeight::get().writes(8_u64))
+		//  Measured:  `9803`
+		//  Estimated: `13084`
+		// Minimum execution time: 55_000_000 picoseconds.
+		Weight::from_parts(57_000_000, 13084)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
-	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
-	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
-	/// Storage: Game GameRoleOf (r:1 w:0)
-	/// Proof: Game GameRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
-	/// Storage: Game CollectionsOf (r:1 w:1)
-	/// Proof: Game CollectionsOf (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
-	/// Storage: Game GamesOf (r:0 w:1)
-	/// Proof: Game GamesOf (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
-	fn remove_collection() -> Weight {
+	/// Storage: Game SealedAuctionOf (r:1 w:0)
+	/// Proof: Game SealedAuctionOf (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Game SealedBidsOf (r:1 w:1)
+	/// Proof: Game SealedBidsOf (max_values: None, max_size: Some(9619), added: 12094, mode: MaxEncodedLen)
+	fn reveal_sealed_bid() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `460`
-		//  Estimated: `3534`
-		// Minimum execution time: 25_000_000 picoseconds.
-		Weight::from_parts(26_000_000, 3534)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		//  Measured:  `9803`
+		//  Estimated: `13084`
+		// Minimum execution time: 41_000_000 picoseconds.
+		Weight::from_parts(43_000_000, 13084)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-	/// Storage: Game NextTradeId (r:1 w:1)
-	/// Proof: Game NextTradeId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Game SealedAuctionOf (r:1 w:1)
+	/// Proof: Game SealedAuctionOf (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Game SealedBidsOf (r:1 w:1)
+	/// Proof: Game SealedBidsOf (max_values: None, max_size: Some(9619), added: 12094, mode: MaxEncodedLen)
+	/// Storage: System Account (r:101 w:101)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: Game BundleOf (r:1 w:1)
 	/// Proof: Game BundleOf (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	/// Storage: Game ItemBalanceOf (r:1 w:1)
-	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
 	/// Storage: Game ReservedBalanceOf (r:1 w:1)
 	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
-	/// Storage: Game TradeConfigOf (r:0 w:1)
-	/// Proof: Game TradeConfigOf (max_values: None, max_size: Some(202), added: 2677, mode: MaxEncodedLen)
-	/// The range of component `s` is `[0, 10]`.
-	/// The range of component `x` is `[0, 10]`.
-	fn create_swap(s: u32, x: u32, ) -> Weight {
+	/// Storage: Game ItemBalanceOf (r:1 w:1)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// The range of component `b` is `[0, 100]`.
+	fn close_sealed_auction(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `712 + b * (96 ±0)`
+		//  Estimated: `13084 + b * (2603 ±0)`
+		// Minimum execution time: 62_000_000 picoseconds.
+		Weight::from_parts(64_120_538, 13084)
+			// Standard Error: 19_562
+			.saturating_add(Weight::from_parts(12_798_105, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(b.into()))
+	}
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game ItemRoyaltyOf (r:1 w:1)
+	/// Proof: Game ItemRoyaltyOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	fn set_royalty() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `294`
-		//  Estimated: `3606`
-		// Minimum execution time: 36_000_000 picoseconds.
-		Weight::from_parts(40_441_450, 3606)
-			// Standard Error: 20_522
-			.saturating_add(Weight::from_parts(15_195_615, 0).saturating_mul(s.into()))
-			// Standard Error: 20_522
-			.saturating_add(Weight::from_parts(150_189, 0).saturating_mul(x.into()))
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+		//  Measured:  `301`
+		//  Estimated: `3549`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(22_000_000, 3549)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-	/// Storage: Game TradeConfigOf (r:1 w:1)
-	/// Proof: Game TradeConfigOf (max_values: None, max_size: Some(202), added: 2677, mode: MaxEncodedLen)
-	/// Storage: Game ItemBalanceOf (r:4 w:4)
-	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
-	/// Storage: Game BundleOf (r:1 w:1)
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game ItemRoyaltyOf (r:1 w:1)
+	/// Proof: Game ItemRoyaltyOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	fn clear_royalty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `371`
+		//  Estimated: `3549`
+		// Minimum execution time: 22_000_000 picoseconds.
+		Weight::from_parts(23_000_000, 3549)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Game Game (r:1 w:1)
+	/// Proof: Game Game (max_values: None, max_size: Some(131), added: 2606, mode: MaxEncodedLen)
+	fn set_trade_fee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `251`
+		//  Estimated: `3596`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 3596)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Game BundleOf (r:101 w:101)
 	/// Proof: Game BundleOf (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
-	/// Storage: Game ReservedBalanceOf (r:2 w:2)
+	/// Storage: Game TradeConfigOf (r:101 w:101)
+	/// Proof: Game TradeConfigOf (max_values: None, max_size: Some(283), added: 2758, mode: MaxEncodedLen)
+	/// Storage: Game BidBookOf (r:1 w:1)
+	/// Proof: Game BidBookOf (max_values: None, max_size: Some(4823), added: 7298, mode: MaxEncodedLen)
+	/// Storage: Game AskBookOf (r:1 w:1)
+	/// Proof: Game AskBookOf (max_values: None, max_size: Some(4823), added: 7298, mode: MaxEncodedLen)
+	/// Storage: Game ReservedBalanceOf (r:1 w:1)
 	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
+	/// Storage: Game ItemBalanceOf (r:2 w:2)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game ItemRoyaltyOf (r:1 w:0)
+	/// Proof: Game ItemRoyaltyOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: Game CollectionRoyaltyOf (r:1 w:0)
+	/// Proof: Game CollectionRoyaltyOf (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: Game GamesOf (r:1 w:0)
+	/// Proof: Game GamesOf (max_values: None, max_size: Some(83), added: 2558, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	fn make_swap() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `726`
-		//  Estimated: `11226`
-		// Minimum execution time: 113_000_000 picoseconds.
-		Weight::from_parts(116_000_000, 11226)
-			.saturating_add(RocksDbWeight::get().reads(9_u64))
-			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	/// The range of component `m` is `[1, 100]`.
+	fn set_book_price(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1024 + m * (253 ±0)`
+		//  Estimated: `8288 + m * (2758 ±0)`
+		// Minimum execution time: 58_000_000 picoseconds.
+		Weight::from_parts(60_384_211, 8288)
+			// Standard Error: 21_037
+			.saturating_add(Weight::from_parts(24_517_842, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 2758).saturating_mul(m.into()))
 	}
-	/// Storage: Game NextTradeId (r:1 w:1)
-	/// Proof: Game NextTradeId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	/// Storage: Game BundleOf (r:1 w:1)
+	/// Storage: Game BundleOf (r:101 w:101)
 	/// Proof: Game BundleOf (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	/// Storage: Game ItemBalanceOf (r:1 w:1)
-	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game TradeConfigOf (r:101 w:101)
+	/// Proof: Game TradeConfigOf (max_values: None, max_size: Some(283), added: 2758, mode: MaxEncodedLen)
+	/// Storage: Game AskBookOf (r:1 w:1)
+	/// Proof: Game AskBookOf (max_values: None, max_size: Some(4823), added: 7298, mode: MaxEncodedLen)
+	/// Storage: Game BidBookOf (r:1 w:1)
+	/// Proof: Game BidBookOf (max_values: None, max_size: Some(4823), added: 7298, mode: MaxEncodedLen)
 	/// Storage: Game ReservedBalanceOf (r:1 w:1)
 	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
-	/// Storage: Game AuctionConfigOf (r:0 w:1)
-	/// Proof: Game AuctionConfigOf (max_values: None, max_size: Some(77), added: 2552, mode: MaxEncodedLen)
-	/// The range of component `s` is `[0, 10]`.
-	fn set_auction(s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `294`
-		//  Estimated: `3606`
-		// Minimum execution time: 34_000_000 picoseconds.
-		Weight::from_parts(39_914_618, 3606)
-			// Standard Error: 28_260
-			.saturating_add(Weight::from_parts(15_344_110, 0).saturating_mul(s.into()))
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
-	}
-	/// Storage: Game AuctionConfigOf (r:1 w:0)
-	/// Proof: Game AuctionConfigOf (max_values: None, max_size: Some(77), added: 2552, mode: MaxEncodedLen)
-	/// Storage: Game HighestBidOf (r:1 w:1)
-	/// Proof: Game HighestBidOf (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
+	/// Storage: Game ItemBalanceOf (r:1 w:1)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game ItemRoyaltyOf (r:1 w:0)
+	/// Proof: Game ItemRoyaltyOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: Game CollectionRoyaltyOf (r:1 w:0)
+	/// Proof: Game CollectionRoyaltyOf (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: Game GamesOf (r:1 w:0)
+	/// Proof: Game GamesOf (max_values: None, max_size: Some(83), added: 2558, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	fn bid_auction() -> Weight {
+	/// The range of component `m` is `[1, 100]`.
+	fn set_book_order(m: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `445`
-		//  Estimated: `3593`
-		// Minimum execution time: 39_000_000 picoseconds.
-		Weight::from_parts(40_000_000, 3593)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		//  Measured:  `1088 + m * (253 ±0)`
+		//  Estimated: `8288 + m * (2758 ±0)`
+		// Minimum execution time: 55_000_000 picoseconds.
+		Weight::from_parts(57_902_631, 8288)
+			// Standard Error: 19_844
+			.saturating_add(Weight::from_parts(23_961_052, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 2758).saturating_mul(m.into()))
 	}
 	/// Storage: Game AuctionConfigOf (r:1 w:1)
 	/// Proof: Game AuctionConfigOf (max_values: None, max_size: Some(77), added: 2552, mode: MaxEncodedLen)
@@ -1309,261 +1349,581 @@ impl WeightInfo for () {
 	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
 	/// Storage: Game ItemBalanceOf (r:2 w:2)
 	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
-	fn close_auction() -> Weight {
+	/// Storage: Game ItemRoyaltyOf (r:2 w:0)
+	/// Proof: Game ItemRoyaltyOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: Game CollectionRoyaltyOf (r:2 w:0)
+	/// Proof: Game CollectionRoyaltyOf (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: Game GamesOf (r:2 w:0)
+	/// Proof: Game GamesOf (max_values: None, max_size: Some(83), added: 2558, mode: MaxEncodedLen)
+	fn expire_trade() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `712`
 		//  Estimated: `6108`
-		// Minimum execution time: 103_000_000 picoseconds.
-		Weight::from_parts(105_000_000, 6108)
-			.saturating_add(RocksDbWeight::get().reads(8_u64))
-			.saturating_add(RocksDbWeight::get().writes(8_u64))
+		// Minimum execution time: 112_000_000 picoseconds.
+		Weight::from_parts(115_000_000, 6108)
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
-	/// Storage: Game NextTradeId (r:1 w:1)
-	/// Proof: Game NextTradeId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	/// Storage: Game BundleOf (r:1 w:1)
-	/// Proof: Game BundleOf (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: Game Game (r:1 w:1)
+	/// Proof: Game Game (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	fn set_payment_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `251`
+		//  Estimated: `3601`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 3601)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Game NextRecipeId (r:1 w:1)
+	/// Proof: Game NextRecipeId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Game Game (r:1 w:1)
+	/// Proof: Game Game (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: Game CollectionsOf (r:1 w:0)
+	/// Proof: Game CollectionsOf (max_values: None, max_size: Some(82), added: 2557, mode: MaxEncodedLen)
+	/// Storage: Game SupplyOf (r:20 w:0)
+	/// Proof: Game SupplyOf (max_values: None, max_size: Some(45), added: 2520, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:10 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	/// Storage: Game TradeConfigOf (r:0 w:1)
-	/// Proof: Game TradeConfigOf (max_values: None, max_size: Some(202), added: 2677, mode: MaxEncodedLen)
-	fn set_order() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `109`
-		//  Estimated: `3606`
-		// Minimum execution time: 52_000_000 picoseconds.
-		Weight::from_parts(52_000_000, 3606)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	/// Storage: Game RecipeOf (r:0 w:1)
+	/// Proof: Game RecipeOf (max_values: None, max_size: Some(227), added: 2702, mode: MaxEncodedLen)
+	/// The range of component `i` is `[1, 10]`.
+	/// The range of component `o` is `[1, 10]`.
+	fn create_recipe(i: u32, o: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `4089`
+		// Minimum execution time: 39_000_000 picoseconds.
+		Weight::from_parts(38_912_614, 4089)
+			// Standard Error: 14_318
+			.saturating_add(Weight::from_parts(3_962_170, 0).saturating_mul(i.into()))
+			// Standard Error: 14_318
+			.saturating_add(Weight::from_parts(6_418_902, 0).saturating_mul(o.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(i.into())))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(o.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(o.into())))
+			.saturating_add(Weight::from_parts(0, 2520).saturating_mul(i.into()))
+			.saturating_add(Weight::from_parts(0, 2559).saturating_mul(o.into()))
 	}
-	/// Storage: Game TradeConfigOf (r:1 w:0)
-	/// Proof: Game TradeConfigOf (max_values: None, max_size: Some(202), added: 2677, mode: MaxEncodedLen)
-	/// Storage: Game BundleOf (r:1 w:1)
-	/// Proof: Game BundleOf (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
-	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
-	/// Storage: Nfts ItemConfigOf (r:1 w:0)
-	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
-	/// Storage: Game ItemBalanceOf (r:1 w:1)
-	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game RecipeOf (r:1 w:1)
+	/// Proof: Game RecipeOf (max_values: None, max_size: Some(227), added: 2702, mode: MaxEncodedLen)
+	/// Storage: Game SupplyOf (r:10 w:0)
+	/// Proof: Game SupplyOf (max_values: None, max_size: Some(45), added: 2520, mode: MaxEncodedLen)
+	/// Storage: Game ReservedBalanceOf (r:10 w:10)
+	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:10 w:10)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	fn sell_item() -> Weight {
+	/// Storage: Game Game (r:1 w:1)
+	/// Proof: Game Game (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	fn remove_recipe() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1238`
+		//  Estimated: `32522`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(64_000_000, 32522)
+			.saturating_add(T::DbWeight::get().reads(33_u64))
+			.saturating_add(T::DbWeight::get().writes(23_u64))
+	}
+	/// Storage: Game RecipeOf (r:1 w:1)
+	/// Proof: Game RecipeOf (max_values: None, max_size: Some(227), added: 2702, mode: MaxEncodedLen)
+	/// Storage: Game ReservedBalanceOf (r:10 w:10)
+	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:20 w:20)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Game SupplyOf (r:20 w:10)
+	/// Proof: Game SupplyOf (max_values: None, max_size: Some(45), added: 2520, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:10 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game Game (r:1 w:0)
+	/// Proof: Game Game (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn craft() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `871`
-		//  Estimated: `3667`
-		// Minimum execution time: 71_000_000 picoseconds.
-		Weight::from_parts(72_000_000, 3667)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		//  Measured:  `1843`
+		//  Estimated: `77741`
+		// Minimum execution time: 261_000_000 picoseconds.
+		Weight::from_parts(268_000_000, 77741)
+			.saturating_add(T::DbWeight::get().reads(64_u64))
+			.saturating_add(T::DbWeight::get().writes(43_u64))
 	}
-	/// Storage: Nfts NextCollectionId (r:1 w:1)
-	/// Proof: Nfts NextCollectionId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	/// Storage: Nfts Collection (r:1 w:1)
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game UpgradeConfigOf (r:1 w:1)
+	/// Proof: Game UpgradeConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn set_upgrade_chance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3549`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(22_000_000, 3549)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Nfts Collection (r:11 w:0)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game SupplyOf (r:11 w:0)
+	/// Proof: Game SupplyOf (max_values: None, max_size: Some(45), added: 2520, mode: MaxEncodedLen)
+	/// Storage: Game SalvageOf (r:1 w:1)
+	/// Proof: Game SalvageOf (max_values: None, max_size: Some(262), added: 2737, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	/// Storage: Nfts CollectionRoleOf (r:0 w:1)
-	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
-	/// Storage: Nfts CollectionConfigOf (r:0 w:1)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
-	/// Storage: Nfts CollectionAccount (r:0 w:1)
-	/// Proof: Nfts CollectionAccount (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
-	fn create_collection() -> Weight {
+	/// The range of component `m` is `[1, 10]`.
+	fn set_salvage(m: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `109`
-		//  Estimated: `3593`
-		// Minimum execution time: 50_000_000 picoseconds.
-		Weight::from_parts(51_000_000, 3593)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+		//  Measured:  `588`
+		//  Estimated: `3727`
+		// Minimum execution time: 34_000_000 picoseconds.
+		Weight::from_parts(33_128_501, 3727)
+			// Standard Error: 11_942
+			.saturating_add(Weight::from_parts(6_204_377, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 2559).saturating_mul(m.into()))
 	}
-	/// Storage: Nfts Collection (r:1 w:0)
-	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
-	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
-	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Game SalvageOf (r:1 w:1)
+	/// Proof: Game SalvageOf (max_values: None, max_size: Some(262), added: 2737, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	/// Storage: Game AddingAcceptance (r:0 w:1)
-	/// Proof: Game AddingAcceptance (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
-	fn set_accept_adding() -> Weight {
+	fn clear_salvage() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `401`
-		//  Estimated: `3593`
-		// Minimum execution time: 37_000_000 picoseconds.
-		Weight::from_parts(38_000_000, 3593)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		//  Measured:  `436`
+		//  Estimated: `3727`
+		// Minimum execution time: 25_000_000 picoseconds.
+		Weight::from_parts(26_000_000, 3727)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
-	/// Storage: Game GameRoleOf (r:1 w:0)
-	/// Proof: Game GameRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
-	/// Storage: Game AddingAcceptance (r:1 w:0)
-	/// Proof: Game AddingAcceptance (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
-	/// Storage: Game CollectionsOf (r:1 w:1)
-	/// Proof: Game CollectionsOf (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
-	/// Storage: Game GamesOf (r:1 w:1)
-	/// Proof: Game GamesOf (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
-	fn add_game_collection() -> Weight {
+	/// Storage: Game SalvageOf (r:1 w:0)
+	/// Proof: Game SalvageOf (max_values: None, max_size: Some(262), added: 2737, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:11 w:11)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game SupplyOf (r:11 w:1)
+	/// Proof: Game SupplyOf (max_values: None, max_size: Some(45), added: 2520, mode: MaxEncodedLen)
+	/// Storage: Game RandomSeed (r:1 w:0)
+	/// Proof: Game RandomSeed (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	fn salvage() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `212`
-		//  Estimated: `3534`
-		// Minimum execution time: 21_000_000 picoseconds.
-		Weight::from_parts(22_000_000, 3534)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		//  Measured:  `1320`
+		//  Estimated: `29139`
+		// Minimum execution time: 152_000_000 picoseconds.
+		Weight::from_parts(157_000_000, 29139)
+			.saturating_add(T::DbWeight::get().reads(24_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
 	}
-	/// Storage: Game TradeConfigOf (r:1 w:0)
-	/// Proof: Game TradeConfigOf (max_values: None, max_size: Some(202), added: 2677, mode: MaxEncodedLen)
+	/// Storage: Game NextTradeId (r:1 w:1)
+	/// Proof: Game NextTradeId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
 	/// Storage: Game BundleOf (r:1 w:1)
 	/// Proof: Game BundleOf (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
-	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
-	/// Storage: Nfts ItemConfigOf (r:1 w:0)
-	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: Game ItemBalanceOf (r:1 w:1)
 	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
 	/// Storage: Game ReservedBalanceOf (r:1 w:1)
 	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
-	fn add_set_price() -> Weight {
+	/// Storage: Game RentalOf (r:0 w:1)
+	/// Proof: Game RentalOf (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// The range of component `s` is `[0, 10]`.
+	fn set_rental(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `874`
-		//  Estimated: `3667`
-		// Minimum execution time: 46_000_000 picoseconds.
-		Weight::from_parts(47_000_000, 3667)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		//  Measured:  `294`
+		//  Estimated: `3606`
+		// Minimum execution time: 35_000_000 picoseconds.
+		Weight::from_parts(39_902_117, 3606)
+			// Standard Error: 27_913
+			.saturating_add(Weight::from_parts(15_482_506, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
-	/// Storage: Game TradeConfigOf (r:1 w:1)
-	/// Proof: Game TradeConfigOf (max_values: None, max_size: Some(202), added: 2677, mode: MaxEncodedLen)
-	/// Storage: Game BundleOf (r:1 w:1)
+	/// Storage: Game RentalOf (r:1 w:1)
+	/// Proof: Game RentalOf (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Game BundleOf (r:1 w:0)
 	/// Proof: Game BundleOf (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
-	/// Storage: Game ReservedBalanceOf (r:2 w:2)
-	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Game UseRightOf (r:1 w:1)
+	/// Proof: Game UseRightOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game NextExpiryBlock (r:1 w:1)
+	/// Proof: Game NextExpiryBlock (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Game TradeExpiryOf (r:1 w:1)
+	/// Proof: Game TradeExpiryOf (max_values: None, max_size: Some(514), added: 2989, mode: MaxEncodedLen)
+	fn rent() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `702`
+		//  Estimated: `5871`
+		// Minimum execution time: 61_000_000 picoseconds.
+		Weight::from_parts(64_000_000, 5871)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: Game RentalOf (r:1 w:1)
+	/// Proof: Game RentalOf (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Game BundleOf (r:1 w:0)
+	/// Proof: Game BundleOf (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: Game UseRightOf (r:1 w:1)
+	/// Proof: Game UseRightOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	fn return_rental() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3606`
+		// Minimum execution time: 27_000_000 picoseconds.
+		Weight::from_parts(29_000_000, 3606)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Game SupplyOf (r:1 w:0)
+	/// Proof: Game SupplyOf (max_values: None, max_size: Some(45), added: 2520, mode: MaxEncodedLen)
+	/// Storage: Game SoulboundOf (r:0 w:1)
+	/// Proof: Game SoulboundOf (max_values: None, max_size: Some(41), added: 2516, mode: MaxEncodedLen)
+	fn set_soulbound() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `398`
+		//  Estimated: `3534`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 3534)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Game OperatorApprovalOf (r:0 w:1)
+	/// Proof: Game OperatorApprovalOf (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	fn approve_operator() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Game OperatorApprovalOf (r:1 w:1)
+	/// Proof: Game OperatorApprovalOf (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	fn revoke_operator() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `161`
+		//  Estimated: `3587`
+		// Minimum execution time: 18_000_000 picoseconds.
+		Weight::from_parts(19_000_000, 3587)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Game OperatorApprovalOf (r:2 w:0)
+	/// Proof: Game OperatorApprovalOf (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
 	/// Storage: Game ItemBalanceOf (r:2 w:2)
 	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game UseRightOf (r:1 w:0)
+	/// Proof: Game UseRightOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game SoulboundOf (r:1 w:0)
+	/// Proof: Game SoulboundOf (max_values: None, max_size: Some(41), added: 2516, mode: MaxEncodedLen)
+	/// Storage: Game OriginItemOf (r:1 w:0)
+	/// Proof: Game OriginItemOf (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	fn transfer_from() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `6184`
+		// Minimum execution time: 39_000_000 picoseconds.
+		Weight::from_parts(41_000_000, 6184)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Game Game (r:1 w:0)
+	/// Proof: Game Game (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: Game NextMatchId (r:1 w:1)
+	/// Proof: Game NextMatchId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	fn cancel_trade() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `611`
-		//  Estimated: `6108`
-		// Minimum execution time: 82_000_000 picoseconds.
-		Weight::from_parts(83_000_000, 6108)
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	/// Storage: Game MatchOf (r:0 w:1)
+	/// Proof: Game MatchOf (max_values: None, max_size: Some(2574), added: 5049, mode: MaxEncodedLen)
+	fn create_match() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `318`
+		//  Estimated: `3601`
+		// Minimum execution time: 30_000_000 picoseconds.
+		Weight::from_parts(31_000_000, 3601)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
-	/// Storage: Game NextPoolId (r:1 w:1)
-	/// Proof: Game NextPoolId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	/// Storage: Game PoolOf (r:1 w:1)
-	/// Proof: Game PoolOf (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: Game MatchOf (r:1 w:1)
+	/// Proof: Game MatchOf (max_values: None, max_size: Some(2574), added: 5049, mode: MaxEncodedLen)
+	/// Storage: Game CollectionsOf (r:1 w:0)
+	/// Proof: Game CollectionsOf (max_values: None, max_size: Some(82), added: 2557, mode: MaxEncodedLen)
+	/// Storage: Game Game (r:1 w:0)
+	/// Proof: Game Game (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	/// Storage: Game ItemBalanceOf (r:1 w:1)
+	/// Storage: Game SoulboundOf (r:10 w:0)
+	/// Proof: Game SoulboundOf (max_values: None, max_size: Some(41), added: 2516, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:10 w:10)
 	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
-	/// Storage: Game ReservedBalanceOf (r:1 w:1)
+	/// Storage: Game ReservedBalanceOf (r:10 w:10)
 	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
-	/// Storage: Game LootTableOf (r:0 w:1)
-	/// Proof: Game LootTableOf (max_values: None, max_size: Some(147), added: 2622, mode: MaxEncodedLen)
 	/// The range of component `s` is `[0, 10]`.
-	fn create_dynamic_pool(s: u32, ) -> Weight {
+	fn join_match(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `294`
-		//  Estimated: `3593`
-		// Minimum execution time: 35_000_000 picoseconds.
-		Weight::from_parts(38_947_576, 3593)
-			// Standard Error: 26_640
-			.saturating_add(Weight::from_parts(16_361_598, 0).saturating_mul(s.into()))
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+		//  Measured:  `689`
+		//  Estimated: `6039`
+		// Minimum execution time: 36_000_000 picoseconds.
+		Weight::from_parts(37_418_205, 6039)
+			// Standard Error: 21_604
+			.saturating_add(Weight::from_parts(11_073_418, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2559).saturating_mul(s.into()))
+	}
+	/// Storage: Game MatchOf (r:1 w:1)
+	/// Proof: Game MatchOf (max_values: None, max_size: Some(2574), added: 5049, mode: MaxEncodedLen)
+	/// Storage: Game Game (r:2 w:0)
+	/// Proof: Game Game (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:17 w:17)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Game ReservedBalanceOf (r:16 w:16)
+	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:16 w:16)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// The range of component `p` is `[1, 16]`.
+	fn settle_match(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `734 + p * (186 ±0)`
+		//  Estimated: `6039 + p * (2603 ±0)`
+		// Minimum execution time: 52_000_000 picoseconds.
+		Weight::from_parts(38_204_561, 6039)
+			// Standard Error: 31_772
+			.saturating_add(Weight::from_parts(24_905_113, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(p.into()))
 	}
-	/// Storage: Game NextPoolId (r:1 w:1)
-	/// Proof: Game NextPoolId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	/// Storage: Nfts Collection (r:1 w:0)
-	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
-	/// Storage: Game SupplyOf (r:1 w:0)
-	/// Proof: Game SupplyOf (max_values: None, max_size: Some(45), added: 2520, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
+	/// Storage: Game MatchOf (r:1 w:1)
+	/// Proof: Game MatchOf (max_values: None, max_size: Some(2574), added: 5049, mode: MaxEncodedLen)
+	/// Storage: Game Game (r:1 w:0)
+	/// Proof: Game Game (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:17 w:17)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	/// Storage: Game LootTableOf (r:0 w:1)
-	/// Proof: Game LootTableOf (max_values: None, max_size: Some(147), added: 2622, mode: MaxEncodedLen)
-	/// Storage: Game PoolOf (r:0 w:1)
-	/// Proof: Game PoolOf (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
-	/// The range of component `s` is `[0, 10]`.
-	fn create_stable_pool(s: u32, ) -> Weight {
+	/// Storage: Game ReservedBalanceOf (r:16 w:16)
+	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:16 w:16)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// The range of component `p` is `[1, 16]`.
+	fn refund_match(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `734 + p * (186 ±0)`
+		//  Estimated: `6039 + p * (2603 ±0)`
+		// Minimum execution time: 45_000_000 picoseconds.
+		Weight::from_parts(33_710_298, 6039)
+			// Standard Error: 28_156
+			.saturating_add(Weight::from_parts(21_336_790, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(p.into()))
+	}
+	/// Storage: Game Game (r:1 w:1)
+	/// Proof: Game Game (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	fn set_treasury_routing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `500`
-		//  Estimated: `3593`
-		// Minimum execution time: 35_000_000 picoseconds.
-		Weight::from_parts(41_774_162, 3593)
-			// Standard Error: 36_564
-			.saturating_add(Weight::from_parts(4_320_318, 0).saturating_mul(s.into()))
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+		//  Measured:  `251`
+		//  Estimated: `3602`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 3602)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-	/// Storage: Game PoolOf (r:1 w:0)
-	/// Proof: Game PoolOf (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
+	/// Storage: Game Game (r:1 w:0)
+	/// Proof: Game Game (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	/// Storage: Game MintRequestOf (r:1 w:1)
-	/// Proof: Game MintRequestOf (max_values: None, max_size: Some(1093), added: 3568, mode: MaxEncodedLen)
-	fn request_mint() -> Weight {
+	fn spend_treasury() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `337`
-		//  Estimated: `4558`
-		// Minimum execution time: 37_000_000 picoseconds.
-		Weight::from_parts(38_000_000, 4558)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		//  Measured:  `459`
+		//  Estimated: `6196`
+		// Minimum execution time: 44_000_000 picoseconds.
+		Weight::from_parts(45_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Game Game (r:1 w:0)
+	/// Proof: Game Game (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Game GameOwnershipAcceptance (r:1 w:1)
+	/// Proof: Game GameOwnershipAcceptance (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Game OwnershipAcceptorOf (r:0 w:2)
+	/// Proof: Game OwnershipAcceptorOf (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	fn set_accept_game_ownership() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `251`
+		//  Estimated: `3602`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 3602)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Game GameOwnershipAcceptance (r:1 w:1)
+	/// Proof: Game GameOwnershipAcceptance (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Game Game (r:1 w:1)
+	/// Proof: Game Game (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Game GameAccount (r:0 w:2)
+	/// Proof: Game GameAccount (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Game OwnershipAcceptorOf (r:0 w:1)
+	/// Proof: Game OwnershipAcceptorOf (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Game CollectionsOf (r:1 w:0)
+	/// Proof: Game CollectionsOf (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:5 w:5)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts OwnershipAcceptance (r:5 w:5)
+	/// Proof: Nfts OwnershipAcceptance (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionAccount (r:0 w:10)
+	/// Proof: Nfts CollectionAccount (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// The range of component `c` is `[0, 5]`.
+	fn transfer_game_ownership(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `594 + c * (119 ±0)`
+		//  Estimated: `6196 + c * (2559 ±0)`
+		// Minimum execution time: 47_000_000 picoseconds.
+		Weight::from_parts(49_312_094, 6196)
+			// Standard Error: 52_318
+			.saturating_add(Weight::from_parts(28_640_118, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2559).saturating_mul(c.into()))
+	}
+	/// Storage: Game Game (r:1 w:1)
+	/// Proof: Game Game (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Game GameRoleOf (r:1 w:2)
+	/// Proof: Game GameRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Game CollectionsOf (r:1 w:0)
+	/// Proof: Game CollectionsOf (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:5 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoleOf (r:5 w:10)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// The range of component `c` is `[0, 5]`.
+	fn set_game_admin(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `385 + c * (98 ±0)`
+		//  Estimated: `3602 + c * (2559 ±0)`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(22_408_311, 3602)
+			// Standard Error: 31_877
+			.saturating_add(Weight::from_parts(19_204_778, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2559).saturating_mul(c.into()))
+	}
+	/// Storage: Game Game (r:1 w:0)
+	/// Proof: Game Game (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Game GrantedRoleOf (r:0 w:1)
+	/// Proof: Game GrantedRoleOf (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	fn grant_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `251`
+		//  Estimated: `3602`
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(16_000_000, 3602)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Game Game (r:1 w:0)
+	/// Proof: Game Game (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Game GrantedRoleOf (r:1 w:1)
+	/// Proof: Game GrantedRoleOf (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	fn revoke_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `330`
+		//  Estimated: `3602`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 3602)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}	/// Storage: Game ReservedBalanceOf (r:1 w:1)
+	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:2 w:2)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game RandomSeed (r:1 w:0)
+	/// Proof: Game RandomSeed (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: Game SupplyOf (r:1 w:1)
+	/// Proof: Game SupplyOf (max_values: None, max_size: Some(45), added: 2520, mode: MaxEncodedLen)
+	/// The range of component `a` is `[1, 10]`.
+	fn process_upgrade_request(a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `812`
+		//  Estimated: `6108`
+		// Minimum execution time: 38_000_000 picoseconds.
+		Weight::from_parts(39_614_078, 6108)
+			// Standard Error: 6_402
+			.saturating_add(Weight::from_parts(1_873_512, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+}
 
+// For backwards compatibility and tests
+impl WeightInfo for () {
 	/// Storage: Game NextGameId (r:1 w:1)
 	/// Proof: Game NextGameId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	/// Storage: Game GameMetadataOf (r:1 w:1)
-	/// Proof: Game GameMetadataOf (max_values: None, max_size: Some(322), added: 2797, mode: MaxEncodedLen)
 	/// Storage: Game GameAccount (r:0 w:1)
 	/// Proof: Game GameAccount (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	/// Storage: Game Game (r:0 w:1)
 	/// Proof: Game Game (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
 	/// Storage: Game GameRoleOf (r:0 w:1)
 	/// Proof: Game GameRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
-	/// The range of component `s` is `[0, 300]`.
-	fn create_game_with_data(s: u32, ) -> Weight {
+	fn create_game() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `109`
-		//  Estimated: `3787`
-		// Minimum execution time: 25_000_000 picoseconds.
-		Weight::from_parts(28_765_534, 0)
-			.saturating_add(Weight::from_parts(0, 3787))
-			// Standard Error: 1_384
-			.saturating_add(Weight::from_parts(10_037, 0).saturating_mul(s.into()))
-			.saturating_add(RocksDbWeight::get().reads(3))
-			.saturating_add(RocksDbWeight::get().writes(6))
+		//  Estimated: `3593`
+		// Minimum execution time: 38_000_000 picoseconds.
+		Weight::from_parts(39_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
+	/// Storage: Game GameRoleOf (r:1 w:0)
+	/// Proof: Game GameRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Game Game (r:1 w:0)
+	/// Proof: Game Game (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
 	/// Storage: Nfts NextCollectionId (r:1 w:1)
 	/// Proof: Nfts NextCollectionId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
 	/// Storage: Nfts Collection (r:1 w:1)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	/// Storage: Nfts CollectionMetadataOf (r:1 w:1)
-	/// Proof: Nfts CollectionMetadataOf (max_values: None, max_size: Some(338), added: 2813, mode: MaxEncodedLen)
+	/// Storage: Game CollectionsOf (r:1 w:1)
+	/// Proof: Game CollectionsOf (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: Game GamesOf (r:1 w:1)
+	/// Proof: Game GamesOf (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: Game CollectionGameOf (r:0 w:1)
+	/// Proof: Game CollectionGameOf (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionRoleOf (r:0 w:1)
 	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionConfigOf (r:0 w:1)
 	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionAccount (r:0 w:1)
 	/// Proof: Nfts CollectionAccount (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
-	/// The range of component `s` is `[0, 300]`.
-	fn create_collection_with_data(_s: u32, ) -> Weight {
+	fn create_game_collection() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `109`
-		//  Estimated: `3803`
-		// Minimum execution time: 42_000_000 picoseconds.
-		Weight::from_parts(52_820_352, 0)
-			.saturating_add(Weight::from_parts(0, 3803))
-			.saturating_add(RocksDbWeight::get().reads(4))
-			.saturating_add(RocksDbWeight::get().writes(7))
+		//  Measured:  `388`
+		//  Estimated: `3593`
+		// Minimum execution time: 63_000_000 picoseconds.
+		Weight::from_parts(64_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
 	/// Storage: Nfts Collection (r:1 w:1)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
@@ -1577,127 +1937,1684 @@ impl WeightInfo for () {
 	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	/// Storage: Nfts ItemMetadataOf (r:1 w:1)
-	/// Proof: Nfts ItemMetadataOf (max_values: None, max_size: Some(391), added: 2866, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:1 w:1)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
 	/// Storage: Game SupplyOf (r:0 w:1)
 	/// Proof: Game SupplyOf (max_values: None, max_size: Some(45), added: 2520, mode: MaxEncodedLen)
 	/// Storage: Nfts Account (r:0 w:1)
 	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
-	/// The range of component `s` is `[0, 300]`.
-	fn create_item_with_data(_s: u32, ) -> Weight {
+	fn create_item() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `487`
+		//  Measured:  `493`
 		//  Estimated: `3956`
-		// Minimum execution time: 55_000_000 picoseconds.
-		Weight::from_parts(62_357_859, 0)
+		// Minimum execution time: 75_000_000 picoseconds.
+		Weight::from_parts(77_000_000, 3956)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Game SupplyOf (r:1 w:1)
+	/// Proof: Game SupplyOf (max_values: None, max_size: Some(45), added: 2520, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:1 w:1)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	fn add_supply() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `513`
+		//  Estimated: `3549`
+		// Minimum execution time: 37_000_000 picoseconds.
+		Weight::from_parts(38_000_000, 3549)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Game ItemBalanceOf (r:1 w:1)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	fn burn() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `191`
+		//  Estimated: `3549`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(22_000_000, 3549)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Game ItemBalanceOf (r:1 w:1)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	fn transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `191`
+		//  Estimated: `3549`
+		// Minimum execution time: 28_000_000 picoseconds.
+		Weight::from_parts(29_000_000, 3549)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:2 w:1)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemMetadataOf (r:1 w:1)
+	/// Proof: Nfts ItemMetadataOf (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Game UpgradeConfigOf (r:1 w:1)
+	/// Proof: Game UpgradeConfigOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:1)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(491), added: 2966, mode: MaxEncodedLen)
+	/// Storage: Game OriginItemOf (r:0 w:1)
+	/// Proof: Game OriginItemOf (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Game LevelOf (r:0 w:1)
+	/// Proof: Game LevelOf (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:0 w:1)
+	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Game SupplyOf (r:10 w:0)
+	/// Proof: Game SupplyOf (max_values: None, max_size: Some(45), added: 2520, mode: MaxEncodedLen)
+	/// The range of component `s` is `[0, 50]`.
+	/// The range of component `m` is `[0, 10]`.
+	fn set_upgrade_item(s: u32, m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `822`
+		//  Estimated: `6036`
+		// Minimum execution time: 115_000_000 picoseconds.
+		Weight::from_parts(117_731_912, 6036)
+			// Standard Error: 2_741
+			.saturating_add(Weight::from_parts(6_528, 0).saturating_mul(s.into()))
+			// Standard Error: 13_706
+			.saturating_add(Weight::from_parts(3_108_420, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(m.into())))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+			.saturating_add(Weight::from_parts(0, 2520).saturating_mul(m.into()))
+	}
+	/// Storage: Game LevelOf (r:1 w:0)
+	/// Proof: Game LevelOf (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Game OriginItemOf (r:1 w:0)
+	/// Proof: Game OriginItemOf (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Game UpgradeConfigOf (r:1 w:0)
+	/// Proof: Game UpgradeConfigOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:12 w:12)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game SupplyOf (r:10 w:10)
+	/// Proof: Game SupplyOf (max_values: None, max_size: Some(45), added: 2520, mode: MaxEncodedLen)
+	/// The range of component `m` is `[0, 10]`.
+	fn upgrade_item(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1041 + m * (162 ±0)`
+		//  Estimated: `6118 + m * (5079 ±0)`
+		// Minimum execution time: 62_000_000 picoseconds.
+		Weight::from_parts(63_912_540, 6118)
+			// Standard Error: 21_930
+			.saturating_add(Weight::from_parts(7_008_746, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(m.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 5079).saturating_mul(m.into()))
+	}
+	/// Storage: Game NextTradeId (r:1 w:1)
+	/// Proof: Game NextTradeId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Game BundleOf (r:1 w:1)
+	/// Proof: Game BundleOf (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:1 w:1)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game ReservedBalanceOf (r:1 w:1)
+	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game TradeConfigOf (r:0 w:1)
+	/// Proof: Game TradeConfigOf (max_values: None, max_size: Some(202), added: 2677, mode: MaxEncodedLen)
+	fn set_price() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `695`
+		//  Estimated: `3606`
+		// Minimum execution time: 67_000_000 picoseconds.
+		Weight::from_parts(68_000_000, 3606)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: Game TradeConfigOf (r:1 w:0)
+	/// Proof: Game TradeConfigOf (max_values: None, max_size: Some(202), added: 2677, mode: MaxEncodedLen)
+	/// Storage: Game BundleOf (r:1 w:1)
+	/// Proof: Game BundleOf (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Game ReservedBalanceOf (r:1 w:1)
+	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:1 w:1)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	fn buy_item() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `874`
+		//  Estimated: `3667`
+		// Minimum execution time: 53_000_000 picoseconds.
+		Weight::from_parts(54_000_000, 3667)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Game NextTradeId (r:1 w:1)
+	/// Proof: Game NextTradeId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Game BundleOf (r:1 w:1)
+	/// Proof: Game BundleOf (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:1 w:1)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game ReservedBalanceOf (r:1 w:1)
+	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game TradeConfigOf (r:0 w:1)
+	/// Proof: Game TradeConfigOf (max_values: None, max_size: Some(202), added: 2677, mode: MaxEncodedLen)
+	/// The range of component `s` is `[0, 10]`.
+	fn set_bundle(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `294`
+		//  Estimated: `3606`
+		// Minimum execution time: 35_000_000 picoseconds.
+		Weight::from_parts(39_573_291, 3606)
+			// Standard Error: 30_111
+			.saturating_add(Weight::from_parts(15_637_767, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: Game TradeConfigOf (r:1 w:1)
+	/// Proof: Game TradeConfigOf (max_values: None, max_size: Some(202), added: 2677, mode: MaxEncodedLen)
+	/// Storage: Game BundleOf (r:1 w:1)
+	/// Proof: Game BundleOf (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:2 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Game ReservedBalanceOf (r:2 w:2)
+	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:2 w:2)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn buy_bundle() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1012`
+		//  Estimated: `6108`
+		// Minimum execution time: 93_000_000 picoseconds.
+		Weight::from_parts(98_000_000, 6108)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: Game NextTradeId (r:1 w:1)
+	/// Proof: Game NextTradeId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Game BundleOf (r:1 w:1)
+	/// Proof: Game BundleOf (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Game TradeConfigOf (r:0 w:1)
+	/// Proof: Game TradeConfigOf (max_values: None, max_size: Some(202), added: 2677, mode: MaxEncodedLen)
+	/// The range of component `s` is `[0, 10]`.
+	fn order_bundle(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `179`
+		//  Estimated: `3606`
+		// Minimum execution time: 49_000_000 picoseconds.
+		Weight::from_parts(51_617_714, 3606)
+			// Standard Error: 7_852
+			.saturating_add(Weight::from_parts(147_850, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Game TradeConfigOf (r:1 w:1)
+	/// Proof: Game TradeConfigOf (max_values: None, max_size: Some(202), added: 2677, mode: MaxEncodedLen)
+	/// Storage: Game BundleOf (r:1 w:1)
+	/// Proof: Game BundleOf (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:2 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:4 w:4)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	fn sell_bundle() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `986`
+		//  Estimated: `11226`
+		// Minimum execution time: 119_000_000 picoseconds.
+		Weight::from_parts(121_000_000, 11226)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Game GameRoleOf (r:1 w:0)
+	/// Proof: Game GameRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Game CollectionsOf (r:1 w:1)
+	/// Proof: Game CollectionsOf (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: Game GamesOf (r:0 w:1)
+	/// Proof: Game GamesOf (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: Game CollectionGameOf (r:1 w:1)
+	/// Proof: Game CollectionGameOf (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	fn remove_collection() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `460`
+		//  Estimated: `3534`
+		// Minimum execution time: 25_000_000 picoseconds.
+		Weight::from_parts(27_000_000, 3534)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Game NextTradeId (r:1 w:1)
+	/// Proof: Game NextTradeId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Game BundleOf (r:1 w:1)
+	/// Proof: Game BundleOf (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:1 w:1)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game ReservedBalanceOf (r:1 w:1)
+	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game TradeConfigOf (r:0 w:1)
+	/// Proof: Game TradeConfigOf (max_values: None, max_size: Some(202), added: 2677, mode: MaxEncodedLen)
+	/// The range of component `s` is `[0, 10]`.
+	/// The range of component `x` is `[0, 10]`.
+	fn create_swap(s: u32, x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `294`
+		//  Estimated: `3606`
+		// Minimum execution time: 36_000_000 picoseconds.
+		Weight::from_parts(40_441_450, 3606)
+			// Standard Error: 20_522
+			.saturating_add(Weight::from_parts(15_195_615, 0).saturating_mul(s.into()))
+			// Standard Error: 20_522
+			.saturating_add(Weight::from_parts(150_189, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: Game TradeConfigOf (r:1 w:1)
+	/// Proof: Game TradeConfigOf (max_values: None, max_size: Some(202), added: 2677, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:4 w:4)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game BundleOf (r:1 w:1)
+	/// Proof: Game BundleOf (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: Game ReservedBalanceOf (r:2 w:2)
+	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn make_swap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `726`
+		//  Estimated: `11226`
+		// Minimum execution time: 113_000_000 picoseconds.
+		Weight::from_parts(116_000_000, 11226)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: Game NextTradeId (r:1 w:1)
+	/// Proof: Game NextTradeId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Game BundleOf (r:1 w:1)
+	/// Proof: Game BundleOf (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:1 w:1)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game ReservedBalanceOf (r:1 w:1)
+	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game AuctionConfigOf (r:0 w:1)
+	/// Proof: Game AuctionConfigOf (max_values: None, max_size: Some(77), added: 2552, mode: MaxEncodedLen)
+	/// The range of component `s` is `[0, 10]`.
+	fn set_auction(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `294`
+		//  Estimated: `3606`
+		// Minimum execution time: 34_000_000 picoseconds.
+		Weight::from_parts(39_914_618, 3606)
+			// Standard Error: 28_260
+			.saturating_add(Weight::from_parts(15_344_110, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: Game AuctionConfigOf (r:1 w:0)
+	/// Proof: Game AuctionConfigOf (max_values: None, max_size: Some(77), added: 2552, mode: MaxEncodedLen)
+	/// Storage: Game HighestBidOf (r:1 w:1)
+	/// Proof: Game HighestBidOf (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn bid_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `445`
+		//  Estimated: `3593`
+		// Minimum execution time: 39_000_000 picoseconds.
+		Weight::from_parts(40_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Game AuctionConfigOf (r:1 w:1)
+	/// Proof: Game AuctionConfigOf (max_values: None, max_size: Some(77), added: 2552, mode: MaxEncodedLen)
+	/// Storage: Game HighestBidOf (r:1 w:1)
+	/// Proof: Game HighestBidOf (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Game BundleOf (r:1 w:1)
+	/// Proof: Game BundleOf (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: Game ReservedBalanceOf (r:2 w:2)
+	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:2 w:2)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	fn close_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `712`
+		//  Estimated: `6108`
+		// Minimum execution time: 103_000_000 picoseconds.
+		Weight::from_parts(105_000_000, 6108)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: Game NextTradeId (r:1 w:1)
+	/// Proof: Game NextTradeId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Game BundleOf (r:1 w:1)
+	/// Proof: Game BundleOf (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Game TradeConfigOf (r:0 w:1)
+	/// Proof: Game TradeConfigOf (max_values: None, max_size: Some(202), added: 2677, mode: MaxEncodedLen)
+	fn set_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `3606`
+		// Minimum execution time: 52_000_000 picoseconds.
+		Weight::from_parts(52_000_000, 3606)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Game TradeConfigOf (r:1 w:0)
+	/// Proof: Game TradeConfigOf (max_values: None, max_size: Some(202), added: 2677, mode: MaxEncodedLen)
+	/// Storage: Game BundleOf (r:1 w:1)
+	/// Proof: Game BundleOf (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:1 w:1)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn sell_item() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `871`
+		//  Estimated: `3667`
+		// Minimum execution time: 71_000_000 picoseconds.
+		Weight::from_parts(72_000_000, 3667)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Nfts NextCollectionId (r:1 w:1)
+	/// Proof: Nfts NextCollectionId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoleOf (r:0 w:1)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:0 w:1)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionAccount (r:0 w:1)
+	/// Proof: Nfts CollectionAccount (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	fn create_collection() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `3593`
+		// Minimum execution time: 50_000_000 picoseconds.
+		Weight::from_parts(51_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Game Game (r:1 w:0)
+	/// Proof: Game Game (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Game AddingAcceptance (r:1 w:1)
+	/// Proof: Game AddingAcceptance (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Game AddingAcceptanceOf (r:1 w:2)
+	/// Proof: Game AddingAcceptanceOf (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	fn set_accept_adding() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `3593`
+		// Minimum execution time: 45_000_000 picoseconds.
+		Weight::from_parts(46_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Game GameRoleOf (r:1 w:0)
+	/// Proof: Game GameRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Game AddingAcceptance (r:1 w:0)
+	/// Proof: Game AddingAcceptance (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Game CollectionsOf (r:1 w:1)
+	/// Proof: Game CollectionsOf (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: Game GamesOf (r:1 w:1)
+	/// Proof: Game GamesOf (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	fn add_game_collection() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `212`
+		//  Estimated: `3534`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(22_000_000, 3534)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Game TradeConfigOf (r:1 w:0)
+	/// Proof: Game TradeConfigOf (max_values: None, max_size: Some(202), added: 2677, mode: MaxEncodedLen)
+	/// Storage: Game BundleOf (r:1 w:1)
+	/// Proof: Game BundleOf (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:1 w:1)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game ReservedBalanceOf (r:1 w:1)
+	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	fn add_set_price() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `874`
+		//  Estimated: `3667`
+		// Minimum execution time: 46_000_000 picoseconds.
+		Weight::from_parts(47_000_000, 3667)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Game TradeConfigOf (r:1 w:1)
+	/// Proof: Game TradeConfigOf (max_values: None, max_size: Some(202), added: 2677, mode: MaxEncodedLen)
+	/// Storage: Game BundleOf (r:1 w:1)
+	/// Proof: Game BundleOf (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: Game ReservedBalanceOf (r:2 w:2)
+	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:2 w:2)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn cancel_trade() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `611`
+		//  Estimated: `6108`
+		// Minimum execution time: 82_000_000 picoseconds.
+		Weight::from_parts(83_000_000, 6108)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: Game NextPoolId (r:1 w:1)
+	/// Proof: Game NextPoolId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Game PoolOf (r:1 w:1)
+	/// Proof: Game PoolOf (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:1 w:1)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game ReservedBalanceOf (r:1 w:1)
+	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game LootTableOf (r:0 w:1)
+	/// Proof: Game LootTableOf (max_values: None, max_size: Some(147), added: 2622, mode: MaxEncodedLen)
+	/// The range of component `s` is `[0, 10]`.
+	fn create_dynamic_pool(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `294`
+		//  Estimated: `3593`
+		// Minimum execution time: 35_000_000 picoseconds.
+		Weight::from_parts(38_947_576, 3593)
+			// Standard Error: 26_640
+			.saturating_add(Weight::from_parts(16_361_598, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: Game NextPoolId (r:1 w:1)
+	/// Proof: Game NextPoolId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game SupplyOf (r:1 w:0)
+	/// Proof: Game SupplyOf (max_values: None, max_size: Some(45), added: 2520, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Game LootTableOf (r:0 w:1)
+	/// Proof: Game LootTableOf (max_values: None, max_size: Some(147), added: 2622, mode: MaxEncodedLen)
+	/// Storage: Game PoolOf (r:0 w:1)
+	/// Proof: Game PoolOf (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// The range of component `s` is `[0, 10]`.
+	fn create_stable_pool(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `500`
+		//  Estimated: `3593`
+		// Minimum execution time: 35_000_000 picoseconds.
+		Weight::from_parts(41_774_162, 3593)
+			// Standard Error: 36_564
+			.saturating_add(Weight::from_parts(4_320_318, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Game PoolOf (r:1 w:0)
+	/// Proof: Game PoolOf (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Game UsedVoucherOf (r:1 w:1)
+	/// Proof: Game UsedVoucherOf (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Game MintRecordOf (r:1 w:1)
+	/// Proof: Game MintRecordOf (max_values: None, max_size: Some(75), added: 2550, mode: MaxEncodedLen)
+	/// Storage: Game MintRequestOf (r:1 w:1)
+	/// Proof: Game MintRequestOf (max_values: None, max_size: Some(1093), added: 3568, mode: MaxEncodedLen)
+	fn request_mint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `337`
+		//  Estimated: `4558`
+		// Minimum execution time: 88_000_000 picoseconds.
+		Weight::from_parts(94_000_000, 4558)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: Game NextGameId (r:1 w:1)
+	/// Proof: Game NextGameId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Game GameMetadataOf (r:1 w:1)
+	/// Proof: Game GameMetadataOf (max_values: None, max_size: Some(322), added: 2797, mode: MaxEncodedLen)
+	/// Storage: Game GameAccount (r:0 w:1)
+	/// Proof: Game GameAccount (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Game Game (r:0 w:1)
+	/// Proof: Game Game (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: Game GameRoleOf (r:0 w:1)
+	/// Proof: Game GameRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// The range of component `s` is `[0, 300]`.
+	fn create_game_with_data(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `3787`
+		// Minimum execution time: 25_000_000 picoseconds.
+		Weight::from_parts(28_765_534, 0)
+			.saturating_add(Weight::from_parts(0, 3787))
+			// Standard Error: 1_384
+			.saturating_add(Weight::from_parts(10_037, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(6))
+	}
+	/// Storage: Nfts NextCollectionId (r:1 w:1)
+	/// Proof: Nfts NextCollectionId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionMetadataOf (r:1 w:1)
+	/// Proof: Nfts CollectionMetadataOf (max_values: None, max_size: Some(338), added: 2813, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoleOf (r:0 w:1)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:0 w:1)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionAccount (r:0 w:1)
+	/// Proof: Nfts CollectionAccount (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// The range of component `s` is `[0, 300]`.
+	fn create_collection_with_data(_s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `3803`
+		// Minimum execution time: 42_000_000 picoseconds.
+		Weight::from_parts(52_820_352, 0)
+			.saturating_add(Weight::from_parts(0, 3803))
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(7))
+	}
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:1)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(491), added: 2966, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:1)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemMetadataOf (r:1 w:1)
+	/// Proof: Nfts ItemMetadataOf (max_values: None, max_size: Some(391), added: 2866, mode: MaxEncodedLen)
+	/// Storage: Game SupplyOf (r:0 w:1)
+	/// Proof: Game SupplyOf (max_values: None, max_size: Some(45), added: 2520, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:0 w:1)
+	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// The range of component `s` is `[0, 300]`.
+	fn create_item_with_data(_s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `487`
+		//  Estimated: `3956`
+		// Minimum execution time: 55_000_000 picoseconds.
+		Weight::from_parts(62_357_859, 0)
 			.saturating_add(Weight::from_parts(0, 3956))
 			.saturating_add(RocksDbWeight::get().reads(7))
 			.saturating_add(RocksDbWeight::get().writes(7))
 	}
-	/// Storage: Game Game (r:1 w:0)
-	/// Proof: Game Game (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
-	/// Storage: Game GameMetadataOf (r:1 w:1)
-	/// Proof: Game GameMetadataOf (max_values: None, max_size: Some(322), added: 2797, mode: MaxEncodedLen)
-	/// The range of component `s` is `[0, 300]`.
-	fn set_game_metadata(_s: u32, ) -> Weight {
+	/// Storage: Game Game (r:1 w:0)
+	/// Proof: Game Game (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: Game GameMetadataOf (r:1 w:1)
+	/// Proof: Game GameMetadataOf (max_values: None, max_size: Some(322), added: 2797, mode: MaxEncodedLen)
+	/// The range of component `s` is `[0, 300]`.
+	fn set_game_metadata(_s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `278`
+		//  Estimated: `3787`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_061_367, 0)
+			.saturating_add(Weight::from_parts(0, 3787))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: Game Game (r:1 w:0)
+	/// Proof: Game Game (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: Game GameMetadataOf (r:1 w:1)
+	/// Proof: Game GameMetadataOf (max_values: None, max_size: Some(322), added: 2797, mode: MaxEncodedLen)
+	/// The range of component `s` is `[0, 300]`.
+	fn clear_game_metadata(_s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `271 + s * (1 ±0)`
+		//  Estimated: `3787`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_275_902, 0)
+			.saturating_add(Weight::from_parts(0, 3787))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: Game NextPoolId (r:1 w:1)
+	/// Proof: Game NextPoolId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Game PoolOf (r:1 w:1)
+	/// Proof: Game PoolOf (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:1 w:1)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game ReservedBalanceOf (r:1 w:1)
+	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game PoolMetadataOf (r:1 w:1)
+	/// Proof: Game PoolMetadataOf (max_values: None, max_size: Some(322), added: 2797, mode: MaxEncodedLen)
+	/// Storage: Game LootTableOf (r:0 w:1)
+	/// Proof: Game LootTableOf (max_values: None, max_size: Some(147), added: 2622, mode: MaxEncodedLen)
+	fn create_dynamic_pool_with_data() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `387`
+		//  Estimated: `3787`
+		// Minimum execution time: 83_000_000 picoseconds.
+		Weight::from_parts(85_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3787))
+			.saturating_add(RocksDbWeight::get().reads(6))
+			.saturating_add(RocksDbWeight::get().writes(7))
+	}
+	/// Storage: Game NextPoolId (r:1 w:1)
+	/// Proof: Game NextPoolId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game SupplyOf (r:1 w:0)
+	/// Proof: Game SupplyOf (max_values: None, max_size: Some(45), added: 2520, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Game PoolMetadataOf (r:1 w:1)
+	/// Proof: Game PoolMetadataOf (max_values: None, max_size: Some(322), added: 2797, mode: MaxEncodedLen)
+	/// Storage: Game LootTableOf (r:0 w:1)
+	/// Proof: Game LootTableOf (max_values: None, max_size: Some(147), added: 2622, mode: MaxEncodedLen)
+	/// Storage: Game PoolOf (r:0 w:1)
+	/// Proof: Game PoolOf (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	fn create_stable_pool_with_data() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `500`
+		//  Estimated: `3787`
+		// Minimum execution time: 40_000_000 picoseconds.
+		Weight::from_parts(42_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3787))
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(5))
+	}
+	/// Storage: Game PoolOf (r:1 w:0)
+	/// Proof: Game PoolOf (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: Game PoolMetadataOf (r:1 w:1)
+	/// Proof: Game PoolMetadataOf (max_values: None, max_size: Some(322), added: 2797, mode: MaxEncodedLen)
+	fn set_pool_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `394`
+		//  Estimated: `3787`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3787))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: Game PoolOf (r:1 w:0)
+	/// Proof: Game PoolOf (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: Game PoolMetadataOf (r:1 w:1)
+	/// Proof: Game PoolMetadataOf (max_values: None, max_size: Some(322), added: 2797, mode: MaxEncodedLen)
+	fn clear_pool_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `650`
+		//  Estimated: `3787`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3787))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: Game Game (r:1 w:1)
+	/// Proof: Game Game (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	fn start_game_destroy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312`
+		//  Estimated: `3562`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 3562)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Game Game (r:1 w:0)
+	/// Proof: Game Game (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Game GameRoleOf (r:1001 w:1000)
+	/// Proof: Game GameRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Game AddingAcceptanceOf (r:1001 w:1000)
+	/// Proof: Game AddingAcceptanceOf (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: Game AddingAcceptance (r:0 w:1000)
+	/// Proof: Game AddingAcceptance (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1000 w:1000)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `r` is `[0, 1000]`.
+	fn destroy_game_roles(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `344 + r * (231 ±0)`
+		//  Estimated: `3562 + r * (2603 ±0)`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(16_000_000, 3562)
+			// Standard Error: 8_472
+			.saturating_add(Weight::from_parts(15_112_384, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(r.into()))
+	}
+	/// Storage: Game Game (r:1 w:1)
+	/// Proof: Game Game (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Game GameRoleOf (r:1 w:0)
+	/// Proof: Game GameRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Game AddingAcceptanceOf (r:1 w:0)
+	/// Proof: Game AddingAcceptanceOf (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: Game OwnershipAcceptorOf (r:1 w:0)
+	/// Proof: Game OwnershipAcceptorOf (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Game CollectionsOf (r:1 w:1)
+	/// Proof: Game CollectionsOf (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: Game GamesOf (r:5 w:5)
+	/// Proof: Game GamesOf (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: Game CollectionGameOf (r:5 w:5)
+	/// Proof: Game CollectionGameOf (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Game GameMetadataOf (r:0 w:1)
+	/// Proof: Game GameMetadataOf (max_values: None, max_size: Some(322), added: 2797, mode: MaxEncodedLen)
+	/// Storage: Game GameAccount (r:0 w:1)
+	/// Proof: Game GameAccount (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// The range of component `c` is `[0, 5]`.
+	fn finish_game_destroy(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `626 + c * (61 ±0)`
+		//  Estimated: `6196 + c * (2508 ±0)`
+		// Minimum execution time: 56_000_000 picoseconds.
+		Weight::from_parts(58_126_509, 6196)
+			// Standard Error: 41_262
+			.saturating_add(Weight::from_parts(4_187_233, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2508).saturating_mul(c.into()))
+	}
+	/// Storage: Game NextTradeId (r:1 w:1)
+	/// Proof: Game NextTradeId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Game BundleOf (r:1 w:1)
+	/// Proof: Game BundleOf (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:1 w:1)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game ReservedBalanceOf (r:1 w:1)
+	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game CandleAuctionOf (r:0 w:1)
+	/// Proof: Game CandleAuctionOf (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// The range of component `s` is `[0, 10]`.
+	fn set_candle_auction(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `294`
+		//  Estimated: `3606`
+		// Minimum execution time: 35_000_000 picoseconds.
+		Weight::from_parts(40_218_774, 3606)
+			// Standard Error: 27_913
+			.saturating_add(Weight::from_parts(15_402_651, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: Game CandleAuctionOf (r:1 w:0)
+	/// Proof: Game CandleAuctionOf (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Game CandleBidsOf (r:1 w:1)
+	/// Proof: Game CandleBidsOf (max_values: None, max_size: Some(6419), added: 8894, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn bid_candle_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6903`
+		//  Estimated: `9884`
+		// Minimum execution time: 52_000_000 picoseconds.
+		Weight::from_parts(54_000_000, 9884)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Game CandleAuctionOf (r:1 w:1)
+	/// Proof: Game CandleAuctionOf (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Game CandleBidsOf (r:1 w:1)
+	/// Proof: Game CandleBidsOf (max_values: None, max_size: Some(6419), added: 8894, mode: MaxEncodedLen)
+	/// Storage: Game RandomSeed (r:1 w:0)
+	/// Proof: Game RandomSeed (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:101 w:101)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Game BundleOf (r:1 w:1)
+	/// Proof: Game BundleOf (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: Game ReservedBalanceOf (r:1 w:1)
+	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:1 w:1)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// The range of component `b` is `[0, 100]`.
+	fn close_candle_auction(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `712 + b * (64 ±0)`
+		//  Estimated: `9884 + b * (2603 ±0)`
+		// Minimum execution time: 61_000_000 picoseconds.
+		Weight::from_parts(63_552_118, 9884)
+			// Standard Error: 19_084
+			.saturating_add(Weight::from_parts(12_604_377, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(b.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(b.into()))
+	}
+	/// Storage: Game NextTradeId (r:1 w:1)
+	/// Proof: Game NextTradeId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Game BundleOf (r:1 w:1)
+	/// Proof: Game BundleOf (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:1 w:1)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game ReservedBalanceOf (r:1 w:1)
+	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game NextExpiryBlock (r:1 w:1)
+	/// Proof: Game NextExpiryBlock (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Game TradeExpiryOf (r:1 w:1)
+	/// Proof: Game TradeExpiryOf (max_values: None, max_size: Some(514), added: 2989, mode: MaxEncodedLen)
+	/// Storage: Game DutchAuctionOf (r:0 w:1)
+	/// Proof: Game DutchAuctionOf (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// The range of component `s` is `[0, 10]`.
+	fn set_dutch_auction(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `294`
+		//  Estimated: `3606`
+		// Minimum execution time: 35_000_000 picoseconds.
+		Weight::from_parts(39_817_402, 3606)
+			// Standard Error: 28_406
+			.saturating_add(Weight::from_parts(15_519_230, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: Game DutchAuctionOf (r:1 w:1)
+	/// Proof: Game DutchAuctionOf (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: Game BundleOf (r:1 w:1)
+	/// Proof: Game BundleOf (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:2 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Game ReservedBalanceOf (r:2 w:2)
+	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:2 w:2)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn buy_dutch_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1012`
+		//  Estimated: `6108`
+		// Minimum execution time: 94_000_000 picoseconds.
+		Weight::from_parts(99_000_000, 6108)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: Game NextTradeId (r:1 w:1)
+	/// Proof: Game NextTradeId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Game BundleOf (r:1 w:1)
+	/// Proof: Game BundleOf (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:1 w:1)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game ReservedBalanceOf (r:1 w:1)
+	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game SealedAuctionOf (r:0 w:1)
+	/// Proof: Game SealedAuctionOf (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// The range of component `s` is `[0, 10]`.
+	fn set_sealed_auction(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `294`
+		//  Estimated: `3606`
+		// Minimum execution time: 35_000_000 picoseconds.
+		Weight::from_parts(40_042_316, 3606)
+			// Standard Error: 28_115
+			.saturating_add(Weight::from_parts(15_447_820, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: Game SealedAuctionOf (r:1 w:0)
+	/// Proof: Game SealedAuctionOf (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Game SealedBidsOf (r:1 w:1)
+	/// Proof: Game SealedBidsOf (max_values: None, max_size: Some(9619), added: 12094, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn commit_sealed_bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `9803`
+		//  Estimated: `13084`
+		// Minimum execution time: 55_000_000 picoseconds.
+		Weight::from_parts(57_000_000, 13084)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Game SealedAuctionOf (r:1 w:0)
+	/// Proof: Game SealedAuctionOf (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Game SealedBidsOf (r:1 w:1)
+	/// Proof: Game SealedBidsOf (max_values: None, max_size: Some(9619), added: 12094, mode: MaxEncodedLen)
+	fn reveal_sealed_bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `9803`
+		//  Estimated: `13084`
+		// Minimum execution time: 41_000_000 picoseconds.
+		Weight::from_parts(43_000_000, 13084)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Game SealedAuctionOf (r:1 w:1)
+	/// Proof: Game SealedAuctionOf (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Game SealedBidsOf (r:1 w:1)
+	/// Proof: Game SealedBidsOf (max_values: None, max_size: Some(9619), added: 12094, mode: MaxEncodedLen)
+	/// Storage: System Account (r:101 w:101)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Game BundleOf (r:1 w:1)
+	/// Proof: Game BundleOf (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: Game ReservedBalanceOf (r:1 w:1)
+	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:1 w:1)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// The range of component `b` is `[0, 100]`.
+	fn close_sealed_auction(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `712 + b * (96 ±0)`
+		//  Estimated: `13084 + b * (2603 ±0)`
+		// Minimum execution time: 62_000_000 picoseconds.
+		Weight::from_parts(64_120_538, 13084)
+			// Standard Error: 19_562
+			.saturating_add(Weight::from_parts(12_798_105, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(b.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(b.into()))
+	}
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game ItemRoyaltyOf (r:1 w:1)
+	/// Proof: Game ItemRoyaltyOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	fn set_royalty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `301`
+		//  Estimated: `3549`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(22_000_000, 3549)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game ItemRoyaltyOf (r:1 w:1)
+	/// Proof: Game ItemRoyaltyOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	fn clear_royalty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `371`
+		//  Estimated: `3549`
+		// Minimum execution time: 22_000_000 picoseconds.
+		Weight::from_parts(23_000_000, 3549)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Game Game (r:1 w:1)
+	/// Proof: Game Game (max_values: None, max_size: Some(131), added: 2606, mode: MaxEncodedLen)
+	fn set_trade_fee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `251`
+		//  Estimated: `3596`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 3596)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Game BundleOf (r:101 w:101)
+	/// Proof: Game BundleOf (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: Game TradeConfigOf (r:101 w:101)
+	/// Proof: Game TradeConfigOf (max_values: None, max_size: Some(283), added: 2758, mode: MaxEncodedLen)
+	/// Storage: Game BidBookOf (r:1 w:1)
+	/// Proof: Game BidBookOf (max_values: None, max_size: Some(4823), added: 7298, mode: MaxEncodedLen)
+	/// Storage: Game AskBookOf (r:1 w:1)
+	/// Proof: Game AskBookOf (max_values: None, max_size: Some(4823), added: 7298, mode: MaxEncodedLen)
+	/// Storage: Game ReservedBalanceOf (r:1 w:1)
+	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:2 w:2)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game ItemRoyaltyOf (r:1 w:0)
+	/// Proof: Game ItemRoyaltyOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: Game CollectionRoyaltyOf (r:1 w:0)
+	/// Proof: Game CollectionRoyaltyOf (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: Game GamesOf (r:1 w:0)
+	/// Proof: Game GamesOf (max_values: None, max_size: Some(83), added: 2558, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `m` is `[1, 100]`.
+	fn set_book_price(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1024 + m * (253 ±0)`
+		//  Estimated: `8288 + m * (2758 ±0)`
+		// Minimum execution time: 58_000_000 picoseconds.
+		Weight::from_parts(60_384_211, 8288)
+			// Standard Error: 21_037
+			.saturating_add(Weight::from_parts(24_517_842, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(m.into())))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 2758).saturating_mul(m.into()))
+	}
+	/// Storage: Game BundleOf (r:101 w:101)
+	/// Proof: Game BundleOf (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: Game TradeConfigOf (r:101 w:101)
+	/// Proof: Game TradeConfigOf (max_values: None, max_size: Some(283), added: 2758, mode: MaxEncodedLen)
+	/// Storage: Game AskBookOf (r:1 w:1)
+	/// Proof: Game AskBookOf (max_values: None, max_size: Some(4823), added: 7298, mode: MaxEncodedLen)
+	/// Storage: Game BidBookOf (r:1 w:1)
+	/// Proof: Game BidBookOf (max_values: None, max_size: Some(4823), added: 7298, mode: MaxEncodedLen)
+	/// Storage: Game ReservedBalanceOf (r:1 w:1)
+	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:1 w:1)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game ItemRoyaltyOf (r:1 w:0)
+	/// Proof: Game ItemRoyaltyOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: Game CollectionRoyaltyOf (r:1 w:0)
+	/// Proof: Game CollectionRoyaltyOf (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: Game GamesOf (r:1 w:0)
+	/// Proof: Game GamesOf (max_values: None, max_size: Some(83), added: 2558, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `m` is `[1, 100]`.
+	fn set_book_order(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1088 + m * (253 ±0)`
+		//  Estimated: `8288 + m * (2758 ±0)`
+		// Minimum execution time: 55_000_000 picoseconds.
+		Weight::from_parts(57_902_631, 8288)
+			// Standard Error: 19_844
+			.saturating_add(Weight::from_parts(23_961_052, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(m.into())))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 2758).saturating_mul(m.into()))
+	}
+	/// Storage: Game AuctionConfigOf (r:1 w:1)
+	/// Proof: Game AuctionConfigOf (max_values: None, max_size: Some(77), added: 2552, mode: MaxEncodedLen)
+	/// Storage: Game HighestBidOf (r:1 w:1)
+	/// Proof: Game HighestBidOf (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Game BundleOf (r:1 w:1)
+	/// Proof: Game BundleOf (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: Game ReservedBalanceOf (r:2 w:2)
+	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:2 w:2)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game ItemRoyaltyOf (r:2 w:0)
+	/// Proof: Game ItemRoyaltyOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: Game CollectionRoyaltyOf (r:2 w:0)
+	/// Proof: Game CollectionRoyaltyOf (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: Game GamesOf (r:2 w:0)
+	/// Proof: Game GamesOf (max_values: None, max_size: Some(83), added: 2558, mode: MaxEncodedLen)
+	fn expire_trade() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `712`
+		//  Estimated: `6108`
+		// Minimum execution time: 112_000_000 picoseconds.
+		Weight::from_parts(115_000_000, 6108)
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: Game Game (r:1 w:1)
+	/// Proof: Game Game (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	fn set_payment_asset() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `278`
-		//  Estimated: `3787`
-		// Minimum execution time: 11_000_000 picoseconds.
-		Weight::from_parts(12_061_367, 0)
-			.saturating_add(Weight::from_parts(0, 3787))
-			.saturating_add(RocksDbWeight::get().reads(2))
-			.saturating_add(RocksDbWeight::get().writes(1))
+		//  Measured:  `251`
+		//  Estimated: `3601`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 3601)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Game NextRecipeId (r:1 w:1)
+	/// Proof: Game NextRecipeId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Game Game (r:1 w:1)
+	/// Proof: Game Game (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: Game CollectionsOf (r:1 w:0)
+	/// Proof: Game CollectionsOf (max_values: None, max_size: Some(82), added: 2557, mode: MaxEncodedLen)
+	/// Storage: Game SupplyOf (r:20 w:0)
+	/// Proof: Game SupplyOf (max_values: None, max_size: Some(45), added: 2520, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:10 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Game RecipeOf (r:0 w:1)
+	/// Proof: Game RecipeOf (max_values: None, max_size: Some(227), added: 2702, mode: MaxEncodedLen)
+	/// The range of component `i` is `[1, 10]`.
+	/// The range of component `o` is `[1, 10]`.
+	fn create_recipe(i: u32, o: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `4089`
+		// Minimum execution time: 39_000_000 picoseconds.
+		Weight::from_parts(38_912_614, 4089)
+			// Standard Error: 14_318
+			.saturating_add(Weight::from_parts(3_962_170, 0).saturating_mul(i.into()))
+			// Standard Error: 14_318
+			.saturating_add(Weight::from_parts(6_418_902, 0).saturating_mul(o.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(i.into())))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(o.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(o.into())))
+			.saturating_add(Weight::from_parts(0, 2520).saturating_mul(i.into()))
+			.saturating_add(Weight::from_parts(0, 2559).saturating_mul(o.into()))
 	}
+	/// Storage: Game RecipeOf (r:1 w:1)
+	/// Proof: Game RecipeOf (max_values: None, max_size: Some(227), added: 2702, mode: MaxEncodedLen)
+	/// Storage: Game SupplyOf (r:10 w:0)
+	/// Proof: Game SupplyOf (max_values: None, max_size: Some(45), added: 2520, mode: MaxEncodedLen)
+	/// Storage: Game ReservedBalanceOf (r:10 w:10)
+	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:10 w:10)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Game Game (r:1 w:1)
+	/// Proof: Game Game (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	fn remove_recipe() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1238`
+		//  Estimated: `32522`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(64_000_000, 32522)
+			.saturating_add(RocksDbWeight::get().reads(33_u64))
+			.saturating_add(RocksDbWeight::get().writes(23_u64))
+	}
+	/// Storage: Game RecipeOf (r:1 w:1)
+	/// Proof: Game RecipeOf (max_values: None, max_size: Some(227), added: 2702, mode: MaxEncodedLen)
+	/// Storage: Game ReservedBalanceOf (r:10 w:10)
+	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:20 w:20)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Game SupplyOf (r:20 w:10)
+	/// Proof: Game SupplyOf (max_values: None, max_size: Some(45), added: 2520, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:10 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
 	/// Storage: Game Game (r:1 w:0)
-	/// Proof: Game Game (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
-	/// Storage: Game GameMetadataOf (r:1 w:1)
-	/// Proof: Game GameMetadataOf (max_values: None, max_size: Some(322), added: 2797, mode: MaxEncodedLen)
-	/// The range of component `s` is `[0, 300]`.
-	fn clear_game_metadata(_s: u32, ) -> Weight {
+	/// Proof: Game Game (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn craft() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `271 + s * (1 ±0)`
-		//  Estimated: `3787`
-		// Minimum execution time: 11_000_000 picoseconds.
-		Weight::from_parts(12_275_902, 0)
-			.saturating_add(Weight::from_parts(0, 3787))
-			.saturating_add(RocksDbWeight::get().reads(2))
-			.saturating_add(RocksDbWeight::get().writes(1))
+		//  Measured:  `1843`
+		//  Estimated: `77741`
+		// Minimum execution time: 261_000_000 picoseconds.
+		Weight::from_parts(268_000_000, 77741)
+			.saturating_add(RocksDbWeight::get().reads(64_u64))
+			.saturating_add(RocksDbWeight::get().writes(43_u64))
 	}
-	/// Storage: Game NextPoolId (r:1 w:1)
-	/// Proof: Game NextPoolId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	/// Storage: Game PoolOf (r:1 w:1)
-	/// Proof: Game PoolOf (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game UpgradeConfigOf (r:1 w:1)
+	/// Proof: Game UpgradeConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn set_upgrade_chance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3549`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(22_000_000, 3549)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Nfts Collection (r:11 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game SupplyOf (r:11 w:0)
+	/// Proof: Game SupplyOf (max_values: None, max_size: Some(45), added: 2520, mode: MaxEncodedLen)
+	/// Storage: Game SalvageOf (r:1 w:1)
+	/// Proof: Game SalvageOf (max_values: None, max_size: Some(262), added: 2737, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `m` is `[1, 10]`.
+	fn set_salvage(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `588`
+		//  Estimated: `3727`
+		// Minimum execution time: 34_000_000 picoseconds.
+		Weight::from_parts(33_128_501, 3727)
+			// Standard Error: 11_942
+			.saturating_add(Weight::from_parts(6_204_377, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(m.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 2559).saturating_mul(m.into()))
+	}
+	/// Storage: Game SalvageOf (r:1 w:1)
+	/// Proof: Game SalvageOf (max_values: None, max_size: Some(262), added: 2737, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn clear_salvage() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `436`
+		//  Estimated: `3727`
+		// Minimum execution time: 25_000_000 picoseconds.
+		Weight::from_parts(26_000_000, 3727)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Game SalvageOf (r:1 w:0)
+	/// Proof: Game SalvageOf (max_values: None, max_size: Some(262), added: 2737, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:11 w:11)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game SupplyOf (r:11 w:1)
+	/// Proof: Game SupplyOf (max_values: None, max_size: Some(45), added: 2520, mode: MaxEncodedLen)
+	/// Storage: Game RandomSeed (r:1 w:0)
+	/// Proof: Game RandomSeed (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	fn salvage() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1320`
+		//  Estimated: `29139`
+		// Minimum execution time: 152_000_000 picoseconds.
+		Weight::from_parts(157_000_000, 29139)
+			.saturating_add(RocksDbWeight::get().reads(24_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+	}
+	/// Storage: Game NextTradeId (r:1 w:1)
+	/// Proof: Game NextTradeId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Game BundleOf (r:1 w:1)
+	/// Proof: Game BundleOf (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: Game ItemBalanceOf (r:1 w:1)
 	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
 	/// Storage: Game ReservedBalanceOf (r:1 w:1)
 	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
-	/// Storage: Game PoolMetadataOf (r:1 w:1)
-	/// Proof: Game PoolMetadataOf (max_values: None, max_size: Some(322), added: 2797, mode: MaxEncodedLen)
-	/// Storage: Game LootTableOf (r:0 w:1)
-	/// Proof: Game LootTableOf (max_values: None, max_size: Some(147), added: 2622, mode: MaxEncodedLen)
-	fn create_dynamic_pool_with_data() -> Weight {
+	/// Storage: Game RentalOf (r:0 w:1)
+	/// Proof: Game RentalOf (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// The range of component `s` is `[0, 10]`.
+	fn set_rental(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `387`
-		//  Estimated: `3787`
-		// Minimum execution time: 83_000_000 picoseconds.
-		Weight::from_parts(85_000_000, 0)
-			.saturating_add(Weight::from_parts(0, 3787))
-			.saturating_add(RocksDbWeight::get().reads(6))
-			.saturating_add(RocksDbWeight::get().writes(7))
+		//  Measured:  `294`
+		//  Estimated: `3606`
+		// Minimum execution time: 35_000_000 picoseconds.
+		Weight::from_parts(39_902_117, 3606)
+			// Standard Error: 27_913
+			.saturating_add(Weight::from_parts(15_482_506, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
-	/// Storage: Game NextPoolId (r:1 w:1)
-	/// Proof: Game NextPoolId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	/// Storage: Nfts Collection (r:1 w:0)
-	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game RentalOf (r:1 w:1)
+	/// Proof: Game RentalOf (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Game BundleOf (r:1 w:0)
+	/// Proof: Game BundleOf (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Game UseRightOf (r:1 w:1)
+	/// Proof: Game UseRightOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game NextExpiryBlock (r:1 w:1)
+	/// Proof: Game NextExpiryBlock (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Game TradeExpiryOf (r:1 w:1)
+	/// Proof: Game TradeExpiryOf (max_values: None, max_size: Some(514), added: 2989, mode: MaxEncodedLen)
+	fn rent() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `702`
+		//  Estimated: `5871`
+		// Minimum execution time: 61_000_000 picoseconds.
+		Weight::from_parts(64_000_000, 5871)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: Game RentalOf (r:1 w:1)
+	/// Proof: Game RentalOf (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Game BundleOf (r:1 w:0)
+	/// Proof: Game BundleOf (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: Game UseRightOf (r:1 w:1)
+	/// Proof: Game UseRightOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	fn return_rental() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3606`
+		// Minimum execution time: 27_000_000 picoseconds.
+		Weight::from_parts(29_000_000, 3606)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
 	/// Storage: Game SupplyOf (r:1 w:0)
 	/// Proof: Game SupplyOf (max_values: None, max_size: Some(45), added: 2520, mode: MaxEncodedLen)
+	/// Storage: Game SoulboundOf (r:0 w:1)
+	/// Proof: Game SoulboundOf (max_values: None, max_size: Some(41), added: 2516, mode: MaxEncodedLen)
+	fn set_soulbound() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `398`
+		//  Estimated: `3534`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 3534)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Game OperatorApprovalOf (r:0 w:1)
+	/// Proof: Game OperatorApprovalOf (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	fn approve_operator() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Game OperatorApprovalOf (r:1 w:1)
+	/// Proof: Game OperatorApprovalOf (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	fn revoke_operator() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `161`
+		//  Estimated: `3587`
+		// Minimum execution time: 18_000_000 picoseconds.
+		Weight::from_parts(19_000_000, 3587)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Game OperatorApprovalOf (r:2 w:0)
+	/// Proof: Game OperatorApprovalOf (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:2 w:2)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game UseRightOf (r:1 w:0)
+	/// Proof: Game UseRightOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game SoulboundOf (r:1 w:0)
+	/// Proof: Game SoulboundOf (max_values: None, max_size: Some(41), added: 2516, mode: MaxEncodedLen)
+	/// Storage: Game OriginItemOf (r:1 w:0)
+	/// Proof: Game OriginItemOf (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	fn transfer_from() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `6184`
+		// Minimum execution time: 39_000_000 picoseconds.
+		Weight::from_parts(41_000_000, 6184)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Game Game (r:1 w:0)
+	/// Proof: Game Game (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: Game NextMatchId (r:1 w:1)
+	/// Proof: Game NextMatchId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	/// Storage: Game PoolMetadataOf (r:1 w:1)
-	/// Proof: Game PoolMetadataOf (max_values: None, max_size: Some(322), added: 2797, mode: MaxEncodedLen)
-	/// Storage: Game LootTableOf (r:0 w:1)
-	/// Proof: Game LootTableOf (max_values: None, max_size: Some(147), added: 2622, mode: MaxEncodedLen)
-	/// Storage: Game PoolOf (r:0 w:1)
-	/// Proof: Game PoolOf (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
-	fn create_stable_pool_with_data() -> Weight {
+	/// Storage: Game MatchOf (r:0 w:1)
+	/// Proof: Game MatchOf (max_values: None, max_size: Some(2574), added: 5049, mode: MaxEncodedLen)
+	fn create_match() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `318`
+		//  Estimated: `3601`
+		// Minimum execution time: 30_000_000 picoseconds.
+		Weight::from_parts(31_000_000, 3601)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Game MatchOf (r:1 w:1)
+	/// Proof: Game MatchOf (max_values: None, max_size: Some(2574), added: 5049, mode: MaxEncodedLen)
+	/// Storage: Game CollectionsOf (r:1 w:0)
+	/// Proof: Game CollectionsOf (max_values: None, max_size: Some(82), added: 2557, mode: MaxEncodedLen)
+	/// Storage: Game Game (r:1 w:0)
+	/// Proof: Game Game (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Game SoulboundOf (r:10 w:0)
+	/// Proof: Game SoulboundOf (max_values: None, max_size: Some(41), added: 2516, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:10 w:10)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game ReservedBalanceOf (r:10 w:10)
+	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// The range of component `s` is `[0, 10]`.
+	fn join_match(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `500`
-		//  Estimated: `3787`
-		// Minimum execution time: 40_000_000 picoseconds.
-		Weight::from_parts(42_000_000, 0)
-			.saturating_add(Weight::from_parts(0, 3787))
-			.saturating_add(RocksDbWeight::get().reads(5))
-			.saturating_add(RocksDbWeight::get().writes(5))
+		//  Measured:  `689`
+		//  Estimated: `6039`
+		// Minimum execution time: 36_000_000 picoseconds.
+		Weight::from_parts(37_418_205, 6039)
+			// Standard Error: 21_604
+			.saturating_add(Weight::from_parts(11_073_418, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2559).saturating_mul(s.into()))
+	}
+	/// Storage: Game MatchOf (r:1 w:1)
+	/// Proof: Game MatchOf (max_values: None, max_size: Some(2574), added: 5049, mode: MaxEncodedLen)
+	/// Storage: Game Game (r:2 w:0)
+	/// Proof: Game Game (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:17 w:17)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Game ReservedBalanceOf (r:16 w:16)
+	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:16 w:16)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// The range of component `p` is `[1, 16]`.
+	fn settle_match(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `734 + p * (186 ±0)`
+		//  Estimated: `6039 + p * (2603 ±0)`
+		// Minimum execution time: 52_000_000 picoseconds.
+		Weight::from_parts(38_204_561, 6039)
+			// Standard Error: 31_772
+			.saturating_add(Weight::from_parts(24_905_113, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(p.into()))
 	}
-	/// Storage: Game PoolOf (r:1 w:0)
-	/// Proof: Game PoolOf (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
-	/// Storage: Game PoolMetadataOf (r:1 w:1)
-	/// Proof: Game PoolMetadataOf (max_values: None, max_size: Some(322), added: 2797, mode: MaxEncodedLen)
-	fn set_pool_metadata() -> Weight {
+	/// Storage: Game MatchOf (r:1 w:1)
+	/// Proof: Game MatchOf (max_values: None, max_size: Some(2574), added: 5049, mode: MaxEncodedLen)
+	/// Storage: Game Game (r:1 w:0)
+	/// Proof: Game Game (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:17 w:17)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Game ReservedBalanceOf (r:16 w:16)
+	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:16 w:16)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// The range of component `p` is `[1, 16]`.
+	fn refund_match(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `734 + p * (186 ±0)`
+		//  Estimated: `6039 + p * (2603 ±0)`
+		// Minimum execution time: 45_000_000 picoseconds.
+		Weight::from_parts(33_710_298, 6039)
+			// Standard Error: 28_156
+			.saturating_add(Weight::from_parts(21_336_790, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(p.into()))
+	}
+	/// Storage: Game Game (r:1 w:1)
+	/// Proof: Game Game (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	fn set_treasury_routing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `394`
-		//  Estimated: `3787`
-		// Minimum execution time: 11_000_000 picoseconds.
-		Weight::from_parts(12_000_000, 0)
-			.saturating_add(Weight::from_parts(0, 3787))
-			.saturating_add(RocksDbWeight::get().reads(2))
-			.saturating_add(RocksDbWeight::get().writes(1))
+		//  Measured:  `251`
+		//  Estimated: `3602`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 3602)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-	/// Storage: Game PoolOf (r:1 w:0)
-	/// Proof: Game PoolOf (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
-	/// Storage: Game PoolMetadataOf (r:1 w:1)
-	/// Proof: Game PoolMetadataOf (max_values: None, max_size: Some(322), added: 2797, mode: MaxEncodedLen)
-	fn clear_pool_metadata() -> Weight {
+	/// Storage: Game Game (r:1 w:0)
+	/// Proof: Game Game (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn spend_treasury() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `650`
-		//  Estimated: `3787`
-		// Minimum execution time: 12_000_000 picoseconds.
-		Weight::from_parts(12_000_000, 0)
-			.saturating_add(Weight::from_parts(0, 3787))
-			.saturating_add(RocksDbWeight::get().reads(2))
-			.saturating_add(RocksDbWeight::get().writes(1))
+		//  Measured:  `459`
+		//  Estimated: `6196`
+		// Minimum execution time: 44_000_000 picoseconds.
+		Weight::from_parts(45_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Game Game (r:1 w:0)
+	/// Proof: Game Game (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Game GameOwnershipAcceptance (r:1 w:1)
+	/// Proof: Game GameOwnershipAcceptance (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Game OwnershipAcceptorOf (r:0 w:2)
+	/// Proof: Game OwnershipAcceptorOf (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	fn set_accept_game_ownership() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `251`
+		//  Estimated: `3602`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 3602)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Game GameOwnershipAcceptance (r:1 w:1)
+	/// Proof: Game GameOwnershipAcceptance (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Game Game (r:1 w:1)
+	/// Proof: Game Game (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Game GameAccount (r:0 w:2)
+	/// Proof: Game GameAccount (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Game OwnershipAcceptorOf (r:0 w:1)
+	/// Proof: Game OwnershipAcceptorOf (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Game CollectionsOf (r:1 w:0)
+	/// Proof: Game CollectionsOf (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:5 w:5)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts OwnershipAcceptance (r:5 w:5)
+	/// Proof: Nfts OwnershipAcceptance (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionAccount (r:0 w:10)
+	/// Proof: Nfts CollectionAccount (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// The range of component `c` is `[0, 5]`.
+	fn transfer_game_ownership(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `594 + c * (119 ±0)`
+		//  Estimated: `6196 + c * (2559 ±0)`
+		// Minimum execution time: 47_000_000 picoseconds.
+		Weight::from_parts(49_312_094, 6196)
+			// Standard Error: 52_318
+			.saturating_add(Weight::from_parts(28_640_118, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2559).saturating_mul(c.into()))
+	}
+	/// Storage: Game Game (r:1 w:1)
+	/// Proof: Game Game (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Game GameRoleOf (r:1 w:2)
+	/// Proof: Game GameRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Game CollectionsOf (r:1 w:0)
+	/// Proof: Game CollectionsOf (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:5 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoleOf (r:5 w:10)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// The range of component `c` is `[0, 5]`.
+	fn set_game_admin(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `385 + c * (98 ±0)`
+		//  Estimated: `3602 + c * (2559 ±0)`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(22_408_311, 3602)
+			// Standard Error: 31_877
+			.saturating_add(Weight::from_parts(19_204_778, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2559).saturating_mul(c.into()))
+	}
+	/// Storage: Game Game (r:1 w:0)
+	/// Proof: Game Game (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Game GrantedRoleOf (r:0 w:1)
+	/// Proof: Game GrantedRoleOf (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	fn grant_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `251`
+		//  Estimated: `3602`
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(16_000_000, 3602)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Game Game (r:1 w:0)
+	/// Proof: Game Game (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Game GrantedRoleOf (r:1 w:1)
+	/// Proof: Game GrantedRoleOf (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	fn revoke_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `330`
+		//  Estimated: `3602`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 3602)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}	/// Storage: Game ReservedBalanceOf (r:1 w:1)
+	/// Proof: Game ReservedBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:2 w:2)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game RandomSeed (r:1 w:0)
+	/// Proof: Game RandomSeed (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: Game SupplyOf (r:1 w:1)
+	/// Proof: Game SupplyOf (max_values: None, max_size: Some(45), added: 2520, mode: MaxEncodedLen)
+	/// The range of component `a` is `[1, 10]`.
+	fn process_upgrade_request(a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `812`
+		//  Estimated: `6108`
+		// Minimum execution time: 38_000_000 picoseconds.
+		Weight::from_parts(39_614_078, 6108)
+			// Standard Error: 6_402
+			.saturating_add(Weight::from_parts(1_873_512, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 }
@@ -0,0 +1,182 @@
+//! Benchmarking setup for pallet-tournament
+
+use super::*;
+#[allow(unused)]
+use crate::Pallet as Tournament;
+use crate::{pallet::BenchmarkHelper as TournamentBenchmarkHelper, Call, Config};
+use frame_benchmarking::{account, benchmarks, Box};
+use frame_support::{assert_ok, dispatch::UnfilteredDispatchable, traits::Currency};
+use frame_system::RawOrigin;
+use gafi_support::game::Package;
+use pallet_game::{BenchmarkHelper as GameBenchmarkHelper, Pallet as PalletGame};
+use pallet_nfts::BenchmarkHelper;
+use scale_info::prelude::{format, string::String};
+use sp_std::vec;
+
+const UNIT: u128 = 1_000_000_000_000_000_000u128;
+
+fn string_to_static_str(s: String) -> &'static str {
+	Box::leak(s.into_boxed_str())
+}
+
+fn new_funded_account<T: Config>(value: u32, seed: u32, amount: u128) -> T::AccountId {
+	let name: String = format!("{}{}", value, seed);
+	let user = account(string_to_static_str(name), value, seed);
+	<T as pallet::Config>::Currency::make_free_balance_be(&user, amount.try_into().ok().unwrap());
+	<T as pallet_game::Config>::Currency::make_free_balance_be(
+		&user,
+		amount.try_into().ok().unwrap(),
+	);
+	return user
+}
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	let events = frame_system::Pallet::<T>::events();
+	let system_event: <T as frame_system::Config>::RuntimeEvent = generic_event.into();
+	// compare to the last event record
+	let frame_system::EventRecord { event, .. } = &events[events.len() - 1];
+	assert_eq!(event, &system_event);
+}
+
+fn do_create_game<T: Config>() -> (T::AccountId, T::AccountId) {
+	let owner = new_funded_account::<T>(0, 0, 1000_000_000u128 * UNIT);
+	let admin = new_funded_account::<T>(1, 1, 1000_000_000u128 * UNIT);
+	assert_ok!(PalletGame::<T>::create_game(
+		RawOrigin::Signed(owner.clone()).into(),
+		T::Lookup::unlookup(admin.clone())
+	));
+	assert_ok!(PalletGame::<T>::create_game_collection(
+		RawOrigin::Signed(admin.clone()).into(),
+		<T as pallet_game::Config>::Helper::game(0)
+	));
+	assert_ok!(PalletGame::<T>::create_item(
+		RawOrigin::Signed(admin.clone()).into(),
+		<T as pallet_nfts::Config>::Helper::collection(0),
+		<T as pallet_nfts::Config>::Helper::item(0),
+		Some(1000)
+	));
+	(owner, admin)
+}
+
+fn do_create_tournament<T: Config>(admin: &T::AccountId, entry_fee: EntryFeeFor<T>) {
+	assert_ok!(Tournament::<T>::create_tournament(
+		RawOrigin::Signed(admin.clone()).into(),
+		<T as pallet_game::Config>::Helper::game(0),
+		entry_fee,
+		0u32.into(),
+		10u32.into(),
+		vec![10_000],
+		T::Lookup::unlookup(admin.clone())
+	));
+}
+
+fn do_register<T: Config>(p: u32) -> Vec<T::AccountId> {
+	let mut players = Vec::new();
+	for i in 0..p {
+		let player = new_funded_account::<T>(i, 2, 1000_000_000u128 * UNIT);
+		assert_ok!(Tournament::<T>::register(
+			RawOrigin::Signed(player.clone()).into(),
+			<T as pallet::Config>::Helper::tournament(0)
+		));
+		players.push(player);
+	}
+	players
+}
+
+benchmarks! {
+	create_tournament {
+		let (_, admin) = do_create_game::<T>();
+		let entry_fee = EntryFee::Currency(<T as pallet::Config>::Currency::minimum_balance());
+
+		let call = Call::<T>::create_tournament {
+			game: <T as pallet_game::Config>::Helper::game(0),
+			entry_fee: entry_fee.clone(),
+			start_block: 0u32.into(),
+			end_block: 10u32.into(),
+			prizes: vec![10_000],
+			submitter: T::Lookup::unlookup(admin.clone()),
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(admin.clone()).into())? }
+	verify {
+		assert_last_event::<T>(Event::TournamentCreated {
+			tournament: <T as pallet::Config>::Helper::tournament(0),
+			who: admin.clone(),
+			game: <T as pallet_game::Config>::Helper::game(0),
+			entry_fee,
+			start_block: 0u32.into(),
+			end_block: 10u32.into(),
+			prizes: vec![10_000],
+			submitter: admin,
+		}.into() );
+	}
+
+	register {
+		let (owner, admin) = do_create_game::<T>();
+		let player = new_funded_account::<T>(0, 2, 1000_000_000u128 * UNIT);
+		assert_ok!(PalletGame::<T>::transfer(
+			RawOrigin::Signed(owner.clone()).into(),
+			<T as pallet_nfts::Config>::Helper::collection(0),
+			<T as pallet_nfts::Config>::Helper::item(0),
+			T::Lookup::unlookup(player.clone()),
+			10
+		));
+		do_create_tournament::<T>(&admin, EntryFee::Item(Package {
+			collection: <T as pallet_nfts::Config>::Helper::collection(0),
+			item: <T as pallet_nfts::Config>::Helper::item(0),
+			amount: 1,
+		}));
+
+		let call = Call::<T>::register {
+			tournament: <T as pallet::Config>::Helper::tournament(0),
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(player.clone()).into())? }
+	verify {
+		assert_last_event::<T>(Event::Registered {
+			tournament: <T as pallet::Config>::Helper::tournament(0),
+			who: player,
+		}.into() );
+	}
+
+	submit_results {
+		let p in 1 .. <T as pallet::Config>::MaxPlayers::get();
+
+		let (_, admin) = do_create_game::<T>();
+		do_create_tournament::<T>(
+			&admin,
+			EntryFee::Currency(<T as pallet::Config>::Currency::minimum_balance()),
+		);
+		let players = do_register::<T>(p);
+		frame_system::Pallet::<T>::set_block_number(10u32.into());
+
+		let call = Call::<T>::submit_results {
+			tournament: <T as pallet::Config>::Helper::tournament(0),
+			winners: vec![players[0].clone()],
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(admin.clone()).into())? }
+	verify {
+		assert_last_event::<T>(Event::ResultsSubmitted {
+			tournament: <T as pallet::Config>::Helper::tournament(0),
+			winners: vec![players[0].clone()],
+		}.into() );
+	}
+
+	cancel_tournament {
+		let p in 1 .. <T as pallet::Config>::MaxPlayers::get();
+
+		let (_, admin) = do_create_game::<T>();
+		do_create_tournament::<T>(
+			&admin,
+			EntryFee::Currency(<T as pallet::Config>::Currency::minimum_balance()),
+		);
+		do_register::<T>(p);
+
+		let call = Call::<T>::cancel_tournament {
+			tournament: <T as pallet::Config>::Helper::tournament(0),
+		};
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(admin.clone()).into())? }
+	verify {
+		assert_last_event::<T>(Event::TournamentCancelled {
+			tournament: <T as pallet::Config>::Helper::tournament(0),
+		}.into() );
+	}
+}
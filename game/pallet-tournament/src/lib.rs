@@ -0,0 +1,278 @@
+// This file is part of Gafi Network.
+
+// Copyright (C) 2021-2022 Grindy Technologies.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Tournament Module
+//!
+//! Tournaments with a prize pool, run by the admins of a game from pallet-game.
+
+// Ensure we're `no_std` when compiling for Wasm.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+mod tournament;
+mod types;
+pub use types::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+mod weights;
+pub use weights::*;
+
+use frame_support::{
+	pallet_prelude::*,
+	traits::{Currency, Incrementable, ReservableCurrency},
+	PalletId,
+};
+use frame_system::{pallet_prelude::BlockNumberFor, Config as SystemConfig};
+use sp_runtime::traits::StaticLookup;
+use sp_std::vec::Vec;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_system::pallet_prelude::{OriginFor, *};
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[cfg(feature = "runtime-benchmarks")]
+	pub trait BenchmarkHelper<TournamentId> {
+		fn tournament(i: u16) -> TournamentId;
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	impl<TournamentId: From<u16>> BenchmarkHelper<TournamentId> for () {
+		fn tournament(i: u16) -> TournamentId {
+			i.into()
+		}
+	}
+
+	/// Configure the pallet by specifying the parameters and types on which it depends.
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_game::Config {
+		/// Because this pallet emits events, it depends on the runtime's definition of an event.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// The currency mechanism, used for paying for reserves and entry fees.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The pallet id, used for deriving the account holding the item entry fees.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// The type used to identify a unique tournament
+		type TournamentId: Member + Parameter + MaxEncodedLen + Copy + Incrementable;
+
+		/// The basic amount of funds that must be reserved for a tournament.
+		#[pallet::constant]
+		type TournamentDeposit: Get<BalanceOf<Self>>;
+
+		/// Maximum number of players registered to a tournament.
+		#[pallet::constant]
+		type MaxPlayers: Get<u32>;
+
+		/// Maximum number of ranks rewarded by a tournament.
+		#[pallet::constant]
+		type MaxPrizes: Get<u32>;
+
+		#[cfg(feature = "runtime-benchmarks")]
+		/// A set of helper functions for benchmarking.
+		type Helper: BenchmarkHelper<Self::TournamentId>;
+	}
+
+	/// Storing next tournament id
+	#[pallet::storage]
+	pub(super) type NextTournamentId<T: Config> = StorageValue<_, T::TournamentId, OptionQuery>;
+
+	/// Storing the tournaments waiting for their results
+	#[pallet::storage]
+	pub(super) type TournamentOf<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::TournamentId, TournamentDetailsFor<T>, OptionQuery>;
+
+	/// Storing the players registered to a tournament
+	#[pallet::storage]
+	pub(super) type PlayersOf<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::TournamentId,
+		BoundedVec<T::AccountId, T::MaxPlayers>,
+		ValueQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		TournamentCreated {
+			tournament: T::TournamentId,
+			who: T::AccountId,
+			game: T::GameId,
+			entry_fee: EntryFeeFor<T>,
+			start_block: BlockNumberFor<T>,
+			end_block: BlockNumberFor<T>,
+			prizes: Vec<u16>,
+			submitter: T::AccountId,
+		},
+		Registered {
+			tournament: T::TournamentId,
+			who: T::AccountId,
+		},
+		ResultsSubmitted {
+			tournament: T::TournamentId,
+			winners: Vec<T::AccountId>,
+		},
+		TournamentCancelled {
+			tournament: T::TournamentId,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		NoPermission,
+		UnknownTournament,
+		IncorrectCollection,
+		InvalidEntryFee,
+		InvalidRegistration,
+		InvalidPrizes,
+		ExceedMaxPrizes,
+		RegistrationClosed,
+		RegistrationNotEnded,
+		AlreadyRegistered,
+		TournamentFull,
+		InvalidResults,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a tournament of `game`.
+		///
+		/// Origin must be Signed and signer should be the Owner or Admin of `game`.
+		///
+		/// - `game`: The game running the tournament.
+		/// - `entry_fee`: The currency or item paid by each player to register.
+		/// - `start_block`: The first block of the registration.
+		/// - `end_block`: The registration is closed from `end_block`, the results can be submitted
+		///   afterwards.
+		/// - `prizes`: The share of the prize pool given to each rank in basis points, adding up to
+		///   10000.
+		/// - `submitter`: The account allowed to submit the results.
+		///
+		/// Emits `TournamentCreated`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create_tournament())]
+		pub fn create_tournament(
+			origin: OriginFor<T>,
+			game: T::GameId,
+			entry_fee: EntryFeeFor<T>,
+			start_block: BlockNumberFor<T>,
+			end_block: BlockNumberFor<T>,
+			prizes: Vec<u16>,
+			submitter: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let submitter = T::Lookup::lookup(submitter)?;
+			Self::do_create_tournament(
+				&sender,
+				&game,
+				entry_fee,
+				start_block,
+				end_block,
+				prizes,
+				&submitter,
+			)?;
+			Ok(())
+		}
+
+		/// Register to a tournament, paying its entry fee.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `tournament`: The tournament id.
+		///
+		/// Emits `Registered`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::register())]
+		pub fn register(origin: OriginFor<T>, tournament: T::TournamentId) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::do_register(&tournament, &sender)?;
+			Ok(())
+		}
+
+		/// Submit the ranked winners of a tournament, sharing the prize pool by the prize table.
+		///
+		/// Origin must be Signed and signer should be the submitter of the tournament.
+		///
+		/// The prizes of the ranks without a winner are given to the first winner.
+		///
+		/// - `tournament`: The tournament id.
+		/// - `winners`: The registered players, from the first rank.
+		///
+		/// Emits `ResultsSubmitted`.
+		///
+		/// Weight: `O(p)` where `p = MaxPlayers`
+		#[pallet::call_index(2)]
+		#[pallet::weight(
+			<T as pallet::Config>::WeightInfo::submit_results(T::MaxPlayers::get())
+		)]
+		pub fn submit_results(
+			origin: OriginFor<T>,
+			tournament: T::TournamentId,
+			winners: Vec<T::AccountId>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::do_submit_results(&tournament, &sender, winners)?;
+			Ok(())
+		}
+
+		/// Cancel a tournament before its results, refunding the entry fees.
+		///
+		/// Origin must be Signed and signer should be the creator of the tournament or an Owner
+		/// or Admin of its game.
+		///
+		/// - `tournament`: The tournament id.
+		///
+		/// Emits `TournamentCancelled`.
+		///
+		/// Weight: `O(p)` where `p = MaxPlayers`
+		#[pallet::call_index(3)]
+		#[pallet::weight(
+			<T as pallet::Config>::WeightInfo::cancel_tournament(T::MaxPlayers::get())
+		)]
+		pub fn cancel_tournament(
+			origin: OriginFor<T>,
+			tournament: T::TournamentId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::do_cancel_tournament(&tournament, &sender)?;
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,267 @@
+use crate::{self as pallet_tournament};
+use frame_support::{
+	parameter_types,
+	traits::{AsEnsureOriginWithArg, ConstU64, OnFinalize, OnInitialize},
+	PalletId,
+};
+use frame_system as system;
+use pallet_balances::AccountData;
+use pallet_nfts::PalletFeatures;
+use sp_core::{sr25519::Signature, ConstU128, ConstU32, H256};
+use sp_runtime::traits::{BlakeTwo256, IdentityLookup, Verify};
+use system::mocking;
+
+type Block = mocking::MockBlock<Test>;
+type AccountPublic = <Signature as Verify>::Signer;
+use sp_runtime::BuildStorage;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		PalletGame: pallet_game,
+		Tournament: pallet_tournament,
+		Balances: pallet_balances,
+		Nfts: pallet_nfts,
+		Assets: pallet_assets,
+	}
+);
+
+impl system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = sp_core::sr25519::Public;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = AccountData<u128>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+pub const EXISTENTIAL_DEPOSIT: u128 = 1000;
+
+parameter_types! {
+	pub ExistentialDeposit: u128 = EXISTENTIAL_DEPOSIT;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u128;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type RuntimeHoldReason = ();
+	type MaxHolds = ();
+}
+
+pub const ITEM_DEPOSIT_VAL: u128 = 3_000_000_000;
+pub const METADATA_DEPOSIT_VAL: u128 = 3_000_000_000;
+pub const BYTE_DEPOSIT_VAL: u128 = 3_000_000;
+
+parameter_types! {
+	pub storage Features: PalletFeatures = PalletFeatures::all_enabled();
+	pub ItemDeposit: u128 = ITEM_DEPOSIT_VAL;
+	pub MetadataDepositBase: u128 = METADATA_DEPOSIT_VAL;
+	pub DepositPerByte: u128 = BYTE_DEPOSIT_VAL;
+}
+
+impl pallet_nfts::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type CollectionId = u32;
+	type ItemId = u32;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<Self::AccountId>>;
+	type ForceOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type Locker = ();
+	type CollectionDeposit = ConstU128<2>;
+	type ItemDeposit = ItemDeposit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type AttributeDepositBase = ConstU128<1>;
+	type DepositPerByte = DepositPerByte;
+	type StringLimit = ConstU32<50>;
+	type KeyLimit = ConstU32<50>;
+	type ValueLimit = ConstU32<50>;
+	type ApprovalsLimit = ConstU32<10>;
+	type ItemAttributesApprovalsLimit = ConstU32<2>;
+	type MaxTips = ConstU32<10>;
+	type MaxDeadlineDuration = ConstU64<10000>;
+	type MaxAttributesPerCall = ConstU32<2>;
+	type Features = Features;
+	/// Off-chain = signature On-chain - therefore no conversion needed.
+	/// It needs to be From<MultiSignature> for benchmarking.
+	type OffchainSignature = Signature;
+	/// Using `AccountPublic` here makes it trivial to convert to `AccountId` via `into_account()`.
+	type OffchainPublic = AccountPublic;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type Helper = ();
+}
+
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = u128;
+	type AssetId = u32;
+	type AssetIdParameter = u32;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<Self::AccountId>>;
+	type ForceOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type AssetDeposit = ConstU128<1>;
+	type AssetAccountDeposit = ConstU128<1>;
+	type MetadataDepositBase = ConstU128<1>;
+	type MetadataDepositPerByte = ConstU128<1>;
+	type ApprovalDeposit = ConstU128<1>;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = ();
+	type RemoveItemsLimit = ConstU32<10>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+pub const GAME_DEPOSIT_VAL: u128 = 5_000_000_000;
+pub const UPGRADE_DEPOSIT_VAL: u128 = 3_000_000_000;
+pub const BUNDLE_DEPOSIT_VAL: u128 = 3_000_000_000;
+pub const MINING_DEPOSIT_VAL: u128 = 5_000_000_000;
+pub const MAX_GAME_COLLECTION_VAL: u32 = 10;
+pub const MAX_ITEM_MINT_VAL: u32 = 10;
+pub const MAX_GAME_SHARE_VAL: u32 = 10;
+pub const MAX_BUNDLE_VAL: u32 = 5;
+pub const MAX_LOOT: u32 = 10;
+pub const MAX_MIN_REQUEST_VAL: u32 = 10;
+pub const MIN_INTERVAL_VAL: u32 = 1;
+pub const REMOVE_ROLES_LIMIT_VAL: u32 = 10;
+pub const MAX_CANDLE_BID_VAL: u32 = 10;
+pub const MAX_SEALED_BID_VAL: u32 = 10;
+pub const MAX_ROYALTY_VAL: u16 = 1000;
+pub const MAX_TRADE_FEE_VAL: u16 = 1000;
+pub const MAX_BOOK_ORDERS_VAL: u32 = 10;
+pub const MAX_EXPIRING_TRADES_VAL: u32 = 10;
+pub const MAX_MATCH_PLAYERS_VAL: u32 = 4;
+
+parameter_types! {
+	pub GameDeposit: u128 = GAME_DEPOSIT_VAL;
+	pub MaxGameCollection: u32 = MAX_GAME_COLLECTION_VAL;
+	pub MiningPoolDeposit: u128 = MINING_DEPOSIT_VAL;
+	pub UpgradeDeposit: u128 = UPGRADE_DEPOSIT_VAL;
+	pub BundleDeposit: u128 = BUNDLE_DEPOSIT_VAL;
+
+	pub MaxMintItem: u32 = MAX_ITEM_MINT_VAL;
+	pub MaxBundle: u32 = MAX_BUNDLE_VAL;
+	pub MaxGameShare: u32 = MAX_GAME_SHARE_VAL;
+
+	pub PalletGameId: PalletId =  PalletId(*b"gamegame");
+	pub MaxItem: u32 = 10;
+	pub MaxLoot: u32 = MAX_LOOT;
+	pub MaxMintRequest: u32 = MAX_MIN_REQUEST_VAL;
+	pub MintInterval: u32 = MIN_INTERVAL_VAL;
+	pub RemoveRolesLimit: u32 = REMOVE_ROLES_LIMIT_VAL;
+	pub MaxCandleBid: u32 = MAX_CANDLE_BID_VAL;
+	pub MaxSealedBid: u32 = MAX_SEALED_BID_VAL;
+	pub MaxRoyalty: u16 = MAX_ROYALTY_VAL;
+	pub MaxTradeFee: u16 = MAX_TRADE_FEE_VAL;
+	pub MaxBookOrders: u32 = MAX_BOOK_ORDERS_VAL;
+	pub MaxExpiringTrades: u32 = MAX_EXPIRING_TRADES_VAL;
+	pub MaxMatchPlayers: u32 = MAX_MATCH_PLAYERS_VAL;
+}
+
+impl pallet_game::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type NftsWeightInfo = ();
+	type Currency = Balances;
+	type AssetId = u32;
+	type Assets = Assets;
+	type PalletId = PalletGameId;
+	type Nfts = Nfts;
+	type GameId = u32;
+	type TradeId = u32;
+	type PoolId = u32;
+	type RecipeId = u32;
+	type MatchId = u32;
+	type MiningPoolDeposit = MiningPoolDeposit;
+	type GameDeposit = GameDeposit;
+	type MaxGameCollection = MaxGameCollection;
+	type MaxGameShare = MaxGameShare;
+	type MaxItem = MaxItem;
+	type MaxMintItem = MaxMintItem;
+	type UpgradeDeposit = UpgradeDeposit;
+	type BundleDeposit = BundleDeposit;
+	type MaxBundle = MaxBundle;
+	type MaxLoot = MaxLoot;
+	type GameRandomness = ();
+	type MaxMintRequest = MaxMintRequest;
+	type MintInterval = MintInterval;
+	type RemoveRolesLimit = RemoveRolesLimit;
+	type MaxCandleBid = MaxCandleBid;
+	type MaxSealedBid = MaxSealedBid;
+	type MaxRoyalty = MaxRoyalty;
+	type MaxTradeFee = MaxTradeFee;
+	type MaxBookOrders = MaxBookOrders;
+	type MaxExpiringTrades = MaxExpiringTrades;
+	type MaxMatchPlayers = MaxMatchPlayers;
+	#[cfg(feature = "runtime-benchmarks")]
+	type Helper = ();
+}
+
+pub const TOURNAMENT_DEPOSIT_VAL: u128 = 3_000_000_000;
+pub const MAX_PLAYERS_VAL: u32 = 10;
+pub const MAX_PRIZES_VAL: u32 = 3;
+
+parameter_types! {
+	pub PalletTournamentId: PalletId = PalletId(*b"gametour");
+	pub TournamentDeposit: u128 = TOURNAMENT_DEPOSIT_VAL;
+	pub MaxPlayers: u32 = MAX_PLAYERS_VAL;
+	pub MaxPrizes: u32 = MAX_PRIZES_VAL;
+}
+
+impl pallet_tournament::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type Currency = Balances;
+	type PalletId = PalletTournamentId;
+	type TournamentId = u32;
+	type TournamentDeposit = TournamentDeposit;
+	type MaxPlayers = MaxPlayers;
+	type MaxPrizes = MaxPrizes;
+	#[cfg(feature = "runtime-benchmarks")]
+	type Helper = ();
+}
+
+pub fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		if System::block_number() > 1 {
+			System::on_finalize(System::block_number());
+		}
+		System::set_block_number(System::block_number() + 1);
+		System::on_initialize(System::block_number());
+	}
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into()
+}
@@ -0,0 +1,350 @@
+use crate::{mock::*, types::*, Error, *};
+use frame_support::{assert_err, assert_ok, traits::Currency};
+use gafi_support::{
+	common::{unit, NativeToken::GAKI},
+	game::{Amount, Package},
+};
+use sp_core::sr25519;
+use sp_keystore::{testing::MemoryKeystore, KeystoreExt};
+
+fn make_deposit(account: &sr25519::Public, balance: u128) {
+	let _ = pallet_balances::Pallet::<Test>::deposit_creating(account, balance);
+}
+
+fn new_account(account: u32, balance: u128) -> sr25519::Public {
+	let keystore = KeystoreExt::new(MemoryKeystore::new());
+	let acc: sr25519::Public = keystore
+		.sr25519_generate_new(sp_runtime::KeyTypeId::from(account), None)
+		.unwrap();
+	make_deposit(&acc, balance);
+	return acc
+}
+
+/// Create game 0 with the item 0 of collection 0, given to `players`.
+fn do_create_game(players: &[sr25519::Public], amount: Amount) -> sr25519::Public {
+	let owner = new_account(0, 10_000 * unit(GAKI));
+	let admin = new_account(1, 10_000 * unit(GAKI));
+	assert_ok!(PalletGame::create_game(
+		RuntimeOrigin::signed(owner.clone()),
+		admin.clone()
+	));
+	assert_ok!(PalletGame::create_game_collection(
+		RuntimeOrigin::signed(admin.clone()),
+		0,
+	));
+	assert_ok!(PalletGame::create_item(
+		RuntimeOrigin::signed(admin.clone()),
+		0,
+		0,
+		Some(1000)
+	));
+	for player in players {
+		assert_ok!(PalletGame::transfer(
+			RuntimeOrigin::signed(owner.clone()),
+			0,
+			0,
+			player.clone(),
+			amount
+		));
+	}
+	admin
+}
+
+fn do_create_tournament(
+	admin: &sr25519::Public,
+	entry_fee: EntryFeeFor<Test>,
+	submitter: &sr25519::Public,
+) {
+	assert_ok!(Tournament::create_tournament(
+		RuntimeOrigin::signed(admin.clone()),
+		0,
+		entry_fee,
+		2,
+		10,
+		vec![7_000, 3_000],
+		submitter.clone(),
+	));
+}
+
+fn do_register(players: &[sr25519::Public]) {
+	for player in players {
+		assert_ok!(Tournament::register(
+			RuntimeOrigin::signed(player.clone()),
+			0
+		));
+	}
+}
+
+#[test]
+pub fn create_tournament_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let admin = do_create_game(&[], 0);
+		let submitter = new_account(2, 1000 * unit(GAKI));
+		let before_balance = Balances::free_balance(&admin);
+
+		do_create_tournament(&admin, EntryFee::Currency(unit(GAKI)), &submitter);
+
+		let details = TournamentOf::<Test>::get(0).unwrap();
+		assert_eq!(details.owner, admin);
+		assert_eq!(details.game, 0);
+		assert_eq!(details.prizes.into_inner(), vec![7_000, 3_000]);
+		assert_eq!(details.submitter, submitter);
+		assert_eq!(
+			Balances::free_balance(&admin),
+			before_balance - TOURNAMENT_DEPOSIT_VAL
+		);
+		System::assert_last_event(RuntimeEvent::Tournament(crate::Event::TournamentCreated {
+			tournament: 0,
+			who: admin,
+			game: 0,
+			entry_fee: EntryFee::Currency(unit(GAKI)),
+			start_block: 2,
+			end_block: 10,
+			prizes: vec![7_000, 3_000],
+			submitter,
+		}));
+	})
+}
+
+#[test]
+pub fn create_tournament_should_fails() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let admin = do_create_game(&[], 0);
+		let player = new_account(2, 1000 * unit(GAKI));
+		let fee = EntryFee::Currency(unit(GAKI));
+
+		assert_err!(
+			Tournament::create_tournament(
+				RuntimeOrigin::signed(player.clone()),
+				0,
+				fee.clone(),
+				2,
+				10,
+				vec![10_000],
+				player.clone(),
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_err!(
+			Tournament::create_tournament(
+				RuntimeOrigin::signed(admin.clone()),
+				0,
+				EntryFee::Item(Package::new(1, 0, 1)),
+				2,
+				10,
+				vec![10_000],
+				player.clone(),
+			),
+			Error::<Test>::IncorrectCollection
+		);
+		assert_err!(
+			Tournament::create_tournament(
+				RuntimeOrigin::signed(admin.clone()),
+				0,
+				fee.clone(),
+				10,
+				10,
+				vec![10_000],
+				player.clone(),
+			),
+			Error::<Test>::InvalidRegistration
+		);
+		assert_err!(
+			Tournament::create_tournament(
+				RuntimeOrigin::signed(admin.clone()),
+				0,
+				fee.clone(),
+				2,
+				10,
+				vec![7_000, 2_000],
+				player.clone(),
+			),
+			Error::<Test>::InvalidPrizes
+		);
+		assert_err!(
+			Tournament::create_tournament(
+				RuntimeOrigin::signed(admin.clone()),
+				0,
+				fee,
+				2,
+				10,
+				vec![2_500; 4],
+				player.clone(),
+			),
+			Error::<Test>::ExceedMaxPrizes
+		);
+	})
+}
+
+#[test]
+pub fn register_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let player = new_account(2, 1000 * unit(GAKI));
+		let admin = do_create_game(&[player.clone()], 10);
+		do_create_tournament(&admin, EntryFee::Item(Package::new(0, 0, 2)), &admin);
+
+		assert_err!(
+			Tournament::register(RuntimeOrigin::signed(player.clone()), 0),
+			Error::<Test>::RegistrationClosed
+		);
+
+		run_to_block(2);
+		assert_ok!(Tournament::register(
+			RuntimeOrigin::signed(player.clone()),
+			0
+		));
+		assert_eq!(PlayersOf::<Test>::get(0).into_inner(), vec![player.clone()]);
+		assert_eq!(PalletGame::inventory_of(&player)[0].free, 8);
+		assert_eq!(
+			PalletGame::inventory_of(&Tournament::escrow_account())[0].free,
+			2
+		);
+		System::assert_last_event(RuntimeEvent::Tournament(crate::Event::Registered {
+			tournament: 0,
+			who: player.clone(),
+		}));
+
+		assert_err!(
+			Tournament::register(RuntimeOrigin::signed(player.clone()), 0),
+			Error::<Test>::AlreadyRegistered
+		);
+
+		run_to_block(10);
+		assert_err!(
+			Tournament::register(RuntimeOrigin::signed(admin.clone()), 0),
+			Error::<Test>::RegistrationClosed
+		);
+	})
+}
+
+#[test]
+pub fn submit_results_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(2);
+		let players = [
+			new_account(2, 1000 * unit(GAKI)),
+			new_account(3, 1000 * unit(GAKI)),
+			new_account(4, 1000 * unit(GAKI)),
+		];
+		let admin = do_create_game(&[], 0);
+		let submitter = new_account(5, 1000 * unit(GAKI));
+		let before_balance = Balances::free_balance(&admin);
+		do_create_tournament(&admin, EntryFee::Currency(10 * unit(GAKI)), &submitter);
+		do_register(&players);
+
+		let winners = vec![players[1].clone(), players[0].clone()];
+		assert_err!(
+			Tournament::submit_results(
+				RuntimeOrigin::signed(submitter.clone()),
+				0,
+				winners.clone()
+			),
+			Error::<Test>::RegistrationNotEnded
+		);
+
+		run_to_block(10);
+		assert_err!(
+			Tournament::submit_results(RuntimeOrigin::signed(admin.clone()), 0, winners.clone()),
+			Error::<Test>::NoPermission
+		);
+		assert_err!(
+			Tournament::submit_results(
+				RuntimeOrigin::signed(submitter.clone()),
+				0,
+				vec![players[1].clone(), players[1].clone()]
+			),
+			Error::<Test>::InvalidResults
+		);
+		assert_ok!(Tournament::submit_results(
+			RuntimeOrigin::signed(submitter.clone()),
+			0,
+			winners.clone()
+		));
+
+		// the prize pool of 30 units is shared by 70% and 30%
+		assert_eq!(
+			Balances::free_balance(&players[1]),
+			1000 * unit(GAKI) + 11 * unit(GAKI)
+		);
+		assert_eq!(
+			Balances::free_balance(&players[0]),
+			1000 * unit(GAKI) - unit(GAKI)
+		);
+		assert_eq!(
+			Balances::free_balance(&players[2]),
+			1000 * unit(GAKI) - 10 * unit(GAKI)
+		);
+		assert_eq!(Balances::reserved_balance(&players[2]), 0);
+
+		assert_eq!(TournamentOf::<Test>::get(0), None);
+		assert_eq!(Balances::free_balance(&admin), before_balance);
+		System::assert_last_event(RuntimeEvent::Tournament(crate::Event::ResultsSubmitted {
+			tournament: 0,
+			winners,
+		}));
+	})
+}
+
+#[test]
+pub fn submit_item_results_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(2);
+		let players = [
+			new_account(2, 1000 * unit(GAKI)),
+			new_account(3, 1000 * unit(GAKI)),
+			new_account(4, 1000 * unit(GAKI)),
+		];
+		let admin = do_create_game(&players, 10);
+		do_create_tournament(&admin, EntryFee::Item(Package::new(0, 0, 2)), &admin);
+		do_register(&players);
+
+		run_to_block(10);
+		assert_ok!(Tournament::submit_results(
+			RuntimeOrigin::signed(admin.clone()),
+			0,
+			vec![players[2].clone(), players[0].clone()]
+		));
+
+		// 30% of 6 items is rounded down, the rest goes to the first rank
+		assert_eq!(PalletGame::inventory_of(&players[2])[0].free, 13);
+		assert_eq!(PalletGame::inventory_of(&players[0])[0].free, 9);
+		assert_eq!(PalletGame::inventory_of(&players[1])[0].free, 8);
+		assert!(PalletGame::inventory_of(&Tournament::escrow_account()).is_empty());
+	})
+}
+
+#[test]
+pub fn cancel_tournament_should_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(2);
+		let players = [
+			new_account(2, 1000 * unit(GAKI)),
+			new_account(3, 1000 * unit(GAKI)),
+		];
+		let admin = do_create_game(&players, 10);
+		let submitter = new_account(5, 1000 * unit(GAKI));
+		do_create_tournament(&admin, EntryFee::Item(Package::new(0, 0, 2)), &submitter);
+		do_register(&players);
+
+		assert_err!(
+			Tournament::cancel_tournament(RuntimeOrigin::signed(submitter.clone()), 0),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Tournament::cancel_tournament(
+			RuntimeOrigin::signed(admin.clone()),
+			0
+		));
+
+		for player in players.iter() {
+			assert_eq!(PalletGame::inventory_of(player)[0].free, 10);
+		}
+		assert_eq!(TournamentOf::<Test>::get(0), None);
+		assert_eq!(PlayersOf::<Test>::get(0).len(), 0);
+		System::assert_last_event(RuntimeEvent::Tournament(
+			crate::Event::TournamentCancelled { tournament: 0 },
+		));
+	})
+}
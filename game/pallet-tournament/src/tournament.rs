@@ -0,0 +1,262 @@
+/// Tournament module holds the entry fees of the players until the prizes are paid
+use crate::*;
+use frame_support::traits::BalanceStatus;
+use gafi_support::game::{Amount, TransferItem};
+use sp_runtime::{
+	traits::{AccountIdConversion, Zero},
+	Saturating,
+};
+
+/// Denominator of the prize shares.
+const BASIS_POINTS: u16 = 10_000;
+
+impl<T: Config> Pallet<T> {
+	/// Generate next id for new tournament
+	pub(crate) fn get_tournament_id() -> T::TournamentId {
+		let id = NextTournamentId::<T>::get().unwrap_or(T::TournamentId::initial_value());
+		NextTournamentId::<T>::set(Some(id.increment()));
+		id
+	}
+
+	/// The account holding the item entry fees.
+	pub(crate) fn escrow_account() -> T::AccountId {
+		<T as pallet::Config>::PalletId::get().into_account_truncating()
+	}
+
+	pub(crate) fn do_create_tournament(
+		who: &T::AccountId,
+		game: &T::GameId,
+		entry_fee: EntryFeeFor<T>,
+		start_block: BlockNumberFor<T>,
+		end_block: BlockNumberFor<T>,
+		prizes: Vec<u16>,
+		submitter: &T::AccountId,
+	) -> DispatchResult {
+		ensure!(
+			pallet_game::Pallet::<T>::is_game_admin(game, who),
+			Error::<T>::NoPermission
+		);
+
+		if let EntryFee::Item(package) = &entry_fee {
+			ensure!(package.amount > 0, Error::<T>::InvalidEntryFee);
+			ensure!(
+				pallet_game::Pallet::<T>::is_game_collection(game, &package.collection),
+				Error::<T>::IncorrectCollection
+			);
+		}
+
+		ensure!(
+			start_block < end_block && <frame_system::Pallet<T>>::block_number() < end_block,
+			Error::<T>::InvalidRegistration
+		);
+
+		let total_shares = prizes
+			.iter()
+			.try_fold(0u16, |total, share| match *share {
+				0 => None,
+				share => total.checked_add(share),
+			})
+			.ok_or(Error::<T>::InvalidPrizes)?;
+		ensure!(total_shares == BASIS_POINTS, Error::<T>::InvalidPrizes);
+		let bounded_prizes: BoundedVec<u16, T::MaxPrizes> =
+			BoundedVec::try_from(prizes.clone()).map_err(|_| Error::<T>::ExceedMaxPrizes)?;
+
+		let deposit = <T as pallet::Config>::TournamentDeposit::get();
+		<T as pallet::Config>::Currency::reserve(who, deposit)?;
+
+		let tournament = Self::get_tournament_id();
+		TournamentOf::<T>::insert(
+			tournament,
+			TournamentDetails {
+				owner: who.clone(),
+				owner_deposit: deposit,
+				game: *game,
+				entry_fee: entry_fee.clone(),
+				start_block,
+				end_block,
+				prizes: bounded_prizes,
+				submitter: submitter.clone(),
+			},
+		);
+
+		Self::deposit_event(Event::<T>::TournamentCreated {
+			tournament,
+			who: who.clone(),
+			game: *game,
+			entry_fee,
+			start_block,
+			end_block,
+			prizes,
+			submitter: submitter.clone(),
+		});
+
+		Ok(())
+	}
+
+	pub(crate) fn do_register(tournament: &T::TournamentId, who: &T::AccountId) -> DispatchResult {
+		let details = TournamentOf::<T>::get(tournament).ok_or(Error::<T>::UnknownTournament)?;
+
+		let block_number = <frame_system::Pallet<T>>::block_number();
+		ensure!(
+			details.start_block <= block_number && block_number < details.end_block,
+			Error::<T>::RegistrationClosed
+		);
+
+		PlayersOf::<T>::try_mutate(tournament, |players| -> DispatchResult {
+			ensure!(!players.contains(who), Error::<T>::AlreadyRegistered);
+			players.try_push(who.clone()).map_err(|_| Error::<T>::TournamentFull)?;
+			Ok(())
+		})?;
+
+		// pay entry fee
+		match details.entry_fee {
+			EntryFee::Currency(fee) => <T as pallet::Config>::Currency::reserve(who, fee)?,
+			EntryFee::Item(package) => pallet_game::Pallet::<T>::do_transfer_item(
+				who,
+				&package.collection,
+				&package.item,
+				&Self::escrow_account(),
+				package.amount,
+			)?,
+		}
+
+		Self::deposit_event(Event::<T>::Registered {
+			tournament: *tournament,
+			who: who.clone(),
+		});
+
+		Ok(())
+	}
+
+	pub(crate) fn do_submit_results(
+		tournament: &T::TournamentId,
+		who: &T::AccountId,
+		winners: Vec<T::AccountId>,
+	) -> DispatchResult {
+		let details = TournamentOf::<T>::get(tournament).ok_or(Error::<T>::UnknownTournament)?;
+		ensure!(details.submitter == *who, Error::<T>::NoPermission);
+		ensure!(
+			<frame_system::Pallet<T>>::block_number() >= details.end_block,
+			Error::<T>::RegistrationNotEnded
+		);
+
+		// validate results
+		let players = PlayersOf::<T>::get(tournament);
+		ensure!(
+			!winners.is_empty() && winners.len() <= details.prizes.len(),
+			Error::<T>::InvalidResults
+		);
+		for (index, winner) in winners.iter().enumerate() {
+			ensure!(players.contains(winner), Error::<T>::InvalidResults);
+			ensure!(
+				!winners[..index].contains(winner),
+				Error::<T>::InvalidResults
+			);
+		}
+
+		// the ranks without a winner go to the first winner
+		let mut shares = details.prizes[..winners.len()].to_vec();
+		let unclaimed = details.prizes[winners.len()..]
+			.iter()
+			.fold(0u16, |total, share| total.saturating_add(*share));
+		shares[0] = shares[0].saturating_add(unclaimed);
+
+		match details.entry_fee {
+			EntryFee::Currency(fee) =>
+				if !fee.is_zero() {
+					for player in players.iter() {
+						let mut remaining = fee;
+						for (winner, share) in winners.iter().zip(shares.iter()).skip(1) {
+							let prize = fee.saturating_mul((*share).into()) / BASIS_POINTS.into();
+							<T as pallet::Config>::Currency::repatriate_reserved(
+								player,
+								winner,
+								prize,
+								BalanceStatus::Free,
+							)?;
+							remaining = remaining.saturating_sub(prize);
+						}
+						<T as pallet::Config>::Currency::repatriate_reserved(
+							player,
+							&winners[0],
+							remaining,
+							BalanceStatus::Free,
+						)?;
+					}
+				},
+			EntryFee::Item(package) => {
+				let pool = package.amount.saturating_mul(players.len() as Amount);
+				let mut remaining = pool;
+				for (winner, share) in winners.iter().zip(shares.iter()).skip(1) {
+					let prize =
+						(u64::from(pool) * u64::from(*share) / u64::from(BASIS_POINTS)) as Amount;
+					if prize > 0 {
+						Self::pay_items(&package.collection, &package.item, winner, prize)?;
+					}
+					remaining = remaining.saturating_sub(prize);
+				}
+				if remaining > 0 {
+					Self::pay_items(&package.collection, &package.item, &winners[0], remaining)?;
+				}
+			},
+		}
+
+		<T as pallet::Config>::Currency::unreserve(&details.owner, details.owner_deposit);
+		TournamentOf::<T>::remove(tournament);
+		PlayersOf::<T>::remove(tournament);
+
+		Self::deposit_event(Event::<T>::ResultsSubmitted {
+			tournament: *tournament,
+			winners,
+		});
+
+		Ok(())
+	}
+
+	pub(crate) fn do_cancel_tournament(
+		tournament: &T::TournamentId,
+		who: &T::AccountId,
+	) -> DispatchResult {
+		let details = TournamentOf::<T>::get(tournament).ok_or(Error::<T>::UnknownTournament)?;
+		ensure!(
+			details.owner == *who || pallet_game::Pallet::<T>::is_game_admin(&details.game, who),
+			Error::<T>::NoPermission
+		);
+
+		// refund entry fees
+		for player in PlayersOf::<T>::take(tournament).iter() {
+			match &details.entry_fee {
+				EntryFee::Currency(fee) => {
+					<T as pallet::Config>::Currency::unreserve(player, *fee);
+				},
+				EntryFee::Item(package) =>
+					Self::pay_items(&package.collection, &package.item, player, package.amount)?,
+			}
+		}
+
+		<T as pallet::Config>::Currency::unreserve(&details.owner, details.owner_deposit);
+		TournamentOf::<T>::remove(tournament);
+
+		Self::deposit_event(Event::<T>::TournamentCancelled {
+			tournament: *tournament,
+		});
+
+		Ok(())
+	}
+
+	/// Transfer `amount` of `item` in `collection` held by the escrow account to `dest`.
+	fn pay_items(
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+		dest: &T::AccountId,
+		amount: Amount,
+	) -> DispatchResult {
+		pallet_game::Pallet::<T>::do_transfer_item(
+			&Self::escrow_account(),
+			collection,
+			item,
+			dest,
+			amount,
+		)
+	}
+}
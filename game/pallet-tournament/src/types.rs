@@ -0,0 +1,59 @@
+use crate::*;
+use codec::{Decode, Encode};
+use frame_support::{
+	pallet_prelude::{BoundedVec, MaxEncodedLen},
+	RuntimeDebug,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use gafi_support::game::Package;
+
+use scale_info::TypeInfo;
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as SystemConfig>::AccountId>>::Balance;
+
+pub type AccountIdLookupOf<T> = <<T as SystemConfig>::Lookup as StaticLookup>::Source;
+
+pub type EntryFeeFor<T> = EntryFee<
+	BalanceOf<T>,
+	<T as pallet_nfts::Config>::CollectionId,
+	<T as pallet_nfts::Config>::ItemId,
+>;
+
+pub type TournamentDetailsFor<T> = TournamentDetails<
+	<T as SystemConfig>::AccountId,
+	BalanceOf<T>,
+	<T as pallet_game::Config>::GameId,
+	EntryFeeFor<T>,
+	BlockNumberFor<T>,
+	BoundedVec<u16, <T as Config>::MaxPrizes>,
+>;
+
+/// The fee paid by each player to register to a tournament.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum EntryFee<Balance, CollectionId, ItemId> {
+	/// An amount of the native currency, reserved until the end of the tournament.
+	Currency(Balance),
+	/// An amount of a game item, held by the pallet account until the end of the tournament.
+	Item(Package<CollectionId, ItemId>),
+}
+
+/// Information about a tournament.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct TournamentDetails<AccountId, Balance, GameId, EntryFee, BlockNumber, Prizes> {
+	/// The game admin who created the tournament.
+	pub(super) owner: AccountId,
+	/// The balance reserved by the owner for the tournament.
+	pub(super) owner_deposit: Balance,
+	/// The game running the tournament.
+	pub(super) game: GameId,
+	/// The fee paid by each player.
+	pub(super) entry_fee: EntryFee,
+	/// The first block of the registration.
+	pub(super) start_block: BlockNumber,
+	/// The registration is closed from this block, then the results can be submitted.
+	pub(super) end_block: BlockNumber,
+	/// The share of the prize pool given to each rank, in basis points.
+	pub(super) prizes: Prizes,
+	/// The account allowed to submit the results.
+	pub(super) submitter: AccountId,
+}
@@ -0,0 +1,219 @@
+//! Autogenerated weights for pallet_tournament
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-08-09, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `admin`, CPU: `<UNKNOWN>`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/release/gafi-node
+// benchmark
+// pallet
+// --chain
+// dev
+// --wasm-execution
+// compiled
+// --pallet
+// pallet_tournament
+// --extrinsic
+// *
+// --steps
+// 50
+// --repeat
+// 20
+// --execution
+// wasm
+// --output
+// ./benchmarking/pallet-tournament/weights.rs
+// --template
+// .maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_tournament.
+pub trait WeightInfo {
+	fn create_tournament() -> Weight;
+	fn register() -> Weight;
+	fn submit_results(p: u32, ) -> Weight;
+	fn cancel_tournament(p: u32, ) -> Weight;
+}
+
+/// Weights for pallet_tournament using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: Game Game (r:1 w:0)
+	/// Proof: Game Game (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: Game CollectionsOf (r:1 w:0)
+	/// Proof: Game CollectionsOf (max_values: None, max_size: Some(82), added: 2557, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Tournament NextTournamentId (r:1 w:1)
+	/// Proof: Tournament NextTournamentId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Tournament TournamentOf (r:0 w:1)
+	/// Proof: Tournament TournamentOf (max_values: None, max_size: Some(150), added: 2625, mode: MaxEncodedLen)
+	fn create_tournament() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `437`
+		//  Estimated: `3601`
+		// Minimum execution time: 33_000_000 picoseconds.
+		Weight::from_parts(34_000_000, 3601)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Tournament TournamentOf (r:1 w:0)
+	/// Proof: Tournament TournamentOf (max_values: None, max_size: Some(150), added: 2625, mode: MaxEncodedLen)
+	/// Storage: Tournament PlayersOf (r:1 w:1)
+	/// Proof: Tournament PlayersOf (max_values: None, max_size: Some(3221), added: 5696, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:2 w:2)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game UseRightOf (r:1 w:0)
+	/// Proof: Game UseRightOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game SoulboundOf (r:1 w:0)
+	/// Proof: Game SoulboundOf (max_values: None, max_size: Some(41), added: 2516, mode: MaxEncodedLen)
+	fn register() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3592`
+		//  Estimated: `6686`
+		// Minimum execution time: 41_000_000 picoseconds.
+		Weight::from_parts(43_000_000, 6686)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Tournament TournamentOf (r:1 w:1)
+	/// Proof: Tournament TournamentOf (max_values: None, max_size: Some(150), added: 2625, mode: MaxEncodedLen)
+	/// Storage: Tournament PlayersOf (r:1 w:1)
+	/// Proof: Tournament PlayersOf (max_values: None, max_size: Some(3221), added: 5696, mode: MaxEncodedLen)
+	/// Storage: System Account (r:101 w:101)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `p` is `[1, 100]`.
+	fn submit_results(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `389 + p * (167 ±0)`
+		//  Estimated: `6686 + p * (2603 ±0)`
+		// Minimum execution time: 42_000_000 picoseconds.
+		Weight::from_parts(29_537_112, 6686)
+			// Standard Error: 19_826
+			.saturating_add(Weight::from_parts(16_104_375, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(p.into()))
+	}
+	/// Storage: Tournament TournamentOf (r:1 w:1)
+	/// Proof: Tournament TournamentOf (max_values: None, max_size: Some(150), added: 2625, mode: MaxEncodedLen)
+	/// Storage: Game Game (r:1 w:0)
+	/// Proof: Game Game (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: Tournament PlayersOf (r:1 w:1)
+	/// Proof: Tournament PlayersOf (max_values: None, max_size: Some(3221), added: 5696, mode: MaxEncodedLen)
+	/// Storage: System Account (r:101 w:101)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `p` is `[1, 100]`.
+	fn cancel_tournament(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `421 + p * (167 ±0)`
+		//  Estimated: `6686 + p * (2603 ±0)`
+		// Minimum execution time: 36_000_000 picoseconds.
+		Weight::from_parts(25_113_640, 6686)
+			// Standard Error: 15_203
+			.saturating_add(Weight::from_parts(11_382_905, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(p.into()))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: Game Game (r:1 w:0)
+	/// Proof: Game Game (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: Game CollectionsOf (r:1 w:0)
+	/// Proof: Game CollectionsOf (max_values: None, max_size: Some(82), added: 2557, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Tournament NextTournamentId (r:1 w:1)
+	/// Proof: Tournament NextTournamentId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Tournament TournamentOf (r:0 w:1)
+	/// Proof: Tournament TournamentOf (max_values: None, max_size: Some(150), added: 2625, mode: MaxEncodedLen)
+	fn create_tournament() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `437`
+		//  Estimated: `3601`
+		// Minimum execution time: 33_000_000 picoseconds.
+		Weight::from_parts(34_000_000, 3601)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Tournament TournamentOf (r:1 w:0)
+	/// Proof: Tournament TournamentOf (max_values: None, max_size: Some(150), added: 2625, mode: MaxEncodedLen)
+	/// Storage: Tournament PlayersOf (r:1 w:1)
+	/// Proof: Tournament PlayersOf (max_values: None, max_size: Some(3221), added: 5696, mode: MaxEncodedLen)
+	/// Storage: Game ItemBalanceOf (r:2 w:2)
+	/// Proof: Game ItemBalanceOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game UseRightOf (r:1 w:0)
+	/// Proof: Game UseRightOf (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Game SoulboundOf (r:1 w:0)
+	/// Proof: Game SoulboundOf (max_values: None, max_size: Some(41), added: 2516, mode: MaxEncodedLen)
+	fn register() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3592`
+		//  Estimated: `6686`
+		// Minimum execution time: 41_000_000 picoseconds.
+		Weight::from_parts(43_000_000, 6686)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Tournament TournamentOf (r:1 w:1)
+	/// Proof: Tournament TournamentOf (max_values: None, max_size: Some(150), added: 2625, mode: MaxEncodedLen)
+	/// Storage: Tournament PlayersOf (r:1 w:1)
+	/// Proof: Tournament PlayersOf (max_values: None, max_size: Some(3221), added: 5696, mode: MaxEncodedLen)
+	/// Storage: System Account (r:101 w:101)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `p` is `[1, 100]`.
+	fn submit_results(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `389 + p * (167 ±0)`
+		//  Estimated: `6686 + p * (2603 ±0)`
+		// Minimum execution time: 42_000_000 picoseconds.
+		Weight::from_parts(29_537_112, 6686)
+			// Standard Error: 19_826
+			.saturating_add(Weight::from_parts(16_104_375, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(p.into()))
+	}
+	/// Storage: Tournament TournamentOf (r:1 w:1)
+	/// Proof: Tournament TournamentOf (max_values: None, max_size: Some(150), added: 2625, mode: MaxEncodedLen)
+	/// Storage: Game Game (r:1 w:0)
+	/// Proof: Game Game (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: Tournament PlayersOf (r:1 w:1)
+	/// Proof: Tournament PlayersOf (max_values: None, max_size: Some(3221), added: 5696, mode: MaxEncodedLen)
+	/// Storage: System Account (r:101 w:101)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `p` is `[1, 100]`.
+	fn cancel_tournament(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `421 + p * (167 ±0)`
+		//  Estimated: `6686 + p * (2603 ±0)`
+		// Minimum execution time: 36_000_000 picoseconds.
+		Weight::from_parts(25_113_640, 6686)
+			// Standard Error: 15_203
+			.saturating_add(Weight::from_parts(11_382_905, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(p.into()))
+	}
+}
@@ -6,7 +6,7 @@
 #[cfg(feature = "std")]
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
-use frame_support::{traits::AsEnsureOriginWithArg};
+use frame_support::{traits::AsEnsureOriginWithArg, PalletId};
 use pallet_grandpa::AuthorityId as GrandpaId;
 use pallet_nfts::{weights::SubstrateWeight as NftsWeight, PalletFeatures};
 use polkadot_runtime_common::SlowAdjustingFeeUpdate;
@@ -47,6 +47,7 @@ use oracle_randomness::SubstrateWeight as OracleRandomnessWeight;
 pub use pallet_balances::Call as BalancesCall;
 use pallet_game::SubstrateWeight as PalletGameWeight;
 pub use pallet_timestamp::Call as TimestampCall;
+use pallet_tournament::SubstrateWeight as PalletTournamentWeight;
 use pallet_transaction_payment::{CurrencyAdapter, Multiplier};
 #[cfg(any(feature = "std", test))]
 pub use sp_runtime::BuildStorage;
@@ -116,10 +117,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	spec_version: 101,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -408,6 +409,38 @@ impl pallet_nfts::Config for Runtime {
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub AssetDeposit: u128 = 10 * unit(GAFI);
+	pub AssetAccountDeposit: u128 = unit(GAFI) / 100;
+	pub AssetsStringLimit: u32 = 50;
+	pub MetadataDepositBase: u128 = unit(GAFI);
+	pub MetadataDepositPerByte: u128 = unit(GAFI) / 100;
+	pub ApprovalDeposit: u128 = unit(GAFI) / 100;
+}
+
+impl pallet_assets::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type AssetId = u32;
+	type AssetIdParameter = u32;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<AccountId>>;
+	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+	type AssetDeposit = AssetDeposit;
+	type AssetAccountDeposit = AssetAccountDeposit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type ApprovalDeposit = ApprovalDeposit;
+	type StringLimit = AssetsStringLimit;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
+	type RemoveItemsLimit = ConstU32<1000>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
 parameter_types! {
 	pub GameDeposit: u128 = 3 * unit(GAFI);
 	pub UpgradeDeposit: u128 = 1 * unit(GAFI);
@@ -423,6 +456,15 @@ parameter_types! {
 
 	pub MaxMintRequest: u32 = 10;
 	pub MintInterval: u32 = 2;
+	pub RemoveRolesLimit: u32 = 1000;
+	pub MaxCandleBid: u32 = 100;
+	pub MaxSealedBid: u32 = 100;
+	pub MaxRoyalty: u16 = 1000;
+	pub MaxTradeFee: u16 = 1000;
+	pub MaxBookOrders: u32 = 100;
+	pub MaxExpiringTrades: u32 = 100;
+	pub MaxMatchPlayers: u32 = 16;
+	pub PalletGameId: PalletId = PalletId(*b"gamegame");
 }
 
 impl pallet_game::Config for Runtime {
@@ -430,9 +472,14 @@ impl pallet_game::Config for Runtime {
 	type WeightInfo = PalletGameWeight<Runtime>;
 	type NftsWeightInfo = NftsWeight<Runtime>;
 	type Currency = Balances;
+	type AssetId = u32;
+	type Assets = Assets;
+	type PalletId = PalletGameId;
 	type Nfts = Nfts;
 	type GameId = u32;
 	type PoolId = u32;
+	type RecipeId = u32;
+	type MatchId = u32;
 	type MiningPoolDeposit = MiningPoolDeposit;
 	type GameDeposit = GameDeposit;
 	type MaxGameCollection = MaxGameCollection;
@@ -446,7 +493,15 @@ impl pallet_game::Config for Runtime {
 	type MaxLoot = MaxLoot;
 	type MaxMintRequest = MaxMintRequest;
 	type MintInterval = MintInterval;
+	type RemoveRolesLimit = RemoveRolesLimit;
 	type GameRandomness = OracleRandomness;
+	type MaxCandleBid = MaxCandleBid;
+	type MaxSealedBid = MaxSealedBid;
+	type MaxRoyalty = MaxRoyalty;
+	type MaxTradeFee = MaxTradeFee;
+	type MaxBookOrders = MaxBookOrders;
+	type MaxExpiringTrades = MaxExpiringTrades;
+	type MaxMatchPlayers = MaxMatchPlayers;
 }
 
 parameter_types! {
@@ -469,6 +524,24 @@ impl oracle_randomness::Config for Runtime {
 	type UnsignedInterval = OracleRandomnessUnsignedInterval;
 }
 
+parameter_types! {
+	pub TournamentDeposit: u128 = 2 * unit(GAFI);
+	pub MaxTournamentPlayers: u32 = 100;
+	pub MaxTournamentPrizes: u32 = 10;
+	pub PalletTournamentId: PalletId = PalletId(*b"gametour");
+}
+
+impl pallet_tournament::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = PalletTournamentWeight<Runtime>;
+	type Currency = Balances;
+	type PalletId = PalletTournamentId;
+	type TournamentId = u32;
+	type TournamentDeposit = TournamentDeposit;
+	type MaxPlayers = MaxTournamentPlayers;
+	type MaxPrizes = MaxTournamentPrizes;
+}
+
 // Create the runtime by composing the FRAME pallets that were previously configured.
 construct_runtime!(
 	pub struct Runtime {
@@ -482,12 +555,14 @@ construct_runtime!(
 
 		RandomnessCollectiveFlip: pallet_insecure_randomness_collective_flip::{Pallet, Storage},
 		Nfts: pallet_nfts::{Pallet, Event<T>, Storage},
+		Assets: pallet_assets,
 
 		TemplateModule: pallet_template,
 		Faucet: pallet_faucet,
 		PalletCache: pallet_cache,
 		Game: pallet_game,
 		OracleRandomness: oracle_randomness,
+		Tournament: pallet_tournament,
 	}
 );
 
@@ -514,6 +589,8 @@ pub type UncheckedExtrinsic =
 	generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
+/// Migrations to apply on runtime upgrade.
+pub type Migrations = (pallet_game::migration::v1::MigrateToV1<Runtime>,);
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -521,6 +598,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
 impl_runtime_apis! {
@@ -691,6 +769,31 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_game_rpc_runtime_api::GameRuntimeApi<Block, AccountId, u32, u32, u32, u32, Balance, BlockNumber>
+		for Runtime
+	{
+		fn inventory(who: AccountId) -> Vec<gafi_support::game::ItemBalance<u32, u32>> {
+			Game::inventory_of(&who)
+		}
+
+		fn active_trades(
+			collection: u32,
+			item: u32,
+		) -> Vec<gafi_support::game::TradeDetails<u32, AccountId, Balance, u32, u32, BlockNumber>> {
+			Game::active_trades(&collection, &item)
+		}
+
+		fn pool_details(
+			pool: u32,
+		) -> Option<gafi_support::game::PoolInfo<AccountId, Balance, BlockNumber, u32, u32>> {
+			Game::pool_info(&pool)
+		}
+
+		fn upgrade_path(collection: u32, item: u32) -> Vec<gafi_support::game::UpgradeLevel<u32, u32, Balance>> {
+			Game::upgrade_path(&collection, &item)
+		}
+	}
+
 	#[cfg(feature = "try-runtime")]
 	impl frame_try_runtime::TryRuntime<Block> for Runtime {
 		fn on_runtime_upgrade(checks: frame_try_runtime::UpgradeCheckSelect) -> (Weight, Weight) {
@@ -1,9 +1,13 @@
-use super::{Bundle, LootTable, MintSettings, Package, TradeType};
+use super::{
+	Bundle, GameRole, LootTable, MatchResult, MintProof, MintSettings, Package, SalvageOutput,
+	TradeType, UpgradeFailure,
+};
 use frame_support::pallet_prelude::DispatchResult;
 use sp_runtime::BoundedVec;
 
 pub type Amount = u32;
 pub type Level = u32;
+pub type Tier = u8;
 
 pub trait GameRandomness {
 	/// Generates a random number from 1 to `total` (inclusive).
@@ -36,6 +40,139 @@ pub trait GameSetting<AccountId, GameId, StringLimit> {
 	/// - `who`: signer and game owner
 	/// - `admin`: admin
 	fn do_create_game(game: &GameId, who: &AccountId, admin: &AccountId) -> DispatchResult;
+
+	/// Do set trade fee
+	///
+	/// Set the fee taken from each trade settlement of items in the game.
+	///
+	/// Parameters:
+	/// - `who`: game owner
+	/// - `game`: game id
+	/// - `fee`: fee in basis points of the price
+	/// - `fee_account`: maybe an account receiving the fees, the game owner if `None`
+	fn do_set_trade_fee(
+		who: &AccountId,
+		game: &GameId,
+		fee: u16,
+		fee_account: Option<AccountId>,
+	) -> DispatchResult;
+}
+
+pub trait PaymentAsset<AccountId, GameId, AssetId> {
+	/// Do set payment asset
+	///
+	/// Set the fungible asset used to pay for the game upgrades, crafting fees and match stakes.
+	///
+	/// Parameters:
+	/// - `who`: game owner
+	/// - `game`: game id
+	/// - `maybe_asset`: maybe an asset id, the native currency if `None`
+	fn do_set_payment_asset(
+		who: &AccountId,
+		game: &GameId,
+		maybe_asset: Option<AssetId>,
+	) -> DispatchResult;
+}
+
+pub trait GameOwnership<AccountId, GameId> {
+	/// Do set accept game ownership
+	///
+	/// Accept to become the owner of the game, the transfer is completed by the current owner.
+	///
+	/// Parameters:
+	/// - `who`: new owner
+	/// - `maybe_game`: maybe a game id, clear the acceptance if `None`
+	fn do_set_accept_game_ownership(who: &AccountId, maybe_game: Option<GameId>) -> DispatchResult;
+
+	/// Do transfer game ownership
+	///
+	/// Move the game, its deposit and the game collections of the owner to the new owner who
+	/// accepted it.
+	///
+	/// Parameters:
+	/// - `who`: game owner
+	/// - `game`: game id
+	/// - `new_owner`: new owner
+	fn do_transfer_game_ownership(
+		who: &AccountId,
+		game: &GameId,
+		new_owner: &AccountId,
+	) -> DispatchResult;
+
+	/// Do set game admin
+	///
+	/// Replace the admin of the game, the roles of the previous admin are moved to the new one,
+	/// including the team of the game collections of the owner.
+	///
+	/// Parameters:
+	/// - `who`: game owner
+	/// - `game`: game id
+	/// - `admin`: new admin
+	fn do_set_game_admin(who: &AccountId, game: &GameId, admin: &AccountId) -> DispatchResult;
+}
+
+pub trait GameRoles<AccountId, GameId> {
+	/// Do grant role
+	///
+	/// Grant a game-scoped role to an account.
+	///
+	/// Parameters:
+	/// - `who`: game owner
+	/// - `game`: game id
+	/// - `account`: account receiving the role
+	/// - `role`: role granted
+	fn do_grant_role(
+		who: &AccountId,
+		game: &GameId,
+		account: &AccountId,
+		role: GameRole,
+	) -> DispatchResult;
+
+	/// Do revoke role
+	///
+	/// Revoke a game-scoped role from an account.
+	///
+	/// Parameters:
+	/// - `who`: game owner
+	/// - `game`: game id
+	/// - `account`: account losing the role
+	/// - `role`: role revoked
+	fn do_revoke_role(
+		who: &AccountId,
+		game: &GameId,
+		account: &AccountId,
+		role: GameRole,
+	) -> DispatchResult;
+}
+
+pub trait GameTreasury<AccountId, GameId, AssetId, Balance> {
+	/// Do set treasury routing
+	///
+	/// Route the mint, trade fee and crafting revenue of the game to its treasury account.
+	///
+	/// Parameters:
+	/// - `who`: game owner
+	/// - `game`: game id
+	/// - `enabled`: whether the revenue is paid to the treasury instead of the owner
+	fn do_set_treasury_routing(who: &AccountId, game: &GameId, enabled: bool) -> DispatchResult;
+
+	/// Do spend treasury
+	///
+	/// Pay from the treasury account of the game.
+	///
+	/// Parameters:
+	/// - `who`: game owner
+	/// - `game`: game id
+	/// - `maybe_asset`: maybe an asset id, the native currency if `None`
+	/// - `dest`: receiver
+	/// - `amount`: amount paid
+	fn do_spend_treasury(
+		who: &AccountId,
+		game: &GameId,
+		maybe_asset: Option<AssetId>,
+		dest: &AccountId,
+		amount: Balance,
+	) -> DispatchResult;
 }
 
 pub trait MutateCollection<AccountId, GameId, CollectionId, CollectionConfig, Fee> {
@@ -140,7 +277,17 @@ pub trait CreateItem<AccountId, CollectionId, ItemId, ItemConfig> {
 }
 
 ///Trait to provide an interface for NFTs minting
-pub trait Mining<AccountId, Price, CollectionId, ItemId, PoolId, BlockNumber, StringLimit> {
+pub trait Mining<
+	AccountId,
+	Price,
+	CollectionId,
+	ItemId,
+	PoolId,
+	BlockNumber,
+	StringLimit,
+	Signature,
+>
+{
 	/// Sets the metadata for a specific pool.
 	///
 	/// # Arguments
@@ -188,7 +335,7 @@ pub trait Mining<AccountId, Price, CollectionId, ItemId, PoolId, BlockNumber, St
 		who: &AccountId,
 		loot_table: LootTable<CollectionId, ItemId>,
 		admin: &AccountId,
-		mint_settings: MintSettings<Price, BlockNumber, CollectionId>,
+		mint_settings: MintSettings<AccountId, Price, BlockNumber, CollectionId>,
 	) -> DispatchResult;
 
 	/// Do create dynamic pool
@@ -205,7 +352,7 @@ pub trait Mining<AccountId, Price, CollectionId, ItemId, PoolId, BlockNumber, St
 		who: &AccountId,
 		loot_table: LootTable<CollectionId, ItemId>,
 		admin: &AccountId,
-		mint_settings: MintSettings<Price, BlockNumber, CollectionId>,
+		mint_settings: MintSettings<AccountId, Price, BlockNumber, CollectionId>,
 	) -> DispatchResult;
 
 	/// Do mint dynamic pool
@@ -238,11 +385,21 @@ pub trait Mining<AccountId, Price, CollectionId, ItemId, PoolId, BlockNumber, St
 		amount: Amount,
 	) -> DispatchResult;
 
+	/// Do request mint
+	///
+	/// Request an `amount` of minting in a pool, executed after the mint interval.
+	///
+	/// - `pool`: minting pool id
+	/// - `who`: signer
+	/// - `target`:  recipient account
+	/// - `amount`: amount of item
+	/// - `maybe_proof`: proof that `who` is allowed to mint in an allowlist or voucher pool
 	fn do_request_mint(
 		pool: &PoolId,
 		who: &AccountId,
 		target: &AccountId,
 		amount: Amount,
+		maybe_proof: Option<MintProof<BlockNumber, Signature>>,
 	) -> DispatchResult;
 }
 
@@ -276,6 +433,7 @@ pub trait UpgradeItem<AccountId, Balance, CollectionId, ItemId, ItemConfig, Stri
 	/// - `data`: metadata
 	/// - `level`: upgrade level
 	/// - `fee`: upgrade fee
+	/// - `materials`: items burned per upgraded item
 	fn do_set_upgrade_item(
 		who: &AccountId,
 		collection: &CollectionId,
@@ -284,23 +442,190 @@ pub trait UpgradeItem<AccountId, Balance, CollectionId, ItemId, ItemConfig, Stri
 		config: &ItemConfig,
 		level: Level,
 		fee: Balance,
+		materials: Bundle<CollectionId, ItemId>,
 	) -> DispatchResult;
 
 	/// Do Upgrade Item
 	///
-	/// Upgrade item to the next level
+	/// Upgrade item to the next level, burning the upgrade materials
 	///
 	/// Parameters:
 	/// - `who`: who
 	/// - `collection`: collection id
 	/// - `item`: item id
-	/// - `amount`: amount of items
+	/// - `amount`: amount of items, at most `MaxMintItem`
 	fn do_upgrade_item(
 		who: &AccountId,
 		collection: &CollectionId,
 		item: &ItemId,
 		amount: Amount,
 	) -> DispatchResult;
+
+	/// Do Set Upgrade Chance
+	///
+	/// Set the success rate of an upgrade level and the outcome of failed attempts
+	///
+	/// Parameters:
+	/// - `who`: collection admin
+	/// - `collection`: collection id
+	/// - `item`: item id
+	/// - `level`: upgrade level
+	/// - `success_rate`: chance of success in basis points
+	/// - `on_failure`: outcome of failed attempts
+	fn do_set_upgrade_chance(
+		who: &AccountId,
+		collection: &CollectionId,
+		item: &ItemId,
+		level: Level,
+		success_rate: u16,
+		on_failure: UpgradeFailure,
+	) -> DispatchResult;
+}
+
+pub trait Crafting<AccountId, Balance, GameId, CollectionId, ItemId, RecipeId> {
+	/// Do Create Recipe
+	///
+	/// Register a recipe of the game burning `input` to produce `output`
+	///
+	/// Parameters:
+	/// - `recipe`: new recipe id
+	/// - `who`: admin of the output collections
+	/// - `game`: game id
+	/// - `input`: items burned by each craft
+	/// - `maybe_fee`: maybe a fee paid by each craft
+	/// - `output`: items produced by each craft
+	/// - `maybe_crafts`: maybe a limit of crafts, required by outputs with a finite supply
+	fn do_create_recipe(
+		recipe: &RecipeId,
+		who: &AccountId,
+		game: &GameId,
+		input: Bundle<CollectionId, ItemId>,
+		maybe_fee: Option<Balance>,
+		output: Bundle<CollectionId, ItemId>,
+		maybe_crafts: Option<Amount>,
+	) -> DispatchResult;
+
+	/// Do Remove Recipe
+	///
+	/// Remove a recipe
+	///
+	/// Parameters:
+	/// - `recipe`: recipe id
+	/// - `who`: recipe owner, or game owner if the game is being destroyed
+	fn do_remove_recipe(recipe: &RecipeId, who: &AccountId) -> DispatchResult;
+
+	/// Do Craft
+	///
+	/// Burn the recipe input `times` times to produce its output
+	///
+	/// Parameters:
+	/// - `who`: crafter
+	/// - `recipe`: recipe id
+	/// - `times`: number of crafts
+	fn do_craft(who: &AccountId, recipe: &RecipeId, times: Amount) -> DispatchResult;
+}
+
+pub trait Salvage<AccountId, CollectionId, ItemId> {
+	/// Do Set Salvage
+	///
+	/// Set the items received when salvaging an item
+	///
+	/// Parameters:
+	/// - `who`: collection admin
+	/// - `collection`: collection id
+	/// - `item`: item id
+	/// - `output`: a fixed bundle or a loot table
+	fn do_set_salvage(
+		who: &AccountId,
+		collection: &CollectionId,
+		item: &ItemId,
+		output: SalvageOutput<Bundle<CollectionId, ItemId>, LootTable<CollectionId, ItemId>>,
+	) -> DispatchResult;
+
+	/// Do Clear Salvage
+	///
+	/// Remove the salvage of an item
+	///
+	/// Parameters:
+	/// - `who`: salvage owner
+	/// - `collection`: collection id
+	/// - `item`: item id
+	fn do_clear_salvage(
+		who: &AccountId,
+		collection: &CollectionId,
+		item: &ItemId,
+	) -> DispatchResult;
+
+	/// Do Salvage
+	///
+	/// Burn an amount of item to receive its salvage output
+	///
+	/// Parameters:
+	/// - `who`: item owner
+	/// - `collection`: collection id
+	/// - `item`: item id
+	/// - `amount`: amount of items
+	fn do_salvage(
+		who: &AccountId,
+		collection: &CollectionId,
+		item: &ItemId,
+		amount: Amount,
+	) -> DispatchResult;
+}
+
+/// Trait for match escrows settled by game servers
+pub trait MatchEscrow<AccountId, Balance, GameId, MatchId, CollectionId, ItemId, BlockNumber> {
+	/// Do Create Match
+	///
+	/// Create a match of `game` where each player stakes `stake` when joining.
+	///
+	/// - `match_id`: match id
+	/// - `who`: game owner or admin
+	/// - `game`: game id
+	/// - `stake`: amount staked by each player
+	/// - `maybe_server`: maybe the server account allowed to submit the result
+	/// - `end_block`: the result must be submitted before `end_block`
+	fn do_create_match(
+		match_id: &MatchId,
+		who: &AccountId,
+		game: &GameId,
+		stake: Balance,
+		maybe_server: Option<AccountId>,
+		end_block: BlockNumber,
+	) -> DispatchResult;
+
+	/// Do Join Match
+	///
+	/// Join a match, reserving the stake and `items`.
+	///
+	/// - `match_id`: match id
+	/// - `who`: player
+	/// - `items`: items of the game put at stake
+	fn do_join_match(
+		match_id: &MatchId,
+		who: &AccountId,
+		items: Bundle<CollectionId, ItemId>,
+	) -> DispatchResult;
+
+	/// Do Settle Match
+	///
+	/// Pay the stakes to the winners and take the items of the losers.
+	///
+	/// - `match_id`: match id
+	/// - `who`: game owner, admin or match server
+	/// - `result`: result of the match
+	fn do_settle_match(
+		match_id: &MatchId,
+		who: &AccountId,
+		result: MatchResult<AccountId>,
+	) -> DispatchResult;
+
+	/// Do Refund Match
+	///
+	/// Refund the stakes and items once the match timed out, any account can call.
+	///
+	/// - `match_id`: match id
+	fn do_refund_match(match_id: &MatchId) -> DispatchResult;
 }
 
 pub trait TransferItem<AccountId, CollectionId, ItemId> {
@@ -323,6 +648,110 @@ pub trait TransferItem<AccountId, CollectionId, ItemId> {
 	) -> DispatchResult;
 }
 
+/// Trait for operators moving items on behalf of their owners
+pub trait Operator<AccountId, CollectionId, ItemId, BlockNumber> {
+	/// Do Approve Operator
+	///
+	/// Allow `operator` to transfer the items of `who`, in `maybe_collection` or in any
+	/// collection, until `maybe_deadline` if any.
+	///
+	/// - `who`: item owner
+	/// - `operator`: approved account
+	/// - `maybe_collection`: maybe the only collection the operator can transfer
+	/// - `maybe_deadline`: maybe the last block of the approval
+	fn do_approve_operator(
+		who: &AccountId,
+		operator: &AccountId,
+		maybe_collection: Option<CollectionId>,
+		maybe_deadline: Option<BlockNumber>,
+	) -> DispatchResult;
+
+	/// Do Revoke Operator
+	///
+	/// Remove an approval of `operator`.
+	///
+	/// - `who`: item owner
+	/// - `operator`: approved account
+	/// - `maybe_collection`: the collection of the approval
+	fn do_revoke_operator(
+		who: &AccountId,
+		operator: &AccountId,
+		maybe_collection: Option<CollectionId>,
+	) -> DispatchResult;
+
+	/// Do Transfer From
+	///
+	/// Transfer amount of item from `from` to `destination` by an approved `operator`
+	///
+	/// - `operator`: approved account
+	/// - `from`: item owner
+	/// - `collection`: collection id
+	/// - `item`: item id
+	/// - `destination`: destination account
+	/// - `amount`: amount of items
+	fn do_transfer_from(
+		operator: &AccountId,
+		from: &AccountId,
+		collection: &CollectionId,
+		item: &ItemId,
+		destination: &AccountId,
+		amount: Amount,
+	) -> DispatchResult;
+}
+
+/// Trait for account-bound items
+pub trait Soulbound<AccountId, CollectionId, ItemId> {
+	/// Do Set Soulbound
+	///
+	/// Bind `item` to its holders, soulbound items can be minted, upgraded and burned but
+	/// never transferred or traded.
+	///
+	/// - `who`: collection admin
+	/// - `collection`: collection id
+	/// - `item`: item id
+	/// - `soulbound`: whether the item is soulbound
+	fn do_set_soulbound(
+		who: &AccountId,
+		collection: &CollectionId,
+		item: &ItemId,
+		soulbound: bool,
+	) -> DispatchResult;
+}
+
+/// Trait for creator royalties
+pub trait Royalty<AccountId, CollectionId, ItemId> {
+	/// Do Set Royalty
+	///
+	/// Set a royalty paid to `beneficiary` whenever items are sold in a trade.
+	/// The royalty of an item overrides the royalty of its collection.
+	///
+	/// - `who`: collection owner
+	/// - `collection`: collection id
+	/// - `maybe_item`: maybe item id, `None` for the whole collection
+	/// - `beneficiary`: royalty beneficiary
+	/// - `rate`: royalty rate in basis points
+	fn do_set_royalty(
+		who: &AccountId,
+		collection: &CollectionId,
+		maybe_item: Option<ItemId>,
+		beneficiary: &AccountId,
+		rate: u16,
+	) -> DispatchResult;
+
+	/// Do Clear Royalty
+	///
+	/// Remove the royalty of a collection or an item.
+	///
+	/// - `who`: collection owner
+	/// - `collection`: collection id
+	/// - `maybe_item`: maybe item id, `None` for the whole collection
+	fn do_clear_royalty(
+		who: &AccountId,
+		collection: &CollectionId,
+		maybe_item: Option<ItemId>,
+	) -> DispatchResult;
+}
+
 pub trait Trade<AccountId, TradeId> {
 	/// Do Cancel Trade
 	///
@@ -474,6 +903,47 @@ pub trait Wholesale<AccountId, CollectionId, ItemId, TradeId, Price, BlockNumber
 	fn do_cancel_bundle(trade: &TradeId, who: &AccountId) -> DispatchResult;
 }
 
+/// Trait for order book matching between retail listings and purchases
+pub trait OrderBook<AccountId, CollectionId, ItemId, TradeId, Price> {
+	/// Do Set Book Price
+	///
+	/// Set item price for selling in the order book.
+	/// The listing is matched against purchases of the same item with the highest price first,
+	/// the rest stays in the book.
+	///
+	/// - `trade`: trade id
+	/// - `who`: seller
+	/// - `package`: items to sell
+	/// - `unit_price`: lowest price of each item
+	///
+	/// Returns the number of filled purchases and the number of closed orders scanned.
+	fn do_set_book_price(
+		trade: &TradeId,
+		who: &AccountId,
+		package: Package<CollectionId, ItemId>,
+		unit_price: Price,
+	) -> Result<(u32, u32), sp_runtime::DispatchError>;
+
+	/// Do Set Book Order
+	///
+	/// Set a purchase in the order book.
+	/// The purchase is matched against listings of the same item with the lowest price first,
+	/// the rest stays in the book.
+	///
+	/// - `trade`: trade id
+	/// - `who`: buyer
+	/// - `package`: items to buy
+	/// - `unit_price`: highest price of each item
+	///
+	/// Returns the number of filled listings and the number of closed orders scanned.
+	fn do_set_book_order(
+		trade: &TradeId,
+		who: &AccountId,
+		package: Package<CollectionId, ItemId>,
+		unit_price: Price,
+	) -> Result<(u32, u32), sp_runtime::DispatchError>;
+}
+
 /// Trait for wishlist functionality
 pub trait Wishlist<AccountId, CollectionId, ItemId, TradeId, Price, BlockNumber> {
 	/// Do Set Wishlist
@@ -591,17 +1061,226 @@ pub trait Auction<AccountId, CollectionId, ItemId, TradeId, Price, Block> {
 	/// - `trade`: auction id
 	fn do_claim_auction(trade: &TradeId) -> DispatchResult;
 
-	// fn do_set_candle_auction(
-	// 	trade: &TradeId,
-	// 	who: &AccountId,
-	// 	bundle: Bundle<CollectionId, ItemId>,
-	// 	maybe_price: Option<Price>,
-	// 	start_block: Block,
-	// 	early_end: Block,
-	// 	end_block: Block,
-	// ) -> DispatchResult;
+	/// Do Set Candle Auction
+	///
+	/// Set candle auction for a bundle may with minimum bid `maybe_price`.
+	/// Bids are accepted until `end_block`, but the auction is considered closed at a random
+	/// block between `early_end` and `end_block`. The highest bid at that block wins.
+	///
+	/// - `trade`: auction id
+	/// - `who`: who
+	/// - `bundle`: bundle for auction
+	/// - `maybe_price`: maybe minimum bid
+	/// - `start_block`: the block when auction start
+	/// - `early_end`: the block when the ending period starts
+	/// - `end_block`: the block when auction end
+	fn do_set_candle_auction(
+		trade: &TradeId,
+		who: &AccountId,
+		bundle: Bundle<CollectionId, ItemId>,
+		maybe_price: Option<Price>,
+		start_block: Block,
+		early_end: Block,
+		end_block: Block,
+	) -> DispatchResult;
+
+	/// Do Bid Candle Auction
+	///
+	/// Make a bid with price, the price must be higher than all bids before.
+	/// The bid is recorded with the current block.
+	///
+	/// - `trade`: auction id
+	/// - `who`: who
+	/// - `price`: price
+	fn do_bid_candle_auction(trade: &TradeId, who: &AccountId, price: Price) -> DispatchResult;
+
+	/// Do Claim Candle Auction
+	///
+	/// Trigger end candle auction, any account can call.
+	/// The closing block is chosen randomly in the ending period.
+	///
+	/// - `trade`: auction id
+	fn do_claim_candle_auction(trade: &TradeId) -> DispatchResult;
+
+	/// Do Set Dutch Auction
+	///
+	/// Set dutch auction for a bundle, the price starts at `start_price` and decreases linearly
+	/// every block until it reaches `floor_price` after `duration` blocks.
+	///
+	/// - `trade`: auction id
+	/// - `who`: who
+	/// - `bundle`: bundle for auction
+	/// - `start_price`: the price when auction start
+	/// - `floor_price`: the lowest price
+	/// - `start_block`: maybe the block when auction start
+	/// - `duration`: number of blocks for the price to reach `floor_price`
+	fn do_set_dutch_auction(
+		trade: &TradeId,
+		who: &AccountId,
+		bundle: Bundle<CollectionId, ItemId>,
+		start_price: Price,
+		floor_price: Price,
+		start_block: Option<Block>,
+		duration: Block,
+	) -> DispatchResult;
+
+	/// Do Buy Dutch Auction
+	///
+	/// The first buyer gets the bundle at the current price.
+	/// The current price must be no greater than `bid_price`.
+	///
+	/// - `trade`: auction id
+	/// - `who`: buyer
+	/// - `bid_price`: the highest price the buyer accepts
+	fn do_buy_dutch_auction(trade: &TradeId, who: &AccountId, bid_price: Price) -> DispatchResult;
+
+	/// Do Cancel Dutch Auction
+	///
+	/// Cancel the dutch auction, unlock items, and unreserve the deposit.
+	///
+	/// - `trade`: auction id
+	/// - `who`: owner
+	fn do_cancel_dutch_auction(trade: &TradeId, who: &AccountId) -> DispatchResult;
 }
 
-pub trait Destroy<E> {
-	fn destroy() -> Result<(), E>;
+/// Trait for sealed-bid auction functionality
+pub trait SealedAuction<AccountId, CollectionId, ItemId, TradeId, Price, Block, Hash> {
+	/// Do Set Sealed Auction
+	///
+	/// Set sealed-bid auction for a bundle may with minimum bid `maybe_price`.
+	/// Bids are committed until `reveal_block` and revealed until `end_block`.
+	///
+	/// - `trade`: auction id
+	/// - `who`: who
+	/// - `bundle`: bundle for auction
+	/// - `maybe_price`: maybe minimum bid
+	/// - `start_block`: maybe the block when auction start
+	/// - `reveal_block`: the block when the reveal period starts
+	/// - `end_block`: the block when auction end
+	fn do_set_sealed_auction(
+		trade: &TradeId,
+		who: &AccountId,
+		bundle: Bundle<CollectionId, ItemId>,
+		maybe_price: Option<Price>,
+		start_block: Option<Block>,
+		reveal_block: Block,
+		end_block: Block,
+	) -> DispatchResult;
+
+	/// Do Commit Sealed Bid
+	///
+	/// Commit the hash of a bid, `deposit` is reserved and must cover the bid.
+	///
+	/// - `trade`: auction id
+	/// - `who`: bidder
+	/// - `commitment`: hash of the bidder, the bid and the salt
+	/// - `deposit`: deposit
+	fn do_commit_sealed_bid(
+		trade: &TradeId,
+		who: &AccountId,
+		commitment: Hash,
+		deposit: Price,
+	) -> DispatchResult;
+
+	/// Do Reveal Sealed Bid
+	///
+	/// Reveal a committed bid in the reveal period.
+	///
+	/// - `trade`: auction id
+	/// - `who`: bidder
+	/// - `bid`: the committed bid
+	/// - `salt`: the committed salt
+	fn do_reveal_sealed_bid(
+		trade: &TradeId,
+		who: &AccountId,
+		bid: Price,
+		salt: [u8; 32],
+	) -> DispatchResult;
+
+	/// Do Claim Sealed Auction
+	///
+	/// Trigger end sealed auction, any account can call.
+	/// The highest revealed bid wins and pays the second-highest price,
+	/// deposits of unrevealed bids are slashed to the seller.
+	///
+	/// - `trade`: auction id
+	fn do_claim_sealed_auction(trade: &TradeId) -> DispatchResult;
+}
+
+/// Trait for item rentals
+pub trait Rental<AccountId, CollectionId, ItemId, TradeId, Price, BlockNumber> {
+	/// Do Set Rental
+	///
+	/// List a bundle for rent at `price_per_block`, the items stay reserved by the owner.
+	///
+	/// - `trade`: rental id
+	/// - `who`: owner
+	/// - `bundle`: bundle for rent
+	/// - `price_per_block`: price paid by the renter for each rented block
+	/// - `max_duration`: the longest rental term
+	fn do_set_rental(
+		trade: &TradeId,
+		who: &AccountId,
+		bundle: Bundle<CollectionId, ItemId>,
+		price_per_block: Price,
+		max_duration: BlockNumber,
+	) -> DispatchResult;
+
+	/// Do Rent
+	///
+	/// Rent the bundle for `duration` blocks, the renter gains the use right of the items
+	/// until the term ends.
+	///
+	/// - `trade`: rental id
+	/// - `who`: renter
+	/// - `duration`: number of rented blocks
+	fn do_rent(trade: &TradeId, who: &AccountId, duration: BlockNumber) -> DispatchResult;
+
+	/// Do Return Rental
+	///
+	/// Return the items once the term ended, any account can call.
+	/// The bundle stays listed for rent.
+	///
+	/// - `trade`: rental id
+	fn do_return_rental(trade: &TradeId) -> DispatchResult;
+
+	/// Do Cancel Rental
+	///
+	/// Cancel a rental which is not rented, unlock items, and unreserve the deposit.
+	///
+	/// - `trade`: rental id
+	/// - `who`: owner
+	fn do_cancel_rental(trade: &TradeId, who: &AccountId) -> DispatchResult;
+}
+
+pub trait Destroy<AccountId, GameId, DestroyWitness> {
+	/// Do Start Game Destroy
+	///
+	/// Mark the game as being destroyed, no collection can be added to it afterwards.
+	///
+	/// - `who`: game owner
+	/// - `game`: game id
+	fn do_start_game_destroy(who: &AccountId, game: &GameId) -> DispatchResult;
+
+	/// Do Destroy Game Roles
+	///
+	/// Remove up to `max_roles` roles and pending acceptances of a game being destroyed, returning
+	/// the deposits of the acceptances.
+	/// Returns the number of roles and acceptances removed.
+	///
+	/// - `game`: game id
+	/// - `max_roles`: maximum number of roles to remove
+	fn do_destroy_game_roles(
+		game: &GameId,
+		max_roles: u32,
+	) -> Result<u32, sp_runtime::DispatchError>;
+
+	/// Do Finish Game Destroy
+	///
+	/// Remove the collections, metadata and ownership of a game being destroyed
+	/// and release the game deposit and the treasury balance to the owner.
+	///
+	/// - `game`: game id
+	/// - `witness`: information on the collections of the game
+	fn do_finish_game_destroy(game: &GameId, witness: &DestroyWitness) -> DispatchResult;
 }
@@ -1,17 +1,21 @@
 use codec::{Decode, Encode};
 use core::primitive::u32;
+#[cfg(feature = "std")]
+use frame_support::serde::{Deserialize, Serialize};
 use frame_support::{pallet_prelude::MaxEncodedLen, RuntimeDebug};
 use scale_info::TypeInfo;
+use sp_core::H256;
 use sp_std::vec::Vec;
 
 use sp_runtime::traits::Printable;
 use sp_std::fmt::{Debug, Formatter};
 
-use super::Amount;
+use super::{Amount, Level, Tier};
 
 pub type Bundle<CollectionId, ItemId> = Vec<Package<CollectionId, ItemId>>;
 pub type LootTable<CollectionId, ItemId> = Vec<Loot<CollectionId, ItemId>>;
 
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default, TypeInfo, MaxEncodedLen)]
 pub struct Package<CollectionId, ItemId> {
 	pub collection: CollectionId,
@@ -29,6 +33,7 @@ impl<CollectionId, ItemId> Package<CollectionId, ItemId> {
 	}
 }
 
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub enum TradeType {
 	SetPrice,
@@ -37,9 +42,30 @@ pub enum TradeType {
 	Wishlist,
 	Auction,
 	Swap,
+	CandleAuction,
+	DutchAuction,
+	SealedAuction,
+	Rental,
+}
+
+/// Game-scoped roles granted by the game owner
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum GameRole {
+	/// Create items and add supply to the game collections.
+	Minter,
+	/// Create minting pools and manage their metadata.
+	PoolManager,
+	/// Set upgrades, recipes and salvages of the game items.
+	UpgradeDesigner,
+	/// Manage the game metadata, soulbound items and matches.
+	Moderator,
+	/// Adjust the trade fee and royalty rates, the accounts receiving them stay with the owner.
+	TradeOperator,
 }
 
 /// Types of the minting pool
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub enum PoolType {
 	/// Item minting chance will change depending on item supply.
@@ -48,38 +74,204 @@ pub enum PoolType {
 	Stable,
 }
 
+/// Outcome of a failed upgrade attempt
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum UpgradeFailure {
+	/// The items are kept at their level.
+	Keep,
+	/// The items drop one level.
+	Downgrade,
+	/// The items are burned.
+	Destroy,
+}
+
+/// Result of a match submitted by the game admin or the match server
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct MatchResult<AccountId> {
+	/// Players sharing the stakes in basis points, the stakes are refunded if empty.
+	pub winners: Vec<(AccountId, u16)>,
+	/// Players losing their items, which are given to another player or burned.
+	pub losers: Vec<(AccountId, Option<AccountId>)>,
+}
+
+/// Items received for each salvaged item
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum SalvageOutput<Bundle, LootTable> {
+	/// The same bundle every time.
+	Fixed(Bundle),
+	/// A loot rolled from the table.
+	Random(LootTable),
+}
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default, TypeInfo, MaxEncodedLen)]
 pub struct NFT<CollectionId, ItemId> {
 	pub collection: CollectionId,
 	pub item: ItemId,
 }
 
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default, TypeInfo, MaxEncodedLen)]
 pub struct Loot<CollectionId, ItemId> {
 	/// Each loot can be an nft or nothing
 	pub maybe_nft: Option<NFT<CollectionId, ItemId>>,
 	pub weight: u32,
+	/// Rarity tier of the loot, used by the pity of minting pools
+	pub tier: Option<Tier>,
 }
 
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-pub enum MintType<CollectionId> {
+pub enum MintType<AccountId, CollectionId> {
 	/// Anyone could mint items.
 	Public,
 	/// Only holders of items in specified collection could mint new items.
 	HolderOf(CollectionId),
+	/// Only accounts proven to be in the allowlist with the specified merkle root could mint
+	/// new items.
+	MerkleAllowlist(H256),
+	/// Only accounts holding a voucher signed by the specified account could mint new items.
+	SignedVoucher(AccountId),
 }
 
 /// Holds the information about minting.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-pub struct MintSettings<Price, BlockNumber, CollectionId> {
+pub struct MintSettings<AccountId, Price, BlockNumber, CollectionId> {
 	/// Whether anyone can mint or if minters are restricted to some subset.
-	pub mint_type: MintType<CollectionId>,
+	pub mint_type: MintType<AccountId, CollectionId>,
 	/// An price per mint.
 	pub price: Price,
 	/// When the mint starts.
 	pub start_block: Option<BlockNumber>,
 	/// When the mint ends.
 	pub end_block: Option<BlockNumber>,
+	/// Maximum items an account can mint over the pool lifetime.
+	pub max_per_account: Option<Amount>,
+	/// Maximum items an account can mint within a window of blocks.
+	pub max_per_window: Option<MintWindow<BlockNumber>>,
+	/// Blocks an account must wait between two mint requests.
+	pub cooldown: Option<BlockNumber>,
+	/// Loot guaranteed to an account after some mints without it.
+	pub pity: Option<Pity>,
+}
+
+/// Guarantees a loot of `tier` to an account after `threshold` mints without one.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Pity {
+	/// Rarity tier of the guaranteed loot.
+	pub tier: Tier,
+	/// Mints without a loot of `tier` before the next mint guarantees one.
+	pub threshold: Amount,
+}
+
+/// Maximum items an account can mint within each window of `length` blocks.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct MintWindow<BlockNumber> {
+	/// Length of the window in blocks.
+	pub length: BlockNumber,
+	/// Maximum items minted within the window.
+	pub max_mints: Amount,
+}
+
+/// Proof that an account is allowed to mint in a restricted pool.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum MintProof<BlockNumber, Signature> {
+	/// Merkle proof of the `(account, max_amount)` leaf in the pool allowlist.
+	Allowlist {
+		max_amount: Amount,
+		proof: Vec<H256>,
+	},
+	/// Voucher signed by the pool signer.
+	Voucher {
+		max_amount: Amount,
+		nonce: u32,
+		expiry: BlockNumber,
+		signature: Signature,
+	},
+}
+
+/// Mint permit signed off-chain by the signer of a pool.
+///
+/// The signed payload is `(genesis_hash, pallet_name, voucher)` SCALE encoded, so a voucher is
+/// only valid on the chain and the pallet instance it was issued for.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct MintVoucher<PoolId, AccountId, BlockNumber> {
+	pub pool: PoolId,
+	/// Account allowed to mint.
+	pub who: AccountId,
+	/// Maximum items minted with the voucher.
+	pub max_amount: Amount,
+	/// Unique number of the voucher within the pool.
+	pub nonce: u32,
+	/// Last block the voucher can be used.
+	pub expiry: BlockNumber,
+}
+
+/// Free, reserved and rented amount of an item held by an account.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct ItemBalance<CollectionId, ItemId> {
+	pub collection: CollectionId,
+	pub item: ItemId,
+	/// Amount that can be transferred or traded.
+	pub free: Amount,
+	/// Amount locked in trades or minting pools.
+	pub reserved: Amount,
+	/// Amount rented from other accounts, usable in games but not transferable.
+	pub rented: Amount,
+}
+
+/// A trade together with the items it holds.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct TradeDetails<TradeId, AccountId, Price, CollectionId, ItemId, BlockNumber> {
+	pub trade: TradeId,
+	pub trade_type: TradeType,
+	pub owner: AccountId,
+	/// Unit price, bundle price or minimum bid depending on `trade_type`.
+	pub maybe_price: Option<Price>,
+	/// Items offered or requested by the trade.
+	pub bundle: Bundle<CollectionId, ItemId>,
+	/// Items required in exchange, only set for swaps.
+	pub maybe_required: Option<Bundle<CollectionId, ItemId>>,
+	pub start_block: Option<BlockNumber>,
+	pub end_block: Option<BlockNumber>,
+}
+
+/// A minting pool together with its current loot table.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct PoolInfo<AccountId, Price, BlockNumber, CollectionId, ItemId> {
+	pub pool_type: PoolType,
+	pub owner: AccountId,
+	pub admin: AccountId,
+	pub mint_settings: MintSettings<AccountId, Price, BlockNumber, CollectionId>,
+	pub table: LootTable<CollectionId, ItemId>,
+	/// Sum of the weights in `table`.
+	pub total_weight: u32,
+}
+
+/// One step in the upgrade path of an item.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct UpgradeLevel<CollectionId, ItemId, Price> {
+	pub level: Level,
+	/// The item received when reaching `level`.
+	pub item: ItemId,
+	/// Fee paid per item to reach `level`.
+	pub fee: Price,
+	/// Items burned per item to reach `level`.
+	pub materials: Bundle<CollectionId, ItemId>,
+	/// Chance of reaching `level` in basis points.
+	pub success_rate: u16,
+	/// What happens to the items when the upgrade fails.
+	pub on_failure: UpgradeFailure,
 }
 
 /// Payload used to hold seed data required to submit a transaction.